@@ -85,6 +85,11 @@ pub struct FeatureFlags {
     #[serde(default)]
     pub enable_strict_custom_scalars: bool,
 
+    /// Report Relay Resolvers that read themselves, directly or through other
+    /// resolvers, even if `resolverDependencyGraph` is not configured
+    #[serde(default)]
+    pub enable_resolver_cycle_validation: bool,
+
     /// Relay Resolvers are a read-time feature that are not actually handled in
     /// our mutation APIs. We are in the process of removing any existing
     /// examples, but this flag is part of a process of removing any existing
@@ -13,6 +13,7 @@
 mod ast;
 mod ir;
 mod minimized_executable;
+mod resolver_graph;
 
 pub use ast::get_definition_references;
 pub use ast::get_reachable_ast;
@@ -22,3 +23,6 @@ pub use ir::ExecutableDefinitionNameMap;
 pub use ir::ExecutableDefinitionNameSet;
 pub use ir::ExecutableDefinitionNameVec;
 pub use minimized_executable::MinProgram;
+pub use resolver_graph::get_resolver_dependency_graph;
+pub use resolver_graph::ResolverDependencies;
+pub use resolver_graph::ResolverDependencyGraph;
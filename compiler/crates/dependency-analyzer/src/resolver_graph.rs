@@ -0,0 +1,356 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::Location;
+use graphql_ir::ExecutableDefinition;
+use graphql_ir::Field as IrField;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::FragmentDefinitionName;
+use graphql_ir::FragmentDefinitionNameMap;
+use graphql_ir::FragmentDefinitionNameSet;
+use graphql_ir::Selection;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use intern::Lookup;
+use relay_transforms::get_resolver_fragment_dependency_name;
+use relay_transforms::relay_resolvers::get_resolver_info;
+use rustc_hash::FxHashMap;
+use rustc_hash::FxHashSet;
+use schema::Field;
+use schema::FieldID;
+use schema::SDLSchema;
+use schema::Schema;
+use serde::Serialize;
+use thiserror::Error;
+
+/// The Relay Resolvers of a project, each with the schema fields and the
+/// resolvers its root fragment reads.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolverDependencyGraph {
+    /// Sorted by `field`.
+    pub resolvers: Vec<ResolverDependencies>,
+}
+
+/// The dependencies of a Relay Resolver. Fields are identified by their
+/// schema coordinate, e.g. `User.name`, and all lists are sorted.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolverDependencies {
+    pub field: StringKey,
+    pub root_fragment: Option<StringKey>,
+    pub live: bool,
+    /// The fields which are not resolvers read by the root fragment,
+    /// including through the fragments it spreads.
+    pub fields: Vec<StringKey>,
+    /// The resolvers read by the root fragment.
+    pub resolvers: Vec<StringKey>,
+    /// The fields read by this resolver or by any resolver it transitively
+    /// reads: a change to any of them invalidates this resolver.
+    pub invalidated_by: Vec<StringKey>,
+    /// The `@live` resolvers this resolver transitively reads.
+    pub live_dependencies: Vec<StringKey>,
+}
+
+/// Builds the dependency graph of the Relay Resolvers of `schema` from the
+/// root fragments in `definitions`, which should be all the definitions of
+/// the project, and reports the resolvers which depend on themselves.
+pub fn get_resolver_dependency_graph(
+    schema: &SDLSchema,
+    definitions: &[ExecutableDefinition],
+) -> DiagnosticsResult<ResolverDependencyGraph> {
+    let mut resolvers: Vec<ResolverNode> = schema
+        .get_fields()
+        .filter_map(|field| {
+            let info = get_resolver_info(schema, field, field.name.location)?.ok()?;
+            Some(ResolverNode {
+                field: schema_coordinate(schema, field)?,
+                root_fragment: get_resolver_fragment_dependency_name(field),
+                live: info.live,
+                fields: Default::default(),
+                resolvers: Default::default(),
+            })
+        })
+        .collect();
+    if resolvers.is_empty() {
+        return Ok(Default::default());
+    }
+    resolvers.sort_by_key(|resolver| resolver.field.lookup());
+
+    let fragments: FragmentDefinitionNameMap<&FragmentDefinition> = definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            ExecutableDefinition::Fragment(fragment) => Some((fragment.name.item, fragment)),
+            ExecutableDefinition::Operation(_) => None,
+        })
+        .collect();
+    for resolver in resolvers.iter_mut() {
+        if let Some(fragment) = resolver.root_fragment.and_then(|name| fragments.get(&name)) {
+            let mut visitor = DependencyVisitor {
+                schema,
+                fragments: &fragments,
+                visited_fragments: Default::default(),
+                resolver,
+            };
+            visitor.visited_fragments.insert(fragment.name.item);
+            visitor.visit_selections(&fragment.selections);
+        }
+    }
+
+    let errors = find_cycles(&resolvers);
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let indices: FxHashMap<StringKey, usize> = resolvers
+        .iter()
+        .enumerate()
+        .map(|(index, resolver)| (resolver.field, index))
+        .collect();
+    let mut transitive = vec![None; resolvers.len()];
+    for index in 0..resolvers.len() {
+        collect_transitive_dependencies(&resolvers, &indices, &mut transitive, index);
+    }
+
+    Ok(ResolverDependencyGraph {
+        resolvers: resolvers
+            .iter()
+            .zip(transitive)
+            .map(|(resolver, transitive)| {
+                let transitive = transitive.expect("Expected all resolvers to be visited.");
+                ResolverDependencies {
+                    field: resolver.field,
+                    root_fragment: resolver.root_fragment.map(|name| name.0),
+                    live: resolver.live,
+                    fields: sorted(resolver.fields.iter().copied()),
+                    resolvers: sorted(resolver.resolvers.iter().map(|(field, _)| *field)),
+                    invalidated_by: sorted(transitive.fields.into_iter()),
+                    live_dependencies: sorted(transitive.live_resolvers.into_iter()),
+                }
+            })
+            .collect(),
+    })
+}
+
+struct ResolverNode {
+    field: StringKey,
+    root_fragment: Option<FragmentDefinitionName>,
+    live: bool,
+    fields: FxHashSet<StringKey>,
+    /// The resolvers read by the root fragment, in the order they are first
+    /// read, with the location where they are.
+    resolvers: Vec<(StringKey, Location)>,
+}
+
+#[derive(Clone, Default)]
+struct TransitiveDependencies {
+    fields: FxHashSet<StringKey>,
+    live_resolvers: FxHashSet<StringKey>,
+}
+
+struct DependencyVisitor<'a> {
+    schema: &'a SDLSchema,
+    fragments: &'a FragmentDefinitionNameMap<&'a FragmentDefinition>,
+    visited_fragments: FragmentDefinitionNameSet,
+    resolver: &'a mut ResolverNode,
+}
+
+impl DependencyVisitor<'_> {
+    fn visit_selections(&mut self, selections: &[Selection]) {
+        for selection in selections {
+            match selection {
+                Selection::ScalarField(field) => {
+                    self.visit_field(field.definition.item, field.alias_or_name_location())
+                }
+                Selection::LinkedField(field) => {
+                    self.visit_field(field.definition.item, field.alias_or_name_location());
+                    self.visit_selections(&field.selections);
+                }
+                Selection::InlineFragment(fragment) => self.visit_selections(&fragment.selections),
+                Selection::Condition(condition) => self.visit_selections(&condition.selections),
+                Selection::FragmentSpread(spread) => {
+                    if self.visited_fragments.insert(spread.fragment.item) {
+                        if let Some(fragment) = self.fragments.get(&spread.fragment.item) {
+                            self.visit_selections(&fragment.selections);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn visit_field(&mut self, field_id: FieldID, location: Location) {
+        let field = self.schema.field(field_id);
+        if field.name.item.lookup().starts_with("__") {
+            return;
+        }
+        let coordinate = match schema_coordinate(self.schema, field) {
+            Some(coordinate) => coordinate,
+            None => return,
+        };
+        // Resolvers whose directive is malformed are not nodes of the graph, the
+        // resolvers transform reports them.
+        if get_resolver_info(self.schema, field, location)
+            .and_then(Result::ok)
+            .is_some()
+        {
+            if !self
+                .resolver
+                .resolvers
+                .iter()
+                .any(|(resolver, _)| *resolver == coordinate)
+            {
+                self.resolver.resolvers.push((coordinate, location));
+            }
+        } else {
+            self.resolver.fields.insert(coordinate);
+        }
+    }
+}
+
+fn schema_coordinate(schema: &SDLSchema, field: &Field) -> Option<StringKey> {
+    let parent_type = field.parent_type?;
+    Some(format!("{}.{}", schema.get_type_name(parent_type), field.name.item).intern())
+}
+
+fn sorted(items: impl Iterator<Item = StringKey>) -> Vec<StringKey> {
+    let mut items: Vec<StringKey> = items.collect();
+    items.sort_by_key(|item| item.lookup());
+    items
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum VisitState {
+    Visiting,
+    Done,
+}
+
+/// Reports a cycle for every resolver read by a resolver which is still
+/// being visited in a depth-first traversal of the graph.
+fn find_cycles(resolvers: &[ResolverNode]) -> Vec<Diagnostic> {
+    let nodes: FxHashMap<StringKey, &ResolverNode> = resolvers
+        .iter()
+        .map(|resolver| (resolver.field, resolver))
+        .collect();
+    let mut states = FxHashMap::default();
+    let mut stack = vec![];
+    let mut errors = vec![];
+    for resolver in resolvers {
+        if !states.contains_key(&resolver.field) {
+            visit_for_cycles(resolver, &nodes, &mut states, &mut stack, &mut errors);
+        }
+    }
+    errors
+}
+
+fn visit_for_cycles<'a>(
+    resolver: &'a ResolverNode,
+    nodes: &FxHashMap<StringKey, &'a ResolverNode>,
+    states: &mut FxHashMap<StringKey, VisitState>,
+    stack: &mut Vec<&'a ResolverNode>,
+    errors: &mut Vec<Diagnostic>,
+) {
+    states.insert(resolver.field, VisitState::Visiting);
+    stack.push(resolver);
+    for (dependency, _) in &resolver.resolvers {
+        match states.get(dependency) {
+            Some(VisitState::Visiting) => {
+                let start = stack
+                    .iter()
+                    .position(|node| node.field == *dependency)
+                    .expect("Expected a resolver being visited to be on the stack.");
+                errors.push(cycle_diagnostic(&stack[start..]));
+            }
+            Some(VisitState::Done) => {}
+            None => {
+                if let Some(node) = nodes.get(dependency) {
+                    visit_for_cycles(node, nodes, states, stack, errors);
+                }
+            }
+        }
+    }
+    stack.pop();
+    states.insert(resolver.field, VisitState::Done);
+}
+
+/// `cycle` lists resolvers which each read the next one, the last one
+/// reading the first one.
+fn cycle_diagnostic(cycle: &[&ResolverNode]) -> Diagnostic {
+    let first = cycle[0];
+    let mut path: Vec<&str> = cycle.iter().map(|node| node.field.lookup()).collect();
+    path.push(first.field.lookup());
+
+    let read_location = |index: usize| {
+        let next = cycle[(index + 1) % cycle.len()].field;
+        cycle[index]
+            .resolvers
+            .iter()
+            .find(|(field, _)| *field == next)
+            .map(|(_, location)| *location)
+            .expect("Expected consecutive resolvers of a cycle to read each other.")
+    };
+
+    let mut diagnostic = Diagnostic::error(
+        ValidationMessage::ResolverCycle {
+            field: first.field,
+            path: path.join(" → "),
+        },
+        read_location(0),
+    );
+    for index in 1..cycle.len() {
+        diagnostic = diagnostic.annotate(
+            format!(
+                "`{}` reads `{}` here",
+                cycle[index].field,
+                cycle[(index + 1) % cycle.len()].field
+            ),
+            read_location(index),
+        );
+    }
+    diagnostic
+}
+
+fn collect_transitive_dependencies(
+    resolvers: &[ResolverNode],
+    indices: &FxHashMap<StringKey, usize>,
+    transitive: &mut Vec<Option<TransitiveDependencies>>,
+    index: usize,
+) -> TransitiveDependencies {
+    if let Some(dependencies) = &transitive[index] {
+        return dependencies.clone();
+    }
+    let resolver = &resolvers[index];
+    let mut dependencies = TransitiveDependencies {
+        fields: resolver.fields.clone(),
+        live_resolvers: Default::default(),
+    };
+    for (field, _) in &resolver.resolvers {
+        let dependency_index = indices[field];
+        if resolvers[dependency_index].live {
+            dependencies.live_resolvers.insert(*field);
+        }
+        let dependency_dependencies =
+            collect_transitive_dependencies(resolvers, indices, transitive, dependency_index);
+        dependencies.fields.extend(dependency_dependencies.fields);
+        dependencies
+            .live_resolvers
+            .extend(dependency_dependencies.live_resolvers);
+    }
+    transitive[index] = Some(dependencies.clone());
+    dependencies
+}
+
+#[derive(Error, Debug, serde::Serialize)]
+#[serde(tag = "type")]
+enum ValidationMessage {
+    #[error(
+        "The Relay Resolver `{field}` depends on itself through its root fragment: {path}. Resolvers may not read themselves, directly or through other resolvers."
+    )]
+    ResolverCycle { field: StringKey, path: String },
+}
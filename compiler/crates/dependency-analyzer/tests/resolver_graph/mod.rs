@@ -0,0 +1,29 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::SourceLocationKey;
+use dependency_analyzer::get_resolver_dependency_graph;
+use fixture_tests::Fixture;
+use graphql_ir::build;
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use relay_test_schema::get_test_schema_with_extensions;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split("%extensions%").collect();
+    if let [base, extensions] = parts.as_slice() {
+        let source_location = SourceLocationKey::standalone(fixture.file_name);
+        let ast = parse_executable(base, source_location).unwrap();
+        let schema = get_test_schema_with_extensions(extensions);
+        let definitions = build(&schema, &ast.definitions).unwrap();
+        let graph = get_resolver_dependency_graph(&schema, &definitions)
+            .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
+        Ok(serde_json::to_string_pretty(&graph).unwrap())
+    } else {
+        panic!("Expected exactly one %extensions% section marker.")
+    }
+}
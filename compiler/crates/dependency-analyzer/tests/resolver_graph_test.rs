@@ -0,0 +1,41 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<159d80e9d1c43f843f6710868fe18e9c>>
+ */
+
+mod resolver_graph;
+
+use resolver_graph::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn resolver_chain() {
+    let input = include_str!("resolver_graph/fixtures/resolver-chain.graphql");
+    let expected = include_str!("resolver_graph/fixtures/resolver-chain.expected");
+    test_fixture(transform_fixture, file!(), "resolver-chain.graphql", "resolver_graph/fixtures/resolver-chain.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn resolver_cycle_invalid() {
+    let input = include_str!("resolver_graph/fixtures/resolver-cycle.invalid.graphql");
+    let expected = include_str!("resolver_graph/fixtures/resolver-cycle.invalid.expected");
+    test_fixture(transform_fixture, file!(), "resolver-cycle.invalid.graphql", "resolver_graph/fixtures/resolver-cycle.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn resolver_reads_itself_invalid() {
+    let input = include_str!("resolver_graph/fixtures/resolver-reads-itself.invalid.graphql");
+    let expected = include_str!("resolver_graph/fixtures/resolver-reads-itself.invalid.expected");
+    test_fixture(transform_fixture, file!(), "resolver-reads-itself.invalid.graphql", "resolver_graph/fixtures/resolver-reads-itself.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn resolver_reads_malformed_resolver() {
+    let input = include_str!("resolver_graph/fixtures/resolver-reads-malformed-resolver.graphql");
+    let expected = include_str!("resolver_graph/fixtures/resolver-reads-malformed-resolver.expected");
+    test_fixture(transform_fixture, file!(), "resolver-reads-malformed-resolver.graphql", "resolver_graph/fixtures/resolver-reads-malformed-resolver.expected", input, expected).await;
+}
@@ -28,6 +28,7 @@ lazy_static! {
     pub static ref RELAY_RESOLVER_WEAK_OBJECT_DIRECTIVE: DirectiveName =
         DirectiveName("__RelayWeakObject".intern());
     pub static ref LIVE_ARGUMENT_NAME: ArgumentName = ArgumentName("live".intern());
+    pub static ref BATCH_ARGUMENT_NAME: ArgumentName = ArgumentName("batch".intern());
     pub static ref IMPORT_NAME_ARGUMENT_NAME: ArgumentName = ArgumentName("import_name".intern());
     pub static ref HAS_OUTPUT_TYPE_ARGUMENT_NAME: ArgumentName =
         ArgumentName("has_output_type".intern());
@@ -42,6 +43,7 @@ lazy_static! {
     pub static ref EDGE_TO_FIELD: StringKey = "edgeTo".intern();
     pub static ref DEPRECATED_FIELD: StringKey = "deprecated".intern();
     pub static ref LIVE_FIELD: StringKey = "live".intern();
+    pub static ref BATCH_FIELD: StringKey = "batch".intern();
     // Using a longer name version for this "special" field
     // help us avoid potential collision with product code (__self, __instance can be used for something else)
     pub static ref RELAY_RESOLVER_MODEL_INSTANCE_FIELD: StringKey = "__relay_model_instance".intern();
@@ -10,6 +10,8 @@ use common::Named;
 use common::WithLocation;
 use docblock_shared::ResolverSourceHash;
 use intern::string_key::StringKey;
+
+use crate::ResolverSignature;
 #[derive(Debug, PartialEq)]
 pub struct DocblockField {
     pub field_name: WithLocation<StringKey>,
@@ -34,6 +36,8 @@ pub struct DocblockAST {
     pub location: Location,
     pub sections: Vec<DocblockSection>,
     pub source_hash: ResolverSourceHash,
+    /// The signature of the function the docblock annotates, if known.
+    pub signature: Option<ResolverSignature>,
 }
 
 impl DocblockAST {
@@ -7,6 +7,7 @@
 
 mod ast;
 mod errors;
+mod signature;
 
 use std::iter::Peekable;
 use std::str::Chars;
@@ -27,25 +28,45 @@ use intern::string_key::Intern;
 use intern::string_key::StringKey;
 use serde::Deserialize;
 use serde::Serialize;
+pub use signature::ResolverSignature;
+pub use signature::ResolverSignatureParam;
 
+/// Docblock sources are part of the compiler's saved state: changing the
+/// serialized shape requires bumping the compiler's saved state format
+/// version so that older saved state is not misread.
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct DocblockSource(TextSource);
+pub struct DocblockSource {
+    text_source: TextSource,
+    /// The signature of the function declared after the docblock, if any.
+    signature: Option<ResolverSignature>,
+}
 
 impl DocblockSource {
     pub fn new(text: impl Into<String>, line_index: usize, column_index: usize) -> Self {
-        Self(TextSource {
-            text: text.into(),
-            line_index,
-            column_index,
-        })
+        Self {
+            text_source: TextSource {
+                text: text.into(),
+                line_index,
+                column_index,
+            },
+            signature: None,
+        }
+    }
+
+    pub fn with_signature(self, signature: Option<ResolverSignature>) -> Self {
+        Self { signature, ..self }
     }
 
     pub fn text_source(&self) -> &TextSource {
-        &self.0
+        &self.text_source
     }
 
     pub fn to_text_source(self) -> TextSource {
-        self.0
+        self.text_source
+    }
+
+    pub fn signature(&self) -> Option<&ResolverSignature> {
+        self.signature.as_ref()
     }
 }
 
@@ -69,6 +90,17 @@ pub fn parse_docblock(
     DocblockParser::new(source, source_location).parse()
 }
 
+/// Parses the contents of `source`, keeping the signature of the function it
+/// annotates.
+pub fn parse_docblock_source(
+    source: &DocblockSource,
+    source_location: SourceLocationKey,
+) -> DiagnosticsResult<DocblockAST> {
+    let mut ast = parse_docblock(&source.text_source.text, source_location)?;
+    ast.signature = source.signature.clone();
+    Ok(ast)
+}
+
 /**
  * Stateful parser that tries to model parsing of docblocks using recursive
  * descent.
@@ -150,6 +182,7 @@ impl<'a> DocblockParser<'a> {
                     Span::new(start, end - 1),
                 ),
                 source_hash: self.source_hash,
+                signature: None,
             })
         } else {
             Err(self.errors)
@@ -0,0 +1,33 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The signature of the function declared after a docblock, with its types
+/// as written in Flow or TypeScript.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ResolverSignature {
+    pub function_name: String,
+    pub params: Vec<ResolverSignatureParam>,
+    /// `None` if the return type is not annotated.
+    pub return_type: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ResolverSignatureParam {
+    /// The name of the parameter, or its pattern if it is destructured.
+    pub name: String,
+    /// `None` if the parameter's type is not annotated.
+    pub type_: Option<String>,
+}
+
+impl ResolverSignature {
+    pub fn param_named(&self, name: &str) -> Option<&ResolverSignatureParam> {
+        self.params.iter().find(|param| param.name == name)
+    }
+}
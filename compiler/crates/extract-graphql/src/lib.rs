@@ -9,12 +9,15 @@
 #![deny(rust_2018_idioms)]
 #![deny(clippy::all)]
 
+mod signature;
+
 use std::iter::Peekable;
 use std::str::CharIndices;
 
 use common::TextSource;
 use docblock_syntax::DocblockSource;
 use graphql_syntax::GraphQLSource;
+use signature::extract_signature;
 
 #[derive(Clone)]
 pub enum JavaScriptSourceFeature {
@@ -85,11 +88,14 @@ impl<'a> Iterator for CharReader<'a> {
     }
 }
 
-/// Extract graphql`text` literals and @RelayResolver comments from JS-like code.
+/// Extract graphql`text` literals and @RelayResolver comments, with the
+/// signature of the function they annotate, from JS-like code, and
+/// %relay(`text`) extension points from ReScript code.
 // This should work for Flow or TypeScript alike.
 pub fn extract(input: &str) -> Vec<JavaScriptSourceFeature> {
     let mut res = Vec::new();
-    if !input.contains("graphql") && !input.contains("@RelayResolver") {
+    if !input.contains("graphql") && !input.contains("@RelayResolver") && !input.contains("%relay")
+    {
         return res;
     }
     let mut it = CharReader::new(input);
@@ -153,6 +159,33 @@ pub fn extract(input: &str) -> Vec<JavaScriptSourceFeature> {
                     }
                 }
             }
+            '%' => {
+                for expected in ['r', 'e', 'l', 'a', 'y', '('] {
+                    match it.next() {
+                        Some((_, c)) if c == expected => {}
+                        _ => continue 'code,
+                    }
+                }
+                let start = loop {
+                    match it.next() {
+                        Some((i, '`')) => break i + 1,
+                        Some((_, ' ' | '\n' | '\r' | '\t')) => {}
+                        _ => continue 'code,
+                    }
+                };
+                let line_index = it.line_index;
+                let column_index = it.column_index;
+                for (i, c) in &mut it {
+                    if c == '`' {
+                        res.push(JavaScriptSourceFeature::GraphQL(GraphQLSource::new(
+                            &input[start..i],
+                            line_index,
+                            column_index,
+                        )));
+                        continue 'code;
+                    }
+                }
+            }
             'a'..='z' | 'A'..='Z' | '_' => {
                 consume_identifier(&mut it);
             }
@@ -196,7 +229,8 @@ pub fn extract(input: &str) -> Vec<JavaScriptSourceFeature> {
                                 let text = &input[start + 2..end - 1];
                                 if text.contains("@RelayResolver") {
                                     res.push(JavaScriptSourceFeature::Docblock(
-                                        DocblockSource::new(text, line_index, column_index),
+                                        DocblockSource::new(text, line_index, column_index)
+                                            .with_signature(extract_signature(&input[end + 1..])),
                                     ));
                                 }
                                 continue 'code;
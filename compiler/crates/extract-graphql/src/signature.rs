@@ -0,0 +1,212 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use docblock_syntax::ResolverSignature;
+use docblock_syntax::ResolverSignatureParam;
+
+/// Reads the signature of the function declared at the start of `input`:
+/// either a function declaration, e.g. `export function name(key: T$key): R {`,
+/// or an arrow function assigned to a constant, e.g.
+/// `export const name = (key: T$key): R =>`.
+///
+/// This is not a JavaScript parser: it only splits the parameters and the
+/// return type at the brackets, which should work for Flow or TypeScript alike.
+pub(crate) fn extract_signature(input: &str) -> Option<ResolverSignature> {
+    let mut rest = input.trim_start();
+    for keyword in ["export", "default", "async"] {
+        rest = strip_keyword(rest, keyword).unwrap_or(rest);
+    }
+    if let Some(after_function) = strip_keyword(rest, "function") {
+        let (function_name, after_name) = read_identifier(after_function)?;
+        let (params, after_params) = read_params(skip_type_params(after_name))?;
+        Some(ResolverSignature {
+            function_name,
+            params,
+            return_type: read_return_type(after_params, ReturnTypeEnd::Body),
+        })
+    } else if let Some(after_const) = strip_keyword(rest, "const") {
+        let (function_name, after_name) = read_identifier(after_const)?;
+        let after_equal = after_name.trim_start().strip_prefix('=')?.trim_start();
+        let after_async = strip_keyword(after_equal, "async").unwrap_or(after_equal);
+        let (params, after_params) = read_params(skip_type_params(after_async))?;
+        Some(ResolverSignature {
+            function_name,
+            params,
+            return_type: read_return_type(after_params, ReturnTypeEnd::Arrow),
+        })
+    } else {
+        None
+    }
+}
+
+fn strip_keyword<'a>(input: &'a str, keyword: &str) -> Option<&'a str> {
+    let rest = input.strip_prefix(keyword)?;
+    if rest.starts_with(char::is_whitespace) {
+        Some(rest.trim_start())
+    } else {
+        None
+    }
+}
+
+fn read_identifier(input: &str) -> Option<(String, &str)> {
+    let input = input.trim_start();
+    let end = input
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .unwrap_or(input.len());
+    if end == 0 {
+        None
+    } else {
+        Some((input[..end].to_string(), &input[end..]))
+    }
+}
+
+fn skip_type_params(input: &str) -> &str {
+    let input = input.trim_start();
+    if input.starts_with('<') {
+        let end = find_at_depth_zero(input, |_, _, c| c == '>', 1).map_or(input.len(), |i| i + 1);
+        input[end..].trim_start()
+    } else {
+        input
+    }
+}
+
+/// Reads the parenthesized parameters at the start of `input`, returning them
+/// with the input following the closing parenthesis.
+fn read_params(input: &str) -> Option<(Vec<ResolverSignatureParam>, &str)> {
+    let inner = input.strip_prefix('(')?;
+    let end = find_at_depth_zero(inner, |_, _, c| c == ')', 0)?;
+    let params = split_at_depth_zero(&inner[..end], ',')
+        .into_iter()
+        .filter(|param| !param.is_empty())
+        .map(|param| {
+            let param = match find_at_depth_zero(param, is_default_value, 0) {
+                Some(index) => param[..index].trim(),
+                None => param,
+            };
+            match find_at_depth_zero(param, |_, _, c| c == ':', 0) {
+                Some(index) => ResolverSignatureParam {
+                    name: param[..index].trim().trim_end_matches('?').to_string(),
+                    type_: Some(param[index + 1..].trim().to_string()),
+                },
+                None => ResolverSignatureParam {
+                    name: param.to_string(),
+                    type_: None,
+                },
+            }
+        })
+        .collect();
+    Some((params, &inner[end + 1..]))
+}
+
+enum ReturnTypeEnd {
+    /// The return type of a function declaration is followed by its body.
+    Body,
+    /// The return type of an arrow function is followed by `=>`.
+    Arrow,
+}
+
+fn read_return_type(input: &str, end: ReturnTypeEnd) -> Option<String> {
+    let input = input.trim_start().strip_prefix(':')?;
+    let end = match end {
+        ReturnTypeEnd::Body => find_at_depth_zero(
+            input,
+            |input, index, c| {
+                // A `{` following a complete type opens the body, otherwise
+                // it opens an object type.
+                c == '{'
+                    && input[..index]
+                        .trim_end()
+                        .chars()
+                        .last()
+                        .is_some_and(|last| !"|&:<,(?".contains(last))
+            },
+            0,
+        ),
+        ReturnTypeEnd::Arrow => find_at_depth_zero(
+            input,
+            |input, index, c| c == '=' && is_arrow(input, index),
+            0,
+        ),
+    }?;
+    let return_type = input[..end].trim();
+    if return_type.is_empty() {
+        None
+    } else {
+        Some(return_type.to_string())
+    }
+}
+
+fn is_default_value(input: &str, index: usize, c: char) -> bool {
+    c == '=' && !is_arrow(input, index)
+}
+
+/// Whether the `=` at `index` is the one of a `=>`.
+fn is_arrow(input: &str, index: usize) -> bool {
+    input[index + 1..].starts_with('>')
+}
+
+fn split_at_depth_zero(input: &str, separator: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut rest = input;
+    while let Some(index) = find_at_depth_zero(rest, |_, _, c| c == separator, 0) {
+        parts.push(rest[..index].trim());
+        rest = &rest[index + 1..];
+    }
+    parts.push(rest.trim());
+    parts
+}
+
+/// Finds the first character outside of brackets for which `predicate`, called
+/// with the input and the index of the character, returns true. The search
+/// starts at `initial_depth`, e.g. 1 to find the bracket closing the one
+/// `input` starts with.
+fn find_at_depth_zero(
+    input: &str,
+    predicate: impl Fn(&str, usize, char) -> bool,
+    initial_depth: usize,
+) -> Option<usize> {
+    let mut depth = initial_depth;
+    let mut chars = input.char_indices();
+    if initial_depth > 0 {
+        chars.next();
+    }
+    for (index, c) in chars {
+        match c {
+            '(' | '[' | '{' | '<' => {
+                if depth == 0 && predicate(input, index, c) {
+                    return Some(index);
+                }
+                depth += 1;
+            }
+            // The `>` of `=>` does not close a bracket.
+            '>' if input[..index].ends_with('=') => {
+                if depth == 0 && predicate(input, index, c) {
+                    return Some(index);
+                }
+            }
+            ')' | ']' | '}' | '>' => {
+                if depth == 0 {
+                    return if predicate(input, index, c) {
+                        Some(index)
+                    } else {
+                        None
+                    };
+                }
+                depth -= 1;
+                if depth == 0 && initial_depth > 0 && predicate(input, index, c) {
+                    return Some(index);
+                }
+            }
+            _ => {
+                if depth == 0 && predicate(input, index, c) {
+                    return Some(index);
+                }
+            }
+        }
+    }
+    None
+}
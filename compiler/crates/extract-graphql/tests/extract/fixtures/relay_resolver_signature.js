@@ -0,0 +1,44 @@
+/**
+ * @RelayResolver User.greeting: String
+ * @rootFragment UserGreetingResolver
+ */
+export function greeting(
+  user: UserGreetingResolver$key,
+  args: {|salutation: ?string|},
+): ?string {
+  const data = readFragment(graphql`
+    fragment UserGreetingResolver on User {
+      name
+    }
+  `, user);
+  return `${args.salutation ?? 'Hello'} ${data.name}`;
+}
+
+/**
+ * @RelayResolver User.shape
+ */
+export function shape(user: User): {| size: number, color: ?string |} {
+  return {size: 1, color: null};
+}
+
+/**
+ * @RelayResolver User.names
+ */
+export const names = async (
+  users: ReadonlyArray<UserNamesResolver$key>,
+  options: {onChange?: (value: string) => void} = {},
+): Promise<Array<string | null>> => {
+  return [];
+};
+
+/**
+ * @RelayResolver User.untyped
+ */
+export default function untyped<T>(key, args) {
+  return null;
+}
+
+/**
+ * @RelayResolver User.notAFunction
+ */
+const notAFunction = 42;
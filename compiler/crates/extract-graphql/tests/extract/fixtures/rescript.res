@@ -0,0 +1,22 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+module Fragment = %relay(`
+  fragment UserAvatar_user on User {
+    name
+  }
+`)
+
+module Query = %relay( `
+  query UserQuery {
+    me {
+      ...UserAvatar_user
+    }
+  }
+`)
+
+let percent = %raw(`10 % 3`)
@@ -16,10 +16,14 @@ pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String>
         .map(|feature| match feature {
             JavaScriptSourceFeature::Docblock(docblock_source) => {
                 let s = docblock_source.text_source();
-                format!(
+                let docblock = format!(
                     "docblock - line: {}, column: {}, text: <{}>",
                     s.line_index, s.column_index, s.text
-                )
+                );
+                match docblock_source.signature() {
+                    Some(signature) => format!("{}\nsignature: {:?}", docblock, signature),
+                    None => docblock,
+                }
             }
             JavaScriptSourceFeature::GraphQL(graphql_source) => {
                 let s = graphql_source.text_source();
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<080455566173c6f7361b4c9ca50ab6d3>>
+ * @generated SignedSource<<f4d4a50ed0a9e999724fbd888ed05555>>
  */
 
 mod extract;
@@ -61,6 +61,20 @@ async fn relay_resolver_and_graphql() {
     test_fixture(transform_fixture, file!(), "relay_resolver_and_graphql.js", "extract/fixtures/relay_resolver_and_graphql.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn relay_resolver_signature() {
+    let input = include_str!("extract/fixtures/relay_resolver_signature.js");
+    let expected = include_str!("extract/fixtures/relay_resolver_signature.expected");
+    test_fixture(transform_fixture, file!(), "relay_resolver_signature.js", "extract/fixtures/relay_resolver_signature.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn rescript() {
+    let input = include_str!("extract/fixtures/rescript.res");
+    let expected = include_str!("extract/fixtures/rescript.expected");
+    test_fixture(transform_fixture, file!(), "rescript.res", "extract/fixtures/rescript.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn simple() {
     let input = include_str!("extract/fixtures/simple.flow");
@@ -19,9 +19,9 @@ use common::ScalarName;
 use common::Span;
 use common::WithLocation;
 use errors::par_try_map;
+use errors::try_map;
 use errors::try2;
 use errors::try3;
-use errors::try_map;
 use graphql_syntax::DefaultValue;
 use graphql_syntax::DirectiveLocation;
 use graphql_syntax::Identifier;
@@ -30,14 +30,12 @@ use graphql_syntax::OperationKind;
 use graphql_syntax::Token;
 use graphql_syntax::TokenKind;
 use indexmap::IndexMap;
+use intern::Lookup;
 use intern::string_key::Intern;
 use intern::string_key::StringKey;
 use intern::string_key::StringKeyMap;
 use intern::string_key::StringKeySet;
-use intern::Lookup;
 use lazy_static::lazy_static;
-use schema::suggestion_list;
-use schema::suggestion_list::GraphQLSuggestions;
 use schema::ArgumentDefinitions;
 use schema::Enum;
 use schema::FieldID;
@@ -47,15 +45,19 @@ use schema::Scalar;
 use schema::Schema;
 use schema::Type;
 use schema::TypeReference;
+use schema::suggestion_list;
+use schema::suggestion_list::GraphQLSuggestions;
 
 use crate::constants::ARGUMENT_DEFINITION;
 use crate::errors::ValidationMessage;
 use crate::errors::ValidationMessageWithData;
 use crate::ir::*;
-use crate::signatures::build_signatures;
 use crate::signatures::FragmentSignature;
 use crate::signatures::FragmentSignatures;
+use crate::signatures::PROVIDER_DIRECTIVE;
 use crate::signatures::ProvidedVariableMetadata;
+use crate::signatures::UNUSED_LOCAL_VARIABLE_DEPRECATED;
+use crate::signatures::build_signatures;
 
 lazy_static! {
     static ref TYPENAME_FIELD_NAME: StringKey = "__typename".intern();
@@ -398,6 +400,23 @@ impl<'schema, 'signatures, 'options> Builder<'schema, 'signatures, 'options> {
             }
             None => Default::default(),
         };
+        let fragment_variable_directives = variable_definitions
+            .iter()
+            .flat_map(|variable_definition| &variable_definition.directives)
+            .filter(|directive| {
+                directive.name.item == *PROVIDER_DIRECTIVE
+                    || directive.name.item == *UNUSED_LOCAL_VARIABLE_DEPRECATED
+            })
+            .map(|directive| {
+                Diagnostic::error(
+                    ValidationMessage::FragmentVariableDirectiveOnOperation(directive.name.item),
+                    directive.name.location,
+                )
+            })
+            .collect::<Vec<_>>();
+        if !fragment_variable_directives.is_empty() {
+            return Err(fragment_variable_directives);
+        }
         self.defined_variables = variable_definitions
             .iter()
             .map(|x| (x.name.item, x.clone()))
@@ -1236,7 +1255,7 @@ impl<'schema, 'signatures, 'options> Builder<'schema, 'signatures, 'options> {
                         None => {
                             let possible_argument_names = argument_definitions
                                 .iter()
-                                .map(|arg_def| arg_def.name.0)
+                                .map(|arg_def| arg_def.name.item.0)
                                 .collect::<Vec<_>>();
                             let suggestions = suggestion_list::suggestion_list(
                                 argument.name.value,
@@ -1268,9 +1287,9 @@ impl<'schema, 'signatures, 'options> Builder<'schema, 'signatures, 'options> {
                 arguments
                     .iter()
                     .flat_map(|args| &args.items)
-                    .all(|arg| arg.name.value != required_arg_def.name.0)
+                    .all(|arg| arg.name.value != required_arg_def.name.item.0)
             })
-            .map(|missing_arg| missing_arg.name.0)
+            .map(|missing_arg| missing_arg.name.item.0)
             .filter(is_non_nullable_field_required)
             .collect::<Vec<_>>();
         if !missing_arg_names.is_empty() {
@@ -1570,7 +1589,7 @@ impl<'schema, 'signatures, 'options> Builder<'schema, 'signatures, 'options> {
             .fields
             .iter()
             .filter(|x| x.type_.is_non_null())
-            .map(|x| x.name.0)
+            .map(|x| x.name.item.0)
             .collect::<StringKeySet>();
 
         let fields = object
@@ -1597,7 +1616,7 @@ impl<'schema, 'signatures, 'options> Builder<'schema, 'signatures, 'options> {
                         let value_span = x.value.span();
                         let value = self.build_value(
                             &x.value,
-                            &field_definition.type_,
+                            &input_field_type(type_definition, field_definition),
                             ValidationLevel::Strict,
                         )?;
                         Ok(Argument {
@@ -1626,6 +1645,15 @@ impl<'schema, 'signatures, 'options> Builder<'schema, 'signatures, 'options> {
                 },
             )
             .collect::<DiagnosticsResult<Vec<Argument>>>()?;
+        if type_definition.is_one_of() && fields.len() != 1 {
+            return Err(vec![Diagnostic::error(
+                ValidationMessage::OneOfInputObjectExpectedExactlyOneField {
+                    type_name: type_definition.name.item.0,
+                    count: fields.len(),
+                },
+                self.location.with_span(object.span),
+            )]);
+        }
         if required_fields.is_empty() {
             Ok(Value::Object(fields))
         } else {
@@ -1721,7 +1749,7 @@ impl<'schema, 'signatures, 'options> Builder<'schema, 'signatures, 'options> {
             .fields
             .iter()
             .filter(|x| x.type_.is_non_null())
-            .map(|x| x.name.0)
+            .map(|x| x.name.item.0)
             .collect::<StringKeySet>();
 
         let mut errors = vec![];
@@ -1750,7 +1778,7 @@ impl<'schema, 'signatures, 'options> Builder<'schema, 'signatures, 'options> {
                     let value_span = obj_entry.value.span();
                     match self.build_constant_value(
                         &obj_entry.value,
-                        &field_definition.type_,
+                        &input_field_type(type_definition, field_definition),
                         validation,
                     ) {
                         Ok(value) => fields.push(ConstantArgument {
@@ -1780,6 +1808,15 @@ impl<'schema, 'signatures, 'options> Builder<'schema, 'signatures, 'options> {
                 )),
             }
         }
+        if type_definition.is_one_of() && object.items.len() != 1 {
+            errors.push(Diagnostic::error(
+                ValidationMessage::OneOfInputObjectExpectedExactlyOneField {
+                    type_name: type_definition.name.item.0,
+                    count: object.items.len(),
+                },
+                self.location.with_span(object.span),
+            ));
+        }
         if !required_fields.is_empty() {
             let mut missing: Vec<StringKey> = required_fields.into_iter().collect();
             missing.sort();
@@ -2098,3 +2135,14 @@ fn variable_definition_requires_argument(variable_definition: &VariableDefinitio
             .named(ProvidedVariableMetadata::directive_name())
             .is_none()
 }
+
+/// The type a value for `field` of `input_object` must match. Fields of a
+/// `@oneOf` input object are nullable in the schema, but the one that is
+/// provided must be non-null.
+fn input_field_type(input_object: &InputObject, field: &schema::Argument) -> TypeReference<Type> {
+    if input_object.is_one_of() {
+        field.type_.non_null()
+    } else {
+        field.type_.clone()
+    }
+}
@@ -92,6 +92,9 @@ pub enum ValidationMessage {
     #[error("Missing required fields '{0:?}' of type '{1}'")] // TODO: print joined
     MissingRequiredFields(Vec<StringKey>, StringKey),
 
+    #[error("OneOf input object '{type_name}' must specify exactly one field, got {count}")]
+    OneOfInputObjectExpectedExactlyOneField { type_name: StringKey, count: usize },
+
     #[error("Unsupported (user-defined) scalar type '{0}'")]
     UnsupportedCustomScalarType(StringKey),
 
@@ -109,6 +112,9 @@ pub enum ValidationMessage {
     #[error("Cannot combine fragment arguments syntax with the '@arguments' directive")]
     FragmentArgumentsAndArgumentDirective,
 
+    #[error("The '@{0}' directive can only be used on fragment variable definitions")]
+    FragmentVariableDirectiveOnOperation(DirectiveName),
+
     #[error("Unexpected fragment argument. Fragment argument syntax is not enabled.")]
     OutsidePassedArgumentsMode,
 
@@ -398,7 +404,7 @@ pub enum ValidationMessage {
     },
 
     #[error(
-        "Variable `${variable_name}` is never used in fragment `{fragment_name}`. `@argumentDefinitions` defines local variables, global variables are implicitly available."
+        "Variable `${variable_name}` is never used in fragment `{fragment_name}`. Fragment variable definitions and `@argumentDefinitions` define local variables, global variables are implicitly available."
     )]
     UnusedFragmentVariable {
         variable_name: VariableName,
@@ -406,7 +412,7 @@ pub enum ValidationMessage {
     },
 
     #[error(
-        "Variable `${variable_name}` of fragment `{fragment_name}` is marked as unused using `unusedLocalVariable_DEPRECATED: true`, but is actually used. `unusedLocalVariable_DEPRECATED: true` should be removed."
+        "Variable `${variable_name}` of fragment `{fragment_name}` is marked as unused using `unusedLocalVariable_DEPRECATED`, but is actually used. `unusedLocalVariable_DEPRECATED` should be removed."
     )]
     UselessUnusedFragmentVariableAnnotation {
         variable_name: VariableName,
@@ -13,6 +13,7 @@ use common::Diagnostic;
 use common::DiagnosticsResult;
 use common::DirectiveName;
 use common::Location;
+use common::NamedItem;
 use common::SourceLocationKey;
 use common::WithLocation;
 use errors::par_try_map;
@@ -49,6 +50,8 @@ lazy_static! {
     pub static ref UNUSED_LOCAL_VARIABLE_DEPRECATED: DirectiveName =
         DirectiveName("unusedLocalVariable_DEPRECATED".intern());
     static ref DIRECTIVES: StringKey = "directives".intern();
+    pub static ref PROVIDER_DIRECTIVE: DirectiveName = DirectiveName("provider".intern());
+    static ref MODULE: ArgumentName = ArgumentName("module".intern());
 }
 
 pub type FragmentSignatures = FragmentDefinitionNameMap<FragmentSignature>;
@@ -188,6 +191,9 @@ fn build_fragment_signature(
         .as_ref()
         .map(|variable_definitions| {
             build_variable_definitions(schema, &variable_definitions.items, fragment.location)
+                .and_then(|variable_definitions| {
+                    build_provided_variables(fragment, variable_definitions)
+                })
         })
         .or_else(|| {
             argument_definition_directives
@@ -209,6 +215,64 @@ fn build_fragment_signature(
     })
 }
 
+/// Replaces the `@provider` directives of the fragment variable definitions
+/// syntax with `ProvidedVariableMetadata`, like `provider` in `@argumentDefinitions`.
+fn build_provided_variables(
+    fragment: &graphql_syntax::FragmentDefinition,
+    mut variable_definitions: Vec<VariableDefinition>,
+) -> DiagnosticsResult<Vec<VariableDefinition>> {
+    for variable_definition in &mut variable_definitions {
+        let provider_index = variable_definition
+            .directives
+            .iter()
+            .position(|directive| directive.name.item == *PROVIDER_DIRECTIVE);
+        let provider_directive = match provider_index {
+            Some(index) => variable_definition.directives.remove(index),
+            None => continue,
+        };
+        if let Some(default_value) = &variable_definition.default_value {
+            return Err(vec![
+                Diagnostic::error(
+                    ValidationMessage::ProvidedVariableIncompatibleWithDefaultValue {
+                        argument_name: variable_definition.name.item.0,
+                    },
+                    provider_directive.name.location,
+                )
+                .annotate("Default value declared here", default_value.location),
+            ]);
+        }
+        let module_name = match provider_directive
+            .arguments
+            .named(*MODULE)
+            .map(|argument| &argument.value.item)
+        {
+            Some(crate::Value::Constant(ConstantValue::String(module_name))) => *module_name,
+            _ => {
+                return Err(vec![Diagnostic::error(
+                    ValidationMessage::LiteralStringArgumentExpectedForDirective {
+                        arg_name: *MODULE,
+                        directive_name: *PROVIDER_DIRECTIVE,
+                    },
+                    provider_directive.name.location,
+                )]);
+            }
+        };
+        variable_definition.directives.push(crate::Directive {
+            name: WithLocation::new(
+                provider_directive.name.location,
+                ProvidedVariableMetadata::directive_name(),
+            ),
+            arguments: Vec::new(),
+            data: Some(Box::new(ProvidedVariableMetadata {
+                module_name,
+                original_variable_name: variable_definition.name.item,
+                fragment_source_location: fragment.location.source_location(),
+            })),
+        });
+    }
+    Ok(variable_definitions)
+}
+
 fn build_fragment_variable_definitions(
     schema: &SDLSchema,
     fragment: &graphql_syntax::FragmentDefinition,
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<624dd9ddfecfe569df689202ad63347c>>
+ * @generated SignedSource<<234aa8bc40a59c9c3627fc7bc5db3374>>
  */
 
 mod parse_with_extensions;
@@ -116,3 +116,17 @@ async fn list_of_custom_scalar_literal_arg_invalid() {
     let expected = include_str!("parse_with_extensions/fixtures/list_of_custom_scalar_literal_arg.invalid.expected");
     test_fixture(transform_fixture, file!(), "list_of_custom_scalar_literal_arg.invalid.graphql", "parse_with_extensions/fixtures/list_of_custom_scalar_literal_arg.invalid.expected", input, expected).await;
 }
+
+#[tokio::test]
+async fn one_of_input_object() {
+    let input = include_str!("parse_with_extensions/fixtures/one-of-input-object.graphql");
+    let expected = include_str!("parse_with_extensions/fixtures/one-of-input-object.expected");
+    test_fixture(transform_fixture, file!(), "one-of-input-object.graphql", "parse_with_extensions/fixtures/one-of-input-object.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn one_of_input_object_invalid() {
+    let input = include_str!("parse_with_extensions/fixtures/one-of-input-object.invalid.graphql");
+    let expected = include_str!("parse_with_extensions/fixtures/one-of-input-object.invalid.expected");
+    test_fixture(transform_fixture, file!(), "one-of-input-object.invalid.graphql", "parse_with_extensions/fixtures/one-of-input-object.invalid.expected", input, expected).await;
+}
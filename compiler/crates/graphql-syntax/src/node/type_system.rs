@@ -75,6 +75,7 @@ impl fmt::Display for TypeSystemDefinition {
                 interfaces,
                 fields,
                 directives,
+                description: _,
             }) => write_object_helper(f, &name.value, interfaces, fields, directives, false),
             TypeSystemDefinition::ObjectTypeExtension(ObjectTypeExtension {
                 name,
@@ -87,6 +88,7 @@ impl fmt::Display for TypeSystemDefinition {
                 interfaces,
                 fields,
                 directives,
+                description: _,
             }) => write_interface_helper(f, &name.value, interfaces, fields, directives, false),
             TypeSystemDefinition::InterfaceTypeExtension(InterfaceTypeExtension {
                 name,
@@ -98,6 +100,7 @@ impl fmt::Display for TypeSystemDefinition {
                 name,
                 directives,
                 members,
+                description: _,
             }) => write_union_type_definition_helper(f, &name.value, directives, members, false),
             TypeSystemDefinition::UnionTypeExtension(UnionTypeExtension {
                 name,
@@ -124,6 +127,7 @@ impl fmt::Display for TypeSystemDefinition {
                 name,
                 directives,
                 fields,
+                description: _,
             }) => {
                 write_input_object_type_definition_helper(f, &name.value, directives, fields, false)
             }
@@ -138,6 +142,7 @@ impl fmt::Display for TypeSystemDefinition {
                 name,
                 directives,
                 values,
+                description: _,
             }) => write_enum_type_definition_helper(f, &name.value, directives, values, false),
             TypeSystemDefinition::EnumTypeExtension(EnumTypeExtension {
                 name,
@@ -147,6 +152,7 @@ impl fmt::Display for TypeSystemDefinition {
             TypeSystemDefinition::ScalarTypeDefinition(ScalarTypeDefinition {
                 name,
                 directives,
+                description: _,
             }) => write_scalar_type_definition_helper(f, &name.value, directives, false),
             TypeSystemDefinition::ScalarTypeExtension(ScalarTypeExtension { name, directives }) => {
                 write_scalar_type_definition_helper(f, &name.value, directives, true)
@@ -236,6 +242,7 @@ pub struct ObjectTypeDefinition {
     pub interfaces: Vec<Identifier>,
     pub directives: Vec<ConstantDirective>,
     pub fields: Option<List<FieldDefinition>>,
+    pub description: Option<StringNode>,
 }
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
@@ -252,6 +259,7 @@ impl From<ObjectTypeExtension> for ObjectTypeDefinition {
             interfaces: ext.interfaces,
             directives: ext.directives,
             fields: ext.fields,
+            description: None,
         }
     }
 }
@@ -265,6 +273,7 @@ pub struct InterfaceTypeDefinition {
     pub interfaces: Vec<Identifier>,
     pub directives: Vec<ConstantDirective>,
     pub fields: Option<List<FieldDefinition>>,
+    pub description: Option<StringNode>,
 }
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
@@ -281,6 +290,7 @@ impl From<InterfaceTypeExtension> for InterfaceTypeDefinition {
             interfaces: ext.interfaces,
             directives: ext.directives,
             fields: ext.fields,
+            description: None,
         }
     }
 }
@@ -293,6 +303,7 @@ pub struct UnionTypeDefinition {
     pub name: Identifier,
     pub directives: Vec<ConstantDirective>,
     pub members: Vec<Identifier>,
+    pub description: Option<StringNode>,
 }
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
@@ -307,6 +318,7 @@ impl From<UnionTypeExtension> for UnionTypeDefinition {
             name: ext.name,
             directives: ext.directives,
             members: ext.members,
+            description: None,
         }
     }
 }
@@ -318,6 +330,7 @@ impl ExtensionIntoDefinition for UnionTypeExtension {
 pub struct ScalarTypeDefinition {
     pub name: Identifier,
     pub directives: Vec<ConstantDirective>,
+    pub description: Option<StringNode>,
 }
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
@@ -330,6 +343,7 @@ impl From<ScalarTypeExtension> for ScalarTypeDefinition {
         Self {
             name: ext.name,
             directives: ext.directives,
+            description: None,
         }
     }
 }
@@ -342,6 +356,7 @@ pub struct EnumTypeDefinition {
     pub name: Identifier,
     pub directives: Vec<ConstantDirective>,
     pub values: Option<List<EnumValueDefinition>>,
+    pub description: Option<StringNode>,
 }
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
@@ -356,6 +371,7 @@ impl From<EnumTypeExtension> for EnumTypeDefinition {
             name: ext.name,
             directives: ext.directives,
             values: ext.values,
+            description: None,
         }
     }
 }
@@ -368,6 +384,7 @@ pub struct InputObjectTypeDefinition {
     pub name: Identifier,
     pub directives: Vec<ConstantDirective>,
     pub fields: Option<List<InputValueDefinition>>,
+    pub description: Option<StringNode>,
 }
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
@@ -382,6 +399,7 @@ impl From<InputObjectTypeExtension> for InputObjectTypeDefinition {
             name: ext.name,
             directives: ext.directives,
             fields: ext.fields,
+            description: None,
         }
     }
 }
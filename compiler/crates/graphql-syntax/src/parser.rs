@@ -22,17 +22,20 @@ use crate::syntax_error::SyntaxError;
 
 type ParseResult<T> = Result<T, ()>;
 
+/// Which parts of the fragment arguments syntax (`fragment Foo($x: Int = 1) on T`
+/// and `...Foo(x: 2)`) are parsed. Both are supported by default, alongside the
+/// `@argumentDefinitions` and `@arguments` directives.
 #[derive(Default, PartialEq)]
 pub enum FragmentArgumentSyntaxKind {
-    #[default]
     None,
     OnlyFragmentVariableDefinitions,
+    #[default]
     SpreadArgumentsAndFragmentVariableDefinitions,
 }
 
 #[derive(Default)]
 pub struct ParserFeatures {
-    /// Whether and how to enable the experimental fragment variables definitions syntax
+    /// Whether and how to enable the fragment variable definitions syntax
     pub fragment_argument_capability: FragmentArgumentSyntaxKind,
 }
 
@@ -384,22 +387,22 @@ impl<'a> Parser<'a> {
                 self.parse_schema_definition()?,
             )),
             "scalar" => Ok(TypeSystemDefinition::ScalarTypeDefinition(
-                self.parse_scalar_type_definition()?,
+                self.parse_scalar_type_definition(description)?,
             )),
             "type" => Ok(TypeSystemDefinition::ObjectTypeDefinition(
-                self.parse_object_type_definition()?,
+                self.parse_object_type_definition(description)?,
             )),
             "interface" => Ok(TypeSystemDefinition::InterfaceTypeDefinition(
-                self.parse_interface_type_definition()?,
+                self.parse_interface_type_definition(description)?,
             )),
             "union" => Ok(TypeSystemDefinition::UnionTypeDefinition(
-                self.parse_union_type_definition()?,
+                self.parse_union_type_definition(description)?,
             )),
             "enum" => Ok(TypeSystemDefinition::EnumTypeDefinition(
-                self.parse_enum_type_definition()?,
+                self.parse_enum_type_definition(description)?,
             )),
             "input" => Ok(TypeSystemDefinition::InputObjectTypeDefinition(
-                self.parse_input_object_type_definition()?,
+                self.parse_input_object_type_definition(description)?,
             )),
             "directive" => Ok(TypeSystemDefinition::DirectiveDefinition(
                 self.parse_directive_definition(description, hack_source)?,
@@ -534,7 +537,10 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn parse_object_type_definition(&mut self) -> ParseResult<ObjectTypeDefinition> {
+    fn parse_object_type_definition(
+        &mut self,
+        description: Option<StringNode>,
+    ) -> ParseResult<ObjectTypeDefinition> {
         self.parse_keyword("type")?;
         let name = self.parse_identifier()?;
         let interfaces = self.parse_implements_interfaces()?;
@@ -545,10 +551,14 @@ impl<'a> Parser<'a> {
             interfaces,
             directives,
             fields,
+            description,
         })
     }
 
-    fn parse_interface_type_definition(&mut self) -> ParseResult<InterfaceTypeDefinition> {
+    fn parse_interface_type_definition(
+        &mut self,
+        description: Option<StringNode>,
+    ) -> ParseResult<InterfaceTypeDefinition> {
         self.parse_keyword("interface")?;
         let name = self.parse_identifier()?;
         let interfaces = self.parse_implements_interfaces()?;
@@ -559,6 +569,7 @@ impl<'a> Parser<'a> {
             interfaces,
             directives,
             fields,
+            description,
         })
     }
 
@@ -566,7 +577,10 @@ impl<'a> Parser<'a> {
      * UnionTypeDefinition :
      *   - Description? union Name Directives? UnionMemberTypes?
      */
-    fn parse_union_type_definition(&mut self) -> ParseResult<UnionTypeDefinition> {
+    fn parse_union_type_definition(
+        &mut self,
+        description: Option<StringNode>,
+    ) -> ParseResult<UnionTypeDefinition> {
         self.parse_keyword("union")?;
         let name = self.parse_identifier()?;
         let directives = self.parse_constant_directives()?;
@@ -575,6 +589,7 @@ impl<'a> Parser<'a> {
             name,
             directives,
             members,
+            description,
         })
     }
 
@@ -616,7 +631,10 @@ impl<'a> Parser<'a> {
      * EnumTypeDefinition :
      *   - Description? enum Name Directives? EnumValuesDefinition?
      */
-    fn parse_enum_type_definition(&mut self) -> ParseResult<EnumTypeDefinition> {
+    fn parse_enum_type_definition(
+        &mut self,
+        description: Option<StringNode>,
+    ) -> ParseResult<EnumTypeDefinition> {
         self.parse_keyword("enum")?;
         let name = self.parse_identifier()?;
         let directives = self.parse_constant_directives()?;
@@ -625,6 +643,7 @@ impl<'a> Parser<'a> {
             name,
             directives,
             values,
+            description,
         })
     }
 
@@ -725,11 +744,18 @@ impl<'a> Parser<'a> {
     /**
      * ScalarTypeDefinition : Description? scalar Name Directives?
      */
-    fn parse_scalar_type_definition(&mut self) -> ParseResult<ScalarTypeDefinition> {
+    fn parse_scalar_type_definition(
+        &mut self,
+        description: Option<StringNode>,
+    ) -> ParseResult<ScalarTypeDefinition> {
         self.parse_keyword("scalar")?;
         let name = self.parse_identifier()?;
         let directives = self.parse_constant_directives()?;
-        Ok(ScalarTypeDefinition { name, directives })
+        Ok(ScalarTypeDefinition {
+            name,
+            directives,
+            description,
+        })
     }
 
     /**
@@ -747,7 +773,10 @@ impl<'a> Parser<'a> {
      * InputObjectTypeDefinition :
      *   - Description? input Name Directives? InputFieldsDefinition?
      */
-    fn parse_input_object_type_definition(&mut self) -> ParseResult<InputObjectTypeDefinition> {
+    fn parse_input_object_type_definition(
+        &mut self,
+        description: Option<StringNode>,
+    ) -> ParseResult<InputObjectTypeDefinition> {
         self.parse_keyword("input")?;
         let name = self.parse_identifier()?;
         let directives = self.parse_constant_directives()?;
@@ -756,6 +785,7 @@ impl<'a> Parser<'a> {
             name,
             directives,
             fields,
+            description,
         })
     }
 
@@ -1025,7 +1055,7 @@ impl<'a> Parser<'a> {
         Ok(interfaces)
     }
 
-    /// FragmentDefinition : fragment FragmentName TypeCondition Directives? SelectionSet
+    /// FragmentDefinition : fragment FragmentName VariableDefinitions? TypeCondition Directives? SelectionSet
     fn parse_fragment_definition(&mut self) -> ParseResult<FragmentDefinition> {
         let start = self.index();
         let fragment = self.parse_keyword("fragment")?;
@@ -1348,7 +1378,7 @@ impl<'a> Parser<'a> {
         }
     }
 
-    /// FragmentSpread : ... FragmentName Directives?
+    /// FragmentSpread : ... FragmentName Arguments? Directives?
     /// InlineFragment : ... TypeCondition? Directives? SelectionSet
     fn parse_spread(&mut self) -> ParseResult<Selection> {
         let start = self.index();
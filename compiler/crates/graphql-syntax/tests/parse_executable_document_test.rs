@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<4934dcac68b7399c330d6808663cc57f>>
+ * @generated SignedSource<<c132430a2e664ad451d417256370a398>>
  */
 
 mod parse_executable_document;
@@ -20,10 +20,10 @@ async fn block_string() {
 }
 
 #[tokio::test]
-async fn fragment_with_variable_defs_invalid() {
-    let input = include_str!("parse_executable_document/fixtures/fragment_with_variable_defs.invalid.graphql");
-    let expected = include_str!("parse_executable_document/fixtures/fragment_with_variable_defs.invalid.expected");
-    test_fixture(transform_fixture, file!(), "fragment_with_variable_defs.invalid.graphql", "parse_executable_document/fixtures/fragment_with_variable_defs.invalid.expected", input, expected).await;
+async fn fragment_with_variable_defs() {
+    let input = include_str!("parse_executable_document/fixtures/fragment_with_variable_defs.graphql");
+    let expected = include_str!("parse_executable_document/fixtures/fragment_with_variable_defs.expected");
+    test_fixture(transform_fixture, file!(), "fragment_with_variable_defs.graphql", "parse_executable_document/fixtures/fragment_with_variable_defs.expected", input, expected).await;
 }
 
 #[tokio::test]
@@ -90,10 +90,10 @@ async fn space_in_variable() {
 }
 
 #[tokio::test]
-async fn spread_with_arguments_invalid() {
-    let input = include_str!("parse_executable_document/fixtures/spread_with_arguments.invalid.graphql");
-    let expected = include_str!("parse_executable_document/fixtures/spread_with_arguments.invalid.expected");
-    test_fixture(transform_fixture, file!(), "spread_with_arguments.invalid.graphql", "parse_executable_document/fixtures/spread_with_arguments.invalid.expected", input, expected).await;
+async fn spread_with_arguments() {
+    let input = include_str!("parse_executable_document/fixtures/spread_with_arguments.graphql");
+    let expected = include_str!("parse_executable_document/fixtures/spread_with_arguments.expected");
+    test_fixture(transform_fixture, file!(), "spread_with_arguments.graphql", "parse_executable_document/fixtures/spread_with_arguments.expected", input, expected).await;
 }
 
 #[tokio::test]
@@ -24,6 +24,7 @@ use graphql_ir::FragmentSpread;
 use graphql_ir::InlineFragment;
 use graphql_ir::LinkedField;
 use graphql_ir::OperationDefinition;
+use graphql_ir::ProvidedVariableMetadata;
 use graphql_ir::ScalarField;
 use graphql_ir::Selection;
 use graphql_ir::Value;
@@ -192,6 +193,10 @@ pub struct PrinterOptions {
     pub json_format: bool,
     /// Print `data` from Directive nodes
     pub debug_directive_data: bool,
+    /// Print fragment arguments with the fragment variable definitions
+    /// (`fragment Foo($x: Int = 1) on T`) and spread arguments (`...Foo(x: 2)`)
+    /// syntax, instead of the `@argumentDefinitions` and `@arguments` directives.
+    pub fragment_arguments_syntax: bool,
 }
 
 struct Printer<'schema, 'writer, W: Write> {
@@ -229,10 +234,11 @@ impl<'schema, 'writer, W: Write> Printer<'schema, 'writer, W> {
         let fragment_name = fragment.name.item;
         let type_condition_name = self.schema.get_type_name(fragment.type_condition);
         write!(self.writer, "fragment {}", fragment_name)?;
-        if fragment
-            .directives
-            .named(DirectiveName("argumentDefinitions".intern()))
-            .is_none()
+        if self.options.fragment_arguments_syntax
+            || fragment
+                .directives
+                .named(DirectiveName("argumentDefinitions".intern()))
+                .is_none()
         {
             self.print_variable_definitions(&fragment.variable_definitions)?;
         }
@@ -318,6 +324,10 @@ impl<'schema, 'writer, W: Write> Printer<'schema, 'writer, W> {
     ) -> FmtResult {
         let fragment_name = field.fragment.item;
         write!(self.writer, "...{}", fragment_name)?;
+        if self.options.fragment_arguments_syntax {
+            self.print_arguments(&field.arguments)?;
+            return self.print_directives(&field.directives, conditions, None);
+        }
         self.print_directives(&field.directives, conditions, None)?;
         if !field.arguments.is_empty() {
             self.print_optional_space()?;
@@ -415,7 +425,18 @@ impl<'schema, 'writer, W: Write> Printer<'schema, 'writer, W> {
         }
         for directive in directives {
             if directive.name.item.0.lookup() == "argumentDefinitions" {
-                self.print_argument_definitions(fragment_argument_definitions.unwrap())?;
+                if !self.options.fragment_arguments_syntax {
+                    self.print_argument_definitions(fragment_argument_definitions.unwrap())?;
+                }
+            } else if self.options.fragment_arguments_syntax
+                && directive.name.item == ProvidedVariableMetadata::directive_name()
+            {
+                let provided_variable = ProvidedVariableMetadata::from(directive).unwrap();
+                self.print_optional_space()?;
+                write!(self.writer, "@provider(module")?;
+                self.print_colon_separator()?;
+                self.print_constant_value(&ConstantValue::String(provided_variable.module_name))?;
+                write!(self.writer, ")")?;
             } else {
                 self.print_directive(directive)?;
             }
@@ -0,0 +1,63 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::build;
+use graphql_ir::node_identifier::LocationAgnosticPartialEq;
+use graphql_ir::ExecutableDefinition;
+use graphql_ir::ARGUMENT_DEFINITION;
+use graphql_syntax::parse_executable;
+use graphql_text_printer::print_fragment;
+use graphql_text_printer::print_operation;
+use graphql_text_printer::PrinterOptions;
+use relay_test_schema::TEST_SCHEMA;
+use relay_transforms::RelayLocationAgnosticBehavior;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let initial_ast = parse_executable(fixture.content, source_location).unwrap();
+    let initial_ir = build(&TEST_SCHEMA, &initial_ast.definitions).unwrap();
+    let options = PrinterOptions {
+        fragment_arguments_syntax: true,
+        ..Default::default()
+    };
+
+    let output = initial_ir
+        .iter()
+        .map(|definition| match definition {
+            ExecutableDefinition::Operation(operation) => {
+                print_operation(&TEST_SCHEMA, operation, options)
+            }
+            ExecutableDefinition::Fragment(fragment) => {
+                print_fragment(&TEST_SCHEMA, fragment, options)
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n");
+
+    // Roundtrip the output back into an IR
+    let roundtrip_ast = parse_executable(output.as_str(), source_location).unwrap();
+    let roundtrip_ir = build(&TEST_SCHEMA, &roundtrip_ast.definitions).unwrap();
+
+    // Check the roundtripped IR matches the initial IR, apart from the `@argumentDefinitions` directives
+    let initial_ir = initial_ir
+        .into_iter()
+        .map(|definition| match definition {
+            ExecutableDefinition::Fragment(mut fragment) => {
+                fragment
+                    .directives
+                    .retain(|directive| directive.name.item != *ARGUMENT_DEFINITION);
+                ExecutableDefinition::Fragment(fragment)
+            }
+            operation => operation,
+        })
+        .collect::<Vec<_>>();
+    assert!(roundtrip_ir.location_agnostic_eq::<RelayLocationAgnosticBehavior>(&initial_ir));
+
+    Ok(output)
+}
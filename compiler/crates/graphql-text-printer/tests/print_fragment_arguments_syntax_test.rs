@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<282ce467e0f799c9547e0e2206b824a6>>
+ */
+
+mod print_fragment_arguments_syntax;
+
+use print_fragment_arguments_syntax::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn argument_definitions() {
+    let input = include_str!("print_fragment_arguments_syntax/fixtures/argument-definitions.graphql");
+    let expected = include_str!("print_fragment_arguments_syntax/fixtures/argument-definitions.expected");
+    test_fixture(transform_fixture, file!(), "argument-definitions.graphql", "print_fragment_arguments_syntax/fixtures/argument-definitions.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn fragment_variable_definitions() {
+    let input = include_str!("print_fragment_arguments_syntax/fixtures/fragment-variable-definitions.graphql");
+    let expected = include_str!("print_fragment_arguments_syntax/fixtures/fragment-variable-definitions.expected");
+    test_fixture(transform_fixture, file!(), "fragment-variable-definitions.graphql", "print_fragment_arguments_syntax/fixtures/fragment-variable-definitions.expected", input, expected).await;
+}
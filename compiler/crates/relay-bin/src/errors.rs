@@ -5,6 +5,8 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::path::PathBuf;
+
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -20,4 +22,10 @@ pub enum Error {
 
     #[error("Unable to run relay compiler. Error details: \n{details}")]
     CompilerError { details: String },
+
+    #[error("Unable to read {path:?}. Error details: \n{details}")]
+    ReadError { path: PathBuf, details: String },
+
+    #[error("Unable to write {path:?}. Error details: \n{details}")]
+    WriteError { path: PathBuf, details: String },
 }
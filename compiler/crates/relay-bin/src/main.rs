@@ -5,11 +5,15 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::env;
 use std::env::current_dir;
+use std::fs;
 use std::path::PathBuf;
 use std::process::Command;
 use std::sync::Arc;
+use std::sync::Mutex;
 
 use clap::ArgEnum;
 use clap::Parser;
@@ -18,21 +22,27 @@ use intern::string_key::Intern;
 use log::error;
 use log::info;
 use relay_compiler::build_project::artifact_writer::ArtifactValidationWriter;
+use relay_compiler::build_project::artifact_writer::NoopArtifactWriter;
 use relay_compiler::build_project::generate_extra_artifacts::default_generate_extra_artifacts_fn;
+use relay_compiler::build_schema_coordinate_index;
 use relay_compiler::compiler::Compiler;
 use relay_compiler::config::Config;
 use relay_compiler::errors::Error as CompilerError;
+use relay_compiler::rewrite_fragment_arguments;
 use relay_compiler::FileSourceKind;
 use relay_compiler::LocalPersister;
 use relay_compiler::OperationPersister;
 use relay_compiler::PersistConfig;
 use relay_compiler::ProjectName;
 use relay_compiler::RemotePersister;
+use relay_compiler::SchemaCoordinateIndex;
 use relay_lsp::start_language_server;
 use relay_lsp::DummyExtraDataProvider;
 use relay_lsp::FieldDefinitionSourceInfo;
 use relay_lsp::FieldSchemaInfo;
 use relay_lsp::LSPExtraDataProvider;
+use relay_transforms::deprecated_usages;
+use relay_transforms::DeprecatedUsage;
 use schema::SDLSchema;
 use schema_documentation::SchemaDocumentationLoader;
 use simplelog::ColorChoice;
@@ -119,10 +129,97 @@ struct LspCommand {
     locate_command: Option<String>,
 }
 
+#[derive(Parser)]
+#[clap(
+    about = "Writes an index from schema coordinates (`Type.field`) to the operations and fragments selecting them, for every project, as JSON.",
+    rename_all = "camel_case"
+)]
+struct SchemaCoordinatesCommand {
+    /// Use this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Index only this project. You can pass this argument multiple times.
+    /// If excluded, all projects will be indexed.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+
+    /// Write the index to this file. If not provided, the index is written
+    /// to stdout.
+    #[clap(long)]
+    out: Option<PathBuf>,
+
+    /// Verbosity level
+    #[clap(long, arg_enum, default_value = "quiet-with-errors")]
+    output: OutputKind,
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Lists the deprecated fields and arguments in use, with the number of usages.",
+    rename_all = "camel_case"
+)]
+struct DeprecationsCommand {
+    /// Use this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// List only the usages in this project. You can pass this argument multiple times.
+    /// If excluded, the usages in all projects will be listed.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+
+    /// Print every usage in the format of the `deprecationConfig.allowlist`
+    /// files instead, to grandfather in the existing usages.
+    #[clap(long)]
+    allowlist: bool,
+
+    /// Verbosity level
+    #[clap(long, arg_enum, default_value = "quiet-with-errors")]
+    output: OutputKind,
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Applies a codemod to the GraphQL documents of the projects, in place.",
+    rename_all = "camel_case"
+)]
+struct CodemodCommand {
+    /// The codemod to apply.
+    #[clap(arg_enum)]
+    codemod: CodemodKind,
+
+    /// Use this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Apply the codemod only to this project. You can pass this argument multiple times.
+    /// If excluded, the codemod is applied to all projects.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+
+    /// Verbosity level
+    #[clap(long, arg_enum, default_value = "quiet-with-errors")]
+    output: OutputKind,
+}
+
+#[derive(ArgEnum, Clone, Copy)]
+enum CodemodKind {
+    /// Rewrites `@argumentDefinitions` and `@arguments` to fragment variable
+    /// definitions and spread arguments.
+    FragmentArguments,
+}
+
 #[derive(clap::Subcommand)]
 enum Commands {
     Compiler(CompileCommand),
     Lsp(LspCommand),
+    SchemaCoordinates(SchemaCoordinatesCommand),
+    Deprecations(DeprecationsCommand),
+    Codemod(CodemodCommand),
 }
 
 #[derive(ArgEnum, Clone, Copy)]
@@ -179,6 +276,9 @@ async fn main() {
     let result = match command {
         Commands::Compiler(command) => handle_compiler_command(command).await,
         Commands::Lsp(command) => handle_lsp_command(command).await,
+        Commands::SchemaCoordinates(command) => handle_schema_coordinates_command(command).await,
+        Commands::Deprecations(command) => handle_deprecations_command(command).await,
+        Commands::Codemod(command) => handle_codemod_command(command).await,
     };
 
     match result {
@@ -317,6 +417,222 @@ async fn handle_compiler_command(command: CompileCommand) -> Result<(), Error> {
     Ok(())
 }
 
+async fn handle_schema_coordinates_command(command: SchemaCoordinatesCommand) -> Result<(), Error> {
+    configure_logger(command.output, TerminalMode::Stderr);
+
+    let mut config = get_config(command.config)?;
+
+    set_project_flag(&mut config, command.projects)?;
+
+    config.artifact_writer = Box::new(NoopArtifactWriter);
+    config.file_source_config = if should_use_watchman() {
+        FileSourceKind::Watchman
+    } else {
+        FileSourceKind::WalkDir
+    };
+
+    // The index is built from the source programs, which the compiler hands
+    // to `generate_extra_artifacts` once a project is built successfully. Any
+    // callback already configured still runs after the index is built.
+    let indexes: Arc<Mutex<BTreeMap<String, SchemaCoordinateIndex>>> = Default::default();
+    let project_indexes = Arc::clone(&indexes);
+    let generate_extra_artifacts = config.generate_extra_artifacts.take();
+    config.generate_extra_artifacts = Some(Box::new(
+        move |config, project_config, schema, programs, artifacts| {
+            let index = build_schema_coordinate_index(
+                &programs.source,
+                &project_config.schema_config.defer_stream_interface,
+            );
+            project_indexes
+                .lock()
+                .unwrap()
+                .insert(project_config.name.to_string(), index);
+            match &generate_extra_artifacts {
+                Some(generate_extra_artifacts) => {
+                    generate_extra_artifacts(config, project_config, schema, programs, artifacts)
+                }
+                None => vec![],
+            }
+        },
+    ));
+
+    let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+    compiler
+        .compile()
+        .await
+        .map_err(|err| Error::CompilerError {
+            details: format!("{}", err),
+        })?;
+
+    let json = serde_json::to_string_pretty(&*indexes.lock().unwrap()).unwrap();
+    match command.out {
+        Some(out) => fs::write(&out, json).map_err(|err| Error::WriteError {
+            path: out,
+            details: format!("{}", err),
+        })?,
+        None => println!("{}", json),
+    }
+
+    Ok(())
+}
+
+async fn handle_deprecations_command(command: DeprecationsCommand) -> Result<(), Error> {
+    configure_logger(command.output, TerminalMode::Stderr);
+
+    let mut config = get_config(command.config)?;
+
+    set_project_flag(&mut config, command.projects)?;
+
+    // Usages should be listed even if the policy would fail the build.
+    for project_config in config.projects.values_mut() {
+        project_config.deprecation_config = None;
+    }
+    config.artifact_writer = Box::new(NoopArtifactWriter);
+    config.file_source_config = if should_use_watchman() {
+        FileSourceKind::Watchman
+    } else {
+        FileSourceKind::WalkDir
+    };
+
+    let usages: Arc<Mutex<BTreeMap<String, Vec<DeprecatedUsage>>>> = Default::default();
+    let project_usages = Arc::clone(&usages);
+    config.generate_extra_artifacts = Some(Box::new(
+        move |_config, project_config, _schema, programs, _artifacts| {
+            project_usages.lock().unwrap().insert(
+                project_config.name.to_string(),
+                deprecated_usages(&programs.source).unwrap_or_default(),
+            );
+            vec![]
+        },
+    ));
+
+    let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+    compiler
+        .compile()
+        .await
+        .map_err(|err| Error::CompilerError {
+            details: format!("{}", err),
+        })?;
+
+    let usages = usages.lock().unwrap();
+    if command.allowlist {
+        let entries: BTreeSet<_> = usages
+            .values()
+            .flatten()
+            .map(|usage| format!("{} {}", usage.coordinate, usage.definition))
+            .collect();
+        for entry in entries {
+            println!("{}", entry);
+        }
+        return Ok(());
+    }
+
+    for (project_name, usages) in usages.iter() {
+        let mut counts: BTreeMap<&str, (usize, &DeprecatedUsage)> = BTreeMap::new();
+        for usage in usages {
+            counts.entry(&usage.coordinate).or_insert((0, usage)).0 += 1;
+        }
+        let mut counts: Vec<_> = counts.into_iter().collect();
+        counts.sort_by(|(_, (a, _)), (_, (b, _))| b.cmp(a));
+
+        println!("{}: {} deprecated usage(s)", project_name, usages.len());
+        for (coordinate, (count, usage)) in counts {
+            let removal_date = usage
+                .removal_date
+                .as_ref()
+                .map_or_else(String::new, |date| format!(" (removal date: {})", date));
+            let reason = usage
+                .reason
+                .map_or_else(String::new, |reason| format!(" {}", reason));
+            println!("{:>8}  {}{}{}", count, coordinate, removal_date, reason);
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_codemod_command(command: CodemodCommand) -> Result<(), Error> {
+    configure_logger(command.output, TerminalMode::Stderr);
+
+    let mut config = get_config(command.config)?;
+
+    set_project_flag(&mut config, command.projects)?;
+
+    config.artifact_writer = Box::new(NoopArtifactWriter);
+    config.file_source_config = if should_use_watchman() {
+        FileSourceKind::Watchman
+    } else {
+        FileSourceKind::WalkDir
+    };
+
+    // The files to rewrite are the sources of the definitions in the programs,
+    // so only the files of the selected projects are changed.
+    let source_paths: Arc<Mutex<BTreeSet<PathBuf>>> = Default::default();
+    let project_source_paths = Arc::clone(&source_paths);
+    config.generate_extra_artifacts = Some(Box::new(
+        move |_config, _project_config, _schema, programs, _artifacts| {
+            let source_locations = programs
+                .source
+                .fragments()
+                .map(|fragment| fragment.name.location.source_location())
+                .chain(
+                    programs
+                        .source
+                        .operations()
+                        .map(|operation| operation.name.location.source_location()),
+                );
+            let mut source_paths = project_source_paths.lock().unwrap();
+            for source_location in source_locations {
+                if !source_location.is_generated() {
+                    source_paths.insert(PathBuf::from(source_location.path()));
+                }
+            }
+            vec![]
+        },
+    ));
+
+    let root_dir = config.root_dir.clone();
+    let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+    compiler
+        .compile()
+        .await
+        .map_err(|err| Error::CompilerError {
+            details: format!("{}", err),
+        })?;
+
+    let source_paths = source_paths.lock().unwrap();
+    let mut rewritten_count = 0;
+    for source_path in source_paths.iter() {
+        let path = root_dir.join(source_path);
+        let source = fs::read_to_string(&path).map_err(|err| Error::ReadError {
+            path: path.clone(),
+            details: format!("{}", err),
+        })?;
+        let is_graphql_file = matches!(
+            path.extension().and_then(|extension| extension.to_str()),
+            Some("graphql" | "gql")
+        );
+        let rewritten = match command.codemod {
+            CodemodKind::FragmentArguments => rewrite_fragment_arguments(&source, is_graphql_file),
+        };
+        if let Some(rewritten) = rewritten {
+            fs::write(&path, rewritten).map_err(|err| Error::WriteError {
+                path: path.clone(),
+                details: format!("{}", err),
+            })?;
+            info!("Rewrote {}", source_path.display());
+            rewritten_count += 1;
+        }
+    }
+    info!(
+        "Rewrote {} of {} file(s).",
+        rewritten_count,
+        source_paths.len()
+    );
+
+    Ok(())
+}
+
 struct ExtraDataProvider {
     locate_command: String,
 }
@@ -125,6 +125,7 @@ pub enum Primitive {
         graphql_module_path: StringKey,
         js_module: JSModuleDependency,
         injected_field_name_details: Option<(StringKey, bool)>,
+        batch: bool,
     },
     RelayResolverWeakObjectWrapper {
         resolver: Box<Primitive>,
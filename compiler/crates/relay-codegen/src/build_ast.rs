@@ -46,17 +46,21 @@ use relay_transforms::relay_resolvers::get_resolver_info;
 use relay_transforms::relay_resolvers::resolver_import_alias;
 use relay_transforms::relay_resolvers::ResolverInfo;
 use relay_transforms::remove_directive;
+use relay_transforms::CatchMetadataDirective;
 use relay_transforms::ClientEdgeMetadata;
 use relay_transforms::ClientEdgeMetadataDirective;
 use relay_transforms::ClientExtensionAbstractTypeMetadataDirective;
 use relay_transforms::ConnectionConstants;
 use relay_transforms::ConnectionMetadata;
 use relay_transforms::DeferDirective;
+use relay_transforms::DependentOperation;
+use relay_transforms::DependentOperationsMetadata;
 use relay_transforms::FragmentAliasMetadata;
 use relay_transforms::FragmentDataInjectionMode;
 use relay_transforms::InlineDirectiveMetadata;
 use relay_transforms::ModuleMetadata;
 use relay_transforms::NoInlineFragmentSpreadMetadata;
+use relay_transforms::PaginationMetadataDirective;
 use relay_transforms::RefetchableMetadata;
 use relay_transforms::RelayDirective;
 use relay_transforms::RelayResolverMetadata;
@@ -547,6 +551,33 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
                     ),
                 operation: Primitive::GraphQLModuleDependency(GraphQLModuleDependency::Name(refetch_metadata.operation_name.into())),
             };
+            if let Some(PaginationMetadataDirective(pagination_metadata)) =
+                PaginationMetadataDirective::find(&fragment.directives)
+            {
+                let metadata = &pagination_metadata[0]; // Validated in `transform_refetchable`
+                let pagination_object = object! {
+                    count: Primitive::string_or_null(metadata.count),
+                    cursor: Primitive::string_or_null(metadata.cursor),
+                    items: Primitive::String(metadata.items),
+                    kind: Primitive::String(metadata.kind.into()),
+                    path: Primitive::Key(
+                        self.array(
+                            metadata
+                                .path
+                                .as_ref()
+                                .expect("Expected path to exist")
+                                .iter()
+                                .cloned()
+                                .map(Primitive::String)
+                                .collect(),
+                        ),
+                    ),
+                };
+                refetch_object.push(ObjectEntry {
+                    key: CODEGEN_CONSTANTS.pagination,
+                    value: Primitive::Key(self.object(pagination_object)),
+                });
+            }
             if let Some(identifier_info) = &refetch_metadata.identifier_info {
                 refetch_object.push(ObjectEntry {
                     key: CODEGEN_CONSTANTS.identifier_info,
@@ -964,6 +995,19 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
         }))
     }
 
+    fn build_catch_field(
+        &mut self,
+        catch_metadata: &CatchMetadataDirective,
+        primitive: Primitive,
+    ) -> Primitive {
+        Primitive::Key(self.object(object! {
+            kind: Primitive::String(CODEGEN_CONSTANTS.catch_field),
+            field: primitive,
+            to: Primitive::String(catch_metadata.to.into()),
+            path: Primitive::String(catch_metadata.path),
+        }))
+    }
+
     fn build_scalar_field(&mut self, field: &ScalarField) -> Primitive {
         let schema_field = self.schema.field(field.definition.item);
         let (name, alias) =
@@ -991,6 +1035,8 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
 
         if let Some(required_metadata) = RequiredMetadataDirective::find(&field.directives) {
             self.build_required_field(required_metadata, primitive)
+        } else if let Some(catch_metadata) = CatchMetadataDirective::find(&field.directives) {
+            self.build_catch_field(catch_metadata, primitive)
         } else {
             primitive
         }
@@ -1089,6 +1135,8 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
 
         if let Some(required_metadata) = RequiredMetadataDirective::find(&field.directives) {
             self.build_required_field(required_metadata, primitive)
+        } else if let Some(catch_metadata) = CatchMetadataDirective::find(&field.directives) {
+            self.build_catch_field(catch_metadata, primitive)
         } else {
             primitive
         }
@@ -1202,7 +1250,7 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
         if let Some(fragment_alias_metadata) = FragmentAliasMetadata::find(&frag_spread.directives)
         {
             let type_condition = fragment_alias_metadata.type_condition;
-            Primitive::Key(self.object(object! {
+            let aliased_fragment_spread = Primitive::Key(self.object(object! {
                 fragment: primitive,
                 kind: Primitive::String(CODEGEN_CONSTANTS.aliased_fragment_spread),
                 name: Primitive::String(fragment_alias_metadata.alias.item),
@@ -1216,7 +1264,18 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
                         t,
                     ))
                 }),
-            }))
+            }));
+            if let Some(required_metadata) =
+                RequiredMetadataDirective::find(&frag_spread.directives)
+            {
+                self.build_required_field(required_metadata, aliased_fragment_spread)
+            } else if let Some(catch_metadata) =
+                CatchMetadataDirective::find(&frag_spread.directives)
+            {
+                self.build_catch_field(catch_metadata, aliased_fragment_spread)
+            } else {
+                aliased_fragment_spread
+            }
         } else if let Some(resolver_metadata) = RelayResolverMetadata::find(&frag_spread.directives)
         {
             let resolver_primitive = match self.variant {
@@ -1265,6 +1324,7 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
             field_path: path,
             field_arguments: vec![], // The model resolver field does not take GraphQL arguments.
             live: is_model_live,
+            batch: false,
             output_type_info: relay_resolver_metadata.output_type_info.clone(),
             fragment_data_injection_mode: Some((
                 WithLocation::new(
@@ -1370,6 +1430,7 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
                         Some((name, is_required))
                     }
                 },
+                batch: relay_resolver_metadata.batch,
             }
         } else {
             Primitive::JSModuleDependency(resolver_js_module)
@@ -1412,6 +1473,13 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
             path: Primitive::String(path),
         };
 
+        if relay_resolver_metadata.batch {
+            object_props.push(ObjectEntry {
+                key: CODEGEN_CONSTANTS.batch,
+                value: Primitive::Bool(true),
+            })
+        }
+
         if let ResolverOutputTypeInfo::Composite(normalization_info) =
             &relay_resolver_metadata.output_type_info
         {
@@ -1893,11 +1961,18 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
                 if let Some(fragment_alias_metadata) =
                     FragmentAliasMetadata::find(&inline_frag.directives)
                 {
-                    Primitive::Key(self.object(object! {
+                    let aliased_inline_fragment = Primitive::Key(self.object(object! {
                         fragment: primitive,
                         kind: Primitive::String(CODEGEN_CONSTANTS.aliased_inline_fragment_spread),
                         name: Primitive::String(fragment_alias_metadata.alias.item),
-                    }))
+                    }));
+                    if let Some(required_metadata) =
+                        RequiredMetadataDirective::find(&inline_frag.directives)
+                    {
+                        self.build_required_field(required_metadata, aliased_inline_fragment)
+                    } else {
+                        aliased_inline_fragment
+                    }
                 } else {
                     primitive
                 }
@@ -2286,6 +2361,12 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
             metadata_items.push(self.build_connection_metadata(connection_metadata))
         }
 
+        if let Some(DependentOperationsMetadata(dependent_operations)) =
+            DependentOperationsMetadata::find(&operation.directives)
+        {
+            metadata_items.push(self.build_dependent_operations_metadata(dependent_operations))
+        }
+
         // sort metadata keys
         metadata_items.sort_unstable_by_key(|entry| entry.key);
 
@@ -2352,6 +2433,38 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
         self.object(params_object)
     }
 
+    fn build_dependent_operations_metadata(
+        &mut self,
+        dependent_operations: &[DependentOperation],
+    ) -> ObjectEntry {
+        let array = dependent_operations
+            .iter()
+            .map(|dependent_operation| {
+                let exported_variables = dependent_operation
+                    .exported_variables
+                    .iter()
+                    .map(|(variable_name, path)| ObjectEntry {
+                        key: variable_name.0,
+                        value: Primitive::Key(
+                            self.array(path.iter().copied().map(Primitive::String).collect()),
+                        ),
+                    })
+                    .collect();
+                let object = object! {
+                    exported_variables: Primitive::Key(self.object(exported_variables)),
+                    operation: Primitive::GraphQLModuleDependency(GraphQLModuleDependency::Name(
+                        dependent_operation.operation_name.into(),
+                    )),
+                };
+                Primitive::Key(self.object(object))
+            })
+            .collect();
+        ObjectEntry {
+            key: CODEGEN_CONSTANTS.dependent_operations,
+            value: Primitive::Key(self.array(array)),
+        }
+    }
+
     fn build_actor_change(
         &mut self,
         context: &mut ContextualMetadata,
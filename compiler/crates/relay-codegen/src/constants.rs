@@ -19,7 +19,9 @@ pub struct CodegenConstants {
     pub args: StringKey,
     pub argument_definitions: StringKey,
     pub backward: StringKey,
+    pub batch: StringKey,
     pub cache_id: StringKey,
+    pub catch_field: StringKey,
     pub client_abstract_types: StringKey,
     pub client_edge_backing_field_key: StringKey,
     pub client_edge_model_resolver: StringKey,
@@ -37,11 +39,13 @@ pub struct CodegenConstants {
     pub default_handle_key: StringKey,
     pub default_value: StringKey,
     pub defer: StringKey,
+    pub dependent_operations: StringKey,
     pub derived_from: StringKey,
     pub direction: StringKey,
     pub document_name: StringKey,
     pub dynamic_key_argument: StringKey,
     pub dynamic_key: StringKey,
+    pub exported_variables: StringKey,
     pub field: StringKey,
     pub fields: StringKey,
     pub filters: StringKey,
@@ -87,6 +91,7 @@ pub struct CodegenConstants {
     pub operation_module_provider: StringKey,
     pub operation_value: StringKey,
     pub operation: StringKey,
+    pub pagination: StringKey,
     pub params: StringKey,
     pub passing_value: StringKey,
     pub path: StringKey,
@@ -115,6 +120,7 @@ pub struct CodegenConstants {
     pub stream: StringKey,
     pub subscription: StringKey,
     pub text: StringKey,
+    pub to: StringKey,
     pub type_: StringKey,
     pub type_discriminator: StringKey,
     pub updatable_query: StringKey,
@@ -134,7 +140,9 @@ lazy_static! {
         args: "args".intern(),
         argument_definitions: "argumentDefinitions".intern(),
         backward: "backward".intern(),
+        batch: "batch".intern(),
         cache_id: "cacheID".intern(),
+        catch_field: "CatchField".intern(),
         client_abstract_types: "clientAbstractTypes".intern(),
         client_edge_backing_field_key: "backingField".intern(),
         client_edge_model_resolver: "modelResolver".intern(),
@@ -152,11 +160,13 @@ lazy_static! {
         default_handle_key: "".intern(),
         default_value: "defaultValue".intern(),
         defer: "Defer".intern(),
+        dependent_operations: "dependentOperations".intern(),
         derived_from: "derivedFrom".intern(),
         direction: "direction".intern(),
         document_name: "documentName".intern(),
         dynamic_key_argument: "__dynamicKey".intern(),
         dynamic_key: "dynamicKey".intern(),
+        exported_variables: "exportedVariables".intern(),
         field: "field".intern(),
         fields: "fields".intern(),
         filters: "filters".intern(),
@@ -202,6 +212,7 @@ lazy_static! {
         operation_module_provider: "operationModuleProvider".intern(),
         operation_value: "Operation".intern(),
         operation: "operation".intern(),
+        pagination: "pagination".intern(),
         params: "params".intern(),
         passing_value: "passingValue".intern(),
         path: "path".intern(),
@@ -230,6 +241,7 @@ lazy_static! {
         stream: "Stream".intern(),
         subscription: "subscription".intern(),
         text: "text".intern(),
+        to: "to".intern(),
         type_: "type".intern(),
         type_discriminator: "TypeDiscriminator".intern(),
         updatable_query: "UpdatableQuery".intern(),
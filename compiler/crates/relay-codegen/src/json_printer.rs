@@ -0,0 +1,183 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use graphql_ir::ExecutableDefinitionName;
+use relay_config::ProjectConfig;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::ast::Ast;
+use crate::ast::AstBuilder;
+use crate::ast::AstKey;
+use crate::ast::GraphQLModuleDependency;
+use crate::ast::JSModuleDependency;
+use crate::ast::ModuleImportName;
+use crate::ast::ObjectEntry;
+use crate::ast::Primitive;
+use crate::ast::ResolverModuleReference;
+use crate::printer::write_static_storage_key;
+
+/// Converts ASTs to JSON values, for runtimes that read artifacts as data
+/// rather than import them as JavaScript modules. Module imports, which have
+/// no JSON equivalent, are described by objects naming the module, e.g.
+/// `{"graphqlModule": "MyQuery"}`.
+pub(crate) struct JSONValuePrinter<'b> {
+    builder: &'b AstBuilder,
+    skip_printing_nulls: bool,
+}
+
+impl<'b> JSONValuePrinter<'b> {
+    pub(crate) fn new(builder: &'b AstBuilder, project_config: &ProjectConfig) -> Self {
+        Self {
+            builder,
+            skip_printing_nulls: project_config
+                .feature_flags
+                .skip_printing_nulls
+                .is_fully_enabled(),
+        }
+    }
+
+    pub(crate) fn print(&self, key: AstKey) -> Value {
+        match self.builder.lookup(key) {
+            Ast::Object(object) => Value::Object(
+                object
+                    .iter()
+                    .filter(|ObjectEntry { value, .. }| !self.is_skipped(value))
+                    .map(|ObjectEntry { key, value }| {
+                        (key.to_string(), self.print_primitive(value))
+                    })
+                    .collect(),
+            ),
+            Ast::Array(array) => Value::Array(
+                array
+                    .iter()
+                    .filter(|value| !self.is_skipped(value))
+                    .map(|value| self.print_primitive(value))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn is_skipped(&self, primitive: &Primitive) -> bool {
+        self.skip_printing_nulls && matches!(primitive, Primitive::SkippableNull)
+    }
+
+    fn print_primitive(&self, primitive: &Primitive) -> Value {
+        match primitive {
+            Primitive::Null | Primitive::SkippableNull => Value::Null,
+            Primitive::Bool(b) => Value::Bool(*b),
+            Primitive::RawString(str) => Value::String(str.clone()),
+            // The JavaScript printer writes these between quotes as is, so
+            // they keep their escape sequences.
+            Primitive::String(key) => parse_json_string(&format!("\"{}\"", key))
+                .unwrap_or_else(|| Value::String(key.to_string())),
+            Primitive::Float(value) => json!(value.as_float()),
+            Primitive::Int(value) => json!(value),
+            Primitive::Variable(variable_name) => {
+                panic!(
+                    "Unexpected variable `{}` in an AST printed without dedupe",
+                    variable_name
+                )
+            }
+            Primitive::Key(key) => self.print(*key),
+            Primitive::StorageKey(field_name, key) => {
+                let mut storage_key = String::new();
+                write_static_storage_key(&mut storage_key, self.builder, *field_name, *key)
+                    .unwrap();
+                parse_json_string(&storage_key).expect("Expected a valid storage key string")
+            }
+            Primitive::GraphQLModuleDependency(dependency) => {
+                let module = match dependency {
+                    GraphQLModuleDependency::Name(
+                        ExecutableDefinitionName::OperationDefinitionName(name),
+                    ) => name.0,
+                    GraphQLModuleDependency::Name(
+                        ExecutableDefinitionName::FragmentDefinitionName(name),
+                    ) => name.0,
+                    GraphQLModuleDependency::Path { path, .. } => *path,
+                };
+                json!({ "graphqlModule": module.to_string() })
+            }
+            Primitive::JSModuleDependency(js_module) => js_module_value(js_module),
+            Primitive::ResolverModuleReference(ResolverModuleReference {
+                field_type,
+                resolver_function_name,
+            }) => json!({
+                "resolverFunctionName": import_name(resolver_function_name),
+                "fieldType": field_type.to_string(),
+            }),
+            Primitive::DynamicImport { module, .. } => {
+                json!({ "dynamicImport": module.to_string() })
+            }
+            Primitive::RelayResolverModel {
+                graphql_module_path,
+                js_module,
+                injected_field_name_details,
+                batch,
+                ..
+            } => {
+                let mut model = Map::new();
+                model.insert(
+                    "graphqlModule".to_string(),
+                    Value::String(graphql_module_path.to_string()),
+                );
+                model.insert("resolver".to_string(), js_module_value(js_module));
+                if let Some((field_name, is_required_field)) = injected_field_name_details {
+                    model.insert(
+                        "injectedFieldName".to_string(),
+                        Value::String(field_name.to_string()),
+                    );
+                    model.insert(
+                        "isRequiredField".to_string(),
+                        Value::Bool(*is_required_field),
+                    );
+                }
+                if *batch {
+                    model.insert("batch".to_string(), Value::Bool(true));
+                }
+                json!({ "resolverModel": model })
+            }
+            Primitive::RelayResolverWeakObjectWrapper {
+                resolver,
+                key,
+                plural,
+                live,
+            } => json!({
+                "weakObjectWrapper": {
+                    "resolver": self.print_primitive(resolver),
+                    "key": key.to_string(),
+                    "plural": plural,
+                    "live": live,
+                }
+            }),
+        }
+    }
+}
+
+fn js_module_value(
+    JSModuleDependency {
+        path,
+        import_name: name,
+    }: &JSModuleDependency,
+) -> Value {
+    json!({
+        "jsModule": path.to_string(),
+        "importName": import_name(name),
+    })
+}
+
+fn import_name(import_name: &ModuleImportName) -> Value {
+    match import_name {
+        ModuleImportName::Default(_) => Value::String("default".to_string()),
+        ModuleImportName::Named { name, .. } => Value::String(name.to_string()),
+    }
+}
+
+fn parse_json_string(str: &str) -> Option<Value> {
+    serde_json::from_str::<String>(str).ok().map(Value::String)
+}
@@ -13,7 +13,9 @@ mod ast;
 mod build_ast;
 mod constants;
 mod indentation;
+mod json_printer;
 pub mod printer;
+mod shared_ast_modules;
 mod top_level_statements;
 mod utils;
 
@@ -35,4 +37,6 @@ pub use printer::print_request_params;
 pub use printer::JSONPrinter;
 pub use printer::Printer;
 pub use relay_config::JsModuleFormat;
+pub use shared_ast_modules::SharedAstModule;
+pub use shared_ast_modules::SharedAsts;
 pub use top_level_statements::TopLevelStatement;
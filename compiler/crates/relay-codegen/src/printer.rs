@@ -9,10 +9,15 @@ use std::borrow::Borrow;
 use std::borrow::Cow;
 use std::fmt::Result as FmtResult;
 use std::fmt::Write;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::path::Path;
+use std::path::PathBuf;
 
 use fnv::FnvBuildHasher;
+use fnv::FnvHashMap;
 use fnv::FnvHashSet;
+use fnv::FnvHasher;
 use graphql_ir::reexport::Intern;
 use graphql_ir::ExecutableDefinitionName;
 use graphql_ir::FragmentDefinition;
@@ -20,9 +25,11 @@ use graphql_ir::OperationDefinition;
 use indexmap::IndexMap;
 use intern::string_key::StringKey;
 use intern::Lookup;
+use relay_config::esm_source_import_specifier;
 use relay_config::DynamicModuleProvider;
 use relay_config::ProjectConfig;
 use schema::SDLSchema;
+use serde_json::Value as JsonValue;
 
 use crate::ast::Ast;
 use crate::ast::AstBuilder;
@@ -43,9 +50,15 @@ use crate::build_ast::build_request;
 use crate::build_ast::build_request_params;
 use crate::build_ast::build_request_params_ast_key;
 use crate::build_ast::build_resolvers_schema;
+use crate::build_ast::md5;
 use crate::constants::CODEGEN_CONSTANTS;
 use crate::indentation::print_indentation;
+use crate::json_printer::JSONValuePrinter;
 use crate::object;
+use crate::shared_ast_modules::shared_ast_module_name;
+use crate::shared_ast_modules::shared_ast_module_path;
+use crate::shared_ast_modules::SharedAstModule;
+use crate::shared_ast_modules::SharedAsts;
 use crate::top_level_statements::TopLevelStatement;
 use crate::top_level_statements::TopLevelStatements;
 use crate::utils::escape;
@@ -130,6 +143,8 @@ pub struct Printer<'p> {
     project_config: &'p ProjectConfig,
     builder: AstBuilder,
     dedupe: bool,
+    shared_asts: SharedAsts,
+    shared_ast_modules: Vec<SharedAstModule>,
 }
 
 impl<'p> Printer<'p> {
@@ -138,6 +153,8 @@ impl<'p> Printer<'p> {
             project_config,
             builder: Default::default(),
             dedupe: true,
+            shared_asts: Default::default(),
+            shared_ast_modules: Default::default(),
         }
     }
 
@@ -146,9 +163,51 @@ impl<'p> Printer<'p> {
             project_config,
             builder: Default::default(),
             dedupe: false,
+            shared_asts: Default::default(),
+            shared_ast_modules: Default::default(),
         }
     }
 
+    /// Sets which subtrees are hoisted into shared modules, if
+    /// `ProjectConfig::shared_ast_modules` is enabled. By default, none are.
+    pub fn with_shared_asts(mut self, shared_asts: SharedAsts) -> Self {
+        self.shared_asts = shared_asts;
+        self
+    }
+
+    /// Returns the shared modules imported by the ASTs printed since the
+    /// last call, if `ProjectConfig::shared_ast_modules` is enabled.
+    pub fn take_shared_ast_modules(&mut self) -> Vec<SharedAstModule> {
+        std::mem::take(&mut self.shared_ast_modules)
+    }
+
+    /// Returns the hashes of the subtrees large enough to be hoisted into
+    /// shared modules of the ASTs printed since the last call, when
+    /// collecting them with `SharedAsts::Collect`.
+    pub fn take_shared_ast_hashes(&mut self) -> FnvHashSet<u64> {
+        match &mut self.shared_asts {
+            SharedAsts::Collect(hashes) => std::mem::take(hashes),
+            SharedAsts::Hoist(_) => Default::default(),
+        }
+    }
+
+    /// A printer for the normalization or reader AST of the artifact at
+    /// `artifact_path`, which hoists subtrees into shared modules if they
+    /// are enabled.
+    fn ast_printer<'b>(
+        &'b mut self,
+        artifact_path: PathBuf,
+        top_level_statements: &'b mut TopLevelStatements,
+    ) -> JSONPrinter<'b> {
+        JSONPrinter::new(&self.builder, self.project_config, top_level_statements)
+            .with_shared_ast_modules(
+                self.project_config,
+                artifact_path,
+                &mut self.shared_asts,
+                &mut self.shared_ast_modules,
+            )
+    }
+
     pub fn print_provided_variables(
         &mut self,
         schema: &SDLSchema,
@@ -177,6 +236,31 @@ impl<'p> Printer<'p> {
         schema: &SDLSchema,
         fragment: &FragmentDefinition,
     ) -> String {
+        let artifact_path = self
+            .project_config
+            .artifact_path_for_definition(fragment.name);
+        let key = self.build_updatable_query(schema, fragment);
+
+        let mut top_level_statements = Default::default();
+        let dedupe = self.dedupe;
+        let printer = self.ast_printer(artifact_path, &mut top_level_statements);
+        printer.print(key, dedupe)
+    }
+
+    pub fn print_updatable_query_json(
+        &mut self,
+        schema: &SDLSchema,
+        fragment: &FragmentDefinition,
+    ) -> JsonValue {
+        let key = self.build_updatable_query(schema, fragment);
+        JSONValuePrinter::new(&self.builder, self.project_config).print(key)
+    }
+
+    fn build_updatable_query(
+        &mut self,
+        schema: &SDLSchema,
+        fragment: &FragmentDefinition,
+    ) -> AstKey {
         let mut fragment_builder = CodegenBuilder::new(
             schema,
             CodegenVariant::Reader,
@@ -185,18 +269,10 @@ impl<'p> Printer<'p> {
             fragment.name.map(|x| x.0),
         );
         let fragment = Primitive::Key(fragment_builder.build_fragment(fragment, true));
-        let key = self.builder.intern(Ast::Object(object! {
+        self.builder.intern(Ast::Object(object! {
             fragment: fragment,
             kind: Primitive::String(CODEGEN_CONSTANTS.updatable_query),
-        }));
-
-        let mut top_level_statements = Default::default();
-        let printer = JSONPrinter::new(
-            &self.builder,
-            self.project_config,
-            &mut top_level_statements,
-        );
-        printer.print(key, self.dedupe)
+        }))
     }
 
     pub fn print_request(
@@ -207,6 +283,34 @@ impl<'p> Printer<'p> {
         request_parameters: RequestParameters<'_>,
         top_level_statements: &mut TopLevelStatements,
     ) -> String {
+        let key = self.build_request(schema, operation, fragment, request_parameters);
+        let artifact_path = self
+            .project_config
+            .artifact_path_for_definition(operation.name);
+        let dedupe = self.dedupe;
+        let printer = self.ast_printer(artifact_path, top_level_statements);
+        printer.print(key, dedupe)
+    }
+
+    /// The `ConcreteRequest` of `operation` as JSON, with its params inline.
+    pub fn print_request_json(
+        &mut self,
+        schema: &SDLSchema,
+        operation: &OperationDefinition,
+        fragment: &FragmentDefinition,
+        request_parameters: RequestParameters<'_>,
+    ) -> JsonValue {
+        let key = self.build_request(schema, operation, fragment, request_parameters);
+        JSONValuePrinter::new(&self.builder, self.project_config).print(key)
+    }
+
+    fn build_request(
+        &mut self,
+        schema: &SDLSchema,
+        operation: &OperationDefinition,
+        fragment: &FragmentDefinition,
+        request_parameters: RequestParameters<'_>,
+    ) -> AstKey {
         let request_parameters = build_request_params_ast_key(
             schema,
             request_parameters,
@@ -215,7 +319,7 @@ impl<'p> Printer<'p> {
             operation.name.map(|x| x.0),
             self.project_config,
         );
-        let key = build_request(
+        build_request(
             schema,
             &mut self.builder,
             operation,
@@ -223,9 +327,7 @@ impl<'p> Printer<'p> {
             request_parameters,
             fragment.name.map(|x| x.0),
             self.project_config,
-        );
-        let printer = JSONPrinter::new(&self.builder, self.project_config, top_level_statements);
-        printer.print(key, self.dedupe)
+        )
     }
 
     pub fn print_preloadable_request(
@@ -261,8 +363,27 @@ impl<'p> Printer<'p> {
             operation.name.map(|x| x.0),
             self.project_config,
         );
-        let printer = JSONPrinter::new(&self.builder, self.project_config, top_level_statements);
-        printer.print(key, self.dedupe)
+        let artifact_path = self
+            .project_config
+            .artifact_path_for_definition(operation.name);
+        let dedupe = self.dedupe;
+        let printer = self.ast_printer(artifact_path, top_level_statements);
+        printer.print(key, dedupe)
+    }
+
+    pub fn print_operation_json(
+        &mut self,
+        schema: &SDLSchema,
+        operation: &OperationDefinition,
+    ) -> JsonValue {
+        let key = build_operation(
+            schema,
+            &mut self.builder,
+            operation,
+            operation.name.map(|x| x.0),
+            self.project_config,
+        );
+        JSONValuePrinter::new(&self.builder, self.project_config).print(key)
     }
 
     pub fn print_fragment(
@@ -278,8 +399,27 @@ impl<'p> Printer<'p> {
             fragment.name.map(|x| x.0),
             self.project_config,
         );
-        let printer = JSONPrinter::new(&self.builder, self.project_config, top_level_statements);
-        printer.print(key, self.dedupe)
+        let artifact_path = self
+            .project_config
+            .artifact_path_for_definition(fragment.name);
+        let dedupe = self.dedupe;
+        let printer = self.ast_printer(artifact_path, top_level_statements);
+        printer.print(key, dedupe)
+    }
+
+    pub fn print_fragment_json(
+        &mut self,
+        schema: &SDLSchema,
+        fragment: &FragmentDefinition,
+    ) -> JsonValue {
+        let key = build_fragment(
+            schema,
+            &mut self.builder,
+            fragment,
+            fragment.name.map(|x| x.0),
+            self.project_config,
+        );
+        JSONValuePrinter::new(&self.builder, self.project_config).print(key)
     }
 
     pub fn print_request_params(
@@ -301,6 +441,23 @@ impl<'p> Printer<'p> {
         printer.print(key, self.dedupe)
     }
 
+    pub fn print_request_params_json(
+        &mut self,
+        schema: &SDLSchema,
+        request_parameters: RequestParameters<'_>,
+        operation: &OperationDefinition,
+    ) -> JsonValue {
+        let key = build_request_params_ast_key(
+            schema,
+            request_parameters,
+            &mut self.builder,
+            operation,
+            operation.name.map(|x| x.0),
+            self.project_config,
+        );
+        JSONValuePrinter::new(&self.builder, self.project_config).print(key)
+    }
+
     pub fn print_resolvers_schema(
         &mut self,
         schema: &SDLSchema,
@@ -320,8 +477,44 @@ pub struct JSONPrinter<'b> {
     builder: &'b AstBuilder,
     eager_es_modules: bool,
     js_module_format: JsModuleFormat,
+    esm_import_extension: Option<String>,
     top_level_statements: &'b mut TopLevelStatements,
     skip_printing_nulls: bool,
+    shared_ast_modules: Option<SharedAstModules<'b>>,
+}
+
+/// State for hoisting subtrees into modules shared between artifacts.
+struct SharedAstModules<'b> {
+    project_config: &'b ProjectConfig,
+    /// The path of the artifact or shared module being printed.
+    artifact_path: PathBuf,
+    directory: PathBuf,
+    min_size: usize,
+    shared_asts: &'b mut SharedAsts,
+    modules: &'b mut Vec<SharedAstModule>,
+    nodes: FnvHashMap<AstKey, SharedAstNode>,
+}
+
+impl SharedAstModules<'_> {
+    fn is_large(&self, node: SharedAstNode) -> bool {
+        node.size.is_some_and(|size| size >= self.min_size)
+    }
+
+    fn is_hoisted(&self, node: SharedAstNode) -> bool {
+        match &self.shared_asts {
+            SharedAsts::Collect(_) => false,
+            SharedAsts::Hoist(hashes) => self.is_large(node) && hashes.contains(&node.hash),
+        }
+    }
+}
+
+/// See `shared_ast_node`.
+#[derive(Clone, Copy)]
+struct SharedAstNode {
+    /// The number of objects and arrays of the subtree, not counting the
+    /// ones of hoisted subtrees, or `None` if it can't be shared.
+    size: Option<usize>,
+    hash: u64,
 }
 
 impl<'b> JSONPrinter<'b> {
@@ -336,12 +529,41 @@ impl<'b> JSONPrinter<'b> {
             duplicates: Default::default(),
             builder,
             js_module_format: project_config.js_module_format,
-            eager_es_modules: project_config.typegen_config.eager_es_modules,
+            esm_import_extension: project_config.esm_import_extension().map(str::to_string),
+            eager_es_modules: project_config.is_es_module(),
             skip_printing_nulls: project_config
                 .feature_flags
                 .skip_printing_nulls
                 .is_fully_enabled(),
+            shared_ast_modules: None,
+        }
+    }
+
+    /// Hoists the subtrees selected by `shared_asts` into shared modules,
+    /// collected in `modules`, if `ProjectConfig::shared_ast_modules` is
+    /// enabled.
+    pub fn with_shared_ast_modules(
+        mut self,
+        project_config: &'b ProjectConfig,
+        artifact_path: PathBuf,
+        shared_asts: &'b mut SharedAsts,
+        modules: &'b mut Vec<SharedAstModule>,
+    ) -> Self {
+        if let (Some(config), Some(directory)) = (
+            &project_config.shared_ast_modules,
+            project_config.shared_ast_modules_path(),
+        ) {
+            self.shared_ast_modules = Some(SharedAstModules {
+                project_config,
+                artifact_path,
+                directory,
+                min_size: config.min_size,
+                shared_asts,
+                modules,
+                nodes: Default::default(),
+            });
         }
+        self
     }
 
     pub fn print(mut self, root_key: AstKey, dedupe: bool) -> String {
@@ -384,6 +606,9 @@ impl<'b> JSONPrinter<'b> {
     /// v0 = {b: 2};
     /// v1 = [{a: 1}, v0];
     fn collect_value_duplicates(&mut self, visited: &mut FnvHashSet<AstKey>, key: AstKey) {
+        if self.is_shared_ast(key) {
+            return;
+        }
         match self.builder.lookup(key) {
             Ast::Array(array) => {
                 if array.is_empty() {
@@ -416,7 +641,65 @@ impl<'b> JSONPrinter<'b> {
         }
     }
 
+    /// Whether the subtree `key` is hoisted into a shared module. When
+    /// collecting the subtrees which could be hoisted, records it instead.
+    fn is_shared_ast(&mut self, key: AstKey) -> bool {
+        match &mut self.shared_ast_modules {
+            Some(shared_ast_modules) => {
+                let node = shared_ast_node(self.builder, shared_ast_modules, key);
+                if shared_ast_modules.is_large(node) {
+                    if let SharedAsts::Collect(hashes) = shared_ast_modules.shared_asts {
+                        hashes.insert(node.hash);
+                    }
+                }
+                shared_ast_modules.is_hoisted(node)
+            }
+            None => false,
+        }
+    }
+
+    /// Prints the subtree `key` into a shared module named after the hash of
+    /// its content, and writes the import of the module.
+    fn print_shared_ast(&mut self, f: &mut String, key: AstKey) -> FmtResult {
+        let shared_ast_modules = self.shared_ast_modules.take().unwrap();
+        let mut top_level_statements = Default::default();
+        // Shared modules are in the same directory, so the modules they
+        // import don't depend on their name.
+        let node = JSONPrinter::new(
+            self.builder,
+            shared_ast_modules.project_config,
+            &mut top_level_statements,
+        )
+        .with_shared_ast_modules(
+            shared_ast_modules.project_config,
+            shared_ast_modules.directory.join("_"),
+            shared_ast_modules.shared_asts,
+            shared_ast_modules.modules,
+        )
+        .print(key, true);
+        let name = shared_ast_module_name(&md5(&format!("{}{}", top_level_statements, node)));
+        let path = shared_ast_module_path(
+            shared_ast_modules.project_config,
+            &shared_ast_modules.directory,
+            &name,
+        );
+        let import_path = shared_ast_modules
+            .project_config
+            .js_module_import_identifier(&shared_ast_modules.artifact_path, &path);
+        shared_ast_modules.modules.push(SharedAstModule {
+            path,
+            node,
+            top_level_statements,
+        });
+        self.shared_ast_modules = Some(shared_ast_modules);
+        let import_path = self.module_path(import_path);
+        self.write_js_dependency(f, ModuleImportName::Default(name.intern()), import_path)
+    }
+
     fn print_ast(&mut self, f: &mut String, key: AstKey, indent: usize, is_dedupe_var: bool) {
+        if indent > 0 && self.is_shared_ast(key) {
+            return self.print_shared_ast(f, key).unwrap();
+        }
         // Only use variable references at depth beyond the top level.
         if indent > 0 && self.duplicates.contains(&key) {
             let v = if self.variable_definitions.contains_key(&key) {
@@ -533,21 +816,17 @@ impl<'b> JSONPrinter<'b> {
                     ),
                     GraphQLModuleDependency::Path { name, path } => (name, path),
                 };
+                let path = self.module_path(format!("{}.graphql", key).intern());
                 self.write_js_dependency(
                     f,
                     ModuleImportName::Default(format!("{}_graphql", variable_name).intern()),
-                    Cow::Owned(format!(
-                        "{}.graphql",
-                        get_module_path(self.js_module_format, *key)
-                    )),
+                    path,
                 )
             }
-            Primitive::JSModuleDependency(JSModuleDependency { path, import_name }) => self
-                .write_js_dependency(
-                    f,
-                    import_name.clone(),
-                    get_module_path(self.js_module_format, *path),
-                ),
+            Primitive::JSModuleDependency(JSModuleDependency { path, import_name }) => {
+                let path = self.source_module_path(*path);
+                self.write_js_dependency(f, import_name.clone(), path)
+            }
             Primitive::ResolverModuleReference(ResolverModuleReference {
                 field_type,
                 resolver_function_name,
@@ -566,10 +845,11 @@ impl<'b> JSONPrinter<'b> {
                     write!(f, "() => JSResource('m#{}')", module)
                 }
                 DynamicModuleProvider::Custom { statement } => {
-                    f.push_str(&statement.lookup().replace(
-                        "<$module>",
-                        &get_module_path(self.js_module_format, *module),
-                    ));
+                    f.push_str(
+                        &statement
+                            .lookup()
+                            .replace("<$module>", &self.module_path(*module)),
+                    );
                     Ok(())
                 }
             },
@@ -578,12 +858,14 @@ impl<'b> JSONPrinter<'b> {
                 graphql_module_name,
                 js_module,
                 injected_field_name_details,
+                batch,
             } => self.write_relay_resolver_model(
                 f,
                 *graphql_module_name,
                 *graphql_module_path,
                 js_module,
                 injected_field_name_details.as_ref().copied(),
+                *batch,
             ),
             Primitive::RelayResolverWeakObjectWrapper {
                 resolver,
@@ -622,6 +904,30 @@ impl<'b> JSONPrinter<'b> {
         }
     }
 
+    /// The path to import the generated module `key` from, with the `esm`
+    /// import extension.
+    fn module_path(&self, key: StringKey) -> Cow<'static, str> {
+        let path = get_module_path(self.js_module_format, key);
+        match &self.esm_import_extension {
+            Some(extension) => Cow::Owned(format!("{}.{}", path, extension)),
+            None => path,
+        }
+    }
+
+    /// The path to import the source module `key` of the project from, e.g. a
+    /// resolver or a provider. With the `esm` module format, it keeps the
+    /// extension of the source, mapped to the one it is compiled to.
+    fn source_module_path(&self, key: StringKey) -> Cow<'static, str> {
+        match self.js_module_format {
+            JsModuleFormat::ESM => {
+                Cow::Owned(format!("./{}", esm_source_import_specifier(key.lookup())))
+            }
+            JsModuleFormat::CommonJS | JsModuleFormat::Haste => {
+                get_module_path(self.js_module_format, key)
+            }
+        }
+    }
+
     fn write_js_dependency(
         &mut self,
         f: &mut String,
@@ -665,9 +971,14 @@ impl<'b> JSONPrinter<'b> {
         graphql_module_path: StringKey,
         js_module: &JSModuleDependency,
         injected_field_name_details: Option<(StringKey, bool)>,
+        batch: bool,
     ) -> FmtResult {
         let relay_runtime_experimental = "relay-runtime/experimental";
-        let resolver_data_injector = "resolverDataInjector";
+        let resolver_data_injector = if batch {
+            "batchResolverDataInjector"
+        } else {
+            "resolverDataInjector"
+        };
 
         self.write_js_dependency(
             f,
@@ -678,20 +989,16 @@ impl<'b> JSONPrinter<'b> {
             Cow::Borrowed(relay_runtime_experimental),
         )?;
         write!(f, "(")?;
+        let graphql_module_path =
+            self.module_path(format!("{}.graphql", graphql_module_path).intern());
         self.write_js_dependency(
             f,
             ModuleImportName::Default(format!("{}_graphql", graphql_module_name).intern()),
-            Cow::Owned(format!(
-                "{}.graphql",
-                get_module_path(self.js_module_format, graphql_module_path)
-            )),
+            graphql_module_path,
         )?;
         write!(f, ", ")?;
-        self.write_js_dependency(
-            f,
-            js_module.import_name.clone(),
-            get_module_path(self.js_module_format, js_module.path),
-        )?;
+        let js_module_path = self.source_module_path(js_module.path);
+        self.write_js_dependency(f, js_module.import_name.clone(), js_module_path)?;
         if let Some((field_name, is_required_field)) = injected_field_name_details {
             write!(f, ", '{}'", field_name)?;
             write!(f, ", {}", is_required_field)?;
@@ -732,7 +1039,7 @@ impl<'b> JSONPrinter<'b> {
 
 pub fn get_module_path(js_module_format: JsModuleFormat, key: StringKey) -> Cow<'static, str> {
     match js_module_format {
-        JsModuleFormat::CommonJS => {
+        JsModuleFormat::CommonJS | JsModuleFormat::ESM => {
             let path = Path::new(key.lookup());
             let extension = path.extension();
 
@@ -753,7 +1060,82 @@ pub fn get_module_path(js_module_format: JsModuleFormat, key: StringKey) -> Cow<
     }
 }
 
-fn write_static_storage_key(
+/// The size and the content hash of the subtree `key`. Its size is `None` if
+/// it imports modules, whose paths are relative to the artifact.
+fn shared_ast_node(
+    builder: &AstBuilder,
+    shared_ast_modules: &mut SharedAstModules<'_>,
+    key: AstKey,
+) -> SharedAstNode {
+    if let Some(node) = shared_ast_modules.nodes.get(&key) {
+        return *node;
+    }
+    let mut hasher = FnvHasher::default();
+    let values: Vec<&Primitive> = match builder.lookup(key) {
+        Ast::Object(object) => {
+            "object".hash(&mut hasher);
+            for entry in object {
+                entry.key.hash(&mut hasher);
+            }
+            object.iter().map(|entry| &entry.value).collect()
+        }
+        Ast::Array(array) => {
+            "array".hash(&mut hasher);
+            array.iter().collect()
+        }
+    };
+    let mut size = Some(1);
+    for value in values {
+        std::mem::discriminant(value).hash(&mut hasher);
+        let value_size = match value {
+            Primitive::Key(key) => {
+                let node = shared_ast_node(builder, shared_ast_modules, *key);
+                node.hash.hash(&mut hasher);
+                // Hoisted subtrees are printed as an import.
+                if shared_ast_modules.is_hoisted(node) {
+                    Some(0)
+                } else {
+                    node.size
+                }
+            }
+            Primitive::StorageKey(name, key) => {
+                name.hash(&mut hasher);
+                shared_ast_node(builder, shared_ast_modules, *key)
+                    .hash
+                    .hash(&mut hasher);
+                Some(0)
+            }
+            Primitive::Variable(_)
+            | Primitive::GraphQLModuleDependency(_)
+            | Primitive::JSModuleDependency(_)
+            | Primitive::DynamicImport { .. }
+            | Primitive::RelayResolverModel { .. }
+            | Primitive::RelayResolverWeakObjectWrapper { .. } => None,
+            Primitive::String(_)
+            | Primitive::Float(_)
+            | Primitive::Int(_)
+            | Primitive::Bool(_)
+            | Primitive::Null
+            | Primitive::SkippableNull
+            | Primitive::RawString(_)
+            | Primitive::ResolverModuleReference(_) => {
+                value.hash(&mut hasher);
+                Some(0)
+            }
+        };
+        size = size
+            .zip(value_size)
+            .map(|(size, value_size)| size + value_size);
+    }
+    let node = SharedAstNode {
+        size,
+        hash: hasher.finish(),
+    };
+    shared_ast_modules.nodes.insert(key, node);
+    node
+}
+
+pub(crate) fn write_static_storage_key(
     f: &mut String,
     builder: &AstBuilder,
     field_name: StringKey,
@@ -0,0 +1,63 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use fnv::FnvHashSet;
+use relay_config::ProjectConfig;
+use relay_config::TypegenLanguage;
+
+use crate::top_level_statements::TopLevelStatements;
+
+/// A subtree of a normalization or reader AST hoisted into a module shared
+/// between artifacts, see `ProjectConfig::shared_ast_modules`.
+pub struct SharedAstModule {
+    pub path: PathBuf,
+    /// The printed subtree, exported by the module.
+    pub node: String,
+    pub top_level_statements: TopLevelStatements,
+}
+
+/// Which subtrees of normalization and reader ASTs are hoisted into shared
+/// modules. Subtrees are identified by a hash of their content, which doesn't
+/// depend on the `AstBuilder` they are built with, so that the subtrees of
+/// different artifacts can be compared.
+pub enum SharedAsts {
+    /// Hoists no subtree, collecting the hashes of the ones large enough to
+    /// be hoisted.
+    Collect(FnvHashSet<u64>),
+    /// Hoists the subtrees large enough with these hashes, which are the ones
+    /// that occur in several artifacts.
+    Hoist(Arc<FnvHashSet<u64>>),
+}
+
+impl Default for SharedAsts {
+    fn default() -> Self {
+        Self::Hoist(Default::default())
+    }
+}
+
+/// The name of the module with the subtree whose printed content has the
+/// hash `hash`, which is also the name it is imported as.
+pub(crate) fn shared_ast_module_name(hash: &str) -> String {
+    format!("shared_{}", &hash[..16])
+}
+
+pub(crate) fn shared_ast_module_path(
+    project_config: &ProjectConfig,
+    directory: &Path,
+    name: &str,
+) -> PathBuf {
+    let extension = match project_config.typegen_config.language {
+        TypegenLanguage::TypeScript => "ts",
+        // ReScript artifacts embed JavaScript that requires the modules.
+        TypegenLanguage::Flow | TypegenLanguage::JavaScript | TypegenLanguage::ReScript => "js",
+    };
+    directory.join(format!("{}.{}", name, extension))
+}
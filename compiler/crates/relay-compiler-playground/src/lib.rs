@@ -353,6 +353,7 @@ fn get_programs(
         Arc::new(NoopPerfLogger),
         None,
         None,
+        None,
     )
     .map_err(|diagnostics: Vec<Diagnostic>| {
         map_diagnostics(diagnostics, &InputType::Document(document_text))
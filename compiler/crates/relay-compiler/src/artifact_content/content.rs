@@ -17,15 +17,21 @@ use graphql_ir::OperationDefinition;
 use relay_codegen::build_request_params;
 use relay_codegen::Printer;
 use relay_codegen::QueryID;
+use relay_codegen::RequestParameters;
+use relay_codegen::SharedAstModule;
 use relay_transforms::is_operation_preloadable;
 use relay_transforms::RelayDataDrivenDependencyMetadata;
 use relay_transforms::ASSIGNABLE_DIRECTIVE;
 use relay_typegen::generate_fragment_type_exports_section;
+use relay_typegen::generate_fragment_validators_section;
+use relay_typegen::generate_mock_resolvers_module_section;
 use relay_typegen::generate_named_validator_export;
 use relay_typegen::generate_operation_type_exports_section;
+use relay_typegen::generate_operation_validators_section;
+use relay_typegen::generate_schema_types_module_section;
 use relay_typegen::generate_split_operation_type_exports_section;
 use relay_typegen::FragmentLocations;
-use relay_typegen::TypegenConfig;
+use relay_typegen::MockResolverTypes;
 use relay_typegen::TypegenLanguage;
 use schema::SDLSchema;
 use signedsource::SIGNING_TOKEN;
@@ -101,7 +107,10 @@ pub fn generate_preloadable_query_parameters(
         project_config,
         &mut section,
         &normalization_operation.name.item.0.to_string(),
-        &format!("./{}.graphql", normalization_operation.name.item.0),
+        &project_config.js_module_import_specifier(format!(
+            "./{}.graphql",
+            normalization_operation.name.item.0
+        )),
     )?;
 
     if project_config.typegen_config.language == TypegenLanguage::Flow {
@@ -136,12 +145,7 @@ pub fn generate_preloadable_query_parameters(
 
     // -- Begin Export Section --
     let mut section = GenericSection::default();
-    write_export_generated_node(
-        &project_config.typegen_config,
-        &mut section,
-        "node",
-        Some(node_type),
-    )?;
+    write_export_generated_node(project_config, &mut section, "node", Some(node_type))?;
     content_sections.push(ContentSection::Generic(section));
     // -- End Export Section --
 
@@ -160,14 +164,7 @@ pub fn generate_updatable_query(
     skip_types: bool,
     fragment_locations: &FragmentLocations,
 ) -> Result<Vec<u8>, FmtError> {
-    let operation_fragment = FragmentDefinition {
-        name: reader_operation.name.map(|x| FragmentDefinitionName(x.0)),
-        variable_definitions: reader_operation.variable_definitions.clone(),
-        selections: reader_operation.selections.clone(),
-        used_global_variables: Default::default(),
-        directives: reader_operation.directives.clone(),
-        type_condition: reader_operation.type_,
-    };
+    let operation_fragment = operation_fragment(reader_operation);
 
     let mut content_sections = ContentSections::default();
 
@@ -256,7 +253,7 @@ pub fn generate_updatable_query(
     // -- Begin Export Query Node Section --
     let mut section = GenericSection::default();
     write_export_generated_node(
-        &project_config.typegen_config,
+        project_config,
         &mut section,
         "node",
         generated_types.exported_type,
@@ -267,21 +264,25 @@ pub fn generate_updatable_query(
     content_sections.into_signed_bytes()
 }
 
-#[allow(clippy::too_many_arguments)]
-pub fn generate_operation(
-    config: &Config,
+/// The reader AST of an operation, printed as the `fragment` of its request.
+pub(super) fn operation_fragment(reader_operation: &OperationDefinition) -> FragmentDefinition {
+    FragmentDefinition {
+        name: reader_operation.name.map(|x| FragmentDefinitionName(x.0)),
+        variable_definitions: reader_operation.variable_definitions.clone(),
+        selections: reader_operation.selections.clone(),
+        used_global_variables: Default::default(),
+        directives: reader_operation.directives.clone(),
+        type_condition: reader_operation.type_,
+    }
+}
+
+/// The `params` of an operation, with its persisted `id` and/or its `text`.
+pub(super) fn operation_request_parameters<'a>(
     project_config: &ProjectConfig,
-    printer: &mut Printer<'_>,
-    schema: &SDLSchema,
-    normalization_operation: &OperationDefinition,
-    reader_operation: &OperationDefinition,
-    typegen_operation: &OperationDefinition,
-    source_hash: String,
+    normalization_operation: &'a OperationDefinition,
     text: &Option<String>,
-    id_and_text_hash: &Option<QueryID>,
-    skip_types: bool,
-    fragment_locations: &FragmentLocations,
-) -> Result<Vec<u8>, FmtError> {
+    id_and_text_hash: &'a Option<QueryID>,
+) -> RequestParameters<'a> {
     let mut request_parameters = build_request_params(normalization_operation);
 
     if id_and_text_hash.is_some() {
@@ -296,15 +297,32 @@ pub fn generate_operation(
     } else {
         request_parameters.text = text.clone();
     }
+    request_parameters
+}
 
-    let operation_fragment = FragmentDefinition {
-        name: reader_operation.name.map(|x| FragmentDefinitionName(x.0)),
-        variable_definitions: reader_operation.variable_definitions.clone(),
-        selections: reader_operation.selections.clone(),
-        used_global_variables: Default::default(),
-        directives: reader_operation.directives.clone(),
-        type_condition: reader_operation.type_,
-    };
+#[allow(clippy::too_many_arguments)]
+pub fn generate_operation(
+    config: &Config,
+    project_config: &ProjectConfig,
+    printer: &mut Printer<'_>,
+    schema: &SDLSchema,
+    normalization_operation: &OperationDefinition,
+    reader_operation: &OperationDefinition,
+    typegen_operation: &OperationDefinition,
+    source_hash: String,
+    text: &Option<String>,
+    id_and_text_hash: &Option<QueryID>,
+    skip_types: bool,
+    fragment_locations: &FragmentLocations,
+) -> Result<Vec<u8>, FmtError> {
+    let request_parameters = operation_request_parameters(
+        project_config,
+        normalization_operation,
+        text,
+        id_and_text_hash,
+    );
+
+    let operation_fragment = operation_fragment(reader_operation);
 
     let mut content_sections = ContentSections::default();
 
@@ -406,7 +424,11 @@ pub fn generate_operation(
 
     // -- Begin Top Level Statements Section --
     let mut section: GenericSection = GenericSection::default();
-    write!(section, "{}", &top_level_statements)?;
+    write_javascript(
+        &project_config.typegen_config.language,
+        &mut section,
+        &top_level_statements.to_string(),
+    )?;
     content_sections.push(ContentSection::Generic(section));
     // -- End Top Level Statements Section --
 
@@ -437,7 +459,7 @@ pub fn generate_operation(
     if is_operation_preloadable(normalization_operation) && id_and_text_hash.is_some() {
         match project_config.typegen_config.language {
             TypegenLanguage::Flow => {
-                if project_config.typegen_config.eager_es_modules {
+                if project_config.is_es_module() {
                     writeln!(
                         section,
                         "import {{ PreloadableQueryRegistry }} from 'relay-runtime';",
@@ -453,22 +475,30 @@ pub fn generate_operation(
                     )?;
                 }
             }
-            TypegenLanguage::JavaScript | TypegenLanguage::TypeScript => {
-                if project_config.typegen_config.eager_es_modules {
+            TypegenLanguage::JavaScript
+            | TypegenLanguage::TypeScript
+            | TypegenLanguage::ReScript => {
+                let mut registry = String::new();
+                if project_config.is_es_module() {
                     writeln!(
-                        section,
+                        registry,
                         "import {{ PreloadableQueryRegistry }} from 'relay-runtime';",
                     )?;
                     writeln!(
-                        section,
+                        registry,
                         "PreloadableQueryRegistry.set(node.params.id, node);",
                     )?;
                 } else {
                     writeln!(
-                        section,
+                        registry,
                         "require('relay-runtime').PreloadableQueryRegistry.set(node.params.id, node);",
                     )?;
                 }
+                write_javascript(
+                    &project_config.typegen_config.language,
+                    &mut section,
+                    &registry,
+                )?;
             }
         }
     }
@@ -478,7 +508,7 @@ pub fn generate_operation(
     // -- Begin Export Section --
     let mut section = GenericSection::default();
     write_export_generated_node(
-        &project_config.typegen_config,
+        project_config,
         &mut section,
         "node",
         generated_types.exported_type,
@@ -486,6 +516,23 @@ pub fn generate_operation(
     content_sections.push(ContentSection::Generic(section));
     // -- End Export Section --
 
+    // -- Begin Runtime Validators Section --
+    if !skip_types {
+        if let Some(validators) = generate_operation_validators_section(
+            typegen_operation,
+            normalization_operation,
+            schema,
+            project_config,
+            fragment_locations,
+            printer.print_provided_variables(schema, normalization_operation),
+        ) {
+            let mut section = GenericSection::default();
+            write!(section, "{}", validators)?;
+            content_sections.push(ContentSection::Generic(section));
+        }
+    }
+    // -- End Runtime Validators Section --
+
     content_sections.into_signed_bytes()
 }
 
@@ -563,7 +610,11 @@ pub fn generate_split_operation(
     let operation =
         printer.print_operation(schema, normalization_operation, &mut top_level_statements);
 
-    write!(section, "{}", &top_level_statements)?;
+    write_javascript(
+        &project_config.typegen_config.language,
+        &mut section,
+        &top_level_statements.to_string(),
+    )?;
     content_sections.push(ContentSection::Generic(section));
     // -- End Top Level Statements Section --
 
@@ -594,7 +645,7 @@ pub fn generate_split_operation(
 
     // -- Begin Export Section --
     let mut section = GenericSection::default();
-    write_export_generated_node(&project_config.typegen_config, &mut section, "node", None)?;
+    write_export_generated_node(project_config, &mut section, "node", None)?;
     content_sections.push(ContentSection::Generic(section));
     // -- End Export Section --
 
@@ -724,7 +775,11 @@ fn generate_read_only_fragment(
     let mut top_level_statements = Default::default();
     let fragment = printer.print_fragment(schema, reader_fragment, &mut top_level_statements);
 
-    write!(section, "{}", &top_level_statements)?;
+    write_javascript(
+        &project_config.typegen_config.language,
+        &mut section,
+        &top_level_statements.to_string(),
+    )?;
     content_sections.push(ContentSection::Generic(section));
     // -- End Top Level Statements Section --
 
@@ -756,7 +811,7 @@ fn generate_read_only_fragment(
     // -- Begin Fragment Node Export Section --
     let mut section = GenericSection::default();
     write_export_generated_node(
-        &project_config.typegen_config,
+        project_config,
         &mut section,
         "node",
         generated_types.exported_type,
@@ -764,6 +819,21 @@ fn generate_read_only_fragment(
     content_sections.push(ContentSection::Generic(section));
     // -- End Fragment Node Export Section --
 
+    // -- Begin Runtime Validators Section --
+    if !skip_types {
+        if let Some(validators) = generate_fragment_validators_section(
+            typegen_fragment,
+            schema,
+            project_config,
+            fragment_locations,
+        ) {
+            let mut section = GenericSection::default();
+            write!(section, "{}", validators)?;
+            content_sections.push(ContentSection::Generic(section));
+        }
+    }
+    // -- End Runtime Validators Section --
+
     content_sections.into_signed_bytes()
 }
 
@@ -847,7 +917,7 @@ fn generate_assignable_fragment(
 
     // -- Begin Fragment Node Export Section --
     let mut section = GenericSection::default();
-    write_export_generated_node(&project_config.typegen_config, &mut section, "node", None)?;
+    write_export_generated_node(project_config, &mut section, "node", None)?;
     content_sections.push(ContentSection::Generic(section));
     // -- End Fragment Node Export Section --
 
@@ -863,7 +933,11 @@ fn generate_assignable_fragment(
         project_config,
         fragment_locations,
     );
-    writeln!(section, "{}", named_validator_export).unwrap();
+    write_javascript(
+        &project_config.typegen_config.language,
+        &mut section,
+        &format!("{}\n", named_validator_export),
+    )?;
     content_sections.push(ContentSection::Generic(section));
     // -- End Export Section --
 
@@ -885,10 +959,34 @@ fn write_variable_value_with_type(
         TypegenLanguage::TypeScript => {
             writeln!(section, "const {}: {} = {};", variable_name, type_, value)
         }
+        TypegenLanguage::ReScript => writeln!(
+            section,
+            "let {}: Js.Json.t = %raw(`{}`)",
+            variable_name,
+            escape_rescript_template(value)
+        ),
     }
 }
 
-fn generate_disable_lint_section(language: &TypegenLanguage) -> Result<GenericSection, FmtError> {
+/// Writes generated JavaScript code. ReScript artifacts embed it with `%%raw`.
+fn write_javascript(language: &TypegenLanguage, section: &mut dyn Write, code: &str) -> FmtResult {
+    match language {
+        TypegenLanguage::ReScript if !code.is_empty() => {
+            writeln!(section, "%%raw(`")?;
+            write!(section, "{}", escape_rescript_template(code))?;
+            writeln!(section, "`)")
+        }
+        _ => write!(section, "{}", code),
+    }
+}
+
+fn escape_rescript_template(code: &str) -> String {
+    code.replace('`', "\\`").replace("${", "\\${")
+}
+
+pub(super) fn generate_disable_lint_section(
+    language: &TypegenLanguage,
+) -> Result<GenericSection, FmtError> {
     let mut section = GenericSection::default();
     match language {
         TypegenLanguage::TypeScript => {
@@ -899,14 +997,20 @@ fn generate_disable_lint_section(language: &TypegenLanguage) -> Result<GenericSe
         TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
             writeln!(section, "/* eslint-disable */")?;
         }
+        // Nested records of different types have fields with the same name.
+        TypegenLanguage::ReScript => {
+            writeln!(section, "@@warning(\"-30\")")?;
+        }
     }
     Ok(section)
 }
 
-fn generate_use_strict_section(language: &TypegenLanguage) -> Result<GenericSection, FmtError> {
+pub(super) fn generate_use_strict_section(
+    language: &TypegenLanguage,
+) -> Result<GenericSection, FmtError> {
     let mut section = GenericSection::default();
     match language {
-        TypegenLanguage::TypeScript => {}
+        TypegenLanguage::TypeScript | TypegenLanguage::ReScript => {}
         TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
             writeln!(section, "'use strict';")?;
         }
@@ -914,7 +1018,7 @@ fn generate_use_strict_section(language: &TypegenLanguage) -> Result<GenericSect
     Ok(section)
 }
 
-fn write_import_type_from(
+pub(super) fn write_import_type_from(
     project_config: &ProjectConfig,
     section: &mut dyn Write,
     type_: &str,
@@ -922,7 +1026,7 @@ fn write_import_type_from(
 ) -> FmtResult {
     let language = &project_config.typegen_config.language;
     match language {
-        TypegenLanguage::JavaScript => Ok(()),
+        TypegenLanguage::JavaScript | TypegenLanguage::ReScript => Ok(()),
         TypegenLanguage::Flow => writeln!(section, "import type {{ {} }} from '{}';", type_, from),
         TypegenLanguage::TypeScript => writeln!(
             section,
@@ -939,11 +1043,12 @@ fn write_import_type_from(
 }
 
 pub fn write_export_generated_node(
-    typegen_config: &TypegenConfig,
+    project_config: &ProjectConfig,
     section: &mut dyn Write,
     variable_node: &str,
     forced_type: Option<String>,
 ) -> FmtResult {
+    let typegen_config = &project_config.typegen_config;
     let export_value = match (typegen_config.language, forced_type) {
         (TypegenLanguage::Flow, None) | (TypegenLanguage::JavaScript, _) => {
             variable_node.to_string()
@@ -955,8 +1060,10 @@ pub fn write_export_generated_node(
         (TypegenLanguage::Flow, Some(forced_type)) => {
             format!("(({}/*: any*/)/*: {}*/)", variable_node, forced_type)
         }
+        // Top-level bindings of ReScript modules are exported.
+        (TypegenLanguage::ReScript, _) => return Ok(()),
     };
-    if typegen_config.eager_es_modules || typegen_config.language == TypegenLanguage::TypeScript {
+    if project_config.is_es_module() || typegen_config.language == TypegenLanguage::TypeScript {
         writeln!(section, "export default {};", export_value)
     } else {
         writeln!(section, "module.exports = {};", export_value)
@@ -1001,13 +1108,20 @@ fn write_source_hash(
     section: &mut dyn Write,
     source_hash: &str,
 ) -> FmtResult {
+    if *language == TypegenLanguage::ReScript {
+        let mut code = String::new();
+        write_source_hash(config, &TypegenLanguage::JavaScript, &mut code, source_hash)?;
+        return write_javascript(language, section, &code);
+    }
     if let Some(is_dev_variable_name) = &config.is_dev_variable_name {
         writeln!(section, "if ({}) {{", is_dev_variable_name)?;
         match language {
             TypegenLanguage::Flow => {
                 writeln!(section, "  (node/*: any*/).hash = \"{}\";", source_hash)?
             }
-            TypegenLanguage::JavaScript => writeln!(section, "  node.hash = \"{}\";", source_hash)?,
+            TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
+                writeln!(section, "  node.hash = \"{}\";", source_hash)?
+            }
             TypegenLanguage::TypeScript => {
                 writeln!(section, "  (node as any).hash = \"{}\";", source_hash)?
             }
@@ -1018,7 +1132,9 @@ fn write_source_hash(
             TypegenLanguage::Flow => {
                 writeln!(section, "(node/*: any*/).hash = \"{}\";", source_hash)?
             }
-            TypegenLanguage::JavaScript => writeln!(section, "node.hash = \"{}\";", source_hash)?,
+            TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
+                writeln!(section, "node.hash = \"{}\";", source_hash)?
+            }
             TypegenLanguage::TypeScript => {
                 writeln!(section, "(node as any).hash = \"{}\";", source_hash)?
             }
@@ -1105,7 +1221,11 @@ pub fn generate_resolvers_schema_module_content(
 
     // -- Begin Top Level Statements Section --
     let mut section: GenericSection = GenericSection::default();
-    write!(section, "{}", &top_level_statements)?;
+    write_javascript(
+        &project_config.typegen_config.language,
+        &mut section,
+        &top_level_statements.to_string(),
+    )?;
     content_sections.push(ContentSection::Generic(section));
     // -- End Top Level Statements Section --
 
@@ -1123,13 +1243,134 @@ pub fn generate_resolvers_schema_module_content(
 
     // -- Begin Exports Section --
     let mut section = GenericSection::default();
-    write_export_generated_node(
-        &project_config.typegen_config,
-        &mut section,
-        "schema_resolvers",
-        None,
+    write_export_generated_node(project_config, &mut section, "schema_resolvers", None)?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Exports Section --
+
+    content_sections.into_signed_bytes()
+}
+
+pub fn generate_schema_types_module_content(
+    config: &Config,
+    project_config: &ProjectConfig,
+    schema: &SDLSchema,
+    fragment_locations: &FragmentLocations,
+) -> Result<Vec<u8>, FmtError> {
+    let mut content_sections = ContentSections::default();
+    // -- Begin Docblock Section --
+    content_sections.push(ContentSection::Docblock(generate_docblock_section(
+        config,
+        project_config,
+        vec![],
+    )?));
+    // -- End Docblock Section --
+
+    // -- Begin Disable Lint Section --
+    content_sections.push(ContentSection::Generic(generate_disable_lint_section(
+        &project_config.typegen_config.language,
+    )?));
+    // -- End Disable Lint Section --
+
+    // -- Begin Types Section --
+    let mut section = GenericSection::default();
+    write!(
+        section,
+        "{}",
+        generate_schema_types_module_section(schema, project_config, fragment_locations)
     )?;
     content_sections.push(ContentSection::Generic(section));
+    // -- End Types Section --
+
+    content_sections.into_signed_bytes()
+}
+
+pub fn generate_mock_resolvers_module_content(
+    config: &Config,
+    project_config: &ProjectConfig,
+    schema: &SDLSchema,
+    fragment_locations: &FragmentLocations,
+    types: &MockResolverTypes,
+) -> Result<Vec<u8>, FmtError> {
+    let mut content_sections = ContentSections::default();
+    // -- Begin Docblock Section --
+    content_sections.push(ContentSection::Docblock(generate_docblock_section(
+        config,
+        project_config,
+        vec![],
+    )?));
+    // -- End Docblock Section --
+
+    // -- Begin Disable Lint Section --
+    content_sections.push(ContentSection::Generic(generate_disable_lint_section(
+        &project_config.typegen_config.language,
+    )?));
+    // -- End Disable Lint Section --
+
+    // -- Begin Types Section --
+    let mut section = GenericSection::default();
+    write!(
+        section,
+        "{}",
+        generate_mock_resolvers_module_section(schema, project_config, fragment_locations, types)
+    )?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Types Section --
+
+    content_sections.into_signed_bytes()
+}
+
+pub fn generate_shared_ast_module_content(
+    config: &Config,
+    project_config: &ProjectConfig,
+    module: &SharedAstModule,
+) -> Result<Vec<u8>, FmtError> {
+    // Shared modules of ReScript projects are required by the JavaScript
+    // embedded in the artifacts.
+    let language = match project_config.typegen_config.language {
+        TypegenLanguage::ReScript => TypegenLanguage::JavaScript,
+        language => language,
+    };
+    let mut content_sections = ContentSections::default();
+    // -- Begin Docblock Section --
+    content_sections.push(ContentSection::Docblock(generate_docblock_section(
+        config,
+        project_config,
+        vec![],
+    )?));
+    // -- End Docblock Section --
+
+    // -- Begin Disable Lint Section --
+    content_sections.push(ContentSection::Generic(generate_disable_lint_section(
+        &language,
+    )?));
+    // -- End Disable Lint Section --
+
+    // -- Begin Use Strict Section --
+    content_sections.push(ContentSection::Generic(generate_use_strict_section(
+        &language,
+    )?));
+    // -- End Use Strict Section --
+
+    // -- Begin Top Level Statements Section --
+    let mut section: GenericSection = GenericSection::default();
+    write!(section, "{}", module.top_level_statements)?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Top Level Statements Section --
+
+    // -- Begin Node Section --
+    let mut section = GenericSection::default();
+    write_variable_value_with_type(&language, &mut section, "node", "any", &module.node)?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Node Section --
+
+    // -- Begin Exports Section --
+    let mut section = GenericSection::default();
+    if project_config.is_es_module() || language == TypegenLanguage::TypeScript {
+        writeln!(section, "export default node;")?;
+    } else {
+        writeln!(section, "module.exports = node;")?;
+    }
+    content_sections.push(ContentSection::Generic(section));
     // -- End Exports Section --
 
     content_sections.into_signed_bytes()
@@ -0,0 +1,123 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use relay_codegen::build_request_params;
+use relay_codegen::Printer;
+use relay_codegen::QueryID;
+use schema::SDLSchema;
+use serde_json::Map;
+use serde_json::Value;
+
+use super::content::operation_fragment;
+use super::content::operation_request_parameters;
+use super::ArtifactContent;
+use crate::config::ProjectConfig;
+
+/// Version of the layout of JSON artifacts, to be bumped on breaking changes
+/// so that runtimes reading them can check they support it.
+pub const JSON_ARTIFACT_SCHEMA_VERSION: u32 = 1;
+
+/// The JSON counterpart of an operation or fragment artifact: an object with
+/// the `schemaVersion`, the `node` exported by the JavaScript artifact, and
+/// the `hash` of its source and `relayHash` of its text if any.
+pub fn generate_json_artifact(
+    project_config: &ProjectConfig,
+    printer: &mut Printer<'_>,
+    schema: &SDLSchema,
+    content: &ArtifactContent,
+) -> Vec<u8> {
+    let (node, source_hash, query_id) = match content {
+        ArtifactContent::Operation {
+            normalization_operation,
+            reader_operation,
+            source_hash,
+            text,
+            id_and_text_hash,
+            ..
+        } => {
+            let request_parameters = operation_request_parameters(
+                project_config,
+                normalization_operation,
+                text,
+                id_and_text_hash,
+            );
+            let node = printer.print_request_json(
+                schema,
+                normalization_operation,
+                &operation_fragment(reader_operation),
+                request_parameters,
+            );
+            (node, Some(source_hash), id_and_text_hash.as_ref())
+        }
+        ArtifactContent::UpdatableQuery {
+            reader_operation,
+            source_hash,
+            ..
+        } => {
+            let node =
+                printer.print_updatable_query_json(schema, &operation_fragment(reader_operation));
+            (node, Some(source_hash), None)
+        }
+        ArtifactContent::PreloadableQueryParameters {
+            normalization_operation,
+            query_id,
+        } => {
+            let mut request_parameters = build_request_params(normalization_operation);
+            let cloned_query_id = Some(query_id.clone());
+            request_parameters.id = &cloned_query_id;
+            let node = printer.print_request_params_json(
+                schema,
+                request_parameters,
+                normalization_operation,
+            );
+            (node, None, Some(query_id))
+        }
+        ArtifactContent::SplitOperation {
+            normalization_operation,
+            source_hash,
+            ..
+        } => {
+            let node = printer.print_operation_json(schema, normalization_operation);
+            (node, source_hash.as_ref(), None)
+        }
+        ArtifactContent::Fragment {
+            reader_fragment,
+            source_hash,
+            ..
+        } => {
+            let node = printer.print_fragment_json(schema, reader_fragment);
+            (node, source_hash.as_ref(), None)
+        }
+        ArtifactContent::ResolversSchema
+        | ArtifactContent::SchemaTypes
+        | ArtifactContent::MockResolvers { .. }
+        | ArtifactContent::ResolverDependencyGraph { .. }
+        | ArtifactContent::TypedHooks { .. }
+        | ArtifactContent::Generic { .. }
+        | ArtifactContent::Json { .. } => {
+            panic!("Expected an operation or fragment artifact to print as JSON")
+        }
+    };
+
+    let mut document = Map::new();
+    document.insert(
+        "schemaVersion".to_string(),
+        Value::from(JSON_ARTIFACT_SCHEMA_VERSION),
+    );
+    if let Some(source_hash) = source_hash {
+        document.insert("hash".to_string(), Value::String(source_hash.clone()));
+    }
+    if let Some(QueryID::Persisted { text_hash, .. }) = query_id {
+        document.insert("relayHash".to_string(), Value::String(text_hash.clone()));
+    }
+    document.insert("node".to_string(), node);
+
+    let mut json = serde_json::to_string_pretty(&Value::Object(document))
+        .expect("Expected JSON values to serialize");
+    json.push('\n');
+    json.into_bytes()
+}
@@ -7,21 +7,34 @@
 
 pub mod content;
 pub mod content_section;
+pub mod json_content;
+pub mod resolver_dependency_graph_content;
+pub mod typed_hooks_content;
 
 use std::sync::Arc;
 
 use common::SourceLocationKey;
 use content::generate_fragment;
+use content::generate_mock_resolvers_module_content;
 use content::generate_operation;
 use content::generate_resolvers_schema_module_content;
+use content::generate_schema_types_module_content;
 use content::generate_split_operation;
 use content::generate_updatable_query;
+use dependency_analyzer::ResolverDependencyGraph;
 use graphql_ir::FragmentDefinition;
 use graphql_ir::OperationDefinition;
+use intern::string_key::StringKey;
+use json_content::generate_json_artifact;
 use relay_codegen::Printer;
 use relay_codegen::QueryID;
+use relay_config::ResolverDependencyGraphFormat;
 use relay_typegen::FragmentLocations;
+use relay_typegen::MockResolverTypes;
+use resolver_dependency_graph_content::generate_resolver_dependency_graph;
 use schema::SDLSchema;
+use typed_hooks_content::generate_typed_hooks;
+use typed_hooks_content::TypedHooksDefinition;
 
 use self::content::generate_preloadable_query_parameters;
 use crate::config::Config;
@@ -58,9 +71,28 @@ pub enum ArtifactContent {
         no_optional_fields_in_raw_response_type: bool,
     },
     ResolversSchema,
+    SchemaTypes,
+    MockResolvers {
+        types: Arc<MockResolverTypes>,
+    },
+    /// See `ProjectConfig::resolver_dependency_graph`.
+    ResolverDependencyGraph {
+        graph: Arc<ResolverDependencyGraph>,
+    },
+    /// Hooks typed for an operation or fragment, that import its artifact
+    /// `artifact_module`, see `ProjectConfig::typed_hooks`.
+    TypedHooks {
+        definition: TypedHooksDefinition,
+        artifact_module: StringKey,
+    },
     Generic {
         content: Vec<u8>,
     },
+    /// The JSON counterpart of the operation or fragment artifact `content`,
+    /// see `ArtifactFormat`.
+    Json {
+        content: Box<ArtifactContent>,
+    },
 }
 
 impl ArtifactContent {
@@ -167,7 +199,38 @@ impl ArtifactContent {
                 generate_resolvers_schema_module_content(config, project_config, printer, schema)
                     .unwrap()
             }
+            ArtifactContent::SchemaTypes => generate_schema_types_module_content(
+                config,
+                project_config,
+                schema,
+                fragment_locations,
+            )
+            .unwrap(),
+            ArtifactContent::MockResolvers { types } => generate_mock_resolvers_module_content(
+                config,
+                project_config,
+                schema,
+                fragment_locations,
+                types,
+            )
+            .unwrap(),
+            ArtifactContent::ResolverDependencyGraph { graph } => {
+                let format = project_config
+                    .resolver_dependency_graph
+                    .as_ref()
+                    .map_or(ResolverDependencyGraphFormat::Json, |config| config.format);
+                generate_resolver_dependency_graph(format, graph).unwrap()
+            }
+            ArtifactContent::TypedHooks {
+                definition,
+                artifact_module,
+            } => {
+                generate_typed_hooks(config, project_config, *definition, *artifact_module).unwrap()
+            }
             ArtifactContent::Generic { content } => content.clone(),
+            ArtifactContent::Json { content } => {
+                generate_json_artifact(project_config, printer, schema, content)
+            }
         }
     }
 }
@@ -0,0 +1,49 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fmt::Error as FmtError;
+use std::fmt::Write;
+
+use dependency_analyzer::ResolverDependencyGraph;
+use relay_config::ResolverDependencyGraphFormat;
+
+/// Prints the dependency graph of the Relay Resolvers of a project as JSON,
+/// or as a Graphviz digraph where resolvers are boxes, bold if they are
+/// `@live`, with an edge to each resolver and field they read.
+pub fn generate_resolver_dependency_graph(
+    format: ResolverDependencyGraphFormat,
+    graph: &ResolverDependencyGraph,
+) -> Result<Vec<u8>, FmtError> {
+    let content = match format {
+        ResolverDependencyGraphFormat::Json => {
+            let mut content = serde_json::to_string_pretty(graph).map_err(|_| FmtError)?;
+            content.push('\n');
+            content
+        }
+        ResolverDependencyGraphFormat::Dot => print_dot(graph)?,
+    };
+    Ok(content.into_bytes())
+}
+
+fn print_dot(graph: &ResolverDependencyGraph) -> Result<String, FmtError> {
+    let mut content = String::new();
+    writeln!(content, "digraph ResolverDependencyGraph {{")?;
+    for resolver in &graph.resolvers {
+        let style = if resolver.live { ", style=bold" } else { "" };
+        writeln!(content, "  \"{}\" [shape=box{}];", resolver.field, style)?;
+    }
+    for resolver in &graph.resolvers {
+        for dependency in &resolver.resolvers {
+            writeln!(content, "  \"{}\" -> \"{}\";", resolver.field, dependency)?;
+        }
+        for field in &resolver.fields {
+            writeln!(content, "  \"{}\" -> \"{}\";", resolver.field, field)?;
+        }
+    }
+    writeln!(content, "}}")?;
+    Ok(content)
+}
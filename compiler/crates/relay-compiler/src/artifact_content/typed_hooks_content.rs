@@ -0,0 +1,313 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fmt::Error as FmtError;
+use std::fmt::Write;
+
+use intern::string_key::StringKey;
+use intern::Lookup;
+use relay_config::JsModuleFormat;
+use relay_typegen::TypegenLanguage;
+
+use super::content::generate_disable_lint_section;
+use super::content::generate_docblock_section;
+use super::content::generate_use_strict_section;
+use super::content::write_import_type_from;
+use super::content_section::ContentSection;
+use super::content_section::ContentSections;
+use super::content_section::GenericSection;
+use crate::config::Config;
+use crate::config::ProjectConfig;
+
+/// The operation or fragment that typed hooks are generated for.
+#[derive(Clone, Copy, Debug)]
+pub enum TypedHooksDefinition {
+    Query(StringKey),
+    Mutation(StringKey),
+    Fragment(StringKey),
+}
+
+impl TypedHooksDefinition {
+    pub fn name(self) -> StringKey {
+        match self {
+            TypedHooksDefinition::Query(name)
+            | TypedHooksDefinition::Mutation(name)
+            | TypedHooksDefinition::Fragment(name) => name,
+        }
+    }
+}
+
+/// A parameter of a generated function: name, type and whether it is
+/// optional.
+type Param<'a> = (&'a str, String, bool);
+
+/// Generates a module with hooks and functions that call the ones of
+/// `react-relay` with the artifact `artifact_module` of `definition`, typed
+/// for it, e.g. `useMyQuery(variables)` for the query `MyQuery`.
+pub fn generate_typed_hooks(
+    config: &Config,
+    project_config: &ProjectConfig,
+    definition: TypedHooksDefinition,
+    artifact_module: StringKey,
+) -> Result<Vec<u8>, FmtError> {
+    let language = &project_config.typegen_config.language;
+    let mut content_sections = ContentSections::default();
+    // -- Begin Docblock Section --
+    content_sections.push(ContentSection::Docblock(generate_docblock_section(
+        config,
+        project_config,
+        vec![],
+    )?));
+    // -- End Docblock Section --
+
+    // -- Begin Disable Lint Section --
+    content_sections.push(ContentSection::Generic(generate_disable_lint_section(
+        language,
+    )?));
+    // -- End Disable Lint Section --
+
+    // -- Begin Use Strict Section --
+    content_sections.push(ContentSection::Generic(generate_use_strict_section(
+        language,
+    )?));
+    // -- End Use Strict Section --
+
+    let name = definition.name();
+    let artifact_import = match project_config.js_module_format {
+        JsModuleFormat::CommonJS | JsModuleFormat::ESM => {
+            project_config.js_module_import_specifier(format!("./{}", artifact_module))
+        }
+        JsModuleFormat::Haste => artifact_module.to_string(),
+    };
+    let (hooks, relay_types, runtime_types, artifact_types) = match definition {
+        TypedHooksDefinition::Query(_) => (
+            vec!["loadQuery", "useLazyLoadQuery", "usePreloadedQuery"],
+            vec!["LoadQueryOptions", "PreloadedQuery"],
+            vec!["CacheConfig", "FetchPolicy", "IEnvironment", "RenderPolicy"],
+            vec![
+                name.to_string(),
+                format!("{}$data", name),
+                format!("{}$variables", name),
+            ],
+        ),
+        TypedHooksDefinition::Mutation(_) => (
+            vec!["useMutation"],
+            vec!["UseMutationConfig"],
+            vec!["Disposable"],
+            vec![name.to_string()],
+        ),
+        TypedHooksDefinition::Fragment(_) => (
+            vec!["useFragment"],
+            vec![],
+            vec![],
+            vec![format!("{}$data", name), format!("{}$key", name)],
+        ),
+    };
+
+    // -- Begin Imports Section --
+    let mut section = GenericSection::default();
+    if *language == TypegenLanguage::Flow {
+        writeln!(section, "/*::")?;
+    }
+    if !relay_types.is_empty() {
+        write_import_type_from(
+            project_config,
+            &mut section,
+            &relay_types.join(", "),
+            "react-relay",
+        )?;
+    }
+    if !runtime_types.is_empty() {
+        write_import_type_from(
+            project_config,
+            &mut section,
+            &runtime_types.join(", "),
+            "relay-runtime",
+        )?;
+    }
+    write_import_type_from(
+        project_config,
+        &mut section,
+        &artifact_types.join(", "),
+        &artifact_import,
+    )?;
+    if *language == TypegenLanguage::Flow {
+        writeln!(section, "*/")?;
+        writeln!(section)?;
+    }
+    if uses_es_modules(project_config) {
+        writeln!(
+            section,
+            "import {{ {} }} from 'react-relay';",
+            hooks.join(", ")
+        )?;
+        writeln!(section, "import node from '{}';", artifact_import)?;
+    } else {
+        writeln!(
+            section,
+            "const {{ {} }} = require('react-relay');",
+            hooks.join(", ")
+        )?;
+        writeln!(section, "const node = require('{}');", artifact_import)?;
+    }
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Imports Section --
+
+    // -- Begin Hooks Section --
+    let mut section = GenericSection::default();
+    let capitalized_name = capitalize(name.lookup());
+    let mut functions = vec![];
+    let mut exports = vec![];
+    match definition {
+        TypedHooksDefinition::Query(_) => {
+            let options_type = match language {
+                TypegenLanguage::Flow => {
+                    "{\n    fetchKey?: string | number,\n    fetchPolicy?: FetchPolicy,\n    networkCacheConfig?: CacheConfig,\n    UNSTABLE_renderPolicy?: RenderPolicy,\n  }"
+                }
+                _ => {
+                    "{\n    fetchKey?: string | number;\n    fetchPolicy?: FetchPolicy;\n    networkCacheConfig?: CacheConfig;\n    UNSTABLE_renderPolicy?: RenderPolicy;\n  }"
+                }
+            };
+            let use_query = format!("use{}", capitalized_name);
+            functions.push(print_function(
+                project_config,
+                &use_query,
+                &[
+                    ("variables", format!("{}$variables", name), false),
+                    ("options", options_type.to_string(), true),
+                ],
+                &format!("{}$data", name),
+                &format!(
+                    "{}(node, variables, options)",
+                    generic_call(language, "useLazyLoadQuery", name)
+                ),
+            )?);
+            let load_query = format!("load{}", capitalized_name);
+            functions.push(print_function(
+                project_config,
+                &load_query,
+                &[
+                    ("environment", "IEnvironment".to_string(), false),
+                    ("variables", format!("{}$variables", name), false),
+                    ("options", "LoadQueryOptions".to_string(), true),
+                ],
+                &format!("PreloadedQuery<{}>", name),
+                &format!(
+                    "{}(environment, node, variables, options)",
+                    generic_call(language, "loadQuery", name)
+                ),
+            )?);
+            let use_preloaded_query = format!("usePreloaded{}", capitalized_name);
+            functions.push(print_function(
+                project_config,
+                &use_preloaded_query,
+                &[("queryRef", format!("PreloadedQuery<{}>", name), false)],
+                &format!("{}$data", name),
+                &format!(
+                    "{}(node, queryRef)",
+                    generic_call(language, "usePreloadedQuery", name)
+                ),
+            )?);
+            exports.extend([use_query, load_query, use_preloaded_query]);
+        }
+        TypedHooksDefinition::Mutation(_) => {
+            let use_mutation = format!("use{}", capitalized_name);
+            functions.push(print_function(
+                project_config,
+                &use_mutation,
+                &[],
+                &format!(
+                    "[(config: UseMutationConfig<{}>) => Disposable, boolean]",
+                    name
+                ),
+                &format!("{}(node)", generic_call(language, "useMutation", name)),
+            )?);
+            exports.push(use_mutation);
+        }
+        TypedHooksDefinition::Fragment(_) => {
+            let use_fragment = format!("use{}", capitalized_name);
+            functions.push(print_function(
+                project_config,
+                &use_fragment,
+                &[("key", format!("{}$key", name), false)],
+                &format!("{}$data", name),
+                "useFragment(node, key)",
+            )?);
+            exports.push(use_fragment);
+        }
+    }
+    write!(section, "{}", functions.join("\n"))?;
+    if !uses_es_modules(project_config) {
+        writeln!(section)?;
+        writeln!(section, "module.exports = {{ {} }};", exports.join(", "))?;
+    }
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Hooks Section --
+
+    content_sections.into_signed_bytes()
+}
+
+fn uses_es_modules(project_config: &ProjectConfig) -> bool {
+    project_config.is_es_module()
+        || project_config.typegen_config.language == TypegenLanguage::TypeScript
+}
+
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// A call of `function` with the type argument `type_argument`, in a Flow
+/// comment for Flow.
+fn generic_call(language: &TypegenLanguage, function: &str, type_argument: StringKey) -> String {
+    match language {
+        TypegenLanguage::Flow => format!("{}/*::<{}>*/", function, type_argument),
+        _ => format!("{}<{}>", function, type_argument),
+    }
+}
+
+fn print_function(
+    project_config: &ProjectConfig,
+    name: &str,
+    params: &[Param<'_>],
+    return_type: &str,
+    return_value: &str,
+) -> Result<String, FmtError> {
+    let mut section = String::new();
+    let language = &project_config.typegen_config.language;
+    let export = if uses_es_modules(project_config) {
+        "export "
+    } else {
+        ""
+    };
+    write!(section, "{}function {}(", export, name)?;
+    if !params.is_empty() {
+        writeln!(section)?;
+        for (param_name, type_, optional) in params {
+            match (language, optional) {
+                (TypegenLanguage::Flow, false) => {
+                    writeln!(section, "  {}/*: {}*/,", param_name, type_)?
+                }
+                (TypegenLanguage::Flow, true) => {
+                    writeln!(section, "  {}/*:: ?: {}*/,", param_name, type_)?
+                }
+                (_, false) => writeln!(section, "  {}: {},", param_name, type_)?,
+                (_, true) => writeln!(section, "  {}?: {},", param_name, type_)?,
+            }
+        }
+    }
+    match language {
+        TypegenLanguage::Flow => writeln!(section, ")/*: {}*/ {{", return_type)?,
+        _ => writeln!(section, "): {} {{", return_type)?,
+    }
+    writeln!(section, "  return {};", return_value)?;
+    writeln!(section, "}}")?;
+    Ok(section)
+}
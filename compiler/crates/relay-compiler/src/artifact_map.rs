@@ -49,15 +49,7 @@ impl ArtifactMap {
     pub fn insert(&self, artifact: Artifact) {
         let artifact_tuple = ArtifactRecord {
             path: artifact.path,
-            persisted_operation_id: match artifact.content {
-                ArtifactContent::Operation {
-                    id_and_text_hash, ..
-                } => match id_and_text_hash {
-                    Some(QueryID::Persisted { id, .. }) => Some(id),
-                    _ => None,
-                },
-                _ => None,
-            },
+            persisted_operation_id: persisted_operation_id(artifact.content),
         };
 
         for source_key in artifact.artifact_source_keys {
@@ -73,6 +65,17 @@ impl ArtifactMap {
     }
 }
 
+fn persisted_operation_id(content: ArtifactContent) -> Option<String> {
+    match content {
+        ArtifactContent::Operation {
+            id_and_text_hash: Some(QueryID::Persisted { id, .. }),
+            ..
+        } => Some(id),
+        ArtifactContent::Json { content } => persisted_operation_id(*content),
+        _ => None,
+    }
+}
+
 impl From<Vec<Artifact>> for ArtifactMap {
     fn from(artifacts: Vec<Artifact>) -> Self {
         let map = ArtifactMap(DashMap::with_capacity(artifacts.len()));
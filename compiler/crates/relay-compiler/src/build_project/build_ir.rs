@@ -7,6 +7,8 @@
 
 use common::Diagnostic;
 use dependency_analyzer::get_reachable_ir;
+use dependency_analyzer::get_resolver_dependency_graph;
+use dependency_analyzer::ResolverDependencyGraph;
 use fnv::FnvHashMap;
 use graphql_ir::ExecutableDefinitionName;
 use graphql_ir::FragmentDefinitionName;
@@ -16,6 +18,8 @@ use graphql_text_printer::print_executable_definition_ast;
 use md5::Digest;
 use md5::Md5;
 use relay_transforms::annotate_resolver_root_fragments;
+use relay_typegen::collect_mock_resolver_types;
+use relay_typegen::MockResolverTypes;
 use schema::SDLSchema;
 
 use super::ProjectAsts;
@@ -24,6 +28,12 @@ use crate::config::ProjectConfig;
 pub struct BuildIRResult {
     pub ir: Vec<graphql_ir::ExecutableDefinition>,
     pub source_hashes: SourceHashes,
+    /// The types of the `MockResolvers` type, collected from all documents
+    /// of the project, including the ones that an incremental build skips.
+    pub mock_resolver_types: Option<MockResolverTypes>,
+    /// The dependency graph of the Relay Resolvers, if it is written, built
+    /// from all documents of the project.
+    pub resolver_dependency_graph: Option<ResolverDependencyGraph>,
 }
 
 /// Map fragments and queries definition names to the md5 of they printed source
@@ -61,6 +71,23 @@ pub fn build_ir(
     if project_config.resolvers_schema_module.is_some() {
         ir = annotate_resolver_root_fragments(schema, ir);
     }
+    let mock_resolver_types = project_config
+        .mock_resolvers_module_path()
+        .map(|_| collect_mock_resolver_types(schema, &ir));
+    // Building the graph reports the cycles between resolvers, which may also
+    // be validated without writing the graph.
+    let writes_resolver_dependency_graph =
+        project_config.resolver_dependency_graph_path().is_some();
+    let resolver_dependency_graph = if writes_resolver_dependency_graph
+        || project_config
+            .feature_flags
+            .enable_resolver_cycle_validation
+    {
+        Some(get_resolver_dependency_graph(schema, &ir)?)
+            .filter(|_| writes_resolver_dependency_graph)
+    } else {
+        None
+    };
     if is_incremental_build {
         let affected_ir = get_reachable_ir(
             ir,
@@ -71,9 +98,16 @@ pub fn build_ir(
         Ok(BuildIRResult {
             ir: affected_ir,
             source_hashes,
+            mock_resolver_types,
+            resolver_dependency_graph,
         })
     } else {
-        Ok(BuildIRResult { ir, source_hashes })
+        Ok(BuildIRResult {
+            ir,
+            source_hashes,
+            mock_resolver_types,
+            resolver_dependency_graph,
+        })
     }
 }
 
@@ -5,16 +5,20 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::path::Path;
 use std::sync::Arc;
 
 use common::DiagnosticsResult;
+use common::SourceLocationKey;
 use fnv::FnvHashMap;
 use relay_config::ProjectName;
 use schema::SDLSchema;
+use schema::SchemaDocument;
 
 use super::build_resolvers_schema::extend_schema_with_resolvers;
 use crate::compiler_state::CompilerState;
 use crate::config::ProjectConfig;
+use crate::config::SchemaLocation;
 use crate::GraphQLAsts;
 
 pub fn build_schema(
@@ -46,8 +50,18 @@ pub fn build_schema(
                     .into_iter()
                     .map(|(schema, location_key)| (schema.as_str(), location_key)),
             );
-            let mut schema =
-                relay_schema::build_schema_with_extensions(&schema_sources, &extensions)?;
+            let server_documents = parse_server_schema(project_config, &schema_sources)?;
+            let mut schema = if project_config.schema_dir_config.federation {
+                relay_schema::build_federated_schema_from_documents_with_extensions(
+                    server_documents,
+                    &extensions,
+                )?
+            } else {
+                relay_schema::build_schema_from_documents_with_extensions(
+                    server_documents,
+                    &extensions,
+                )?
+            };
 
             if project_config.feature_flags.enable_relay_resolver_transform {
                 extend_schema_with_resolvers(
@@ -62,3 +76,79 @@ pub fn build_schema(
         }
     }
 }
+
+/// Parses the server schema files of the project, adding the type name prefix
+/// from `schemaDirConfig.typePrefixes` to the types defined in matching files.
+fn parse_server_schema(
+    project_config: &ProjectConfig,
+    schema_sources: &[(&str, SourceLocationKey)],
+) -> DiagnosticsResult<Vec<SchemaDocument>> {
+    let schema_dir = match &project_config.schema_location {
+        SchemaLocation::Directory(schema_dir)
+            if !project_config.schema_dir_config.type_prefixes.is_empty() =>
+        {
+            schema_dir
+        }
+        _ => return schema::parse_server_sdls(schema_sources),
+    };
+    let documents = schema_sources
+        .iter()
+        .map(|(sdl, location_key)| graphql_syntax::parse_schema_document(sdl, *location_key))
+        .collect::<DiagnosticsResult<Vec<_>>>();
+    let documents = match documents {
+        Ok(documents) => documents,
+        Err(errors) if schema_sources.len() > 1 => {
+            parse_combined_server_schema(schema_sources).map_err(|_| errors)?
+        }
+        Err(errors) => return Err(errors),
+    };
+    Ok(documents
+        .into_iter()
+        .zip(schema_sources)
+        .map(|(mut document, (_, location_key))| {
+            let type_prefix = Path::new(location_key.path())
+                .strip_prefix(schema_dir)
+                .ok()
+                .and_then(|relative_path| {
+                    project_config.schema_dir_config.type_prefix(relative_path)
+                });
+            if let Some(type_prefix) = type_prefix {
+                schema::prefix_type_names(&mut document, type_prefix);
+            }
+            document
+        })
+        .collect())
+}
+
+/// As in `schema::parse_server_sdls`, the files may not be syntactically
+/// complete on their own, so they are joined together before parsing. The
+/// definitions are then split back into one document per file, by the file
+/// their name is in, so that the type prefix of the file can be added to them.
+fn parse_combined_server_schema(
+    schema_sources: &[(&str, SourceLocationKey)],
+) -> DiagnosticsResult<Vec<SchemaDocument>> {
+    let mut combined_sdl = String::new();
+    let mut source_ends = Vec::with_capacity(schema_sources.len());
+    for (sdl, _) in schema_sources {
+        combined_sdl.push_str(sdl);
+        combined_sdl.push('\n');
+        source_ends.push(combined_sdl.len() as u32);
+    }
+    let combined_document =
+        graphql_syntax::parse_schema_document(&combined_sdl, SourceLocationKey::Generated)?;
+    let mut documents: Vec<SchemaDocument> = schema_sources
+        .iter()
+        .map(|_| SchemaDocument {
+            location: combined_document.location,
+            definitions: vec![],
+        })
+        .collect();
+    for definition in combined_document.definitions {
+        let start = definition.location().start;
+        let index = source_ends
+            .partition_point(|end| *end <= start)
+            .min(documents.len() - 1);
+        documents[index].definitions.push(definition);
+    }
+    Ok(documents)
+}
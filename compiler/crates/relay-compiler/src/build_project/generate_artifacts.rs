@@ -10,23 +10,31 @@ use std::sync::Arc;
 
 use common::NamedItem;
 use common::SourceLocationKey;
+use dependency_analyzer::ResolverDependencyGraph;
 use fnv::FnvHashMap;
 use graphql_ir::FragmentDefinition;
 use graphql_ir::OperationDefinition;
+use graphql_syntax::OperationKind;
 use graphql_text_printer::OperationPrinter;
 use graphql_text_printer::PrinterOptions;
+use intern::string_key::Intern;
 use intern::string_key::StringKey;
 use intern::Lookup;
 use relay_codegen::QueryID;
 use relay_config::ResolversSchemaModuleConfig;
+use relay_config::TypegenLanguage;
 use relay_transforms::ArtifactSourceKeyData;
 use relay_transforms::ClientEdgeGeneratedQueryMetadataDirective;
 use relay_transforms::Programs;
 use relay_transforms::RawResponseGenerationMode;
 use relay_transforms::RefetchableDerivedFromMetadata;
 use relay_transforms::SplitOperationMetadata;
+use relay_transforms::ASSIGNABLE_DIRECTIVE;
+use relay_transforms::INLINE_DIRECTIVE_NAME;
 use relay_transforms::UPDATABLE_DIRECTIVE;
+use relay_typegen::MockResolverTypes;
 
+use super::artifact_content::typed_hooks_content::TypedHooksDefinition;
 pub use super::artifact_content::ArtifactContent;
 use super::build_ir::SourceHashes;
 use crate::artifact_map::ArtifactSourceKey;
@@ -48,6 +56,8 @@ pub fn generate_artifacts(
     project_config: &ProjectConfig,
     programs: &Programs,
     source_hashes: Arc<SourceHashes>,
+    mock_resolver_types: Option<Arc<MockResolverTypes>>,
+    resolver_dependency_graph: Option<Arc<ResolverDependencyGraph>>,
 ) -> Vec<Artifact> {
     let printer_options = PrinterOptions {
         compact: project_config
@@ -57,7 +67,7 @@ pub fn generate_artifacts(
         ..Default::default()
     };
     let mut operation_printer = OperationPrinter::new(&programs.operation_text, printer_options);
-    return group_operations(programs).into_values().map(|operations| {
+    let mut artifacts: Vec<Artifact> = group_operations(programs).into_values().map(|operations| {
             if let Some(normalization) = operations.normalization {
                 // We have a normalization AST... so we'll move forward with that
                 if let Some(metadata) = SplitOperationMetadata::find(&normalization.directives)
@@ -196,7 +206,96 @@ pub fn generate_artifacts(
                 _ => vec![],
             }
         )
+        .chain(
+            project_config
+                .schema_types_module_path()
+                .map(|path| generate_schema_types_module_artifact(project_config, path))
+        )
+        .chain(
+            project_config
+                .mock_resolvers_module_path()
+                .zip(mock_resolver_types)
+                .map(|(path, types)| generate_mock_resolvers_module_artifact(project_config, path, types))
+        )
+        .chain(
+            project_config
+                .resolver_dependency_graph_path()
+                .zip(resolver_dependency_graph)
+                .map(|(path, graph)| generate_resolver_dependency_graph_artifact(path, graph))
+        )
+        .collect();
+
+    // Typed hooks are generated here rather than by `generate_extra_artifacts`,
+    // so that `ProjectConfig::typed_hooks` works whichever hook is installed.
+    let typed_hooks_artifacts: Vec<Artifact> = artifacts
+        .iter()
+        .filter_map(|artifact| generate_typed_hooks_artifact(project_config, artifact))
         .collect();
+    artifacts.extend(typed_hooks_artifacts);
+    artifacts
+}
+
+/// Adds the JSON counterparts of operation and fragment artifacts if the
+/// project's `ArtifactFormat` writes JSON, dropping the JavaScript artifacts
+/// if it doesn't write them. This runs after operations are persisted, so
+/// that JSON artifacts include their ids.
+pub fn generate_json_artifacts(
+    project_config: &ProjectConfig,
+    artifacts: Vec<Artifact>,
+) -> Vec<Artifact> {
+    if !project_config.artifact_format.writes_json() {
+        return artifacts;
+    }
+    let writes_js = project_config.artifact_format.writes_js();
+    let mut result = Vec::with_capacity(artifacts.len() * 2);
+    for artifact in artifacts {
+        if !has_json_counterpart(&artifact.content) {
+            result.push(artifact);
+            continue;
+        }
+        let path = artifact.path.with_extension("json");
+        let artifact_source_keys = artifact.artifact_source_keys.clone();
+        let source_file = artifact.source_file;
+        let content = if writes_js {
+            let content = artifact.content.clone();
+            result.push(artifact);
+            content
+        } else {
+            artifact.content
+        };
+        result.push(Artifact {
+            artifact_source_keys,
+            path,
+            content: ArtifactContent::Json {
+                content: Box::new(content),
+            },
+            source_file,
+        });
+    }
+    result
+}
+
+fn has_json_counterpart(content: &ArtifactContent) -> bool {
+    match content {
+        ArtifactContent::Operation { .. }
+        | ArtifactContent::UpdatableQuery { .. }
+        | ArtifactContent::PreloadableQueryParameters { .. }
+        | ArtifactContent::SplitOperation { .. } => true,
+        // Assignable fragments have no reader AST.
+        ArtifactContent::Fragment {
+            typegen_fragment, ..
+        } => typegen_fragment
+            .directives
+            .named(*ASSIGNABLE_DIRECTIVE)
+            .is_none(),
+        ArtifactContent::ResolversSchema
+        | ArtifactContent::SchemaTypes
+        | ArtifactContent::MockResolvers { .. }
+        | ArtifactContent::ResolverDependencyGraph { .. }
+        | ArtifactContent::TypedHooks { .. }
+        | ArtifactContent::Generic { .. }
+        | ArtifactContent::Json { .. } => false,
+    }
 }
 
 fn generate_normalization_artifact(
@@ -253,6 +352,70 @@ pub fn generate_preloadable_query_parameters_artifact(
     }
 }
 
+/// The module with the hooks typed for the operation or fragment of
+/// `artifact`, next to it, if `ProjectConfig::typed_hooks` is enabled and it
+/// is a query, mutation or fragment that is read with hooks. The module
+/// imports the JavaScript artifact, so it is not generated if the
+/// `ArtifactFormat` doesn't write it.
+pub fn generate_typed_hooks_artifact(
+    project_config: &ProjectConfig,
+    artifact: &Artifact,
+) -> Option<Artifact> {
+    let extension = match project_config.typegen_config.language {
+        TypegenLanguage::TypeScript => "ts",
+        TypegenLanguage::Flow => "js",
+        TypegenLanguage::JavaScript | TypegenLanguage::ReScript => return None,
+    };
+    if !project_config.typed_hooks || !project_config.artifact_format.writes_js() {
+        return None;
+    }
+    let definition = match &artifact.content {
+        ArtifactContent::Operation {
+            typegen_operation, ..
+        } => {
+            if RefetchableDerivedFromMetadata::find(&typegen_operation.directives).is_some()
+                || ClientEdgeGeneratedQueryMetadataDirective::find(&typegen_operation.directives)
+                    .is_some()
+            {
+                return None;
+            }
+            let name = typegen_operation.name.item.0;
+            match typegen_operation.kind {
+                OperationKind::Query => TypedHooksDefinition::Query(name),
+                OperationKind::Mutation => TypedHooksDefinition::Mutation(name),
+                OperationKind::Subscription => return None,
+            }
+        }
+        ArtifactContent::Fragment {
+            reader_fragment, ..
+        } => {
+            let directives = &reader_fragment.directives;
+            if directives.named(*INLINE_DIRECTIVE_NAME).is_some()
+                || directives.named(*UPDATABLE_DIRECTIVE).is_some()
+                || directives.named(*ASSIGNABLE_DIRECTIVE).is_some()
+                || ClientEdgeGeneratedQueryMetadataDirective::find(directives).is_some()
+                || ArtifactSourceKeyData::find(directives).is_some()
+            {
+                return None;
+            }
+            TypedHooksDefinition::Fragment(reader_fragment.name.item.0)
+        }
+        _ => return None,
+    };
+    let artifact_module = artifact.path.file_stem()?.to_str()?.intern();
+    Some(Artifact {
+        artifact_source_keys: artifact.artifact_source_keys.clone(),
+        path: artifact
+            .path
+            .with_file_name(format!("{}.hooks.{}", definition.name(), extension)),
+        content: ArtifactContent::TypedHooks {
+            definition,
+            artifact_module,
+        },
+        source_file: artifact.source_file,
+    })
+}
+
 fn generate_updatable_query_artifact(
     artifact_source: ArtifactSourceKey,
     project_config: &ProjectConfig,
@@ -391,3 +554,51 @@ fn generate_resolvers_schema_module_artifact(path: PathBuf) -> Artifact {
         source_file: SourceLocationKey::generated(),
     }
 }
+
+fn generate_schema_types_module_artifact(
+    project_config: &ProjectConfig,
+    path: PathBuf,
+) -> Artifact {
+    let extension = match project_config.typegen_config.language {
+        TypegenLanguage::TypeScript => "ts",
+        TypegenLanguage::Flow | TypegenLanguage::JavaScript => "js.flow",
+        TypegenLanguage::ReScript => "res",
+    };
+    Artifact {
+        artifact_source_keys: vec![ArtifactSourceKey::Schema()],
+        path: path.with_extension(extension),
+        content: ArtifactContent::SchemaTypes,
+        source_file: SourceLocationKey::generated(),
+    }
+}
+
+fn generate_mock_resolvers_module_artifact(
+    project_config: &ProjectConfig,
+    path: PathBuf,
+    types: Arc<MockResolverTypes>,
+) -> Artifact {
+    let extension = match project_config.typegen_config.language {
+        TypegenLanguage::TypeScript => "ts",
+        TypegenLanguage::Flow | TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
+            "js.flow"
+        }
+    };
+    Artifact {
+        artifact_source_keys: vec![ArtifactSourceKey::Schema()],
+        path: path.with_extension(extension),
+        content: ArtifactContent::MockResolvers { types },
+        source_file: SourceLocationKey::generated(),
+    }
+}
+
+fn generate_resolver_dependency_graph_artifact(
+    path: PathBuf,
+    graph: Arc<ResolverDependencyGraph>,
+) -> Artifact {
+    Artifact {
+        artifact_source_keys: vec![ArtifactSourceKey::Schema()],
+        path,
+        content: ArtifactContent::ResolverDependencyGraph { graph },
+        source_file: SourceLocationKey::generated(),
+    }
+}
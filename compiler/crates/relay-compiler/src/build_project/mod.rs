@@ -33,14 +33,19 @@ use common::sync::*;
 use common::Diagnostic;
 use common::PerfLogEvent;
 use common::PerfLogger;
+use common::SourceLocationKey;
 use common::WithDiagnostics;
 use dashmap::mapref::entry::Entry;
+use dashmap::DashMap;
 use dashmap::DashSet;
+use dependency_analyzer::ResolverDependencyGraph;
 use fnv::FnvBuildHasher;
 use fnv::FnvHashMap;
 use fnv::FnvHashSet;
 pub use generate_artifacts::generate_artifacts;
+pub use generate_artifacts::generate_json_artifacts;
 pub use generate_artifacts::generate_preloadable_query_parameters_artifact;
+pub use generate_artifacts::generate_typed_hooks_artifact;
 pub use generate_artifacts::Artifact;
 pub use generate_artifacts::ArtifactContent;
 use graphql_ir::FragmentDefinitionNameSet;
@@ -51,15 +56,22 @@ use log::warn;
 use rayon::iter::IntoParallelRefIterator;
 use rayon::slice::ParallelSlice;
 use relay_codegen::Printer;
+use relay_codegen::SharedAstModule;
+use relay_codegen::SharedAsts;
 use relay_config::ProjectName;
 use relay_transforms::apply_transforms;
+use relay_transforms::AutoDeferMetadata;
 use relay_transforms::CustomTransformsConfig;
+use relay_transforms::DeprecatedUsageAllowlist;
+use relay_transforms::FieldCostHints;
 use relay_transforms::Programs;
 use relay_typegen::FragmentLocations;
+use relay_typegen::MockResolverTypes;
 use rustc_hash::FxHashMap;
 use schema::SDLSchema;
 pub use source_control::add_to_mercurial;
 pub use validate::validate;
+pub use validate::validate_deprecations;
 pub use validate::AdditionalValidations;
 
 use self::log_program_stats::print_stats;
@@ -68,6 +80,7 @@ pub use self::project_asts::get_project_asts;
 pub use self::project_asts::ProjectAstData;
 pub use self::project_asts::ProjectAsts;
 use super::artifact_content;
+use super::artifact_content::content::generate_shared_ast_module_content;
 use crate::artifact_map::ArtifactMap;
 use crate::artifact_map::ArtifactSourceKey;
 use crate::compiler_state::ArtifactMapKind;
@@ -79,7 +92,12 @@ use crate::file_source::SourceControlUpdateStatus;
 use crate::graphql_asts::GraphQLAsts;
 
 type BuildProjectOutput = WithDiagnostics<(ProjectName, Arc<SDLSchema>, Programs, Vec<Artifact>)>;
-type BuildProgramsOutput = WithDiagnostics<(Programs, Arc<SourceHashes>)>;
+type BuildProgramsOutput = WithDiagnostics<(
+    Programs,
+    Arc<SourceHashes>,
+    Option<Arc<MockResolverTypes>>,
+    Option<Arc<ResolverDependencyGraph>>,
+)>;
 
 pub enum BuildProjectFailure {
     Error(BuildProjectError),
@@ -101,9 +119,22 @@ pub fn build_raw_program(
     schema: Arc<SDLSchema>,
     log_event: &impl PerfLogEvent,
     is_incremental_build: bool,
-) -> Result<(Program, SourceHashes), BuildProjectError> {
+) -> Result<
+    (
+        Program,
+        SourceHashes,
+        Option<MockResolverTypes>,
+        Option<ResolverDependencyGraph>,
+    ),
+    BuildProjectError,
+> {
     // Build a type aware IR.
-    let BuildIRResult { ir, source_hashes } = log_event.time("build_ir_time", || {
+    let BuildIRResult {
+        ir,
+        source_hashes,
+        mock_resolver_types,
+        resolver_dependency_graph,
+    } = log_event.time("build_ir_time", || {
         build_ir::build_ir(project_config, project_asts, &schema, is_incremental_build).map_err(
             |errors| BuildProjectError::ValidationErrors {
                 errors,
@@ -117,7 +148,12 @@ pub fn build_raw_program(
         Program::from_definitions(schema, ir)
     });
 
-    Ok((program, source_hashes))
+    Ok((
+        program,
+        source_hashes,
+        mock_resolver_types,
+        resolver_dependency_graph,
+    ))
 }
 
 pub fn validate_program(
@@ -128,22 +164,100 @@ pub fn validate_program(
 ) -> Result<Vec<Diagnostic>, BuildProjectError> {
     let timer = log_event.start("validate_time");
     log_event.number("validate_documents_count", program.document_count());
-    let result = validate(program, project_config, &config.additional_validations).map_or_else(
-        |errors| {
-            Err(BuildProjectError::ValidationErrors {
-                errors,
-                project_name: project_config.name,
-            })
-        },
-        |result| Ok(result.diagnostics),
-    );
+    let allowlist = load_deprecated_usage_allowlist(config, project_config)?;
+    let result = match (
+        validate(program, project_config, &config.additional_validations),
+        validate_deprecations(program, project_config, &allowlist),
+    ) {
+        (Ok(result), Ok(deprecations_result)) => {
+            let mut diagnostics = result.diagnostics;
+            diagnostics.extend(deprecations_result.diagnostics);
+            Ok(diagnostics)
+        }
+        (Err(mut errors), Err(deprecation_errors)) => {
+            errors.extend(deprecation_errors);
+            Err(errors)
+        }
+        (Err(errors), Ok(_)) | (Ok(_), Err(errors)) => Err(errors),
+    }
+    .map_err(|errors| BuildProjectError::ValidationErrors {
+        errors,
+        project_name: project_config.name,
+    });
 
     log_event.stop(timer);
 
     result
 }
 
+fn load_deprecated_usage_allowlist(
+    config: &Config,
+    project_config: &ProjectConfig,
+) -> Result<DeprecatedUsageAllowlist, BuildProjectError> {
+    let mut allowlist = DeprecatedUsageAllowlist::default();
+    if let Some(deprecation_config) = &project_config.deprecation_config {
+        for path in &deprecation_config.allowlist {
+            let file = config.root_dir.join(path);
+            let text = std::fs::read_to_string(&file)
+                .map_err(|source| BuildProjectError::ReadFileError { file, source })?;
+            allowlist.extend_from_str(&text);
+        }
+    }
+    Ok(allowlist)
+}
+
+fn load_field_cost_hints(
+    config: &Config,
+    project_config: &ProjectConfig,
+) -> Result<Option<FieldCostHints>, BuildProjectError> {
+    match &project_config.auto_defer {
+        Some(auto_defer_config) => {
+            let file = config.root_dir.join(&auto_defer_config.cost_hints);
+            let text = std::fs::read_to_string(&file).map_err(|source| {
+                BuildProjectError::ReadFileError {
+                    file: file.clone(),
+                    source,
+                }
+            })?;
+            let field_cost_hints = FieldCostHints::from_json(&text)
+                .map_err(|source| BuildProjectError::InvalidCostHintsFile { file, source })?;
+            Ok(Some(field_cost_hints))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Reports the fragment spreads of the built queries deferred by `autoDefer`,
+/// and the queries still over the budget once they are deferred.
+fn log_auto_deferred_spreads(project_config: &ProjectConfig, programs: &Programs) {
+    let budget_ms = match &project_config.auto_defer {
+        Some(auto_defer_config) => auto_defer_config.budget_ms,
+        None => return,
+    };
+    for operation in programs.normalization.operations() {
+        if let Some(metadata) = AutoDeferMetadata::find(&operation.directives) {
+            for spread in &metadata.deferred_spreads {
+                info!(
+                    "[{}] Deferred `...{}` in `{}` (estimated {}ms) with label `{}`",
+                    project_config.name,
+                    spread.fragment_name,
+                    operation.name.item,
+                    spread.cost_ms,
+                    spread.label
+                );
+            }
+            if metadata.cost_ms > budget_ms {
+                info!(
+                    "[{}] `{}` is estimated at {}ms after deferring, over the budget of {}ms",
+                    project_config.name, operation.name.item, metadata.cost_ms, budget_ms
+                );
+            }
+        }
+    }
+}
+
 /// Apply various chains of transforms to create a set of output programs.
+#[allow(clippy::too_many_arguments)]
 pub fn transform_program(
     project_config: &ProjectConfig,
     program: Arc<Program>,
@@ -151,6 +265,7 @@ pub fn transform_program(
     perf_logger: Arc<impl PerfLogger + 'static>,
     log_event: &impl PerfLogEvent,
     custom_transforms_config: Option<&CustomTransformsConfig>,
+    field_cost_hints: Option<&FieldCostHints>,
 ) -> Result<Programs, BuildProjectFailure> {
     let timer = log_event.start("apply_transforms_time");
     let result = apply_transforms(
@@ -160,6 +275,7 @@ pub fn transform_program(
         perf_logger,
         Some(print_stats),
         custom_transforms_config,
+        field_cost_hints,
     )
     .map_err(|errors| {
         BuildProjectFailure::Error(BuildProjectError::ValidationErrors {
@@ -200,13 +316,14 @@ pub fn build_programs(
             true
         };
     log_event.bool("is_incremental_build", is_incremental_build);
-    let (program, source_hashes) = build_raw_program(
-        project_config,
-        project_asts,
-        schema,
-        log_event,
-        is_incremental_build,
-    )?;
+    let (program, source_hashes, mock_resolver_types, resolver_dependency_graph) =
+        build_raw_program(
+            project_config,
+            project_asts,
+            schema,
+            log_event,
+            is_incremental_build,
+        )?;
 
     if compiler_state.should_cancel_current_build() {
         debug!("Build is cancelled: updates in source code/or new file changes are pending.");
@@ -217,6 +334,7 @@ pub fn build_programs(
     // FIXME: Return non-fatal diagnostics from transforms (only validations for now)
     let diagnostics = validate_program(config, project_config, &program, log_event)?;
 
+    let field_cost_hints = load_field_cost_hints(config, project_config)?;
     let programs = transform_program(
         project_config,
         Arc::new(program),
@@ -224,10 +342,17 @@ pub fn build_programs(
         Arc::clone(&perf_logger),
         log_event,
         config.custom_transforms.as_ref(),
+        field_cost_hints.as_ref(),
     )?;
+    log_auto_deferred_spreads(project_config, &programs);
 
     Ok(WithDiagnostics {
-        item: (programs, Arc::new(source_hashes)),
+        item: (
+            programs,
+            Arc::new(source_hashes),
+            mock_resolver_types.map(Arc::new),
+            resolver_dependency_graph.map(Arc::new),
+        ),
         diagnostics,
     })
 }
@@ -269,7 +394,7 @@ pub fn build_project(
 
     // Apply different transform pipelines to produce the `Programs`.
     let WithDiagnostics {
-        item: (programs, source_hashes),
+        item: (programs, source_hashes, mock_resolver_types, resolver_dependency_graph),
         diagnostics,
     } = build_programs(
         config,
@@ -289,7 +414,13 @@ pub fn build_project(
 
     // Generate artifacts by collecting information from the `Programs`.
     let artifacts_timer = log_event.start("generate_artifacts_time");
-    let artifacts = generate_artifacts(project_config, &programs, Arc::clone(&source_hashes));
+    let artifacts = generate_artifacts(
+        project_config,
+        &programs,
+        Arc::clone(&source_hashes),
+        mock_resolver_types,
+        resolver_dependency_graph,
+    );
     log_event.stop(artifacts_timer);
 
     log_event.number(
@@ -370,6 +501,8 @@ pub async fn commit_project(
         });
     }
 
+    artifacts = generate_json_artifacts(project_config, artifacts);
+
     if source_control_update_status.is_started() {
         debug!("commit_project cancelled before writing artifacts due to source control updates");
         return Err(BuildProjectFailure::Cancelled);
@@ -402,7 +535,7 @@ pub async fn commit_project(
         ArtifactMapKind::Unconnected(existing_artifacts) => {
             let mut existing_artifacts = existing_artifacts.clone();
             let write_artifacts_time = log_event.start("write_artifacts_time");
-            write_artifacts(
+            let shared_ast_module_artifacts = write_artifacts(
                 config,
                 project_config,
                 schema,
@@ -411,6 +544,7 @@ pub async fn commit_project(
                 &fragment_locations,
                 &artifacts_file_hash_map,
             )?;
+            artifacts.extend(shared_ast_module_artifacts);
             for artifact in &artifacts {
                 if !existing_artifacts.remove(&artifact.path) {
                     debug!(
@@ -438,7 +572,7 @@ pub async fn commit_project(
                 log_event.start("write_artifacts_incremental_time");
 
             // Write or update artifacts
-            write_artifacts(
+            let shared_ast_module_artifacts = write_artifacts(
                 config,
                 project_config,
                 schema,
@@ -447,6 +581,7 @@ pub async fn commit_project(
                 &fragment_locations,
                 &artifacts_file_hash_map,
             )?;
+            artifacts.extend(shared_ast_module_artifacts);
             artifacts.into_par_iter().for_each(|artifact| {
                 current_paths_map.insert(artifact);
             });
@@ -545,9 +680,21 @@ fn write_artifacts<F: Fn() -> bool + Sync + Send>(
     artifacts: &[Artifact],
     fragment_locations: &FragmentLocations,
     artifacts_file_hash_map: &Option<FxHashMap<String, Option<String>>>,
-) -> Result<(), BuildProjectFailure> {
+) -> Result<Vec<Artifact>, BuildProjectFailure> {
+    let shared_asts = find_shared_asts(
+        config,
+        project_config,
+        schema,
+        artifacts,
+        fragment_locations,
+    );
+    let shared_ast_modules: DashMap<PathBuf, (SharedAstModule, Vec<ArtifactSourceKey>)> =
+        Default::default();
     artifacts.par_chunks(8192).try_for_each_init(
-        || Printer::with_dedupe(project_config),
+        || {
+            Printer::with_dedupe(project_config)
+                .with_shared_asts(SharedAsts::Hoist(Arc::clone(&shared_asts)))
+        },
         |mut printer, artifacts| {
             for artifact in artifacts {
                 if should_stop_updating_artifacts() {
@@ -562,6 +709,13 @@ fn write_artifacts<F: Fn() -> bool + Sync + Send>(
                     artifact.source_file,
                     fragment_locations,
                 );
+                for module in printer.take_shared_ast_modules() {
+                    shared_ast_modules
+                        .entry(module.path.clone())
+                        .or_insert_with(|| (module, vec![]))
+                        .1
+                        .extend(artifact.artifact_source_keys.iter().cloned());
+                }
                 let file_hash = match artifact.path.to_str() {
                     Some(key) => artifacts_file_hash_map
                         .as_ref()
@@ -578,5 +732,84 @@ fn write_artifacts<F: Fn() -> bool + Sync + Send>(
             Ok(())
         },
     )?;
-    Ok(())
+    write_shared_ast_modules(config, project_config, shared_ast_modules)
+}
+
+/// The hashes of the subtrees of normalization and reader ASTs to hoist into
+/// shared modules: the ones which occur in at least two artifacts. Incremental
+/// builds only see the artifacts they rebuild, so they may inline subtrees that
+/// are shared with the artifacts left untouched.
+fn find_shared_asts(
+    config: &Config,
+    project_config: &ProjectConfig,
+    schema: &SDLSchema,
+    artifacts: &[Artifact],
+    fragment_locations: &FragmentLocations,
+) -> Arc<FnvHashSet<u64>> {
+    if project_config.shared_ast_modules.is_none() {
+        return Default::default();
+    }
+    let artifact_counts: DashMap<u64, usize> = Default::default();
+    artifacts.par_chunks(8192).for_each_init(
+        || {
+            Printer::with_dedupe(project_config)
+                .with_shared_asts(SharedAsts::Collect(Default::default()))
+        },
+        |printer, artifacts| {
+            for artifact in artifacts {
+                artifact.content.as_bytes(
+                    config,
+                    project_config,
+                    printer,
+                    schema,
+                    artifact.source_file,
+                    fragment_locations,
+                );
+                for hash in printer.take_shared_ast_hashes() {
+                    *artifact_counts.entry(hash).or_default() += 1;
+                }
+            }
+        },
+    );
+    Arc::new(
+        artifact_counts
+            .into_iter()
+            .filter(|(_, count)| *count >= 2)
+            .map(|(hash, _)| hash)
+            .collect(),
+    )
+}
+
+/// Writes the modules with the subtrees of normalization and reader ASTs
+/// shared between artifacts. They are generated from the definitions of all
+/// artifacts importing them, so they are removed once none of them does.
+fn write_shared_ast_modules(
+    config: &Config,
+    project_config: &ProjectConfig,
+    shared_ast_modules: DashMap<PathBuf, (SharedAstModule, Vec<ArtifactSourceKey>)>,
+) -> Result<Vec<Artifact>, BuildProjectFailure> {
+    let mut artifacts = vec![];
+    for (path, (module, artifact_source_keys)) in shared_ast_modules {
+        let content = generate_shared_ast_module_content(config, project_config, &module).unwrap();
+        let absolute_path = config.root_dir.join(&path);
+        if config
+            .artifact_writer
+            .should_write(&absolute_path, &content, None)?
+        {
+            config
+                .artifact_writer
+                .write(absolute_path, content.clone())?;
+        }
+        artifacts.push(Artifact {
+            artifact_source_keys: artifact_source_keys
+                .into_iter()
+                .collect::<FnvHashSet<_>>()
+                .into_iter()
+                .collect(),
+            path,
+            content: ArtifactContent::Generic { content },
+            source_file: SourceLocationKey::generated(),
+        });
+    }
+    Ok(artifacts)
 }
@@ -76,8 +76,13 @@ pub async fn persist_operations(
                     let artifact_path = root_dir.join(&artifact.path);
                     let extracted_persist_id = if config.repersist_operations {
                         None
-                    } else {
+                    } else if project_config.artifact_format.writes_js() {
                         extract_persist_id(&artifact_path, &text_hash)
+                    } else {
+                        extract_persist_id_from_json(
+                            &artifact_path.with_extension("json"),
+                            &text_hash,
+                        )
                     };
                     if let Some(id) = extracted_persist_id {
                         *id_and_text_hash = Some(QueryID::Persisted { id, text_hash });
@@ -147,6 +152,21 @@ fn extract_persist_id(path: &PathBuf, text_hash: &str) -> Option<String> {
     extract_request_id(&content)
 }
 
+/// Reads the id of a JSON artifact, see `generate_json_artifact`.
+fn extract_persist_id_from_json(path: &PathBuf, text_hash: &str) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+
+    // If the existing hash doesn't match the hash of the new query test, abort.
+    if json["relayHash"].as_str() != Some(text_hash) {
+        return None;
+    }
+
+    json["node"]["params"]["id"]
+        .as_str()
+        .map(|id| id.to_owned())
+}
+
 fn extract_relay_hash(content: &str) -> Option<&str> {
     RELAY_HASH_REGEX
         .captures(content)
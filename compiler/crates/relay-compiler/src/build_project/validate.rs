@@ -5,21 +5,25 @@
  * LICENSE file in the root directory of this source tree.
  */
 
-use common::escalate_and_check;
+use chrono::DateTime;
+use chrono::Utc;
 use common::CriticalDiagnostics;
 use common::DiagnosticsResult;
 use common::FeatureFlags;
 use common::StableDiagnostics;
 use common::WithDiagnostics;
+use common::escalate_and_check;
 use errors::try_all;
 use graphql_ir::Program;
 use relay_config::ProjectConfig;
+use relay_transforms::DeprecatedUsageAllowlist;
 use relay_transforms::disallow_circular_no_inline_fragments;
 use relay_transforms::disallow_readtime_features_in_mutations;
 use relay_transforms::disallow_reserved_aliases;
 use relay_transforms::disallow_typename_on_root;
 use relay_transforms::validate_assignable_directive;
 use relay_transforms::validate_connections;
+use relay_transforms::validate_deprecated_fields;
 use relay_transforms::validate_global_variable_names;
 use relay_transforms::validate_module_names;
 use relay_transforms::validate_no_double_underscore_alias;
@@ -80,6 +84,30 @@ pub fn validate(
         ),
     ]);
 
+    escalate_validation_errors(output, project_config)
+}
+
+/// Reports usages of deprecated fields and arguments according to the
+/// `deprecationConfig` of the project. Usages in `allowlist` are skipped.
+pub fn validate_deprecations(
+    program: &Program,
+    project_config: &ProjectConfig,
+    allowlist: &DeprecatedUsageAllowlist,
+) -> DiagnosticsResult<WithDiagnostics<()>> {
+    let output = match &project_config.deprecation_config {
+        Some(deprecation_config) => {
+            validate_deprecated_fields(program, deprecation_config, allowlist, &today())
+        }
+        None => Ok(()),
+    };
+
+    escalate_validation_errors(output, project_config)
+}
+
+fn escalate_validation_errors<T>(
+    output: DiagnosticsResult<T>,
+    project_config: &ProjectConfig,
+) -> DiagnosticsResult<WithDiagnostics<()>> {
     match output {
         Ok(_) => Ok(WithDiagnostics {
             item: (),
@@ -102,3 +130,49 @@ pub fn validate(
         }
     }
 }
+
+/// The current UTC date, formatted as `YYYY-MM-DD`.
+fn today() -> String {
+    format_date(Utc::now())
+}
+
+fn format_date(date_time: DateTime<Utc>) -> String {
+    date_time.format("%Y-%m-%d").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, min, sec)
+            .unwrap()
+    }
+
+    #[test]
+    fn formats_month_boundaries() {
+        assert_eq!(format_date(utc(2023, 1, 31, 23, 59, 59)), "2023-01-31");
+        assert_eq!(format_date(utc(2023, 2, 1, 0, 0, 0)), "2023-02-01");
+        assert_eq!(format_date(utc(2023, 12, 31, 23, 59, 59)), "2023-12-31");
+        assert_eq!(format_date(utc(2024, 1, 1, 0, 0, 0)), "2024-01-01");
+    }
+
+    #[test]
+    fn formats_leap_year_boundaries() {
+        assert_eq!(format_date(utc(2024, 2, 28, 23, 59, 59)), "2024-02-28");
+        assert_eq!(format_date(utc(2024, 2, 29, 0, 0, 0)), "2024-02-29");
+        assert_eq!(format_date(utc(2024, 3, 1, 0, 0, 0)), "2024-03-01");
+        assert_eq!(format_date(utc(2023, 2, 28, 23, 59, 59)), "2023-02-28");
+        assert_eq!(format_date(utc(2023, 3, 1, 0, 0, 0)), "2023-03-01");
+        assert_eq!(format_date(utc(2100, 3, 1, 0, 0, 0)), "2100-03-01");
+        assert_eq!(format_date(utc(2000, 2, 29, 12, 0, 0)), "2000-02-29");
+    }
+
+    #[test]
+    fn formatted_dates_order_like_dates() {
+        // Removal dates are compared to today as strings.
+        assert!(format_date(utc(2024, 2, 29, 0, 0, 0)) < format_date(utc(2024, 10, 1, 0, 0, 0)));
+    }
+}
@@ -260,6 +260,11 @@ pub enum ArtifactMapKind {
     Mapping(ArtifactMap),
 }
 
+/// Version of the serialized shape of `CompilerState`. It is part of the
+/// saved state version, so bump it whenever a serialized type changes shape
+/// and saved state written by an older compiler would be misread.
+pub const SAVED_STATE_FORMAT_VERSION: u32 = 1;
+
 #[derive(Serialize, Deserialize, Debug, Default)]
 pub struct CompilerState {
     pub graphql_sources: FnvHashMap<ProjectName, GraphQLSources>,
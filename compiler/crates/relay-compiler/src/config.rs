@@ -28,6 +28,9 @@ use log::warn;
 use persist_query::PersistError;
 use rayon::prelude::*;
 use regex::Regex;
+use relay_config::ArtifactFormat;
+use relay_config::AutoDeferConfig;
+use relay_config::DeprecationConfig;
 use relay_config::DiagnosticReportConfig;
 pub use relay_config::ExtraArtifactsConfig;
 use relay_config::JsModuleFormat;
@@ -37,9 +40,12 @@ pub use relay_config::PersistConfig;
 pub use relay_config::ProjectConfig;
 use relay_config::ProjectName;
 pub use relay_config::RemotePersistConfig;
+use relay_config::ResolverDependencyGraphConfig;
 use relay_config::ResolversSchemaModuleConfig;
 use relay_config::SchemaConfig;
+use relay_config::SchemaDirConfig;
 pub use relay_config::SchemaLocation;
+use relay_config::SharedAstModulesConfig;
 use relay_config::TypegenConfig;
 pub use relay_config::TypegenLanguage;
 use relay_transforms::CustomTransformsConfig;
@@ -59,6 +65,7 @@ use crate::build_project::get_artifacts_file_hash_map::GetArtifactsFileHashMapFn
 use crate::build_project::AdditionalValidations;
 use crate::compiler_state::CompilerState;
 use crate::compiler_state::ProjectSet;
+use crate::compiler_state::SAVED_STATE_FORMAT_VERSION;
 use crate::errors::ConfigValidationError;
 use crate::errors::Error;
 use crate::errors::Result;
@@ -289,6 +296,7 @@ Example file:
     ) -> Result<Self> {
         let mut hash = Sha1::new();
         serde_json::to_writer(&mut hash, &config_file).unwrap();
+        hash.update(SAVED_STATE_FORMAT_VERSION.to_le_bytes());
 
         let is_multi_project = match config_file {
             ConfigFile::MultiProject(_) => true,
@@ -324,6 +332,19 @@ Example file:
                         }),
                     }?;
 
+                let schema_dir_config =
+                    match (&schema_location, config_file_project.schema_dir_config) {
+                        (SchemaLocation::File(_), Some(_)) => Err(Error::ConfigFileValidation {
+                            config_path: config_path.clone(),
+                            validation_errors: vec![
+                                ConfigValidationError::SchemaDirConfigWithoutSchemaDir {
+                                    project_name,
+                                },
+                            ],
+                        }),
+                        (_, schema_dir_config) => Ok(schema_dir_config.unwrap_or_default()),
+                    }?;
+
                 let shard_strip_regex = config_file_project
                     .shard_strip_regex
                     .map(|s| Regex::new(&s))
@@ -362,6 +383,7 @@ Example file:
                     shard_output: config_file_project.shard_output,
                     shard_strip_regex,
                     schema_location,
+                    schema_dir_config,
                     schema_config: config_file_project.schema_config,
                     typegen_config: config_file_project.typegen_config,
                     persist: config_file_project.persist,
@@ -376,7 +398,13 @@ Example file:
                     js_module_format: config_file_project.js_module_format,
                     module_import_config: config_file_project.module_import_config,
                     diagnostic_report_config: config_file_project.diagnostic_report_config,
+                    deprecation_config: config_file_project.deprecation_config,
+                    auto_defer: config_file_project.auto_defer,
                     resolvers_schema_module: config_file_project.resolvers_schema_module,
+                    shared_ast_modules: config_file_project.shared_ast_modules,
+                    resolver_dependency_graph: config_file_project.resolver_dependency_graph,
+                    artifact_format: config_file_project.artifact_format,
+                    typed_hooks: config_file_project.typed_hooks,
                     codegen_command: config_file_project.codegen_command,
                 };
                 Ok((project_name, project_config))
@@ -486,6 +514,22 @@ Example file:
                     })
                 }
             }
+
+            // Branded ID types are exported from the shared schema types module
+            if project_config.typegen_config.branded_id_types
+                && project_config.typegen_config.schema_types_module.is_none()
+            {
+                errors.push(
+                    ConfigValidationError::BrandedIdTypesWithoutSchemaTypesModule { project_name },
+                );
+            }
+
+            // Runtime validators are written in TypeScript or JavaScript
+            if project_config.typegen_config.runtime_validators.is_some()
+                && project_config.typegen_config.language == TypegenLanguage::ReScript
+            {
+                errors.push(ConfigValidationError::RuntimeValidatorsWithReScript { project_name });
+            }
         }
     }
 
@@ -726,6 +770,38 @@ pub struct SingleProjectConfigFile {
 
     #[serde(default)]
     pub resolvers_schema_module: Option<ResolversSchemaModuleConfig>,
+
+    /// Report usages of deprecated fields and arguments as warnings or
+    /// errors. By default, they are not reported by the compiler.
+    #[serde(default)]
+    pub deprecation_config: Option<DeprecationConfig>,
+
+    /// Defer the most expensive fragment spreads of queries whose estimated
+    /// cost, from per-field cost hints, exceeds a budget.
+    #[serde(default)]
+    pub auto_defer: Option<AutoDeferConfig>,
+
+    /// Hoist large subtrees occurring in several normalization and reader ASTs
+    /// into content-addressed modules shared between artifacts.
+    #[serde(default)]
+    pub shared_ast_modules: Option<SharedAstModulesConfig>,
+
+    /// Write the dependency graph of the Relay Resolvers of the project, as
+    /// JSON or Graphviz DOT: the schema fields and resolvers read by each
+    /// resolver, and the fields which invalidate it.
+    #[serde(default)]
+    pub resolver_dependency_graph: Option<ResolverDependencyGraphConfig>,
+
+    /// Write operation and fragment artifacts as JavaScript modules, JSON
+    /// files, or both.
+    #[serde(default)]
+    pub artifact_format: ArtifactFormat,
+
+    /// Emit a module next to the artifacts of queries, mutations and
+    /// fragments, with hooks and functions typed for them, e.g.
+    /// `useMyQuery(variables)` and `useMyFragment(key)`.
+    #[serde(default)]
+    pub typed_hooks: bool,
 }
 
 impl Default for SingleProjectConfigFile {
@@ -749,6 +825,12 @@ impl Default for SingleProjectConfigFile {
             feature_flags: None,
             module_import_config: Default::default(),
             resolvers_schema_module: Default::default(),
+            deprecation_config: None,
+            auto_defer: None,
+            shared_ast_modules: None,
+            resolver_dependency_graph: None,
+            artifact_format: Default::default(),
+            typed_hooks: false,
         }
     }
 }
@@ -854,6 +936,45 @@ impl SingleProjectConfigFile {
             feature_flags: self.feature_flags,
             module_import_config: self.module_import_config,
             resolvers_schema_module: self.resolvers_schema_module,
+            deprecation_config: self.deprecation_config.map(|deprecation_config| {
+                DeprecationConfig {
+                    allowlist: deprecation_config
+                        .allowlist
+                        .into_iter()
+                        .map(|path| {
+                            normalize_path_from_config(
+                                current_dir.clone(),
+                                common_root_dir.clone(),
+                                path,
+                            )
+                        })
+                        .collect(),
+                    ..deprecation_config
+                }
+            }),
+            auto_defer: self.auto_defer.map(|auto_defer| AutoDeferConfig {
+                cost_hints: normalize_path_from_config(
+                    current_dir.clone(),
+                    common_root_dir.clone(),
+                    auto_defer.cost_hints,
+                ),
+                ..auto_defer
+            }),
+            shared_ast_modules: self.shared_ast_modules.map(|shared_ast_modules| {
+                SharedAstModulesConfig {
+                    path: shared_ast_modules.path.map(|path| {
+                        normalize_path_from_config(
+                            current_dir.clone(),
+                            common_root_dir.clone(),
+                            path,
+                        )
+                    }),
+                    ..shared_ast_modules
+                }
+            }),
+            resolver_dependency_graph: self.resolver_dependency_graph,
+            artifact_format: self.artifact_format,
+            typed_hooks: self.typed_hooks,
             ..Default::default()
         };
 
@@ -955,6 +1076,11 @@ pub struct ConfigFileProject {
     schema: Option<PathBuf>,
     schema_dir: Option<PathBuf>,
 
+    /// Include and exclude globs and per-file type name prefixes for the
+    /// files in `schema_dir`.
+    #[serde(default)]
+    schema_dir_config: Option<SchemaDirConfig>,
+
     /// If this option is set, the compiler will persist queries using this
     /// config.
     persist: Option<PersistConfig>,
@@ -995,9 +1121,41 @@ pub struct ConfigFileProject {
     #[serde(default)]
     pub diagnostic_report_config: DiagnosticReportConfig,
 
+    /// Report usages of deprecated fields and arguments as warnings or
+    /// errors. By default, they are not reported by the compiler.
+    #[serde(default)]
+    pub deprecation_config: Option<DeprecationConfig>,
+
+    /// Defer the most expensive fragment spreads of queries whose estimated
+    /// cost, from per-field cost hints, exceeds a budget.
+    #[serde(default)]
+    pub auto_defer: Option<AutoDeferConfig>,
+
     #[serde(default)]
     pub resolvers_schema_module: Option<ResolversSchemaModuleConfig>,
 
+    /// Hoist large subtrees occurring in several normalization and reader ASTs
+    /// into content-addressed modules shared between artifacts.
+    #[serde(default)]
+    pub shared_ast_modules: Option<SharedAstModulesConfig>,
+
+    /// Write the dependency graph of the Relay Resolvers of the project, as
+    /// JSON or Graphviz DOT: the schema fields and resolvers read by each
+    /// resolver, and the fields which invalidate it.
+    #[serde(default)]
+    pub resolver_dependency_graph: Option<ResolverDependencyGraphConfig>,
+
+    /// Write operation and fragment artifacts as JavaScript modules, JSON
+    /// files, or both.
+    #[serde(default)]
+    pub artifact_format: ArtifactFormat,
+
+    /// Emit a module next to the artifacts of queries, mutations and
+    /// fragments, with hooks and functions typed for them, e.g.
+    /// `useMyQuery(variables)` and `useMyFragment(key)`.
+    #[serde(default)]
+    pub typed_hooks: bool,
+
     #[serde(default)]
     pub codegen_command: Option<String>,
 }
@@ -9,7 +9,7 @@ use std::path::Path;
 
 use common::DiagnosticsResult;
 use common::SourceLocationKey;
-use docblock_syntax::parse_docblock;
+use docblock_syntax::parse_docblock_source;
 use docblock_syntax::DocblockAST;
 use errors::try_all;
 use graphql_syntax::ExecutableDefinition;
@@ -59,10 +59,7 @@ fn parse_source_to_ast(
     let source_location =
         SourceLocationKey::embedded(file_path.to_str().unwrap(), docblock_source.index);
 
-    parse_docblock(
-        &docblock_source.docblock_source.text_source().text,
-        source_location,
-    )
+    parse_docblock_source(&docblock_source.docblock_source, source_location)
 }
 
 fn parse_source(
@@ -183,6 +183,16 @@ pub enum ConfigValidationError {
         base_project_name: ProjectName,
     },
 
+    #[error(
+        "The project `{project_name}` enables `brandedIdTypes`, which requires `schemaTypesModule` to be set."
+    )]
+    BrandedIdTypesWithoutSchemaTypesModule { project_name: ProjectName },
+
+    #[error(
+        "The project `{project_name}` enables `runtimeValidators`, which are not supported with the `rescript` language."
+    )]
+    RuntimeValidatorsWithReScript { project_name: ProjectName },
+
     #[error("Project `{project_name}` needs to define exactly one of `schema` or `schema_dir`.")]
     ProjectNeedsSchemaXorSchemaDir { project_name: ProjectName },
 
@@ -210,6 +220,11 @@ pub enum ConfigValidationError {
         schema_dir: PathBuf,
     },
 
+    #[error(
+        "The project `{project_name}` defines `schemaDirConfig`, which requires the schema to be configured with `schemaDir`."
+    )]
+    SchemaDirConfigWithoutSchemaDir { project_name: ProjectName },
+
     #[error(
         "The `schemaExtensions` configured for project `{project_name}` does not exist at `{extension_dir}`."
     )]
@@ -278,4 +293,20 @@ pub enum BuildProjectError {
         #[serde(skip_serializing)]
         source: io::Error,
     },
+
+    #[error("Failed to read file `{file}`: {source}")]
+    ReadFileError {
+        file: PathBuf,
+        #[serde(skip_serializing)]
+        source: io::Error,
+    },
+
+    #[error(
+        "Failed to parse the cost hints file `{file}`, expected a JSON object mapping schema coordinates to milliseconds: {source}"
+    )]
+    InvalidCostHintsFile {
+        file: PathBuf,
+        #[serde(skip_serializing)]
+        source: serde_json::Error,
+    },
 }
@@ -19,6 +19,7 @@ use fnv::FnvHashSet;
 use log::warn;
 use rayon::iter::IntoParallelRefIterator;
 use relay_config::ProjectName;
+use relay_config::SchemaDirConfig;
 use relay_typegen::TypegenLanguage;
 
 use super::file_filter::FileFilter;
@@ -140,6 +141,7 @@ pub struct FileCategorizer {
     source_mapping: PathMapping<ProjectSet>,
     schema_file_mapping: HashMap<PathBuf, ProjectSet>,
     schema_dir_mapping: PathMapping<ProjectSet>,
+    schema_dir_configs: HashMap<ProjectName, (PathBuf, SchemaDirConfig)>,
 }
 
 impl FileCategorizer {
@@ -178,8 +180,13 @@ impl FileCategorizer {
         }
 
         let mut schema_dir_mapping_map: HashMap<PathBuf, ProjectSet> = Default::default();
+        let mut schema_dir_configs = HashMap::new();
         for (&project_name, project_config) in &config.projects {
             if let SchemaLocation::Directory(directory) = &project_config.schema_location {
+                schema_dir_configs.insert(
+                    project_name,
+                    (directory.clone(), project_config.schema_dir_config.clone()),
+                );
                 match schema_dir_mapping_map.entry(directory.clone()) {
                     Entry::Vacant(entry) => {
                         entry.insert(ProjectSet::of(project_name));
@@ -220,6 +227,7 @@ impl FileCategorizer {
             generated_dir_mapping: PathMapping::new(generated_dir_mapping),
             schema_file_mapping,
             schema_dir_mapping: PathMapping::new(schema_dir_mapping),
+            schema_dir_configs,
             source_mapping: PathMapping::new(source_mapping),
         }
     }
@@ -277,7 +285,17 @@ impl FileCategorizer {
             } else if let Some(project_set) = self.extensions_mapping.find(path) {
                 Ok(FileGroup::Extension { project_set })
             } else if let Some(project_set) = self.schema_dir_mapping.find(path) {
-                Ok(FileGroup::Schema { project_set })
+                let included_projects = project_set
+                    .into_iter()
+                    .filter(|project_name| self.is_included_in_schema_dir(*project_name, path))
+                    .collect::<Vec<_>>();
+                if included_projects.is_empty() {
+                    Ok(FileGroup::Ignore)
+                } else {
+                    Ok(FileGroup::Schema {
+                        project_set: ProjectSet::new(included_projects),
+                    })
+                }
             } else {
                 Err(Cow::Borrowed(
                     "Expected *.graphql/*.gql file to be either a schema or extension.",
@@ -290,6 +308,19 @@ impl FileCategorizer {
         }
     }
 
+    /// Applies the include and exclude globs of the project's `schemaDirConfig`
+    /// to a file in its schema directory.
+    fn is_included_in_schema_dir(&self, project_name: ProjectName, path: &Path) -> bool {
+        match self.schema_dir_configs.get(&project_name) {
+            Some((directory, schema_dir_config)) => {
+                path.strip_prefix(directory).map_or(false, |relative_path| {
+                    schema_dir_config.is_included(relative_path)
+                })
+            }
+            None => true,
+        }
+    }
+
     fn in_relative_generated_dir(&self, path: &Path) -> bool {
         path.components().any(|comp| match comp {
             Component::Normal(comp) => comp == self.default_generated_dir,
@@ -341,7 +372,11 @@ impl<T: Clone> PathMapping<T> {
 }
 
 fn is_source_code_extension(extension: &OsStr) -> bool {
-    extension == "js" || extension == "jsx" || extension == "ts" || extension == "tsx"
+    extension == "js"
+        || extension == "jsx"
+        || extension == "ts"
+        || extension == "tsx"
+        || extension == "res"
 }
 
 fn is_schema_extension(extension: &OsStr) -> bool {
@@ -354,10 +389,13 @@ fn is_extra_extensions(extension: &OsStr) -> bool {
 
 fn is_valid_source_code_extension(typegen_language: &TypegenLanguage, extension: &OsStr) -> bool {
     match typegen_language {
-        TypegenLanguage::TypeScript => is_source_code_extension(extension),
+        TypegenLanguage::TypeScript => {
+            extension == "js" || extension == "jsx" || extension == "ts" || extension == "tsx"
+        }
         TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
             extension == "js" || extension == "jsx"
         }
+        TypegenLanguage::ReScript => extension == "res",
     }
 }
 
@@ -377,7 +415,8 @@ mod tests {
                         "src/vendor": "public",
                         "src/custom": "with_custom_generated_dir",
                         "src/typescript": "typescript",
-                        "src/custom_overlapping": ["with_custom_generated_dir", "overlapping_generated_dir"]
+                        "src/custom_overlapping": ["with_custom_generated_dir", "overlapping_generated_dir"],
+                        "src/sharded": "sharded"
                     },
                     "projects": {
                         "public": {
@@ -400,6 +439,14 @@ mod tests {
                         "overlapping_generated_dir": {
                             "schema": "graphql/__generated__/custom.graphql",
                             "language": "flow"
+                        },
+                        "sharded": {
+                            "schemaDir": "graphql/sharded",
+                            "schemaDirConfig": {
+                                "includes": ["services/**/*.graphql"],
+                                "excludes": ["services/legacy/**"]
+                            },
+                            "language": "flow"
                         }
                     }
                 }
@@ -491,6 +538,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_categorize_schema_dir_globs() {
+        let config = create_test_config();
+        let categorizer = FileCategorizer::from_config(&config);
+
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from(
+                    "graphql/sharded/services/users/schema.graphql"
+                ))
+                .unwrap(),
+            FileGroup::Schema {
+                project_set: ProjectSet::of("sharded".intern().into())
+            },
+        );
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from(
+                    "graphql/sharded/services/legacy/schema.graphql"
+                ))
+                .unwrap(),
+            FileGroup::Ignore,
+        );
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from("graphql/sharded/README.graphql"))
+                .unwrap(),
+            FileGroup::Ignore,
+        );
+    }
+
     #[test]
     fn test_invalid_extension() {
         let config = create_test_config();
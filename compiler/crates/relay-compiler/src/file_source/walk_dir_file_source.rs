@@ -49,6 +49,9 @@ fn get_expected_file_extensions(config: &Config) -> HashSet<&str> {
                 file_extensions.insert("ts");
                 file_extensions.insert("tsx");
             }
+            TypegenLanguage::ReScript => {
+                file_extensions.insert("res");
+            }
         }
     }
     file_extensions
@@ -26,7 +26,7 @@ pub fn get_watchman_expr(config: &Config) -> Expr {
             })
             .map(|(path, project)| {
                 Expr::All(vec![
-                    // Ending in *.js(x) or *.ts(x) depending on the project language.
+                    // Ending in *.js(x), *.ts(x) or *.res depending on the project language.
                     Expr::Suffix(match &project.typegen_config.language {
                         TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
                             vec![PathBuf::from("js"), PathBuf::from("jsx")]
@@ -39,6 +39,7 @@ pub fn get_watchman_expr(config: &Config) -> Expr {
                                 PathBuf::from("tsx"),
                             ]
                         }
+                        TypegenLanguage::ReScript => vec![PathBuf::from("res")],
                     }),
                     // In the related source root.
                     Expr::DirName(DirNameTerm {
@@ -0,0 +1,274 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::DirectiveName;
+use common::SourceLocationKey;
+use common::Span;
+use extract_graphql::JavaScriptSourceFeature;
+use graphql_ir::ARGUMENT_DEFINITION;
+use graphql_ir::DIRECTIVE_ARGUMENTS;
+use graphql_syntax::parse_executable;
+use graphql_syntax::ConstantValue;
+use graphql_syntax::Directive;
+use graphql_syntax::ExecutableDefinition;
+use graphql_syntax::FragmentDefinition;
+use graphql_syntax::FragmentSpread;
+use graphql_syntax::Selection;
+use graphql_syntax::Value;
+use intern::Lookup;
+
+/// Rewrites the fragments declared with `@argumentDefinitions` and the fragment
+/// spreads passing `@arguments` in a source file to the fragment variable
+/// definitions and spread arguments syntax:
+///
+/// ```graphql
+/// fragment Foo on User @argumentDefinitions(size: {type: "Int", defaultValue: 32}) {
+///   ...Bar @arguments(size: $size)
+/// }
+/// ```
+///
+/// becomes
+///
+/// ```graphql
+/// fragment Foo($size: Int = 32) on User {
+///   ...Bar(size: $size)
+/// }
+/// ```
+///
+/// `is_graphql_file` selects whether the whole file is a GraphQL document or
+/// the GraphQL literals are extracted from JavaScript. Documents that don't
+/// parse, and definitions that can't be rewritten faithfully, are left as is.
+/// Returns `None` if there is nothing to rewrite.
+pub fn rewrite_fragment_arguments(source: &str, is_graphql_file: bool) -> Option<String> {
+    if is_graphql_file {
+        return rewrite_document(source);
+    }
+    let mut edits = vec![];
+    for feature in extract_graphql::extract(source) {
+        if let JavaScriptSourceFeature::GraphQL(graphql_source) = feature {
+            let text_source = graphql_source.text_source();
+            let start = match byte_offset(source, text_source.line_index, text_source.column_index)
+            {
+                Some(start) => start,
+                None => continue,
+            };
+            let end = start + text_source.text.len();
+            if source.get(start..end) != Some(text_source.text.as_str()) {
+                continue;
+            }
+            if let Some(text) = rewrite_document(&text_source.text) {
+                edits.push(Edit {
+                    span: Span::new(start as u32, end as u32),
+                    text,
+                });
+            }
+        }
+    }
+    if edits.is_empty() {
+        None
+    } else {
+        Some(apply_edits(source, edits))
+    }
+}
+
+struct Edit {
+    span: Span,
+    text: String,
+}
+
+fn rewrite_document(text: &str) -> Option<String> {
+    let document = parse_executable(text, SourceLocationKey::generated()).ok()?;
+    let mut edits = vec![];
+    for definition in &document.definitions {
+        match definition {
+            ExecutableDefinition::Operation(operation) => {
+                rewrite_selections(text, &operation.selections.items, &mut edits);
+            }
+            ExecutableDefinition::Fragment(fragment) => {
+                rewrite_fragment_definition(text, fragment, &mut edits);
+                rewrite_selections(text, &fragment.selections.items, &mut edits);
+            }
+        }
+    }
+    if edits.is_empty() {
+        None
+    } else {
+        Some(apply_edits(text, edits))
+    }
+}
+
+fn rewrite_fragment_definition(text: &str, fragment: &FragmentDefinition, edits: &mut Vec<Edit>) {
+    if fragment.variable_definitions.is_some() {
+        return;
+    }
+    let directive = match fragment
+        .directives
+        .iter()
+        .find(|directive| DirectiveName(directive.name.value) == *ARGUMENT_DEFINITION)
+    {
+        Some(directive) => directive,
+        None => return,
+    };
+    let mut variable_definitions = vec![];
+    if let Some(arguments) = &directive.arguments {
+        for argument in &arguments.items {
+            match build_variable_definition(text, argument.name.value.lookup(), &argument.value) {
+                Some(variable_definition) => variable_definitions.push(variable_definition),
+                None => return,
+            }
+        }
+    }
+    if !variable_definitions.is_empty() {
+        edits.push(Edit {
+            span: Span::new(fragment.name.span.end, fragment.name.span.end),
+            text: format!("({})", variable_definitions.join(", ")),
+        });
+    }
+    edits.push(remove_directive(text, directive));
+}
+
+/// Prints the variable definition for an `@argumentDefinitions` argument, or
+/// returns `None` if it has keys that can't be expressed as a variable definition.
+fn build_variable_definition(text: &str, name: &str, value: &Value) -> Option<String> {
+    let object = match value {
+        Value::Constant(ConstantValue::Object(object)) => object,
+        _ => return None,
+    };
+    let mut type_ = None;
+    let mut default_value = None;
+    let mut directives = vec![];
+    for item in &object.items {
+        match item.name.value.lookup() {
+            "type" => type_ = Some(item.value.get_string_literal()?),
+            "defaultValue" => default_value = Some(span_text(text, item.value.span())),
+            "provider" => {
+                item.value.get_string_literal()?;
+                directives.push(format!(
+                    "@provider(module: {})",
+                    span_text(text, item.value.span())
+                ));
+            }
+            "unusedLocalVariable_DEPRECATED" => match &item.value {
+                ConstantValue::Boolean(boolean) if boolean.value => {
+                    directives.push("@unusedLocalVariable_DEPRECATED".to_string())
+                }
+                _ => return None,
+            },
+            "directives" => match &item.value {
+                ConstantValue::List(list) => {
+                    for directive in &list.items {
+                        directives.push(directive.get_string_literal()?.lookup().to_string());
+                    }
+                }
+                _ => return None,
+            },
+            _ => return None,
+        }
+    }
+    let mut variable_definition = format!("${}: {}", name, type_?);
+    if let Some(default_value) = default_value {
+        variable_definition.push_str(" = ");
+        variable_definition.push_str(default_value);
+    }
+    for directive in directives {
+        variable_definition.push(' ');
+        variable_definition.push_str(&directive);
+    }
+    Some(variable_definition)
+}
+
+fn rewrite_selections(text: &str, selections: &[Selection], edits: &mut Vec<Edit>) {
+    for selection in selections {
+        match selection {
+            Selection::FragmentSpread(spread) => rewrite_fragment_spread(text, spread, edits),
+            Selection::InlineFragment(inline_fragment) => {
+                rewrite_selections(text, &inline_fragment.selections.items, edits)
+            }
+            Selection::LinkedField(linked_field) => {
+                rewrite_selections(text, &linked_field.selections.items, edits)
+            }
+            Selection::ScalarField(_) => {}
+        }
+    }
+}
+
+fn rewrite_fragment_spread(text: &str, spread: &FragmentSpread, edits: &mut Vec<Edit>) {
+    if spread.arguments.is_some() {
+        return;
+    }
+    let directive = match spread
+        .directives
+        .iter()
+        .find(|directive| directive.name.value == *DIRECTIVE_ARGUMENTS)
+    {
+        Some(directive) => directive,
+        None => return,
+    };
+    if let Some(arguments) = &directive.arguments {
+        if !arguments.items.is_empty() {
+            edits.push(Edit {
+                span: Span::new(spread.name.span.end, spread.name.span.end),
+                text: span_text(text, arguments.span).to_string(),
+            });
+        }
+    }
+    edits.push(remove_directive(text, directive));
+}
+
+/// Removes the directive together with the whitespace before it.
+fn remove_directive(text: &str, directive: &Directive) -> Edit {
+    let start = directive.span.start as usize;
+    let whitespace = text[..start].len() - text[..start].trim_end().len();
+    Edit {
+        span: Span::new((start - whitespace) as u32, directive.span.end),
+        text: String::new(),
+    }
+}
+
+fn span_text(text: &str, span: Span) -> &str {
+    &text[span.start as usize..span.end as usize]
+}
+
+fn apply_edits(text: &str, mut edits: Vec<Edit>) -> String {
+    edits.sort_by_key(|edit| (edit.span.start, edit.span.end));
+    let mut result = String::with_capacity(text.len());
+    let mut position = 0;
+    for edit in edits {
+        result.push_str(&text[position..edit.span.start as usize]);
+        result.push_str(&edit.text);
+        position = edit.span.end as usize;
+    }
+    result.push_str(&text[position..]);
+    result
+}
+
+/// Converts the zero-indexed line and column (in characters) of a text source
+/// extracted from JavaScript to a byte offset, with the same line terminators
+/// as `extract_graphql`.
+fn byte_offset(source: &str, line_index: usize, column_index: usize) -> Option<usize> {
+    let mut line = 0;
+    let mut column = 0;
+    let mut chars = source.char_indices().peekable();
+    while let Some((index, ch)) = chars.next() {
+        if line == line_index && column == column_index {
+            return Some(index);
+        }
+        match ch {
+            '\r' if chars.peek().is_some_and(|(_, next)| *next == '\n') => {}
+            '\n' | '\r' | '\u{2028}' | '\u{2029}' => {
+                line += 1;
+                column = 0;
+            }
+            _ => column += 1,
+        }
+    }
+    if line == line_index && column == column_index {
+        Some(source.len())
+    } else {
+        None
+    }
+}
@@ -18,10 +18,12 @@ pub mod config;
 mod docblocks;
 pub mod errors;
 pub mod file_source;
+mod fragment_arguments_codemod;
 mod graphql_asts;
 mod operation_persister;
 mod red_to_green;
 pub mod saved_state;
+mod schema_coordinate_index;
 pub mod status_reporter;
 
 pub use artifact_map::ArtifactSourceKey;
@@ -68,7 +70,11 @@ pub use file_source::FileSourceSubscriptionNextChange;
 pub use file_source::FsSourceReader;
 pub use file_source::SourceControlUpdateStatus;
 pub use file_source::SourceReader;
+pub use fragment_arguments_codemod::rewrite_fragment_arguments;
 pub use graphql_asts::GraphQLAsts;
 pub use operation_persister::LocalPersister;
 pub use operation_persister::RemotePersister;
 pub use relay_config::ProjectName;
+pub use schema_coordinate_index::build_schema_coordinate_index;
+pub use schema_coordinate_index::SchemaCoordinateIndex;
+pub use schema_coordinate_index::SchemaCoordinateUsage;
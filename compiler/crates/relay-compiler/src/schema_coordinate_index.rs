@@ -0,0 +1,299 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use common::Location;
+use common::NamedItem;
+use common::WithLocation;
+use graphql_ir::Argument;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::FragmentSpread;
+use graphql_ir::InlineFragment;
+use graphql_ir::LinkedField;
+use graphql_ir::OperationDefinition;
+use graphql_ir::Program;
+use graphql_ir::ScalarField;
+use graphql_ir::Visitor;
+use intern::string_key::StringKey;
+use intern::Lookup;
+use relay_config::DeferStreamInterface;
+use schema::FieldID;
+use schema::Schema;
+use schema::Type;
+use serde::Serialize;
+
+/// A map from schema coordinates (`Type.field` and `Type.field(argument:)`)
+/// to the operations and fragments that select them.
+pub type SchemaCoordinateIndex = BTreeMap<String, Vec<SchemaCoordinateUsage>>;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaCoordinateUsage {
+    /// Name of the operation or fragment with the selection.
+    pub definition: String,
+
+    /// `query`, `mutation`, `subscription` or `fragment`.
+    pub kind: String,
+
+    /// Path of the file containing the definition, relative to the root of
+    /// the config.
+    pub file: String,
+
+    /// The selection is deferred: it is inside a `@defer`ed inline fragment,
+    /// or all operations include the definition only through `@defer`ed
+    /// fragment spreads.
+    pub deferred: bool,
+
+    /// The selected field is defined in a client schema extension.
+    pub client_extension: bool,
+
+    /// The operations that include the selection, either directly or
+    /// through (nested) fragment spreads.
+    pub operations: Vec<String>,
+}
+
+/// Builds the index of all schema coordinates selected in `program`. Only the
+/// fields selected directly in a definition are attributed to it, the
+/// operations reaching it through fragment spreads are listed in
+/// `operations`.
+pub fn build_schema_coordinate_index(
+    program: &Program,
+    defer_stream_interface: &DeferStreamInterface,
+) -> SchemaCoordinateIndex {
+    let mut builder = SchemaCoordinateIndexBuilder {
+        program,
+        defer_stream_interface,
+        definitions: Default::default(),
+        spreads: Default::default(),
+        selections: Default::default(),
+        current_definition: None,
+        parent_types: Vec::new(),
+        defer_depth: 0,
+    };
+    builder.visit_program(program);
+    builder.build()
+}
+
+struct DefinitionInfo {
+    kind: String,
+    file: &'static str,
+    is_operation: bool,
+}
+
+/// A selection of a schema coordinate: the selecting definition, whether it
+/// is deferred, and whether the field is a client extension.
+type Selection = (StringKey, bool, bool);
+
+struct SchemaCoordinateIndexBuilder<'a> {
+    program: &'a Program,
+    defer_stream_interface: &'a DeferStreamInterface,
+    definitions: HashMap<StringKey, DefinitionInfo>,
+    /// The fragments spread in each definition, and whether the spread is
+    /// deferred.
+    spreads: HashMap<StringKey, Vec<(StringKey, bool)>>,
+    selections: BTreeMap<String, Vec<Selection>>,
+    current_definition: Option<StringKey>,
+    parent_types: Vec<Type>,
+    defer_depth: usize,
+}
+
+impl SchemaCoordinateIndexBuilder<'_> {
+    fn enter_definition(
+        &mut self,
+        name: StringKey,
+        kind: String,
+        location: Location,
+        type_: Type,
+        is_operation: bool,
+    ) {
+        self.definitions.insert(
+            name,
+            DefinitionInfo {
+                kind,
+                file: location.source_location().path(),
+                is_operation,
+            },
+        );
+        self.current_definition = Some(name);
+        self.parent_types = vec![type_];
+        self.defer_depth = 0;
+    }
+
+    fn add_field(&mut self, field: &WithLocation<FieldID>, arguments: &[Argument]) {
+        let schema = &self.program.schema;
+        let field = schema.field(field.item);
+        if field.name.item.lookup().starts_with("__") {
+            return;
+        }
+        let parent_type = *self
+            .parent_types
+            .last()
+            .expect("Expected a parent type for the field");
+        let field_coordinate = format!("{}.{}", schema.get_type_name(parent_type), field.name.item);
+        let selection = (
+            self.current_definition
+                .expect("Expected fields to be selected in a definition"),
+            self.defer_depth > 0,
+            field.is_extension,
+        );
+        for argument in arguments {
+            self.selections
+                .entry(format!("{}({}:)", field_coordinate, argument.name.item))
+                .or_default()
+                .push(selection);
+        }
+        self.selections
+            .entry(field_coordinate)
+            .or_default()
+            .push(selection);
+    }
+
+    /// Returns the operations reaching each definition through fragment
+    /// spreads, and whether they only reach it through deferred spreads.
+    /// Operations reach themselves.
+    fn reaching_operations(&self) -> HashMap<StringKey, BTreeMap<String, bool>> {
+        let mut reaching_operations: HashMap<StringKey, BTreeMap<String, bool>> = HashMap::new();
+        for (operation_name, _) in self
+            .definitions
+            .iter()
+            .filter(|(_, definition)| definition.is_operation)
+        {
+            let mut visited = HashSet::new();
+            let mut stack = vec![(*operation_name, false)];
+            while let Some((definition_name, deferred)) = stack.pop() {
+                if !visited.insert((definition_name, deferred)) {
+                    continue;
+                }
+                reaching_operations
+                    .entry(definition_name)
+                    .or_default()
+                    .entry(operation_name.to_string())
+                    .and_modify(|all_deferred| *all_deferred &= deferred)
+                    .or_insert(deferred);
+                if let Some(spreads) = self.spreads.get(&definition_name) {
+                    stack.extend(spreads.iter().map(|(fragment_name, is_deferred)| {
+                        (*fragment_name, deferred || *is_deferred)
+                    }));
+                }
+            }
+        }
+        reaching_operations
+    }
+
+    fn build(self) -> SchemaCoordinateIndex {
+        let reaching_operations = self.reaching_operations();
+        self.selections
+            .into_iter()
+            .map(|(coordinate, selections)| {
+                let usages: BTreeSet<_> = selections
+                    .into_iter()
+                    .map(|(definition_name, deferred, client_extension)| {
+                        let definition = &self.definitions[&definition_name];
+                        let operations = reaching_operations.get(&definition_name);
+                        let deferred_in_all_operations = operations.map_or(false, |operations| {
+                            operations.values().all(|all_deferred| *all_deferred)
+                        });
+                        SchemaCoordinateUsage {
+                            definition: definition_name.to_string(),
+                            kind: definition.kind.clone(),
+                            file: definition.file.to_string(),
+                            deferred: deferred || deferred_in_all_operations,
+                            client_extension,
+                            operations: operations
+                                .map(|operations| operations.keys().cloned().collect())
+                                .unwrap_or_default(),
+                        }
+                    })
+                    .collect();
+                (coordinate, usages.into_iter().collect())
+            })
+            .collect()
+    }
+}
+
+impl Visitor for SchemaCoordinateIndexBuilder<'_> {
+    const NAME: &'static str = "SchemaCoordinateIndexBuilder";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_operation(&mut self, operation: &OperationDefinition) {
+        self.enter_definition(
+            operation.name.item.0,
+            operation.kind.to_string(),
+            operation.name.location,
+            operation.type_,
+            true,
+        );
+        self.default_visit_operation(operation);
+    }
+
+    fn visit_fragment(&mut self, fragment: &FragmentDefinition) {
+        self.enter_definition(
+            fragment.name.item.0,
+            "fragment".to_string(),
+            fragment.name.location,
+            fragment.type_condition,
+            false,
+        );
+        self.default_visit_fragment(fragment);
+    }
+
+    fn visit_scalar_field(&mut self, field: &ScalarField) {
+        self.add_field(&field.definition, &field.arguments);
+    }
+
+    fn visit_linked_field(&mut self, field: &LinkedField) {
+        self.add_field(&field.definition, &field.arguments);
+        let field_type = self
+            .program
+            .schema
+            .field(field.definition.item)
+            .type_
+            .inner();
+        self.parent_types.push(field_type);
+        self.default_visit_linked_field(field);
+        self.parent_types.pop();
+    }
+
+    fn visit_inline_fragment(&mut self, fragment: &InlineFragment) {
+        let is_deferred = fragment
+            .directives
+            .named(self.defer_stream_interface.defer_name)
+            .is_some();
+        if is_deferred {
+            self.defer_depth += 1;
+        }
+        let parent_type = fragment
+            .type_condition
+            .or_else(|| self.parent_types.last().copied())
+            .expect("Expected a parent type for the inline fragment");
+        self.parent_types.push(parent_type);
+        self.default_visit_inline_fragment(fragment);
+        self.parent_types.pop();
+        if is_deferred {
+            self.defer_depth -= 1;
+        }
+    }
+
+    fn visit_fragment_spread(&mut self, spread: &FragmentSpread) {
+        let is_deferred = self.defer_depth > 0
+            || spread
+                .directives
+                .named(self.defer_stream_interface.defer_name)
+                .is_some();
+        if let Some(definition) = self.current_definition {
+            self.spreads
+                .entry(definition)
+                .or_default()
+                .push((spread.fragment.item.0, is_deferred));
+        }
+    }
+}
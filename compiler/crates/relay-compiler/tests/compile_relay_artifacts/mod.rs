@@ -122,6 +122,7 @@ pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String>
         relay_resolvers_allow_legacy_verbose_syntax: FeatureFlag::Disabled,
         enable_relay_resolver_mutations: false,
         enable_strict_custom_scalars: false,
+        enable_resolver_cycle_validation: false,
         allow_required_in_mutation_response: FeatureFlag::Disabled,
         allow_resolvers_in_mutation_response: FeatureFlag::Disabled,
     };
@@ -213,6 +214,7 @@ pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String>
         Arc::new(ConsoleLogger),
         None,
         None,
+        None,
     )
     .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
 
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<f7c4dc54dc731835b1cb648cc89ad592>>
+ * @generated SignedSource<<76f9e43aeda36783a91ac31bbb79d74c>>
  */
 
 mod compile_relay_artifacts;
@@ -159,6 +159,13 @@ async fn auto_filled_argument_on_match() {
     test_fixture(transform_fixture, file!(), "auto-filled-argument-on-match.graphql", "compile_relay_artifacts/fixtures/auto-filled-argument-on-match.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn catch_directive() {
+    let input = include_str!("compile_relay_artifacts/fixtures/catch-directive.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/catch-directive.expected");
+    test_fixture(transform_fixture, file!(), "catch-directive.graphql", "compile_relay_artifacts/fixtures/catch-directive.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn circular_inline_fragment_invalid() {
     let input = include_str!("compile_relay_artifacts/fixtures/circular-inline-fragment.invalid.graphql");
@@ -488,6 +495,13 @@ async fn delete_edge_plural() {
     test_fixture(transform_fixture, file!(), "delete-edge-plural.graphql", "compile_relay_artifacts/fixtures/delete-edge-plural.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn dependent_operation() {
+    let input = include_str!("compile_relay_artifacts/fixtures/dependent-operation.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/dependent-operation.expected");
+    test_fixture(transform_fixture, file!(), "dependent-operation.graphql", "compile_relay_artifacts/fixtures/dependent-operation.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn directive_with_conditions() {
     let input = include_str!("compile_relay_artifacts/fixtures/directive_with_conditions.graphql");
@@ -1181,6 +1195,13 @@ async fn relay_model_resolver() {
     test_fixture(transform_fixture, file!(), "relay-model-resolver.graphql", "compile_relay_artifacts/fixtures/relay-model-resolver.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn relay_model_resolver_batch() {
+    let input = include_str!("compile_relay_artifacts/fixtures/relay-model-resolver-batch.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/relay-model-resolver-batch.expected");
+    test_fixture(transform_fixture, file!(), "relay-model-resolver-batch.graphql", "compile_relay_artifacts/fixtures/relay-model-resolver-batch.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn relay_resolver() {
     let input = include_str!("compile_relay_artifacts/fixtures/relay-resolver.graphql");
@@ -1202,6 +1223,13 @@ async fn relay_resolver_backing_client_edge() {
     test_fixture(transform_fixture, file!(), "relay-resolver-backing-client-edge.graphql", "compile_relay_artifacts/fixtures/relay-resolver-backing-client-edge.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn relay_resolver_batch() {
+    let input = include_str!("compile_relay_artifacts/fixtures/relay-resolver-batch.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/relay-resolver-batch.expected");
+    test_fixture(transform_fixture, file!(), "relay-resolver-batch.graphql", "compile_relay_artifacts/fixtures/relay-resolver-batch.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn relay_resolver_edge_to_interface_with_child_interface_and_no_implementors() {
     let input = include_str!("compile_relay_artifacts/fixtures/relay-resolver-edge-to-interface-with-child-interface-and-no-implementors.graphql");
@@ -1426,6 +1454,13 @@ async fn required_directive() {
     test_fixture(transform_fixture, file!(), "required-directive.graphql", "compile_relay_artifacts/fixtures/required-directive.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn required_on_aliased_fragments() {
+    let input = include_str!("compile_relay_artifacts/fixtures/required-on-aliased-fragments.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/required-on-aliased-fragments.expected");
+    test_fixture(transform_fixture, file!(), "required-on-aliased-fragments.graphql", "compile_relay_artifacts/fixtures/required-on-aliased-fragments.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn resolver_field_with_all_fragment_args_omitted() {
     let input = include_str!("compile_relay_artifacts/fixtures/resolver-field-with-all-fragment-args-omitted.graphql");
@@ -0,0 +1,25 @@
+const {graphql} = require('relay-runtime');
+
+const userFragment = graphql`
+  fragment UserComponent_user on User
+    @argumentDefinitions(size: {type: "[Int]", defaultValue: [32]}) {
+    profilePicture(size: $size) {
+      uri
+    }
+  }
+`;
+
+// A comment with a non-ASCII character: ü
+const query = graphql`
+  query UserComponentQuery($id: ID!) {
+    node(id: $id) {
+      ...UserComponent_user @arguments(size: [48])
+    }
+  }
+`;
+
+const unchanged = graphql`
+  fragment UserComponent_name on User {
+    name
+  }
+`;
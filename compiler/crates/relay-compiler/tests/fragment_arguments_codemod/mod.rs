@@ -0,0 +1,15 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use fixture_tests::Fixture;
+use relay_compiler::rewrite_fragment_arguments;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let is_graphql_file = fixture.file_name.ends_with(".graphql");
+    Ok(rewrite_fragment_arguments(fixture.content, is_graphql_file)
+        .unwrap_or_else(|| fixture.content.to_string()))
+}
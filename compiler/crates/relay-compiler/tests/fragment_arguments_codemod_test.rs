@@ -0,0 +1,41 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<1385868f9c0eb279a24810624d4a3153>>
+ */
+
+mod fragment_arguments_codemod;
+
+use fragment_arguments_codemod::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn argument_definitions() {
+    let input = include_str!("fragment_arguments_codemod/fixtures/argument-definitions.graphql");
+    let expected = include_str!("fragment_arguments_codemod/fixtures/argument-definitions.expected");
+    test_fixture(transform_fixture, file!(), "argument-definitions.graphql", "fragment_arguments_codemod/fixtures/argument-definitions.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn arguments() {
+    let input = include_str!("fragment_arguments_codemod/fixtures/arguments.graphql");
+    let expected = include_str!("fragment_arguments_codemod/fixtures/arguments.expected");
+    test_fixture(transform_fixture, file!(), "arguments.graphql", "fragment_arguments_codemod/fixtures/arguments.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn javascript_module() {
+    let input = include_str!("fragment_arguments_codemod/fixtures/javascript-module.js");
+    let expected = include_str!("fragment_arguments_codemod/fixtures/javascript-module.expected");
+    test_fixture(transform_fixture, file!(), "javascript-module.js", "fragment_arguments_codemod/fixtures/javascript-module.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn unsupported_argument_definitions() {
+    let input = include_str!("fragment_arguments_codemod/fixtures/unsupported-argument-definitions.graphql");
+    let expected = include_str!("fragment_arguments_codemod/fixtures/unsupported-argument-definitions.expected");
+    test_fixture(transform_fixture, file!(), "unsupported-argument-definitions.graphql", "fragment_arguments_codemod/fixtures/unsupported-argument-definitions.expected", input, expected).await;
+}
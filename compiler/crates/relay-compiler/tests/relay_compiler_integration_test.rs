@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<ed48f4268d08f4756f45e1977f5661c8>>
+ * @generated SignedSource<<e3ed282a61557e6779cf0746015612db>>
  */
 
 mod relay_compiler_integration;
@@ -61,6 +61,27 @@ async fn custom_scalar_variable_default_arg_non_strict() {
     test_fixture(transform_fixture, file!(), "custom_scalar_variable_default_arg_non_strict.input", "relay_compiler_integration/fixtures/custom_scalar_variable_default_arg_non_strict.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn esm_module_format() {
+    let input = include_str!("relay_compiler_integration/fixtures/esm_module_format.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/esm_module_format.expected");
+    test_fixture(transform_fixture, file!(), "esm_module_format.input", "relay_compiler_integration/fixtures/esm_module_format.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn flow_doc_comments() {
+    let input = include_str!("relay_compiler_integration/fixtures/flow_doc_comments.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/flow_doc_comments.expected");
+    test_fixture(transform_fixture, file!(), "flow_doc_comments.input", "relay_compiler_integration/fixtures/flow_doc_comments.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn json_artifact_format() {
+    let input = include_str!("relay_compiler_integration/fixtures/json_artifact_format.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/json_artifact_format.expected");
+    test_fixture(transform_fixture, file!(), "json_artifact_format.input", "relay_compiler_integration/fixtures/json_artifact_format.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn live_resolver_implements_interface_field() {
     let input = include_str!("relay_compiler_integration/fixtures/live_resolver_implements_interface_field.input");
@@ -68,6 +89,27 @@ async fn live_resolver_implements_interface_field() {
     test_fixture(transform_fixture, file!(), "live_resolver_implements_interface_field.input", "relay_compiler_integration/fixtures/live_resolver_implements_interface_field.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn mock_resolvers() {
+    let input = include_str!("relay_compiler_integration/fixtures/mock_resolvers.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/mock_resolvers.expected");
+    test_fixture(transform_fixture, file!(), "mock_resolvers.input", "relay_compiler_integration/fixtures/mock_resolvers.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_refetchable_fragment() {
+    let input = include_str!("relay_compiler_integration/fixtures/paginated_refetchable_fragment.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/paginated_refetchable_fragment.expected");
+    test_fixture(transform_fixture, file!(), "paginated_refetchable_fragment.input", "relay_compiler_integration/fixtures/paginated_refetchable_fragment.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_refetchable_fragment_const_arguments_invalid() {
+    let input = include_str!("relay_compiler_integration/fixtures/paginated_refetchable_fragment_const_arguments.invalid.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/paginated_refetchable_fragment_const_arguments.invalid.expected");
+    test_fixture(transform_fixture, file!(), "paginated_refetchable_fragment_const_arguments.invalid.input", "relay_compiler_integration/fixtures/paginated_refetchable_fragment_const_arguments.invalid.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn preloadable_query_flow() {
     let input = include_str!("relay_compiler_integration/fixtures/preloadable_query_flow.input");
@@ -89,6 +131,27 @@ async fn preloadable_query_typescript() {
     test_fixture(transform_fixture, file!(), "preloadable_query_typescript.input", "relay_compiler_integration/fixtures/preloadable_query_typescript.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn resolver_cycle_validation_invalid() {
+    let input = include_str!("relay_compiler_integration/fixtures/resolver_cycle_validation.invalid.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/resolver_cycle_validation.invalid.expected");
+    test_fixture(transform_fixture, file!(), "resolver_cycle_validation.invalid.input", "relay_compiler_integration/fixtures/resolver_cycle_validation.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn resolver_dependency_graph() {
+    let input = include_str!("relay_compiler_integration/fixtures/resolver_dependency_graph.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/resolver_dependency_graph.expected");
+    test_fixture(transform_fixture, file!(), "resolver_dependency_graph.input", "relay_compiler_integration/fixtures/resolver_dependency_graph.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn resolver_dependency_graph_dot() {
+    let input = include_str!("relay_compiler_integration/fixtures/resolver_dependency_graph_dot.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/resolver_dependency_graph_dot.expected");
+    test_fixture(transform_fixture, file!(), "resolver_dependency_graph_dot.input", "relay_compiler_integration/fixtures/resolver_dependency_graph_dot.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn resolver_on_interface() {
     let input = include_str!("relay_compiler_integration/fixtures/resolver_on_interface.input");
@@ -145,6 +208,62 @@ async fn resolvers_schema_module_apply_to_normalization_ast() {
     test_fixture(transform_fixture, file!(), "resolvers_schema_module_apply_to_normalization_ast.input", "relay_compiler_integration/fixtures/resolvers_schema_module_apply_to_normalization_ast.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn schema_dir_duplicate_type_in_file() {
+    let input = include_str!("relay_compiler_integration/fixtures/schema_dir_duplicate_type_in_file.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/schema_dir_duplicate_type_in_file.expected");
+    test_fixture(transform_fixture, file!(), "schema_dir_duplicate_type_in_file.input", "relay_compiler_integration/fixtures/schema_dir_duplicate_type_in_file.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn schema_dir_duplicate_type_invalid() {
+    let input = include_str!("relay_compiler_integration/fixtures/schema_dir_duplicate_type.invalid.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/schema_dir_duplicate_type.invalid.expected");
+    test_fixture(transform_fixture, file!(), "schema_dir_duplicate_type.invalid.input", "relay_compiler_integration/fixtures/schema_dir_duplicate_type.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn schema_dir_federation() {
+    let input = include_str!("relay_compiler_integration/fixtures/schema_dir_federation.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/schema_dir_federation.expected");
+    test_fixture(transform_fixture, file!(), "schema_dir_federation.input", "relay_compiler_integration/fixtures/schema_dir_federation.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn schema_dir_federation_conflicting_field_types_invalid() {
+    let input = include_str!("relay_compiler_integration/fixtures/schema_dir_federation_conflicting_field_types.invalid.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/schema_dir_federation_conflicting_field_types.invalid.expected");
+    test_fixture(transform_fixture, file!(), "schema_dir_federation_conflicting_field_types.invalid.input", "relay_compiler_integration/fixtures/schema_dir_federation_conflicting_field_types.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn schema_dir_type_prefixes() {
+    let input = include_str!("relay_compiler_integration/fixtures/schema_dir_type_prefixes.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/schema_dir_type_prefixes.expected");
+    test_fixture(transform_fixture, file!(), "schema_dir_type_prefixes.input", "relay_compiler_integration/fixtures/schema_dir_type_prefixes.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn schema_dir_type_prefixes_incomplete_files() {
+    let input = include_str!("relay_compiler_integration/fixtures/schema_dir_type_prefixes_incomplete_files.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/schema_dir_type_prefixes_incomplete_files.expected");
+    test_fixture(transform_fixture, file!(), "schema_dir_type_prefixes_incomplete_files.input", "relay_compiler_integration/fixtures/schema_dir_type_prefixes_incomplete_files.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn shared_ast_modules() {
+    let input = include_str!("relay_compiler_integration/fixtures/shared_ast_modules.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/shared_ast_modules.expected");
+    test_fixture(transform_fixture, file!(), "shared_ast_modules.input", "relay_compiler_integration/fixtures/shared_ast_modules.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn shared_ast_modules_single_use() {
+    let input = include_str!("relay_compiler_integration/fixtures/shared_ast_modules_single_use.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/shared_ast_modules_single_use.expected");
+    test_fixture(transform_fixture, file!(), "shared_ast_modules_single_use.input", "relay_compiler_integration/fixtures/shared_ast_modules_single_use.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn simple_fragment() {
     let input = include_str!("relay_compiler_integration/fixtures/simple_fragment.input");
@@ -152,6 +271,27 @@ async fn simple_fragment() {
     test_fixture(transform_fixture, file!(), "simple_fragment.input", "relay_compiler_integration/fixtures/simple_fragment.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn typed_hooks_flow() {
+    let input = include_str!("relay_compiler_integration/fixtures/typed_hooks_flow.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/typed_hooks_flow.expected");
+    test_fixture(transform_fixture, file!(), "typed_hooks_flow.input", "relay_compiler_integration/fixtures/typed_hooks_flow.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn typed_hooks_json_artifact_format() {
+    let input = include_str!("relay_compiler_integration/fixtures/typed_hooks_json_artifact_format.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/typed_hooks_json_artifact_format.expected");
+    test_fixture(transform_fixture, file!(), "typed_hooks_json_artifact_format.input", "relay_compiler_integration/fixtures/typed_hooks_json_artifact_format.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn typed_hooks_typescript() {
+    let input = include_str!("relay_compiler_integration/fixtures/typed_hooks_typescript.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/typed_hooks_typescript.expected");
+    test_fixture(transform_fixture, file!(), "typed_hooks_typescript.input", "relay_compiler_integration/fixtures/typed_hooks_typescript.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn typescript_resolver_type_import() {
     let input = include_str!("relay_compiler_integration/fixtures/typescript_resolver_type_import.input");
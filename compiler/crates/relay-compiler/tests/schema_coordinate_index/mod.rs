@@ -0,0 +1,38 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::build;
+use graphql_ir::Program;
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use relay_compiler::build_schema_coordinate_index;
+use relay_config::DeferStreamInterface;
+use relay_test_schema::get_test_schema;
+use relay_test_schema::get_test_schema_with_extensions;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split("%extensions%").collect();
+    let (base, schema) = match parts.as_slice() {
+        [base, extensions] => (base, get_test_schema_with_extensions(extensions)),
+        [base] => (base, get_test_schema()),
+        _ => panic!("Invalid fixture input {}", fixture.content),
+    };
+
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let ast = parse_executable(base, source_location)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(base, &diagnostics))?;
+    let ir = build(&schema, &ast.definitions)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(base, &diagnostics))?;
+    let program = Program::from_definitions(Arc::clone(&schema), ir);
+
+    let index = build_schema_coordinate_index(&program, &DeferStreamInterface::default());
+    Ok(serde_json::to_string_pretty(&index).unwrap())
+}
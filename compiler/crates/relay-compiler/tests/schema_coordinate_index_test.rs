@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<578407635a2e1e15717bfce1c87f2ce3>>
+ */
+
+mod schema_coordinate_index;
+
+use schema_coordinate_index::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn schema_coordinate_index() {
+    let input = include_str!("schema_coordinate_index/fixtures/schema_coordinate_index.graphql");
+    let expected = include_str!("schema_coordinate_index/fixtures/schema_coordinate_index.expected");
+    test_fixture(transform_fixture, file!(), "schema_coordinate_index.graphql", "schema_coordinate_index/fixtures/schema_coordinate_index.expected", input, expected).await;
+}
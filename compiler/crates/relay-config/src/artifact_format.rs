@@ -0,0 +1,33 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The file format of operation and fragment artifacts.
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ArtifactFormat {
+    /// JavaScript modules, e.g. `MyQuery.graphql.js`
+    #[default]
+    Js,
+    /// JSON files with the reader and normalization ASTs, e.g.
+    /// `MyQuery.graphql.json`, for runtimes that don't run JavaScript.
+    Json,
+    /// Both JavaScript modules and JSON files.
+    JsAndJson,
+}
+
+impl ArtifactFormat {
+    pub fn writes_js(self) -> bool {
+        matches!(self, ArtifactFormat::Js | ArtifactFormat::JsAndJson)
+    }
+
+    pub fn writes_json(self) -> bool {
+        matches!(self, ArtifactFormat::Json | ArtifactFormat::JsAndJson)
+    }
+}
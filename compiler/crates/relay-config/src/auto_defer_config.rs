@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Configuration for deferring the most expensive fragment spreads of queries
+/// whose estimated cost exceeds a budget.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct AutoDeferConfig {
+    /// JSON file mapping schema coordinates of fields to their estimated cost
+    /// in milliseconds, e.g. `{"User.friends": 120}`, relative to the root of
+    /// the config. Fields without a hint cost nothing.
+    pub cost_hints: PathBuf,
+
+    /// The estimated cost, in milliseconds, that the initial payload of a
+    /// query should not exceed. Fragment spreads are deferred, most expensive
+    /// first, until it fits.
+    pub budget_ms: u64,
+}
@@ -0,0 +1,42 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// How the compiler reports selections of deprecated fields and arguments.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DeprecationPolicy {
+    /// Report usages as warnings.
+    Warn,
+    /// Report usages as errors.
+    Error,
+    /// Report usages as errors once the removal date of the deprecation has
+    /// passed, and as warnings before that. The date is read from a `date`
+    /// argument of `@deprecated`, or from a `YYYY-MM-DD` date in the
+    /// deprecation reason. Deprecations without a date are warnings.
+    ErrorAfterDate,
+}
+
+/// Configuration for reporting usages of deprecated fields and arguments.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct DeprecationConfig {
+    pub policy: DeprecationPolicy,
+
+    /// Files listing existing usages that are not reported, relative to the
+    /// root of the config. Each line contains a schema coordinate and the
+    /// operation or fragment using it, separated by whitespace, e.g.
+    /// `User.email ProfileQuery`. Lines starting with `#` are ignored.
+    /// `relay-compiler deprecations --allowlist` prints all current usages
+    /// in this format.
+    #[serde(default)]
+    pub allowlist: Vec<PathBuf>,
+}
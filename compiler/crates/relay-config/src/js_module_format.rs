@@ -5,6 +5,8 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::path::Path;
+
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -16,6 +18,10 @@ pub enum JsModuleFormat {
     CommonJS,
     /// Facebook style, e.g. `require('MyModule')`
     Haste,
+    /// ES modules for native ESM resolution, e.g.
+    /// `import MyModule from '../path/MyModule.js'`. Relative import
+    /// specifiers get the `esmImportExtension`.
+    ESM,
 }
 
 impl Default for JsModuleFormat {
@@ -23,3 +29,19 @@ impl Default for JsModuleFormat {
         JsModuleFormat::CommonJS
     }
 }
+
+/// The specifier to import a source module of the project with, with the
+/// `esm` module format: the extension of the source is replaced with the one
+/// of the JavaScript module it is compiled to, e.g. `./Foo.ts` becomes
+/// `./Foo.js` and `./Foo.mts` becomes `./Foo.mjs`. Other specifiers are kept
+/// as they are.
+pub fn esm_source_import_specifier(specifier: &str) -> String {
+    let path = Path::new(specifier);
+    let extension = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("js" | "jsx" | "ts" | "tsx") => "js",
+        Some("mjs" | "mts") => "mjs",
+        Some("cjs" | "cts") => "cjs",
+        _ => return specifier.to_string(),
+    };
+    path.with_extension(extension).to_string_lossy().to_string()
+}
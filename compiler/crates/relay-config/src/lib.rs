@@ -9,25 +9,38 @@
 #![deny(rust_2018_idioms)]
 #![deny(clippy::all)]
 
+mod artifact_format;
+mod auto_defer_config;
 mod connection_interface;
 mod defer_stream_interface;
+mod deprecation_config;
 mod diagnostic_report_config;
 mod js_module_format;
 mod module_import_config;
 mod non_node_id_fields_config;
+mod pagination_interface;
 mod project_config;
 mod project_name;
+mod resolver_dependency_graph_config;
 mod resolvers_schema_module_config;
+mod schema_dir_config;
+mod shared_ast_modules_config;
 mod typegen_config;
 
+pub use artifact_format::ArtifactFormat;
+pub use auto_defer_config::AutoDeferConfig;
 pub use connection_interface::ConnectionInterface;
 pub use defer_stream_interface::DeferStreamInterface;
+pub use deprecation_config::DeprecationConfig;
+pub use deprecation_config::DeprecationPolicy;
 pub use diagnostic_report_config::DiagnosticLevel;
 pub use diagnostic_report_config::DiagnosticReportConfig;
+pub use js_module_format::esm_source_import_specifier;
 pub use js_module_format::JsModuleFormat;
 pub use module_import_config::DynamicModuleProvider;
 pub use module_import_config::ModuleImportConfig;
 pub use non_node_id_fields_config::NonNodeIdFieldsConfig;
+pub use pagination_interface::PaginationInterface;
 pub use project_config::ExtraArtifactsConfig;
 pub use project_config::LocalPersistAlgorithm;
 pub use project_config::LocalPersistConfig;
@@ -37,8 +50,17 @@ pub use project_config::RemotePersistConfig;
 pub use project_config::SchemaConfig;
 pub use project_config::SchemaLocation;
 pub use project_name::ProjectName;
+pub use resolver_dependency_graph_config::ResolverDependencyGraphConfig;
+pub use resolver_dependency_graph_config::ResolverDependencyGraphFormat;
 pub use resolvers_schema_module_config::ResolversSchemaModuleConfig;
+pub use schema_dir_config::GlobPattern;
+pub use schema_dir_config::SchemaDirConfig;
+pub use shared_ast_modules_config::SharedAstModulesConfig;
 pub use typegen_config::CustomScalarType;
 pub use typegen_config::CustomScalarTypeImport;
+pub use typegen_config::MockResolversConfig;
+pub use typegen_config::RuntimeValidatorLibrary;
+pub use typegen_config::RuntimeValidatorsConfig;
+pub use typegen_config::SchemaTypesModuleConfig;
 pub use typegen_config::TypegenConfig;
 pub use typegen_config::TypegenLanguage;
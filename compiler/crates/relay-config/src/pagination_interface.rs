@@ -0,0 +1,41 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::ArgumentName;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Configuration of the fields and arguments Relay should expect on fields
+/// paginated with `@paginated`, as an alternative to cursor connections.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct PaginationInterface {
+    /// The list field containing the items of a page.
+    pub items: StringKey,
+    /// The offset argument of `@paginated(kind: OFFSET)` fields.
+    pub offset_arg: ArgumentName,
+    /// The page size argument of `@paginated(kind: OFFSET)` fields.
+    pub limit_arg: ArgumentName,
+    /// The page number argument of `@paginated(kind: PAGE)` fields.
+    pub page_arg: ArgumentName,
+    /// The page size argument of `@paginated(kind: PAGE)` fields.
+    pub page_size_arg: ArgumentName,
+}
+
+impl Default for PaginationInterface {
+    fn default() -> Self {
+        PaginationInterface {
+            items: "items".intern(),
+            offset_arg: ArgumentName("offset".intern()),
+            limit_arg: ArgumentName("limit".intern()),
+            page_arg: ArgumentName("page".intern()),
+            page_size_arg: ArgumentName("pageSize".intern()),
+        }
+    }
+}
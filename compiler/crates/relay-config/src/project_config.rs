@@ -29,12 +29,20 @@ use serde::Deserializer;
 use serde::Serialize;
 use serde_json::Value;
 
+use crate::artifact_format::ArtifactFormat;
+use crate::auto_defer_config::AutoDeferConfig;
 use crate::connection_interface::ConnectionInterface;
 use crate::defer_stream_interface::DeferStreamInterface;
+use crate::deprecation_config::DeprecationConfig;
 use crate::diagnostic_report_config::DiagnosticReportConfig;
+use crate::esm_source_import_specifier;
 use crate::module_import_config::ModuleImportConfig;
 use crate::non_node_id_fields_config::NonNodeIdFieldsConfig;
+use crate::pagination_interface::PaginationInterface;
+use crate::resolver_dependency_graph_config::ResolverDependencyGraphConfig;
 use crate::resolvers_schema_module_config::ResolversSchemaModuleConfig;
+use crate::schema_dir_config::SchemaDirConfig;
+use crate::shared_ast_modules_config::SharedAstModulesConfig;
 use crate::JsModuleFormat;
 use crate::ProjectName;
 use crate::TypegenConfig;
@@ -188,6 +196,9 @@ pub struct SchemaConfig {
     #[serde(default)]
     pub defer_stream_interface: DeferStreamInterface,
 
+    #[serde(default)]
+    pub pagination_interface: PaginationInterface,
+
     /// The name of the variable expected by the `node` query.
     #[serde(default = "default_node_interface_id_variable_name")]
     pub node_interface_id_variable_name: StringKey,
@@ -217,6 +228,7 @@ impl Default for SchemaConfig {
         Self {
             connection_interface: ConnectionInterface::default(),
             defer_stream_interface: DeferStreamInterface::default(),
+            pagination_interface: PaginationInterface::default(),
             node_interface_id_field: default_node_interface_id_field(),
             node_interface_id_variable_name: default_node_interface_id_variable_name(),
             non_node_id_fields: None,
@@ -236,6 +248,7 @@ pub struct ProjectConfig {
     pub schema_extensions: Vec<PathBuf>,
     pub enabled: bool,
     pub schema_location: SchemaLocation,
+    pub schema_dir_config: SchemaDirConfig,
     pub schema_config: SchemaConfig,
     pub typegen_config: TypegenConfig,
     pub persist: Option<PersistConfig>,
@@ -247,7 +260,13 @@ pub struct ProjectConfig {
     pub js_module_format: JsModuleFormat,
     pub module_import_config: ModuleImportConfig,
     pub diagnostic_report_config: DiagnosticReportConfig,
+    pub deprecation_config: Option<DeprecationConfig>,
+    pub auto_defer: Option<AutoDeferConfig>,
     pub resolvers_schema_module: Option<ResolversSchemaModuleConfig>,
+    pub shared_ast_modules: Option<SharedAstModulesConfig>,
+    pub resolver_dependency_graph: Option<ResolverDependencyGraphConfig>,
+    pub artifact_format: ArtifactFormat,
+    pub typed_hooks: bool,
     pub codegen_command: Option<String>,
 }
 
@@ -265,6 +284,7 @@ impl Default for ProjectConfig {
             schema_extensions: vec![],
             enabled: true,
             schema_location: SchemaLocation::File(PathBuf::default()),
+            schema_dir_config: Default::default(),
             schema_config: Default::default(),
             typegen_config: Default::default(),
             persist: None,
@@ -275,7 +295,13 @@ impl Default for ProjectConfig {
             js_module_format: Default::default(),
             module_import_config: Default::default(),
             diagnostic_report_config: Default::default(),
+            deprecation_config: None,
+            auto_defer: None,
             resolvers_schema_module: Default::default(),
+            shared_ast_modules: None,
+            resolver_dependency_graph: None,
+            artifact_format: Default::default(),
+            typed_hooks: false,
             codegen_command: Default::default(),
         }
     }
@@ -294,6 +320,7 @@ impl Debug for ProjectConfig {
             schema_extensions,
             enabled,
             schema_location,
+            schema_dir_config,
             schema_config,
             typegen_config,
             persist,
@@ -305,7 +332,13 @@ impl Debug for ProjectConfig {
             js_module_format,
             module_import_config,
             diagnostic_report_config,
+            deprecation_config,
+            auto_defer,
             resolvers_schema_module,
+            shared_ast_modules,
+            resolver_dependency_graph,
+            artifact_format,
+            typed_hooks,
             codegen_command,
         } = self;
         f.debug_struct("ProjectConfig")
@@ -319,6 +352,7 @@ impl Debug for ProjectConfig {
             .field("schema_extensions", schema_extensions)
             .field("enabled", enabled)
             .field("schema_location", schema_location)
+            .field("schema_dir_config", schema_dir_config)
             .field("schema_config", schema_config)
             .field("typegen_config", typegen_config)
             .field("persist", persist)
@@ -330,7 +364,13 @@ impl Debug for ProjectConfig {
             .field("js_module_format", js_module_format)
             .field("module_import_config", module_import_config)
             .field("diagnostic_report_config", diagnostic_report_config)
+            .field("deprecation_config", deprecation_config)
+            .field("auto_defer", auto_defer)
             .field("resolvers_schema_module", resolvers_schema_module)
+            .field("shared_ast_modules", shared_ast_modules)
+            .field("resolver_dependency_graph", resolver_dependency_graph)
+            .field("artifact_format", artifact_format)
+            .field("typed_hooks", typed_hooks)
             .field("codegen_command", codegen_command)
             .finish()
     }
@@ -396,20 +436,119 @@ impl ProjectConfig {
                 format!("{}.js", artifact_file_name)
             }
             TypegenLanguage::TypeScript => format!("{}.ts", artifact_file_name),
+            // ReScript module names can't contain dots, `Foo.graphql` is
+            // written to `Foo_graphql.res`.
+            TypegenLanguage::ReScript => {
+                format!("{}.res", artifact_file_name.replace('.', "_"))
+            }
         };
 
         self.create_path_for_artifact(source_file, filename)
     }
 
+    /// Path of the module with the enum and input object types of the schema,
+    /// without extension, if it is enabled.
+    pub fn schema_types_module_path(&self) -> Option<PathBuf> {
+        if self.typegen_config.language == TypegenLanguage::JavaScript {
+            return None;
+        }
+        let config = self.typegen_config.schema_types_module.as_ref()?;
+        Some(match (&config.path, &self.output) {
+            (Some(path), _) => path.clone(),
+            (None, Some(output)) => output.join("SchemaTypes"),
+            (None, None) => PathBuf::from("__generated__/SchemaTypes"),
+        })
+    }
+
+    /// Path of the module with the `MockResolvers` type, without extension,
+    /// if it is enabled.
+    pub fn mock_resolvers_module_path(&self) -> Option<PathBuf> {
+        if matches!(
+            self.typegen_config.language,
+            TypegenLanguage::JavaScript | TypegenLanguage::ReScript
+        ) {
+            return None;
+        }
+        let config = self.typegen_config.mock_resolvers.as_ref()?;
+        Some(match (&config.path, &self.output) {
+            (Some(path), _) => path.clone(),
+            (None, Some(output)) => output.join("MockResolvers"),
+            (None, None) => PathBuf::from("__generated__/MockResolvers"),
+        })
+    }
+
+    /// Directory of the modules with the subtrees of normalization and reader
+    /// ASTs shared between artifacts, if it is enabled.
+    pub fn shared_ast_modules_path(&self) -> Option<PathBuf> {
+        let config = self.shared_ast_modules.as_ref()?;
+        Some(match (&config.path, &self.output) {
+            (Some(path), _) => path.clone(),
+            (None, Some(output)) => output.join("shared"),
+            (None, None) => PathBuf::from("__generated__/shared"),
+        })
+    }
+
+    /// Path of the dependency graph of the Relay Resolvers, if it is enabled.
+    pub fn resolver_dependency_graph_path(&self) -> Option<PathBuf> {
+        let config = self.resolver_dependency_graph.as_ref()?;
+        Some(match (&config.path, &self.output) {
+            (Some(path), _) => path.clone(),
+            (None, Some(output)) => output
+                .join("ResolverDependencyGraph")
+                .with_extension(config.format.extension()),
+            (None, None) => PathBuf::from("__generated__/ResolverDependencyGraph")
+                .with_extension(config.format.extension()),
+        })
+    }
+
+    /// Whether generated artifacts are ES modules, with the `esm` module
+    /// format or `eagerEsModules`.
+    pub fn is_es_module(&self) -> bool {
+        self.typegen_config.eager_es_modules || matches!(self.js_module_format, JsModuleFormat::ESM)
+    }
+
+    /// The extension appended to relative import specifiers, with the `esm`
+    /// module format.
+    pub fn esm_import_extension(&self) -> Option<&str> {
+        match self.js_module_format {
+            JsModuleFormat::ESM => Some(
+                self.typegen_config
+                    .esm_import_extension
+                    .trim_start_matches('.'),
+            ),
+            JsModuleFormat::CommonJS | JsModuleFormat::Haste => None,
+        }
+    }
+
+    /// Appends the `esm` import extension to the relative import specifier of
+    /// a generated module, e.g. `./Foo.graphql` becomes `./Foo.graphql.js`.
+    pub fn js_module_import_specifier(&self, specifier: String) -> String {
+        match self.esm_import_extension() {
+            Some(extension) => format!("{}.{}", specifier, extension),
+            None => specifier,
+        }
+    }
+
+    /// The specifier to import a source module of the project with, e.g. a
+    /// resolver. With the `esm` module format, its extension is the one of
+    /// the JavaScript module the source is compiled to, rather than the `esm`
+    /// import extension.
+    pub fn js_source_module_import_specifier(&self, specifier: &str) -> String {
+        match self.js_module_format {
+            JsModuleFormat::ESM => esm_source_import_specifier(specifier),
+            JsModuleFormat::CommonJS | JsModuleFormat::Haste => specifier.to_string(),
+        }
+    }
+
     /// Generates identifier for importing module at `target_module_path` from module at `importing_artifact_path`.
-    /// Import Identifier is a relative path in CommonJS projects and a module name in Haste projects.
+    /// Import Identifier is a relative path in CommonJS and ESM projects and a module name in Haste projects.
     pub fn js_module_import_identifier(
         &self,
         importing_artifact_path: &PathBuf,
         target_module_path: &PathBuf,
     ) -> StringKey {
         match self.js_module_format {
-            JsModuleFormat::CommonJS => {
+            JsModuleFormat::CommonJS | JsModuleFormat::ESM => {
                 let importing_artifact_directory = importing_artifact_path.parent().unwrap_or_else(||{
                     panic!(
                         "expected importing_artifact_path: {:?} to have a parent path, maybe it's not a file?",
@@ -0,0 +1,46 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Configuration for writing the dependency graph of the Relay Resolvers of
+/// the project: the schema fields and the resolvers read by the root fragment
+/// of each resolver.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct ResolverDependencyGraphConfig {
+    /// Path of the graph, relative to the root of the config. Defaults to
+    /// `ResolverDependencyGraph.json` (or `.dot`) in the `artifactDirectory`,
+    /// or in `__generated__`.
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+
+    #[serde(default)]
+    pub format: ResolverDependencyGraphFormat,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResolverDependencyGraphFormat {
+    /// A JSON object with the dependencies of each resolver.
+    #[default]
+    Json,
+    /// A Graphviz digraph.
+    Dot,
+}
+
+impl ResolverDependencyGraphFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ResolverDependencyGraphFormat::Json => "json",
+            ResolverDependencyGraphFormat::Dot => "dot",
+        }
+    }
+}
@@ -0,0 +1,106 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fmt;
+use std::path::Path;
+
+use fnv::FnvBuildHasher;
+use glob::Pattern;
+use indexmap::IndexMap;
+use serde::de::Error;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+type FnvIndexMap<K, V> = IndexMap<K, V, FnvBuildHasher>;
+
+/// Configuration for projects that read their schema from a `schemaDir`.
+/// All glob patterns are matched against paths relative to the schema
+/// directory.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct SchemaDirConfig {
+    /// Only files matching one of these patterns are part of the schema.
+    /// Defaults to all `*.graphql` files in the directory.
+    #[serde(default)]
+    pub includes: Vec<GlobPattern>,
+
+    /// Files matching one of these patterns are not part of the schema, even
+    /// if they match `includes`.
+    #[serde(default)]
+    pub excludes: Vec<GlobPattern>,
+
+    /// A map from glob patterns to a prefix that is added to the names of all
+    /// types defined in the matching files. References to those types within
+    /// the same file are renamed as well. The first matching pattern wins.
+    #[serde(default)]
+    pub type_prefixes: FnvIndexMap<GlobPattern, String>,
+
+    /// Treat each file as the schema of a federation subgraph, and compose
+    /// them into the supergraph schema. Types may then be defined in multiple
+    /// subgraphs, and the federation directives (`@key`, `@external`, ...)
+    /// don't need to be defined.
+    #[serde(default)]
+    pub federation: bool,
+}
+
+impl SchemaDirConfig {
+    /// Returns true if the file at `relative_path` (relative to the schema
+    /// directory) is part of the schema.
+    pub fn is_included(&self, relative_path: &Path) -> bool {
+        (self.includes.is_empty()
+            || self
+                .includes
+                .iter()
+                .any(|include| include.matches_path(relative_path)))
+            && !self
+                .excludes
+                .iter()
+                .any(|exclude| exclude.matches_path(relative_path))
+    }
+
+    /// Returns the prefix for the names of the types defined in the file at
+    /// `relative_path` (relative to the schema directory).
+    pub fn type_prefix(&self, relative_path: &Path) -> Option<&str> {
+        self.type_prefixes
+            .iter()
+            .find(|(pattern, _)| pattern.matches_path(relative_path))
+            .map(|(_, prefix)| prefix.as_str())
+    }
+}
+
+/// A glob pattern that is validated when the config is parsed.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct GlobPattern(Pattern);
+
+impl GlobPattern {
+    pub fn matches_path(&self, path: &Path) -> bool {
+        self.0.matches_path(path)
+    }
+}
+
+impl fmt::Debug for GlobPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0.as_str())
+    }
+}
+
+impl Serialize for GlobPattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for GlobPattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        Pattern::new(&pattern).map(GlobPattern).map_err(|error| {
+            D::Error::custom(format!("Invalid glob pattern `{}`: {}", pattern, error))
+        })
+    }
+}
@@ -0,0 +1,36 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Configuration for hoisting subtrees of normalization and reader ASTs into
+/// modules shared between artifacts. Each hoisted subtree is written to a
+/// module named after the hash of its content, so the same subtree printed
+/// in different artifacts is imported from the same module. Subtrees that
+/// occur in a single artifact are not hoisted.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct SharedAstModulesConfig {
+    /// Directory of the shared modules, relative to the root of the config.
+    /// Defaults to `shared` in the `artifactDirectory`, or
+    /// `__generated__/shared`.
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+
+    /// The minimum number of objects and arrays in a subtree for it to be
+    /// hoisted into a shared module, not counting the ones of subtrees it
+    /// contains that are hoisted themselves.
+    #[serde(default = "default_min_size")]
+    pub min_size: usize,
+}
+
+fn default_min_size() -> usize {
+    40
+}
@@ -32,6 +32,7 @@ pub enum TypegenLanguage {
     JavaScript,
     TypeScript,
     Flow,
+    ReScript,
 }
 
 impl TypegenLanguage {
@@ -58,6 +59,47 @@ pub struct CustomScalarTypeImport {
     pub path: PathBuf,
 }
 
+/// The library used by generated runtime validators.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RuntimeValidatorLibrary {
+    Zod,
+    Valibot,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct RuntimeValidatorsConfig {
+    /// The validation library, "zod" or "valibot".
+    pub library: RuntimeValidatorLibrary,
+
+    /// A module exporting a validator for each custom scalar, named after
+    /// the GraphQL scalar, e.g. `export const DateTime = z.string().datetime();`.
+    /// Custom scalars are not validated if this is not set.
+    #[serde(default)]
+    pub custom_scalar_parsers: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct SchemaTypesModuleConfig {
+    /// Path of the module, relative to the root of the config and without
+    /// extension. Defaults to `SchemaTypes` in the `artifactDirectory`, or
+    /// to `__generated__/SchemaTypes` if there is none.
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct MockResolversConfig {
+    /// Path of the module, relative to the root of the config and without
+    /// extension. Defaults to `MockResolvers` in the `artifactDirectory`, or
+    /// to `__generated__/MockResolvers` if there is none.
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct TypegenConfig {
@@ -106,6 +148,12 @@ pub struct TypegenConfig {
     #[serde(default)]
     pub eager_es_modules: bool,
 
+    /// The extension, without the leading dot, appended to relative import
+    /// specifiers of generated modules with the `esm` module format, e.g.
+    /// `js` or `mjs`.
+    #[serde(default = "default_esm_import_extension")]
+    pub esm_import_extension: String,
+
     /// Keep the previous compiler behavior by outputting an union
     /// of the raw type and null, and not the **correct** behavior
     /// of an union with the raw type, null and undefined.
@@ -123,6 +171,47 @@ pub struct TypegenConfig {
     /// https://github.com/apollographql/specs/pull/42
     #[serde(default)]
     pub experimental_emit_semantic_nullability_types: bool,
+
+    /// When set, operation and fragment artifacts export a `validators`
+    /// object with runtime validators for the `$data`, `$variables` and
+    /// `$rawResponse` types, to check network payloads at runtime.
+    #[serde(default)]
+    pub runtime_validators: Option<RuntimeValidatorsConfig>,
+
+    /// When set, the enum and input object types of the schema are emitted
+    /// once into a shared module, and artifacts import them from it instead
+    /// of repeating them. Enums are still imported from their own modules if
+    /// `enumModuleSuffix` is set.
+    #[serde(default)]
+    pub schema_types_module: Option<SchemaTypesModuleConfig>,
+
+    /// When set, `ID` fields are typed with a branded (TypeScript) or opaque
+    /// (Flow) type per object type, e.g. `UserID` for `User.id`, so that IDs
+    /// of different types can't be mixed up. The brand of a field or argument
+    /// can be set with `@idType(name: "User")` in the schema. The ID types
+    /// are exported from the shared module, `schemaTypesModule` is required.
+    #[serde(default)]
+    pub branded_id_types: bool,
+
+    /// When set, the descriptions of fields and input fields in the schema
+    /// are emitted as JSDoc comments on their properties in the generated
+    /// types, with a `@deprecated` tag for deprecated fields, so they show
+    /// up in editors. Flow types get `//` line comments instead, as they are
+    /// printed within a `/*:: */` comment.
+    #[serde(default)]
+    pub emit_doc_comments: bool,
+
+    /// When set, a module exports a `MockResolvers` type for the resolvers
+    /// of `MockPayloadGenerator` in `relay-test-utils`, with the object,
+    /// interface, union and enum types reachable from documents, and
+    /// `@relay_test_operation` operations export a `$mockData` type: a deep
+    /// partial of their raw response.
+    #[serde(default)]
+    pub mock_resolvers: Option<MockResolversConfig>,
+}
+
+fn default_esm_import_extension() -> String {
+    "js".to_string()
 }
 
 impl Default for TypegenConfig {
@@ -136,8 +225,14 @@ impl Default for TypegenConfig {
             require_custom_scalar_types: Default::default(),
             no_future_proof_enums: Default::default(),
             eager_es_modules: Default::default(),
+            esm_import_extension: default_esm_import_extension(),
             typescript_exclude_undefined_from_nullable_union: Default::default(),
             experimental_emit_semantic_nullability_types: Default::default(),
+            runtime_validators: Default::default(),
+            schema_types_module: Default::default(),
+            branded_id_types: Default::default(),
+            emit_doc_comments: Default::default(),
+            mock_resolvers: Default::default(),
         }
     }
 }
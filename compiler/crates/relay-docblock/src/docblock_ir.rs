@@ -21,6 +21,7 @@ use docblock_shared::DEFAULT_VALUE;
 use docblock_shared::KEY_RESOLVER_ID_FIELD;
 use docblock_shared::OUTPUT_TYPE_FIELD;
 use docblock_shared::PROVIDER_ARG_NAME;
+use docblock_syntax::ResolverSignature;
 use graphql_ir::reexport::StringKey;
 use graphql_ir::FragmentDefinitionName;
 use graphql_syntax::parse_field_definition;
@@ -30,6 +31,7 @@ use graphql_syntax::parse_identifier_and_implements_interfaces;
 use graphql_syntax::parse_type;
 use graphql_syntax::ConstantValue;
 use graphql_syntax::ExecutableDefinition;
+use graphql_syntax::FieldDefinition;
 use graphql_syntax::FragmentDefinition;
 use graphql_syntax::Identifier;
 use graphql_syntax::InputValueDefinition;
@@ -52,6 +54,7 @@ use crate::ir::StrongObjectIr;
 use crate::ir::TerseRelayResolverIr;
 use crate::ir::UnpopulatedIrField;
 use crate::ir::WeakObjectIr;
+use crate::signature::ResolverSignatureTypes;
 use crate::untyped_representation::AllowedFieldName;
 use crate::untyped_representation::UntypedDocblockRepresentation;
 use crate::DocblockIr;
@@ -89,6 +92,7 @@ pub(crate) fn parse_docblock_ir(
         description,
         mut fields,
         source_hash,
+        signature,
     } = untyped_representation;
 
     let resolver_field = match fields.remove(&AllowedFieldName::RelayResolverField) {
@@ -145,6 +149,7 @@ pub(crate) fn parse_docblock_ir(
                     definitions_in_file,
                     docblock_location,
                     source_hash,
+                    signature.as_ref(),
                 )?)
             } else {
                 match get_optional_unpopulated_field_named(
@@ -175,6 +180,7 @@ pub(crate) fn parse_docblock_ir(
     };
 
     validate_strict_resolver_flavors(parse_options, &parsed_docblock_ir)?;
+    validate_batch_resolver(&parsed_docblock_ir)?;
 
     assert_all_fields_removed(
         fields,
@@ -246,6 +252,7 @@ fn parse_relay_resolver_ir(
 
     Ok(LegacyVerboseResolverIr {
         live: get_optional_unpopulated_field_named(fields, AllowedFieldName::LiveField)?,
+        batch: get_optional_unpopulated_field_named(fields, AllowedFieldName::BatchField)?,
         on,
         root_fragment: root_fragment
             .map(|root_fragment| root_fragment.value.map(FragmentDefinitionName)),
@@ -337,10 +344,32 @@ fn parse_terse_relay_resolver_ir(
     definitions_in_file: Option<&Vec<ExecutableDefinition>>,
     location: Location,
     source_hash: ResolverSourceHash,
+    signature: Option<&ResolverSignature>,
 ) -> DiagnosticsResult<TerseRelayResolverIr> {
+    let mut signature_types = signature.map(|signature| {
+        ResolverSignatureTypes::new(
+            signature,
+            fields.contains_key(&AllowedFieldName::LiveField),
+            fields.contains_key(&AllowedFieldName::BatchField),
+        )
+    });
     let root_fragment =
         get_optional_populated_field_named(fields, AllowedFieldName::RootFragmentField)?;
     let type_str = relay_resolver_field.value;
+    let root_fragment = match &signature_types {
+        Some(signature_types) => {
+            infer_root_fragment(root_fragment, signature_types, type_str.location)?
+        }
+        None => root_fragment,
+    };
+    if let Some(signature_types) = &mut signature_types {
+        // Fragment arguments are passed to the resolver with the field arguments.
+        let fragment_argument_names =
+            get_fragment_argument_names(root_fragment, definitions_in_file);
+        if let Some(arguments) = &mut signature_types.arguments {
+            arguments.retain(|argument| !fragment_argument_names.contains(&argument.name));
+        }
+    }
 
     // Validate that the right hand side of the @RelayResolver field is a valid identifier
     let type_name = extract_identifier(relay_resolver_field)?;
@@ -367,8 +396,18 @@ fn parse_terse_relay_resolver_ir(
         }
     };
 
+    let field_definition = &remaining_source[1..];
+    let completed_field_definition = signature_types
+        .as_ref()
+        .map(|signature_types| {
+            complete_field_definition(field_definition, signature_types, type_str.location)
+        })
+        .transpose()?
+        .flatten();
     let mut field = parse_field_definition(
-        &remaining_source[1..],
+        completed_field_definition
+            .as_deref()
+            .unwrap_or(field_definition),
         type_str.location.source_location(),
         span_start + 1,
     )?;
@@ -389,6 +428,9 @@ fn parse_terse_relay_resolver_ir(
     }
 
     validate_field_arguments(&field.arguments, location.source_location())?;
+    if let Some(signature_types) = &signature_types {
+        validate_signature(&field, signature_types, type_str.location.source_location())?;
+    }
 
     let (fragment_type_condition, fragment_arguments) = parse_fragment_definition(
         root_fragment,
@@ -423,11 +465,210 @@ fn parse_terse_relay_resolver_ir(
         location,
         deprecated: fields.remove(&AllowedFieldName::DeprecatedField),
         live: get_optional_unpopulated_field_named(fields, AllowedFieldName::LiveField)?,
+        batch: get_optional_unpopulated_field_named(fields, AllowedFieldName::BatchField)?,
         fragment_arguments,
         source_hash,
     })
 }
 
+/// Infers the `@rootFragment` of a terse resolver from the fragment key type of
+/// its function, or checks that they match.
+fn infer_root_fragment(
+    root_fragment: Option<PopulatedIrField>,
+    signature_types: &ResolverSignatureTypes<'_>,
+    location: Location,
+) -> DiagnosticsResult<Option<PopulatedIrField>> {
+    match (root_fragment, signature_types.root_fragment) {
+        (None, Some(fragment_name)) => Ok(Some(PopulatedIrField {
+            key_location: location,
+            value: WithLocation::new(location, fragment_name),
+        })),
+        (Some(root_fragment), Some(fragment_name)) if root_fragment.value.item != fragment_name => {
+            Err(vec![Diagnostic::error(
+                IrParsingErrorMessages::SignatureRootFragmentMismatch {
+                    function_name: signature_types.function_name.intern(),
+                    fragment_key_type: signature_types
+                        .fragment_key_type
+                        .unwrap_or_default()
+                        .intern(),
+                    root_fragment: root_fragment.value.item,
+                },
+                root_fragment.value.location,
+            )])
+        }
+        (root_fragment, _) => Ok(root_fragment),
+    }
+}
+
+fn get_fragment_argument_names(
+    root_fragment: Option<PopulatedIrField>,
+    definitions_in_file: Option<&Vec<ExecutableDefinition>>,
+) -> Vec<StringKey> {
+    root_fragment
+        .and_then(|root_fragment| {
+            assert_fragment_definition(root_fragment.value, definitions_in_file).ok()
+        })
+        .and_then(|fragment_definition| {
+            fragment_definition
+                .directives
+                .named(ARGUMENT_DEFINITIONS.0)
+                .and_then(|directive| directive.arguments.as_ref())
+                .map(|arguments| arguments.items.iter().map(|arg| arg.name.value).collect())
+        })
+        .unwrap_or_default()
+}
+
+/// Fills in the arguments and the type of a terse resolver field definition
+/// from the signature of its function, if the docblock omits them. Returns
+/// `None` if there is nothing to infer.
+fn complete_field_definition(
+    field_definition: &str,
+    signature_types: &ResolverSignatureTypes<'_>,
+    location: Location,
+) -> DiagnosticsResult<Option<String>> {
+    let name_end = field_definition
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(field_definition.len());
+    let after_name = &field_definition[name_end..];
+    let arguments_end = if after_name.trim_start().starts_with('(') {
+        match after_name.find(')') {
+            Some(index) => name_end + index + 1,
+            // Let the parser report the unclosed arguments.
+            None => return Ok(None),
+        }
+    } else {
+        name_end
+    };
+    let has_arguments = arguments_end > name_end;
+    let has_type = field_definition[arguments_end..]
+        .trim_start()
+        .starts_with(':');
+
+    let function_name = signature_types.function_name.intern();
+    let mut errors = vec![];
+    let arguments = match &signature_types.arguments {
+        Some(arguments) if !has_arguments && !arguments.is_empty() => {
+            let arguments = arguments
+                .iter()
+                .filter_map(|argument| match argument.type_.to_argument_type() {
+                    Some(type_) => Some(format!("{}: {}", argument.name, type_)),
+                    None => {
+                        errors.push(Diagnostic::error(
+                            IrParsingErrorMessages::CannotInferArgumentType {
+                                function_name,
+                                argument_name: argument.name,
+                                argument_type: argument.type_text.as_str().intern(),
+                            },
+                            location,
+                        ));
+                        None
+                    }
+                })
+                .collect::<Vec<_>>();
+            format!("({})", arguments.join(", "))
+        }
+        _ => String::new(),
+    };
+    let type_ = if has_type {
+        String::new()
+    } else {
+        match signature_types
+            .return_type
+            .as_ref()
+            .and_then(|return_type| return_type.to_field_type())
+        {
+            Some(type_) => format!(": {}", type_),
+            None => {
+                errors.push(Diagnostic::error(
+                    IrParsingErrorMessages::CannotInferFieldType { function_name },
+                    location,
+                ));
+                String::new()
+            }
+        }
+    };
+
+    if !errors.is_empty() {
+        Err(errors)
+    } else if arguments.is_empty() && type_.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(format!(
+            "{}{}{}{}",
+            &field_definition[..arguments_end],
+            arguments,
+            type_,
+            &field_definition[arguments_end..]
+        )))
+    }
+}
+
+/// Validates that the signature of a terse resolver's function agrees with the
+/// field definition of its docblock.
+fn validate_signature(
+    field: &FieldDefinition,
+    signature_types: &ResolverSignatureTypes<'_>,
+    source_location: SourceLocationKey,
+) -> DiagnosticsResult<()> {
+    let function_name = signature_types.function_name.intern();
+    let mut errors = vec![];
+    if let (Some(return_type), Some(return_type_text)) = (
+        &signature_types.return_type,
+        signature_types.return_type_text,
+    ) {
+        if !return_type.is_compatible_with(&field.type_) {
+            errors.push(Diagnostic::error(
+                IrParsingErrorMessages::SignatureReturnTypeMismatch {
+                    function_name,
+                    return_type: return_type_text.intern(),
+                    field_name: field.name.value,
+                    field_type: field.type_.to_string().intern(),
+                },
+                Location::new(source_location, field.type_.span()),
+            ));
+        }
+    }
+    for argument in signature_types.arguments.iter().flatten() {
+        let field_argument = field.arguments.as_ref().and_then(|arguments| {
+            arguments
+                .items
+                .iter()
+                .find(|field_argument| field_argument.name.value == argument.name)
+        });
+        match field_argument {
+            None => errors.push(Diagnostic::error(
+                IrParsingErrorMessages::SignatureArgumentMissing {
+                    function_name,
+                    argument_name: argument.name,
+                    field_name: field.name.value,
+                },
+                Location::new(source_location, field.name.span),
+            )),
+            Some(field_argument) => {
+                if !argument
+                    .type_
+                    .is_compatible_with_argument(&field_argument.type_)
+                {
+                    errors.push(Diagnostic::error(
+                        IrParsingErrorMessages::SignatureArgumentTypeMismatch {
+                            function_name,
+                            argument_name: argument.name,
+                            signature_type: argument.type_text.as_str().intern(),
+                            argument_type: field_argument.type_.to_string().intern(),
+                        },
+                        Location::new(source_location, field_argument.type_.span()),
+                    ));
+                }
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 fn combine_on_type_on_interface_fields(
     on_type_opt: Option<IrField>,
     on_interface_opt: Option<IrField>,
@@ -867,6 +1108,26 @@ fn validate_strict_resolver_flavors(
     }
 }
 
+// Batch resolvers return the values of all the records of a read at once, which
+// a @live resolver, subscribed to for each record, can't do.
+fn validate_batch_resolver(docblock_ir: &DocblockIr) -> DiagnosticsResult<()> {
+    let (batch, live) = match docblock_ir {
+        DocblockIr::LegacyVerboseResolver(resolver_ir) => (resolver_ir.batch, resolver_ir.live),
+        DocblockIr::TerseRelayResolver(terse_ir) => (terse_ir.batch, terse_ir.live),
+        DocblockIr::StrongObjectResolver(_) | DocblockIr::WeakObjectType(_) => return Ok(()),
+    };
+    match (batch, live) {
+        (Some(batch), Some(live)) => Err(vec![
+            Diagnostic::error(
+                IrParsingErrorMessages::IncompatibleLiveAndBatch,
+                batch.key_location,
+            )
+            .annotate("@live", live.key_location),
+        ]),
+        _ => Ok(()),
+    }
+}
+
 fn validate_field_arguments(
     arguments: &Option<List<InputValueDefinition>>,
     source_location: SourceLocationKey,
@@ -160,6 +160,61 @@ pub enum IrParsingErrorMessages {
 
     #[error("@outputType is incompatible with @rootFragment")]
     IncompatibleOutputTypeAndRootFragment,
+
+    #[error("@batch is incompatible with @live")]
+    IncompatibleLiveAndBatch,
+
+    #[error(
+        "Unable to infer the type of this field from the return type of `{function_name}`. Only `string` (inferred as `String`), `number` (inferred as `Float`), `boolean` and arrays of these can be inferred. Please specify its GraphQL type, e.g. `Int`, in the `@RelayResolver` docblock."
+    )]
+    CannotInferFieldType { function_name: StringKey },
+
+    #[error(
+        "Unable to infer the GraphQL type of the argument `{argument_name}` from its type `{argument_type}` in the signature of `{function_name}`. Only `string` (inferred as `String`), `number` (inferred as `Float`), `boolean` and arrays of these can be inferred. Please specify the arguments of this field in the `@RelayResolver` docblock."
+    )]
+    CannotInferArgumentType {
+        function_name: StringKey,
+        argument_name: StringKey,
+        argument_type: StringKey,
+    },
+
+    #[error(
+        "The return type of `{function_name}` (`{return_type}`) does not match the type of the field `{field_name}` (`{field_type}`). A `string` matches `String` or `ID`, a `number` matches `Int` or `Float`, and a `boolean` matches `Boolean`."
+    )]
+    SignatureReturnTypeMismatch {
+        function_name: StringKey,
+        return_type: StringKey,
+        field_name: StringKey,
+        field_type: StringKey,
+    },
+
+    #[error(
+        "`{function_name}` expects the fragment key `{fragment_key_type}`, but the `@rootFragment` of this resolver is `{root_fragment}`."
+    )]
+    SignatureRootFragmentMismatch {
+        function_name: StringKey,
+        fragment_key_type: StringKey,
+        root_fragment: StringKey,
+    },
+
+    #[error(
+        "`{function_name}` expects an argument `{argument_name}`, but the field `{field_name}` does not define it."
+    )]
+    SignatureArgumentMissing {
+        function_name: StringKey,
+        argument_name: StringKey,
+        field_name: StringKey,
+    },
+
+    #[error(
+        "The type of the argument `{argument_name}` in the signature of `{function_name}` (`{signature_type}`) does not match its GraphQL type (`{argument_type}`). A `string` matches `String` or `ID`, a `number` matches `Int` or `Float`, and a `boolean` matches `Boolean`. A nullable GraphQL argument must also be nullable in the signature."
+    )]
+    SignatureArgumentTypeMismatch {
+        function_name: StringKey,
+        argument_name: StringKey,
+        signature_type: StringKey,
+        argument_type: StringKey,
+    },
 }
 
 #[derive(
@@ -20,6 +20,7 @@ use common::ObjectName;
 use common::Span;
 use common::WithLocation;
 use docblock_shared::ResolverSourceHash;
+use docblock_shared::BATCH_ARGUMENT_NAME;
 use docblock_shared::FRAGMENT_KEY_ARGUMENT_NAME;
 use docblock_shared::GENERATED_FRAGMENT_ARGUMENT_NAME;
 use docblock_shared::HAS_OUTPUT_TYPE_ARGUMENT_NAME;
@@ -408,6 +409,7 @@ trait ResolverIr: Sized {
     fn output_type(&self) -> Option<OutputType>;
     fn deprecated(&self) -> Option<IrField>;
     fn live(&self) -> Option<UnpopulatedIrField>;
+    fn batch(&self) -> Option<UnpopulatedIrField>;
     fn named_import(&self) -> Option<StringKey>;
     fn source_hash(&self) -> ResolverSourceHash;
 
@@ -492,6 +494,13 @@ trait ResolverIr: Sized {
             arguments.push(true_argument(LIVE_ARGUMENT_NAME.0, live_field.key_location))
         }
 
+        if let Some(batch_field) = self.batch() {
+            arguments.push(true_argument(
+                BATCH_ARGUMENT_NAME.0,
+                batch_field.key_location,
+            ))
+        }
+
         let schema = project_config.schema;
 
         if let Some(output_type) = self.output_type() {
@@ -745,6 +754,9 @@ pub struct TerseRelayResolverIr {
     pub root_fragment: Option<WithLocation<FragmentDefinitionName>>,
     pub deprecated: Option<IrField>,
     pub live: Option<UnpopulatedIrField>,
+    /// The resolver is called once with the keys of all the records it is
+    /// read on in a plural list.
+    pub batch: Option<UnpopulatedIrField>,
     pub location: Location,
     pub fragment_arguments: Option<Vec<Argument>>,
     pub source_hash: ResolverSourceHash,
@@ -830,6 +842,10 @@ impl ResolverIr for TerseRelayResolverIr {
         self.live
     }
 
+    fn batch(&self) -> Option<UnpopulatedIrField> {
+        self.batch
+    }
+
     fn named_import(&self) -> Option<StringKey> {
         Some(self.field.name.value)
     }
@@ -871,6 +887,9 @@ pub struct LegacyVerboseResolverIr {
     pub hack_source: Option<WithLocation<StringKey>>,
     pub deprecated: Option<IrField>,
     pub live: Option<UnpopulatedIrField>,
+    /// The resolver is called once with the keys of all the records it is
+    /// read on in a plural list.
+    pub batch: Option<UnpopulatedIrField>,
     pub location: Location,
     pub fragment_arguments: Option<Vec<Argument>>,
     pub source_hash: ResolverSourceHash,
@@ -1005,6 +1024,10 @@ impl ResolverIr for LegacyVerboseResolverIr {
         self.live
     }
 
+    fn batch(&self) -> Option<UnpopulatedIrField> {
+        self.batch
+    }
+
     fn named_import(&self) -> Option<StringKey> {
         Some(self.field.name.value)
     }
@@ -1225,6 +1248,7 @@ impl ResolverIr for StrongObjectIr {
                 arguments: None,
             }],
             fields: Some(List::generated(fields)),
+            description: None,
         });
 
         Ok(vec![type_])
@@ -1261,6 +1285,10 @@ impl ResolverIr for StrongObjectIr {
         self.live
     }
 
+    fn batch(&self) -> Option<UnpopulatedIrField> {
+        None
+    }
+
     fn named_import(&self) -> Option<StringKey> {
         Some(self.type_name.value)
     }
@@ -1343,6 +1371,7 @@ impl WeakObjectIr {
                 vec![resolver_source_hash_directive(source_hash)],
                 location,
             )])),
+            description: None,
         })
     }
 
@@ -1384,6 +1413,7 @@ impl WeakObjectIr {
                     },
                 ])),
             }],
+            description: None,
         })
     }
 
@@ -1434,6 +1464,10 @@ impl ResolverIr for WeakObjectIr {
         None
     }
 
+    fn batch(&self) -> Option<UnpopulatedIrField> {
+        None
+    }
+
     fn named_import(&self) -> Option<StringKey> {
         None
     }
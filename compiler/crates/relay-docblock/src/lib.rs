@@ -8,6 +8,7 @@
 mod docblock_ir;
 mod errors;
 mod ir;
+mod signature;
 mod untyped_representation;
 
 use common::Diagnostic;
@@ -15,6 +16,7 @@ use common::DiagnosticsResult;
 use common::FeatureFlag;
 use common::Location;
 use docblock_ir::parse_docblock_ir;
+use docblock_shared::BATCH_FIELD;
 use docblock_shared::DEPRECATED_FIELD;
 use docblock_shared::EDGE_TO_FIELD;
 use docblock_shared::EMPTY_STRING;
@@ -0,0 +1,324 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use docblock_syntax::ResolverSignature;
+use graphql_syntax::TypeAnnotation;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use intern::Lookup;
+
+const FRAGMENT_KEY_SUFFIX: &str = "$key";
+const ARRAY_TYPES: [&str; 3] = ["Array", "$ReadOnlyArray", "ReadonlyArray"];
+const LIVE_STATE_TYPE: &str = "LiveState";
+
+/// A Flow or TypeScript type, as far as it can be mapped to a GraphQL type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum SignatureType {
+    String,
+    Number,
+    Boolean,
+    List(Box<SignatureType>),
+    Nullable(Box<SignatureType>),
+    /// Any other type, e.g. an object or a model type, which we don't try to
+    /// map to a GraphQL type.
+    Unknown,
+}
+
+impl SignatureType {
+    /// Parses `?T`, `T | null`, `T | void`, `T | undefined`, `Array<T>`,
+    /// `$ReadOnlyArray<T>`, `ReadonlyArray<T>`, `T[]`, `readonly T[]` and the
+    /// `string`, `number` and `boolean` types.
+    pub(crate) fn parse(text: &str) -> Self {
+        let text = text.trim();
+        if let Some(inner) = text.strip_prefix('?') {
+            return Self::parse(inner).into_nullable();
+        }
+
+        let members = split_at_depth_zero(text, '|')
+            .into_iter()
+            .filter(|member| !member.is_empty())
+            .collect::<Vec<_>>();
+        if members.len() > 1 {
+            let non_null_members = members
+                .iter()
+                .filter(|member| !matches!(**member, "null" | "void" | "undefined"))
+                .collect::<Vec<_>>();
+            return match non_null_members.as_slice() {
+                [member] => Self::parse(member).into_nullable(),
+                _ if non_null_members.len() == members.len() => Self::Unknown,
+                _ => Self::Unknown.into_nullable(),
+            };
+        }
+
+        if let Some(inner) = strip_brackets(text, '(', ')') {
+            return Self::parse(inner);
+        }
+        if let Some(item) = text.strip_suffix("[]") {
+            return Self::List(Box::new(Self::parse(
+                item.trim_start().strip_prefix("readonly ").unwrap_or(item),
+            )));
+        }
+        if let Some(item) = strip_generic(text, &ARRAY_TYPES) {
+            return Self::List(Box::new(Self::parse(item)));
+        }
+        match text {
+            "string" => Self::String,
+            "number" => Self::Number,
+            "boolean" => Self::Boolean,
+            _ => Self::Unknown,
+        }
+    }
+
+    fn into_nullable(self) -> Self {
+        match self {
+            Self::Nullable(_) => self,
+            _ => Self::Nullable(Box::new(self)),
+        }
+    }
+
+    fn non_null(&self) -> &Self {
+        match self {
+            Self::Nullable(inner) => inner,
+            _ => self,
+        }
+    }
+
+    /// The GraphQL type of a field returning this type. Relay Resolver fields
+    /// are always nullable, so the nullability of the outermost type is
+    /// ignored.
+    pub(crate) fn to_field_type(&self) -> Option<String> {
+        match self.non_null() {
+            Self::String => Some("String".to_string()),
+            // GraphQL has no type matching all numbers, `Float` is the closest.
+            Self::Number => Some("Float".to_string()),
+            Self::Boolean => Some("Boolean".to_string()),
+            Self::List(item) => Some(format!("[{}]", item.to_argument_type()?)),
+            Self::Nullable(_) | Self::Unknown => None,
+        }
+    }
+
+    /// The GraphQL type of an argument, or of a list item, of this type.
+    pub(crate) fn to_argument_type(&self) -> Option<String> {
+        match self {
+            Self::Nullable(inner) => inner.to_field_type(),
+            _ => Some(format!("{}!", self.to_field_type()?)),
+        }
+    }
+
+    /// Whether a value of this type can be a value of the GraphQL `type_`.
+    /// Types which can't be mapped to GraphQL, including custom scalars, are
+    /// compatible with anything.
+    pub(crate) fn is_compatible_with(&self, type_: &TypeAnnotation) -> bool {
+        match (self, type_) {
+            (Self::Nullable(_), TypeAnnotation::NonNull(_)) => false,
+            (_, TypeAnnotation::NonNull(non_null)) => self.is_compatible_with(&non_null.type_),
+            (Self::Nullable(inner), _) => inner.is_compatible_with(type_),
+            (Self::Unknown, _) => true,
+            (Self::List(item), TypeAnnotation::List(list)) => item.is_compatible_with(&list.type_),
+            (_, TypeAnnotation::Named(named)) => match named.name.value.lookup() {
+                "String" | "ID" => *self == Self::String,
+                "Int" | "Float" => *self == Self::Number,
+                "Boolean" => *self == Self::Boolean,
+                _ => true,
+            },
+            _ => false,
+        }
+    }
+
+    /// Like `is_compatible_with`, but a nullable GraphQL argument also requires
+    /// the function to accept a missing value.
+    pub(crate) fn is_compatible_with_argument(&self, type_: &TypeAnnotation) -> bool {
+        self.is_compatible_with(type_)
+            && (matches!(type_, TypeAnnotation::NonNull(_))
+                || matches!(self, Self::Nullable(_) | Self::Unknown))
+    }
+}
+
+/// An argument of a resolver field, as declared by the object type of the
+/// resolver function's `args` parameter.
+#[derive(Debug)]
+pub(crate) struct SignatureArgument {
+    pub(crate) name: StringKey,
+    pub(crate) type_text: String,
+    pub(crate) type_: SignatureType,
+}
+
+/// What the signature of a terse resolver function tells us about its field.
+#[derive(Debug)]
+pub(crate) struct ResolverSignatureTypes<'a> {
+    pub(crate) function_name: &'a str,
+    /// The return type as written, after unwrapping `LiveState` for `@live`
+    /// resolvers and the array for `@batch` resolvers.
+    pub(crate) return_type_text: Option<&'a str>,
+    pub(crate) return_type: Option<SignatureType>,
+    /// The type of the fragment key parameter, e.g. `UserNameResolver$key`.
+    pub(crate) fragment_key_type: Option<&'a str>,
+    pub(crate) root_fragment: Option<StringKey>,
+    pub(crate) arguments: Option<Vec<SignatureArgument>>,
+}
+
+impl<'a> ResolverSignatureTypes<'a> {
+    pub(crate) fn new(signature: &'a ResolverSignature, live: bool, batch: bool) -> Self {
+        let mut params = signature
+            .params
+            .iter()
+            .filter_map(|param| param.type_.as_deref())
+            .map(|type_| {
+                if batch {
+                    strip_array(type_).unwrap_or(type_)
+                } else {
+                    type_.trim()
+                }
+            })
+            .peekable();
+
+        let fragment_key_type = params
+            .peek()
+            .copied()
+            .filter(|type_| is_fragment_key(type_));
+        if fragment_key_type.is_some() {
+            params.next();
+        }
+        let arguments = params
+            .next()
+            .and_then(|type_| strip_object_type(type_))
+            .map(parse_arguments);
+
+        let return_type_text = signature.return_type.as_deref().map(|return_type| {
+            let return_type = if live {
+                strip_generic(return_type, &[LIVE_STATE_TYPE]).unwrap_or(return_type)
+            } else {
+                return_type
+            };
+            if batch {
+                strip_array(return_type).unwrap_or(return_type)
+            } else {
+                return_type.trim()
+            }
+        });
+
+        Self {
+            function_name: &signature.function_name,
+            return_type_text,
+            return_type: return_type_text.map(SignatureType::parse),
+            fragment_key_type,
+            root_fragment: fragment_key_type
+                .map(|type_| type_[..type_.len() - FRAGMENT_KEY_SUFFIX.len()].intern()),
+            arguments,
+        }
+    }
+}
+
+fn is_fragment_key(type_: &str) -> bool {
+    type_.len() > FRAGMENT_KEY_SUFFIX.len()
+        && type_.ends_with(FRAGMENT_KEY_SUFFIX)
+        && type_
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Parses the properties of an object type, e.g. `a: string, b?: ?number`.
+fn parse_arguments(properties: &str) -> Vec<SignatureArgument> {
+    split_at_depth_zero(properties, ',')
+        .into_iter()
+        .flat_map(|property| split_at_depth_zero(property, ';'))
+        .filter_map(|property| {
+            let colon = find_at_depth_zero(property, ':')?;
+            let name = property[..colon].trim();
+            let name = name.strip_prefix("readonly ").unwrap_or(name);
+            let name = name.trim_start_matches(['+', '-']).trim();
+            let (name, optional) = match name.strip_suffix('?') {
+                Some(name) => (name.trim(), true),
+                None => (name, false),
+            };
+            if name.is_empty() || name.starts_with("...") {
+                return None;
+            }
+            let type_text = property[colon + 1..].trim().to_string();
+            let type_ = SignatureType::parse(&type_text);
+            Some(SignatureArgument {
+                name: name.intern(),
+                type_: if optional {
+                    type_.into_nullable()
+                } else {
+                    type_
+                },
+                type_text,
+            })
+        })
+        .collect()
+}
+
+fn strip_object_type(text: &str) -> Option<&str> {
+    let text = text.trim();
+    strip_brackets(text, '{', '}').map(|inner| {
+        inner
+            .strip_prefix('|')
+            .and_then(|inner| inner.strip_suffix('|'))
+            .unwrap_or(inner)
+    })
+}
+
+fn strip_array(text: &str) -> Option<&str> {
+    let text = text.trim();
+    let text = text.strip_prefix("readonly ").unwrap_or(text);
+    text.strip_suffix("[]")
+        .or_else(|| strip_generic(text, &ARRAY_TYPES))
+        .map(str::trim)
+}
+
+/// Returns the type argument of `text` if it is one of the generic types
+/// `names`, e.g. `T` for `Array<T>`.
+fn strip_generic<'a>(text: &'a str, names: &[&str]) -> Option<&'a str> {
+    let text = text.trim();
+    names.iter().find_map(|name| {
+        strip_brackets(text.strip_prefix(name)?.trim_start(), '<', '>').map(str::trim)
+    })
+}
+
+/// Returns the contents of `text` if it is enclosed in a single pair of
+/// `open` and `close` brackets.
+fn strip_brackets(text: &str, open: char, close: char) -> Option<&str> {
+    let inner = text.strip_prefix(open)?.strip_suffix(close)?;
+    if find_at_depth_zero(&format!("{}{}", inner, close), close) == Some(inner.len()) {
+        Some(inner.trim())
+    } else {
+        None
+    }
+}
+
+fn split_at_depth_zero(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut rest = text;
+    while let Some(index) = find_at_depth_zero(rest, separator) {
+        parts.push(rest[..index].trim());
+        rest = &rest[index + 1..];
+    }
+    parts.push(rest.trim());
+    parts
+}
+
+/// Finds the first `target` character outside of brackets, including a
+/// closing bracket which doesn't match an opening one.
+fn find_at_depth_zero(text: &str, target: char) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut previous = None;
+    for (index, c) in text.char_indices() {
+        if depth == 0 && c == target {
+            return Some(index);
+        }
+        match c {
+            '(' | '[' | '{' | '<' => depth += 1,
+            // The `>` of `=>` does not close a bracket.
+            '>' if previous == Some('=') => {}
+            ')' | ']' | '}' | '>' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        previous = Some(c);
+    }
+    None
+}
@@ -15,10 +15,12 @@ use common::WithLocation;
 use docblock_shared::ResolverSourceHash;
 use docblock_syntax::DocblockAST;
 use docblock_syntax::DocblockSection;
+use docblock_syntax::ResolverSignature;
 use graphql_ir::reexport::StringKey;
 
 use crate::errors::UntypedRepresentationErrorMessages;
 use crate::ir::IrField;
+use crate::BATCH_FIELD;
 use crate::DEPRECATED_FIELD;
 use crate::EDGE_TO_FIELD;
 use crate::EMPTY_STRING;
@@ -52,6 +54,7 @@ pub enum AllowedFieldName {
     EdgeToField,
     DeprecatedField,
     LiveField,
+    BatchField,
     OutputTypeField,
     WeakField,
 }
@@ -67,6 +70,7 @@ impl Display for AllowedFieldName {
             AllowedFieldName::EdgeToField => write!(f, "{}", *EDGE_TO_FIELD),
             AllowedFieldName::DeprecatedField => write!(f, "{}", *DEPRECATED_FIELD),
             AllowedFieldName::LiveField => write!(f, "{}", *LIVE_FIELD),
+            AllowedFieldName::BatchField => write!(f, "{}", *BATCH_FIELD),
             AllowedFieldName::OutputTypeField => write!(f, "{}", *OUTPUT_TYPE_FIELD),
             AllowedFieldName::WeakField => write!(f, "{}", *WEAK_FIELD),
         }
@@ -86,6 +90,7 @@ impl TryFrom<WithLocation<StringKey>> for AllowedFieldName {
             value if value == *EDGE_TO_FIELD => Ok(AllowedFieldName::EdgeToField),
             value if value == *DEPRECATED_FIELD => Ok(AllowedFieldName::DeprecatedField),
             value if value == *LIVE_FIELD => Ok(AllowedFieldName::LiveField),
+            value if value == *BATCH_FIELD => Ok(AllowedFieldName::BatchField),
             value if value == *OUTPUT_TYPE_FIELD => Ok(AllowedFieldName::OutputTypeField),
             value if value == *WEAK_FIELD => Ok(AllowedFieldName::WeakField),
             invalid_value => Err(Diagnostic::error(
@@ -106,14 +111,16 @@ pub(crate) struct UntypedDocblockRepresentation {
     pub(crate) description: Option<WithLocation<StringKey>>,
     pub(crate) fields: HashMap<AllowedFieldName, IrField>,
     pub(crate) source_hash: ResolverSourceHash,
+    pub(crate) signature: Option<ResolverSignature>,
 }
 
 impl UntypedDocblockRepresentation {
-    fn new(source_hash: ResolverSourceHash) -> Self {
+    fn new(source_hash: ResolverSourceHash, signature: Option<ResolverSignature>) -> Self {
         Self {
             description: None,
             fields: HashMap::new(),
             source_hash,
+            signature,
         }
     }
 }
@@ -122,7 +129,7 @@ pub(crate) fn parse_untyped_docblock_representation(
     ast: &DocblockAST,
 ) -> Result<UntypedDocblockRepresentation, Vec<Diagnostic>> {
     let mut untyped_repr: UntypedDocblockRepresentation =
-        UntypedDocblockRepresentation::new(ast.source_hash);
+        UntypedDocblockRepresentation::new(ast.source_hash, ast.signature.clone());
     let mut errors = vec![];
     for section in &ast.sections {
         match section {
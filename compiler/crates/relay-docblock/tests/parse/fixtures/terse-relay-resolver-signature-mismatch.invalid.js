@@ -0,0 +1,15 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+// expected-to-throw
+
+/**
+ * @RelayResolver User.greeting(salutation: String!, count: Int): Boolean
+ */
+export function greeting(args: {salutation: ?string, count: number, name: string}): ?string {
+  return null;
+}
@@ -0,0 +1,22 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+// expected-to-throw
+
+/**
+ * @RelayResolver User.greeting: String
+ * @rootFragment UserGreetingResolver
+ */
+export function greeting(user: UserNameResolver$key): ?string {
+  return null;
+}
+
+graphql`
+  fragment UserGreetingResolver on User {
+    name
+  }
+`
@@ -0,0 +1,44 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/**
+ * @RelayResolver User.greeting
+ */
+export function greeting(
+  user: UserGreetingResolver$key,
+  args: {|salutation: ?string, count: number, first: ?number|},
+): ?string {
+  return null;
+}
+
+graphql`
+  fragment UserGreetingResolver on User @argumentDefinitions(first: {type: "Int", defaultValue: 10}) {
+    name
+  }
+`
+
+/**
+ * @RelayResolver User.nicknames: [String!]
+ * @rootFragment UserNicknamesResolver
+ */
+export function nicknames(user: UserNicknamesResolver$key): $ReadOnlyArray<string> {
+  return [];
+}
+
+graphql`
+  fragment UserNicknamesResolver on User {
+    name
+  }
+`
+
+/**
+ * @RelayResolver User.online
+ * @live
+ */
+export const online = (): LiveState<boolean | null> => {
+  return store;
+};
@@ -8,7 +8,7 @@
 use common::Diagnostic;
 use common::FeatureFlag;
 use common::SourceLocationKey;
-use docblock_syntax::parse_docblock;
+use docblock_syntax::parse_docblock_source;
 use extract_graphql::JavaScriptSourceFeature;
 use fixture_tests::Fixture;
 use graphql_cli::DiagnosticPrinter;
@@ -50,8 +50,8 @@ pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String>
         .filter_map(|(i, source)| match source {
             JavaScriptSourceFeature::GraphQL(_) => None,
             JavaScriptSourceFeature::Docblock(docblock_source) => Some(
-                parse_docblock(
-                    &docblock_source.text_source().text,
+                parse_docblock_source(
+                    docblock_source,
                     SourceLocationKey::Embedded {
                         path: format!("/path/to/test/fixture/{}", fixture.file_name).intern(),
                         index: i as u16,
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<0d097e7d292511eaacec9df447a793d7>>
+ * @generated SignedSource<<826f2aa140f1818b7f1a4b3a69e5781e>>
  */
 
 mod parse;
@@ -292,6 +292,13 @@ async fn terse_relay_resolver() {
     test_fixture(transform_fixture, file!(), "terse-relay-resolver.js", "parse/fixtures/terse-relay-resolver.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn terse_relay_resolver_batch_live_invalid() {
+    let input = include_str!("parse/fixtures/terse-relay-resolver-batch-live.invalid.js");
+    let expected = include_str!("parse/fixtures/terse-relay-resolver-batch-live.invalid.expected");
+    test_fixture(transform_fixture, file!(), "terse-relay-resolver-batch-live.invalid.js", "parse/fixtures/terse-relay-resolver-batch-live.invalid.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn terse_relay_resolver_forbidden_fields_invalid() {
     let input = include_str!("parse/fixtures/terse-relay-resolver-forbidden-fields.invalid.js");
@@ -340,3 +347,31 @@ async fn terse_relay_resolver_non_nullable_list_item() {
     let expected = include_str!("parse/fixtures/terse-relay-resolver-non-nullable-list-item.expected");
     test_fixture(transform_fixture, file!(), "terse-relay-resolver-non-nullable-list-item.js", "parse/fixtures/terse-relay-resolver-non-nullable-list-item.expected", input, expected).await;
 }
+
+#[tokio::test]
+async fn terse_relay_resolver_signature() {
+    let input = include_str!("parse/fixtures/terse-relay-resolver-signature.js");
+    let expected = include_str!("parse/fixtures/terse-relay-resolver-signature.expected");
+    test_fixture(transform_fixture, file!(), "terse-relay-resolver-signature.js", "parse/fixtures/terse-relay-resolver-signature.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn terse_relay_resolver_signature_cannot_infer_invalid() {
+    let input = include_str!("parse/fixtures/terse-relay-resolver-signature-cannot-infer.invalid.js");
+    let expected = include_str!("parse/fixtures/terse-relay-resolver-signature-cannot-infer.invalid.expected");
+    test_fixture(transform_fixture, file!(), "terse-relay-resolver-signature-cannot-infer.invalid.js", "parse/fixtures/terse-relay-resolver-signature-cannot-infer.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn terse_relay_resolver_signature_mismatch_invalid() {
+    let input = include_str!("parse/fixtures/terse-relay-resolver-signature-mismatch.invalid.js");
+    let expected = include_str!("parse/fixtures/terse-relay-resolver-signature-mismatch.invalid.expected");
+    test_fixture(transform_fixture, file!(), "terse-relay-resolver-signature-mismatch.invalid.js", "parse/fixtures/terse-relay-resolver-signature-mismatch.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn terse_relay_resolver_signature_root_fragment_mismatch_invalid() {
+    let input = include_str!("parse/fixtures/terse-relay-resolver-signature-root-fragment-mismatch.invalid.js");
+    let expected = include_str!("parse/fixtures/terse-relay-resolver-signature-root-fragment-mismatch.invalid.expected");
+    test_fixture(transform_fixture, file!(), "terse-relay-resolver-signature-root-fragment-mismatch.invalid.js", "parse/fixtures/terse-relay-resolver-signature-root-fragment-mismatch.invalid.expected", input, expected).await;
+}
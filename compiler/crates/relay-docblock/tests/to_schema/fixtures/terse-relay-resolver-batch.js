@@ -0,0 +1,20 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/**
+ * @RelayResolver User.favorite_color: String
+ * @rootFragment myRootFragment
+ * @batch
+ *
+ * Resolved once for all the users of a list.
+ */
+
+graphql`
+  fragment myRootFragment on User {
+    id
+  }
+`
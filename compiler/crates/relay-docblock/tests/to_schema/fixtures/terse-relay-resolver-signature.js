@@ -0,0 +1,25 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/**
+ * @RelayResolver User.favorite_colors
+ * @batch
+ *
+ * The type of this field and its root fragment are inferred from the signature.
+ */
+export function favorite_colors(
+  users: ReadonlyArray<UserFavoriteColorsResolver$key>,
+  args: {limit: number; shade?: string},
+): ReadonlyArray<Array<string | null>> {
+  return [];
+}
+
+graphql`
+  fragment UserFavoriteColorsResolver on User {
+    id
+  }
+`
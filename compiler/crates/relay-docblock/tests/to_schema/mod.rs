@@ -10,7 +10,7 @@ use std::sync::Arc;
 use common::DiagnosticsResult;
 use common::FeatureFlag;
 use common::SourceLocationKey;
-use docblock_syntax::parse_docblock;
+use docblock_syntax::parse_docblock_source;
 use docblock_syntax::DocblockSource;
 use extract_graphql::JavaScriptSourceFeature;
 use fixture_tests::Fixture;
@@ -56,8 +56,8 @@ pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String>
         .collect::<Vec<ExecutableDefinition>>();
 
     let mut stringify = |i: usize, source: &DocblockSource| -> DiagnosticsResult<String> {
-        let ast = parse_docblock(
-            &source.text_source().text,
+        let ast = parse_docblock_source(
+            source,
             SourceLocationKey::Embedded {
                 path: format!("/path/to/test/fixture/{}", fixture.file_name).intern(),
                 index: i as u16,
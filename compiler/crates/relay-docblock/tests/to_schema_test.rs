@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<1ffacfeab59157dc3df605ce3d320d2c>>
+ * @generated SignedSource<<961cc524cee1d728155701ddb89b08ba>>
  */
 
 mod to_schema;
@@ -222,6 +222,13 @@ async fn terse_relay_resolver() {
     test_fixture(transform_fixture, file!(), "terse-relay-resolver.js", "to_schema/fixtures/terse-relay-resolver.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn terse_relay_resolver_batch() {
+    let input = include_str!("to_schema/fixtures/terse-relay-resolver-batch.js");
+    let expected = include_str!("to_schema/fixtures/terse-relay-resolver-batch.expected");
+    test_fixture(transform_fixture, file!(), "terse-relay-resolver-batch.js", "to_schema/fixtures/terse-relay-resolver-batch.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn terse_relay_resolver_id_invalid() {
     let input = include_str!("to_schema/fixtures/terse-relay-resolver-id.invalid.js");
@@ -243,6 +250,13 @@ async fn terse_relay_resolver_non_existent_type_invalid() {
     test_fixture(transform_fixture, file!(), "terse-relay-resolver-non-existent-type.invalid.js", "to_schema/fixtures/terse-relay-resolver-non-existent-type.invalid.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn terse_relay_resolver_signature() {
+    let input = include_str!("to_schema/fixtures/terse-relay-resolver-signature.js");
+    let expected = include_str!("to_schema/fixtures/terse-relay-resolver-signature.expected");
+    test_fixture(transform_fixture, file!(), "terse-relay-resolver-signature.js", "to_schema/fixtures/terse-relay-resolver-signature.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn terse_relay_resolver_with_output_type() {
     let input = include_str!("to_schema/fixtures/terse-relay-resolver-with-output-type.js");
@@ -13,12 +13,13 @@ use common::Named;
 use common::NamedItem;
 use common::Span;
 use fnv::FnvHashSet;
+use graphql_ir::ARGUMENT_DEFINITION;
+use graphql_ir::DIRECTIVE_ARGUMENTS;
 use graphql_ir::FragmentDefinitionName;
 use graphql_ir::OperationDefinitionName;
 use graphql_ir::Program;
 use graphql_ir::VariableDefinition;
 use graphql_ir::VariableName;
-use graphql_ir::DIRECTIVE_ARGUMENTS;
 use graphql_syntax::Argument;
 use graphql_syntax::ConstantValue;
 use graphql_syntax::Directive;
@@ -34,12 +35,9 @@ use graphql_syntax::ScalarField;
 use graphql_syntax::Selection;
 use graphql_syntax::TokenKind;
 use graphql_syntax::Value;
-use intern::string_key::StringKey;
 use intern::Lookup;
+use intern::string_key::StringKey;
 use log::debug;
-use lsp_types::request::Completion;
-use lsp_types::request::Request;
-use lsp_types::request::ResolveCompletionItem;
 use lsp_types::CompletionItem;
 use lsp_types::CompletionItemKind;
 use lsp_types::CompletionResponse;
@@ -47,6 +45,9 @@ use lsp_types::Documentation;
 use lsp_types::InsertTextFormat;
 use lsp_types::MarkupContent;
 use lsp_types::MarkupKind;
+use lsp_types::request::Completion;
+use lsp_types::request::Request;
+use lsp_types::request::ResolveCompletionItem;
 use schema::Argument as SchemaArgument;
 use schema::Directive as SchemaDirective;
 use schema::InputObject;
@@ -58,12 +59,12 @@ use schema::Type;
 use schema::TypeReference;
 use schema::TypeWithFields;
 
+use crate::LSPRuntimeError;
+use crate::SchemaDocumentation;
 use crate::lsp_runtime_error::LSPRuntimeResult;
 use crate::node_resolution_info::TypePath;
 use crate::node_resolution_info::TypePathItem;
 use crate::server::GlobalState;
-use crate::LSPRuntimeError;
-use crate::SchemaDocumentation;
 
 #[derive(Debug, Clone)]
 pub enum CompletionKind {
@@ -98,7 +99,8 @@ pub enum CompletionKind {
 pub enum ArgumentKind {
     Field,
     Directive(DirectiveName),
-    ArgumentsDirective(StringKey),
+    /// Arguments of a fragment spread, passed with `...Foo(x: 1)` or `@arguments`.
+    FragmentArguments(StringKey),
 }
 
 #[derive(Debug)]
@@ -135,7 +137,7 @@ trait ArgumentLike {
 
 impl ArgumentLike for &SchemaArgument {
     fn name(&self) -> StringKey {
-        self.name.0
+        self.name.item.0
     }
     fn type_(&self) -> &TypeReference<Type> {
         &self.type_
@@ -215,6 +217,19 @@ impl CompletionRequestBuilder {
                         ));
                         let type_name = fragment.type_condition.type_.value;
                         let type_path = vec![TypePathItem::FragmentDefinition { type_name }];
+                        if let Some(variable_definitions) = &fragment.variable_definitions {
+                            for variable_definition in &variable_definitions.items {
+                                if let Some(req) = self.build_request_from_directives(
+                                    &variable_definition.directives,
+                                    DirectiveLocation::VariableDefinition,
+                                    position_span,
+                                    type_path.clone(),
+                                    None,
+                                ) {
+                                    return Some(req);
+                                }
+                            }
+                        }
                         if let Some(req) = self.build_request_from_selection_or_directives(
                             &fragment.selections,
                             &fragment.directives,
@@ -277,19 +292,33 @@ impl CompletionRequestBuilder {
                     }
                     Selection::FragmentSpread(spread) => {
                         let FragmentSpread {
-                            name, directives, ..
+                            name,
+                            arguments,
+                            directives,
+                            ..
                         } = spread;
                         if name.span.contains(position_span) {
-                            Some(self.new_request(CompletionKind::FragmentSpread, type_path))
-                        } else {
-                            self.build_request_from_directives(
-                                directives,
-                                DirectiveLocation::FragmentSpread,
-                                position_span,
-                                type_path,
-                                Some(name.value),
-                            )
+                            return Some(
+                                self.new_request(CompletionKind::FragmentSpread, type_path),
+                            );
+                        }
+                        if let Some(arguments) = arguments {
+                            if arguments.span.contains(position_span) {
+                                return self.build_request_from_arguments(
+                                    arguments,
+                                    position_span,
+                                    type_path,
+                                    ArgumentKind::FragmentArguments(name.value),
+                                );
+                            }
                         }
+                        self.build_request_from_directives(
+                            directives,
+                            DirectiveLocation::FragmentSpread,
+                            position_span,
+                            type_path,
+                            Some(name.value),
+                        )
                     }
                     Selection::InlineFragment(node) => {
                         let InlineFragment {
@@ -623,7 +652,7 @@ impl CompletionRequestBuilder {
                         type_path,
                         if let Some(fragment_spread_name) = fragment_spread_name {
                             if directive.name.value == *DIRECTIVE_ARGUMENTS {
-                                ArgumentKind::ArgumentsDirective(fragment_spread_name)
+                                ArgumentKind::FragmentArguments(fragment_spread_name)
                             } else {
                                 ArgumentKind::Directive(DirectiveName(directive.name.value))
                             }
@@ -754,7 +783,7 @@ fn completion_items_for_request(
                     has_colon,
                 ))
             }
-            ArgumentKind::ArgumentsDirective(fragment_spread_name) => {
+            ArgumentKind::FragmentArguments(fragment_spread_name) => {
                 let fragment = program.fragment(FragmentDefinitionName(fragment_spread_name))?;
                 Some(resolve_completion_items_for_argument_name(
                     fragment.variable_definitions.iter(),
@@ -782,7 +811,7 @@ fn completion_items_for_request(
                     let (_, field) = request.type_path.resolve_current_field(schema)?;
                     &field.arguments.named(ArgumentName(argument_name))?.type_
                 }
-                ArgumentKind::ArgumentsDirective(fragment_spread_name) => {
+                ArgumentKind::FragmentArguments(fragment_spread_name) => {
                     let fragment =
                         program.fragment(FragmentDefinitionName(fragment_spread_name))?;
                     &fragment
@@ -844,7 +873,7 @@ fn completion_items_for_request(
                 input_object
                     .fields
                     .iter()
-                    .find(|field| field.name.0 == *field_name)
+                    .find(|field| field.name.item.0 == *field_name)
                     .and_then(|field| resolve_root_input_field(schema, &field.type_))
             }
 
@@ -1184,7 +1213,13 @@ fn resolve_completion_items_for_fragment_spread(
             if args.is_empty() {
                 return CompletionItem::new_simple(label, detail);
             }
-            let insert_text = format!("{} @arguments({})", label, args.join(", "));
+            // Fragments declared with `@argumentDefinitions` are spread with `@arguments`,
+            // fragments with variable definitions take the arguments on the spread.
+            let insert_text = if fragment.directives.named(*ARGUMENT_DEFINITION).is_some() {
+                format!("{} @arguments({})", label, args.join(", "))
+            } else {
+                format!("{}({})", label, args.join(", "))
+            };
             CompletionItem {
                 label,
                 kind: None,
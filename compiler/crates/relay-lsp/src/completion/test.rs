@@ -11,8 +11,8 @@ use std::sync::Arc;
 
 use common::SourceLocationKey;
 use common::Span;
-use graphql_ir::build;
 use graphql_ir::Program;
+use graphql_ir::build;
 use graphql_syntax::parse_executable;
 use graphql_syntax::parse_executable_with_error_recovery;
 use intern::string_key::Intern;
@@ -417,6 +417,9 @@ fn directive() {
             "__clientField",
             "appendEdge",
             "required",
+            "catch",
+            "exportAs",
+            "paginated",
             "stream_connection",
             "match",
             "customDirective",
@@ -455,6 +458,9 @@ fn directive_on_scalar_field() {
             "__clientField",
             "appendEdge",
             "required",
+            "catch",
+            "exportAs",
+            "paginated",
             "stream_connection",
             "match",
             "customDirective",
@@ -573,6 +579,82 @@ fn fragment_spread_on_interface() {
     assert_labels(items.unwrap(), vec!["TestFragment", "TestFragment2"]);
 }
 
+#[test]
+fn fragment_spread_snippet() {
+    let items = parse_and_resolve_completion_items(
+        r#"
+            fragment Test on Viewer {
+               ...T|
+            }
+        "#,
+        Some(build_test_program(
+            r#"
+        fragment TestFragment($count: Int!) on Viewer {
+           __typename
+        }
+
+        fragment TestFragment2 on Viewer
+          @argumentDefinitions(count: {type: "Int!"}) {
+            __typename
+        }
+      "#,
+        )),
+    )
+    .unwrap();
+    let insert_texts = items
+        .into_iter()
+        .map(|item| (item.label, item.insert_text.unwrap()))
+        .collect::<HashMap<_, _>>();
+    assert_eq!(
+        insert_texts.get("TestFragment").unwrap(),
+        "TestFragment(count: $1)"
+    );
+    assert_eq!(
+        insert_texts.get("TestFragment2").unwrap(),
+        "TestFragment2 @arguments(count: $1)"
+    );
+}
+
+#[test]
+fn fragment_spread_arguments() {
+    let items = parse_and_resolve_completion_items(
+        r#"
+            fragment Test on Viewer {
+               ...TestFragment(|)
+            }
+        "#,
+        Some(build_test_program(
+            r#"
+        fragment TestFragment($count: Int!, $cursor: ID) on Viewer {
+           __typename
+        }
+      "#,
+        )),
+    );
+    assert_labels(items.unwrap(), vec!["count", "cursor"]);
+}
+
+#[test]
+fn fragment_variable_definition_directive() {
+    let items = parse_and_resolve_completion_items(
+        r#"
+            fragment Test($count: Int @|) on Viewer {
+               __typename
+            }
+        "#,
+        None,
+    );
+    assert_labels(
+        items.unwrap(),
+        vec![
+            "_auto_hoisted",
+            "configurable",
+            "provider",
+            "unusedLocalVariable_DEPRECATED",
+        ],
+    );
+}
+
 #[test]
 fn argument_value_object() {
     let items = parse_and_resolve_completion_items(
@@ -742,6 +824,9 @@ fn empty_directive() {
             "__clientField",
             "appendEdge",
             "required",
+            "catch",
+            "exportAs",
+            "paginated",
             "stream_connection",
             "match",
             "customDirective",
@@ -148,6 +148,7 @@ fn transform_program<TPerfLogger: PerfLogger + 'static>(
         perf_logger,
         None,
         custom_transforms_config,
+        None,
     )
     .map_err(|errors| format!("{:?}", errors))
 }
@@ -37,6 +37,7 @@ fn argument_definition_hover_info(directive_name: &str) -> Option<MarkedString>
         "argumentDefinitions" => Some(
             r#"
 `@argumentDefinitions` is a directive used to specify arguments taken by a fragment.
+Fragments can also declare their arguments as variable definitions: `fragment Foo($count: Int = 10) on User`.
 
 ---
 @see: https://relay.dev/docs/en/graphql-in-relay.html#argumentdefinitions
@@ -45,6 +46,7 @@ fn argument_definition_hover_info(directive_name: &str) -> Option<MarkedString>
         "arguments" => Some(
             r#"
 `@arguments` is a directive used to pass arguments to a fragment that was defined using `@argumentDefinitions`.
+Fragments that declare variable definitions take their arguments on the spread: `...Foo(count: 20)`.
 
 ---
 @see: https://relay.dev/docs/en/graphql-in-relay.html#arguments
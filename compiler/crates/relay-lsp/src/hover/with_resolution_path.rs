@@ -454,6 +454,7 @@ fn get_hover_contents<'a>(
             schema,
             schema_name,
             schema_documentation,
+            program,
             content_consumer_type,
         ),
         HoverBehavior::ScalarOrLinkedField(field_name, selection_path) => {
@@ -471,6 +472,7 @@ fn get_hover_contents<'a>(
             schema,
             schema_name,
             schema_documentation,
+            program,
             content_consumer_type,
         ),
         HoverBehavior::InlineFragment(inline_fragment_path) => on_hover_inline_fragment(
@@ -553,6 +555,7 @@ fn on_hover_constant_value<'a>(
     schema: &SDLSchema,
     schema_name: StringKey,
     schema_documentation: &impl SchemaDocumentation,
+    program: &Program,
     content_consumer_type: ContentConsumerType,
 ) -> Option<HoverContents> {
     match constant_value_parent.find_constant_value_root() {
@@ -568,6 +571,7 @@ fn on_hover_constant_value<'a>(
             schema,
             schema_name,
             schema_documentation,
+            program,
             content_consumer_type,
         ),
     }
@@ -578,6 +582,7 @@ fn on_hover_argument_path<'a>(
     schema: &SDLSchema,
     schema_name: StringKey,
     schema_documentation: &impl SchemaDocumentation,
+    program: &Program,
     content_consumer_type: ContentConsumerType,
 ) -> Option<HoverContents> {
     let ArgumentPath {
@@ -610,6 +615,14 @@ fn on_hover_argument_path<'a>(
             schema_documentation,
             content_consumer_type,
         ),
+        ArgumentRoot::FragmentSpread(fragment_spread_path) => get_fragment_spread_hover_content(
+            fragment_spread_path,
+            schema,
+            schema_name,
+            schema_documentation,
+            program,
+            content_consumer_type,
+        ),
     }?;
 
     let mut contents = vec![argument_info];
@@ -712,7 +725,7 @@ fn get_scalar_or_linked_field_hover_content(
             let arg_type_name = schema.get_type_name(arg.type_.inner()).lookup();
             hover_contents.push(MarkedString::from_markdown(format!(
                 "{}: **{}**{}\n\n{}",
-                arg.name,
+                arg.name.item,
                 content_consumer_type.render_text_with_params(
                     &schema.get_type_string(&arg.type_),
                     &GraphQLSchemaExplorerParams {
@@ -729,7 +742,7 @@ fn get_scalar_or_linked_field_hover_content(
                 if let Some(description) = schema_documentation.get_field_argument_description(
                     parent_type_name,
                     field.name.item.lookup(),
-                    arg.name.0.lookup(),
+                    arg.name.item.0.lookup(),
                 ) {
                     description.to_string()
                 } else {
@@ -844,6 +857,25 @@ fn on_hover_fragment_spread<'a>(
     program: &Program,
     content_consumer_type: ContentConsumerType,
 ) -> Option<HoverContents> {
+    let content = get_fragment_spread_hover_content(
+        fragment_spread_path,
+        schema,
+        schema_name,
+        schema_documentation,
+        program,
+        content_consumer_type,
+    )?;
+    Some(HoverContents::Array(content))
+}
+
+fn get_fragment_spread_hover_content<'a>(
+    fragment_spread_path: &'a FragmentSpreadPath<'a>,
+    schema: &SDLSchema,
+    schema_name: StringKey,
+    schema_documentation: &impl SchemaDocumentation,
+    program: &Program,
+    content_consumer_type: ContentConsumerType,
+) -> Option<Vec<MarkedString>> {
     // TODO eventually show information about whether the fragment spread is
     // infallible, fallible, interface-on-interface, etc.
 
@@ -948,7 +980,7 @@ For example:
         hover_contents.push(MarkedString::String(type_description.to_string()));
     }
 
-    Some(HoverContents::Array(hover_contents))
+    Some(hover_contents)
 }
 
 fn on_hover_directive<'a>(
@@ -15,7 +15,7 @@ use crossbeam::channel::SendError;
 use crossbeam::channel::Sender;
 use dashmap::mapref::entry::Entry;
 use dashmap::DashMap;
-use docblock_syntax::parse_docblock;
+use docblock_syntax::parse_docblock_source;
 use extract_graphql::JavaScriptSourceFeature;
 use fnv::FnvBuildHasher;
 use graphql_ir::build_ir_with_extra_features;
@@ -290,28 +290,28 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
         for (index, docblock_source) in docblock_sources.iter().enumerate() {
             let source_location_key = SourceLocationKey::embedded(url.as_ref(), index);
             let text_source = docblock_source.text_source();
-            let text = &text_source.text;
-            let result = parse_docblock(text, source_location_key).and_then(|ast| {
-                parse_docblock_ast(
-                    project_config.name,
-                    &ast,
-                    Some(&executable_definitions),
-                    ParseOptions {
-                        enable_output_type: &project_config
-                            .feature_flags
-                            .relay_resolver_enable_output_type,
-                        enable_strict_resolver_flavors: &project_config
-                            .feature_flags
-                            .relay_resolvers_enable_strict_resolver_flavors,
-                        allow_legacy_verbose_syntax: &project_config
-                            .feature_flags
-                            .relay_resolvers_allow_legacy_verbose_syntax,
-                        enable_interface_output_type: &project_config
-                            .feature_flags
-                            .relay_resolver_enable_interface_output_type,
-                    },
-                )
-            });
+            let result =
+                parse_docblock_source(docblock_source, source_location_key).and_then(|ast| {
+                    parse_docblock_ast(
+                        project_config.name,
+                        &ast,
+                        Some(&executable_definitions),
+                        ParseOptions {
+                            enable_output_type: &project_config
+                                .feature_flags
+                                .relay_resolver_enable_output_type,
+                            enable_strict_resolver_flavors: &project_config
+                                .feature_flags
+                                .relay_resolvers_enable_strict_resolver_flavors,
+                            allow_legacy_verbose_syntax: &project_config
+                                .feature_flags
+                                .relay_resolvers_allow_legacy_verbose_syntax,
+                            enable_interface_output_type: &project_config
+                                .feature_flags
+                                .relay_resolver_enable_interface_output_type,
+                        },
+                    )
+                });
 
             if let Err(errors) = result {
                 diagnostics.extend(errors.iter().map(|diagnostic| {
@@ -405,7 +405,7 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
                 true
             };
         log_event.bool("is_incremental_build", is_incremental_build);
-        let (base_program, _) = build_raw_program(
+        let (base_program, _, _, _) = build_raw_program(
             project_config,
             project_asts,
             schema,
@@ -459,6 +459,7 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
             Arc::clone(&self.lsp_state.perf_logger),
             log_event,
             self.lsp_state.config.custom_transforms.as_ref(),
+            None,
         )?;
         Ok(())
     }
@@ -11,7 +11,7 @@ use common::SourceLocationKey;
 use common::Span;
 use common::TextSource;
 use dashmap::DashMap;
-use docblock_syntax::parse_docblock;
+use docblock_syntax::parse_docblock_source;
 use extract_graphql::JavaScriptSourceFeature;
 use graphql_syntax::parse_executable_with_error_recovery;
 use graphql_syntax::ExecutableDefinition;
@@ -168,7 +168,7 @@ pub fn extract_feature_from_text(
 
             let text_source = &docblock_source.text_source();
             let text = &text_source.text;
-            let docblock_ir = parse_docblock(text, source_location_key)
+            let docblock_ir = parse_docblock_source(docblock_source, source_location_key)
                 .and_then(|ast| {
                     parse_docblock_ast(
                         project_config.name,
@@ -22,6 +22,7 @@ use intern::intern;
 use lazy_static::lazy_static;
 use schema::ArgumentDefinitions;
 use schema::SDLSchema;
+use schema::SchemaDocument;
 use schema::TypeReference;
 
 const RELAY_EXTENSIONS: &str = include_str!("./relay-extensions.graphql");
@@ -39,24 +40,61 @@ pub fn build_schema_with_extensions<T: AsRef<str>, U: AsRef<str>>(
     server_sdls: &[(T, SourceLocationKey)],
     extension_sdls: &[(U, SourceLocationKey)],
 ) -> DiagnosticsResult<SDLSchema> {
-    let extensions: Vec<(&str, SourceLocationKey)> =
-        once((RELAY_EXTENSIONS, SourceLocationKey::generated()))
-            .chain(
-                extension_sdls
-                    .iter()
-                    .map(|(source, location_key)| (source.as_ref(), *location_key)),
-            )
-            .collect();
+    build_schema_from_documents_with_extensions(
+        schema::parse_server_sdls(server_sdls)?,
+        extension_sdls,
+    )
+}
+
+/// Same as `build_schema_with_extensions`, but for server schema documents
+/// that were already parsed (and possibly rewritten) by the caller.
+pub fn build_schema_from_documents_with_extensions<U: AsRef<str>>(
+    server_documents: Vec<SchemaDocument>,
+    extension_sdls: &[(U, SourceLocationKey)],
+) -> DiagnosticsResult<SDLSchema> {
+    let mut schema = schema::build_schema_from_documents_with_extensions(
+        server_documents,
+        &with_relay_extensions(extension_sdls),
+    )?;
+    remove_label_arguments(&mut schema);
+    Ok(schema)
+}
+
+/// Same as `build_schema_from_documents_with_extensions`, but the server
+/// schema documents are federation subgraphs that are composed into a single
+/// supergraph schema.
+pub fn build_federated_schema_from_documents_with_extensions<U: AsRef<str>>(
+    subgraph_documents: Vec<SchemaDocument>,
+    extension_sdls: &[(U, SourceLocationKey)],
+) -> DiagnosticsResult<SDLSchema> {
+    let mut schema = schema::build_federated_schema_from_documents_with_extensions(
+        subgraph_documents,
+        &with_relay_extensions(extension_sdls),
+    )?;
+    remove_label_arguments(&mut schema);
+    Ok(schema)
+}
 
-    let mut schema = schema::build_schema_with_extensions(server_sdls, &extensions)?;
+fn with_relay_extensions<U: AsRef<str>>(
+    extension_sdls: &[(U, SourceLocationKey)],
+) -> Vec<(&str, SourceLocationKey)> {
+    once((RELAY_EXTENSIONS, SourceLocationKey::generated()))
+        .chain(
+            extension_sdls
+                .iter()
+                .map(|(source, location_key)| (source.as_ref(), *location_key)),
+        )
+        .collect()
+}
 
+fn remove_label_arguments(schema: &mut SDLSchema) {
     // Remove label arg from @defer and @stream directives since the compiler
     // adds these arguments.
     for directive_name in &[*DEFER, *STREAM] {
         if let Some(directive) = schema.get_directive_mut(*directive_name) {
             let mut next_args: Vec<_> = directive.arguments.iter().cloned().collect();
             for arg in next_args.iter_mut() {
-                if arg.name == *LABEL {
+                if arg.name.item == *LABEL {
                     if let TypeReference::NonNull(of) = &arg.type_ {
                         arg.type_ = *of.clone()
                     };
@@ -65,5 +103,4 @@ pub fn build_schema_with_extensions<T: AsRef<str>, U: AsRef<str>>(
             directive.arguments = ArgumentDefinitions::new(next_args);
         }
     }
-    Ok(schema)
 }
@@ -46,6 +46,7 @@ pub fn apply_transforms<TPerfLogger>(
     perf_logger: Arc<TPerfLogger>,
     print_stats: Option<fn(extra_info: &str, program: &Program) -> ()>,
     custom_transforms_config: Option<&CustomTransformsConfig>,
+    field_cost_hints: Option<&FieldCostHints>,
 ) -> DiagnosticsResult<Programs>
 where
     TPerfLogger: PerfLogger + 'static,
@@ -80,6 +81,7 @@ where
                         Arc::clone(&base_fragment_names),
                         Arc::clone(&perf_logger),
                         custom_transforms_config,
+                        field_cost_hints,
                     )?;
 
                     try_join(
@@ -165,6 +167,9 @@ fn apply_common_transforms(
             &project_config.schema_config.defer_stream_interface,
         )
     });
+    program = log_event.time("paginated_directive", || {
+        paginated_directive(&program, &project_config.schema_config.pagination_interface)
+    })?;
     program = log_event.time("mask", || mask(&program));
     program = log_event.time("transform_defer_stream", || {
         transform_defer_stream(
@@ -183,6 +188,7 @@ fn apply_common_transforms(
     program = log_event.time("transform_subscriptions", || {
         transform_subscriptions(&program)
     })?;
+    program = log_event.time("dependent_operations", || dependent_operations(&program))?;
     program = log_event.time("transform_refetchable_fragment", || {
         transform_refetchable_fragment(
             &program,
@@ -263,6 +269,7 @@ fn apply_reader_transforms(
     })?;
 
     program = log_event.time("required_directive", || required_directive(&program))?;
+    program = log_event.time("catch_directive", || catch_directive(&program))?;
 
     program = log_event.time("client_edges", || {
         client_edges(&program, project_config, &base_fragment_names)
@@ -333,6 +340,7 @@ fn apply_operation_transforms(
     base_fragment_names: Arc<FragmentDefinitionNameSet>,
     perf_logger: Arc<impl PerfLogger>,
     custom_transforms_config: Option<&CustomTransformsConfig>,
+    field_cost_hints: Option<&FieldCostHints>,
 ) -> DiagnosticsResult<Arc<Program>> {
     let log_event = perf_logger.create_event("apply_operation_transforms");
     log_event.string("project", project_config.name.to_string());
@@ -391,6 +399,19 @@ fn apply_operation_transforms(
         })?;
     }
 
+    if let (Some(auto_defer_config), Some(field_cost_hints)) =
+        (&project_config.auto_defer, field_cost_hints)
+    {
+        program = log_event.time("auto_defer", || {
+            auto_defer(
+                &program,
+                &project_config.schema_config.defer_stream_interface,
+                field_cost_hints,
+                auto_defer_config.budget_ms,
+            )
+        })?;
+    }
+
     program = apply_after_custom_transforms(
         &program,
         custom_transforms,
@@ -668,7 +689,9 @@ fn apply_typegen_transforms(
     program = log_event.time("transform_subscriptions", || {
         transform_subscriptions(&program)
     })?;
+    program = log_event.time("dependent_operations", || dependent_operations(&program))?;
     program = log_event.time("required_directive", || required_directive(&program))?;
+    program = log_event.time("catch_directive", || catch_directive(&program))?;
     program = log_event.time("generate_relay_resolvers_model_fragments", || {
         generate_relay_resolvers_model_fragments(
             project_config.name,
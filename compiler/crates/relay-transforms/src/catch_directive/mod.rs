@@ -0,0 +1,397 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+mod validation_message;
+
+use std::sync::Arc;
+
+use common::ArgumentName;
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::DirectiveName;
+use common::Location;
+use common::NamedItem;
+use common::WithLocation;
+use graphql_ir::associated_data_impl;
+use graphql_ir::Directive;
+use graphql_ir::Field;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::FragmentSpread;
+use graphql_ir::InlineFragment;
+use graphql_ir::LinkedField;
+use graphql_ir::OperationDefinition;
+use graphql_ir::Program;
+use graphql_ir::ScalarField;
+use graphql_ir::Selection;
+use graphql_ir::Transformed;
+use graphql_ir::Transformer;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use intern::string_key::StringKeyMap;
+use intern::Lookup;
+use lazy_static::lazy_static;
+
+use self::validation_message::ValidationMessage;
+use crate::DirectiveFinder;
+use crate::FragmentAliasMetadata;
+use crate::REQUIRED_DIRECTIVE_NAME;
+
+lazy_static! {
+    pub static ref CATCH_DIRECTIVE_NAME: DirectiveName = DirectiveName("catch".intern());
+    static ref TO_ARGUMENT: ArgumentName = ArgumentName("to".intern());
+    /// Added to the fields within a `@catch` field. The field errors of these
+    /// fields are caught, so they are only null if they are semantically
+    /// nullable.
+    pub static ref WITHIN_CATCH_METADATA_KEY: DirectiveName =
+        DirectiveName("__withinCatch".intern());
+    static ref RESULT_TO: StringKey = "RESULT".intern();
+    static ref NULL_TO: StringKey = "NULL".intern();
+    static ref INLINE_DIRECTIVE_NAME: DirectiveName = DirectiveName("inline".intern());
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CatchMetadataDirective {
+    pub to: CatchTo,
+    pub path: StringKey,
+}
+associated_data_impl!(CatchMetadataDirective);
+
+/// Validates `@catch` on fields and aliased fragment spreads, and adds
+/// `CatchMetadataDirective` to them for codegen and typegen.
+pub fn catch_directive(program: &Program) -> DiagnosticsResult<Program> {
+    let mut transform = CatchDirective::new(program);
+
+    let next_program = transform
+        .transform_program(program)
+        .replace_or_else(|| program.clone());
+
+    if transform.errors.is_empty() {
+        Ok(next_program)
+    } else {
+        Err(transform.errors)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct CatchMetadata {
+    to: CatchTo,
+    directive_location: Location,
+}
+
+struct MaybeCatchField {
+    catch: Option<CatchMetadata>,
+    field_name: WithLocation<StringKey>,
+}
+
+struct CatchDirective<'s> {
+    program: &'s Program,
+    errors: Vec<Diagnostic>,
+    path: Vec<&'s str>,
+    within_catch: bool,
+    parent_inline_fragment_directive: Option<Location>,
+    path_catch_map: StringKeyMap<MaybeCatchField>,
+}
+
+impl<'program> CatchDirective<'program> {
+    fn new(program: &'program Program) -> Self {
+        Self {
+            program,
+            errors: Default::default(),
+            path: vec![],
+            within_catch: false,
+            parent_inline_fragment_directive: None,
+            path_catch_map: Default::default(),
+        }
+    }
+
+    fn reset_state(&mut self) {
+        self.path_catch_map = Default::default();
+        self.parent_inline_fragment_directive = None;
+    }
+
+    fn current_path(&self, name: &'program str) -> StringKey {
+        let mut path = self.path.clone();
+        path.push(name);
+        path.join(".").intern()
+    }
+
+    fn get_catch_metadata(
+        &mut self,
+        directives: &[Directive],
+        path: StringKey,
+        field_name: WithLocation<StringKey>,
+    ) -> Option<CatchMetadata> {
+        let catch = directives.named(*CATCH_DIRECTIVE_NAME).map(|directive| {
+            let to = directive
+                .arguments
+                .named(*TO_ARGUMENT)
+                .map_or(CatchTo::Result, |argument| {
+                    CatchTo::from(argument.value.item.expect_constant().unwrap_enum())
+                });
+            CatchMetadata {
+                to,
+                directive_location: directive.name.location,
+            }
+        });
+
+        if let Some(catch) = catch {
+            if let Some(required) = directives.named(*REQUIRED_DIRECTIVE_NAME) {
+                self.errors.push(
+                    Diagnostic::error(
+                        ValidationMessage::CatchWithRequired,
+                        catch.directive_location,
+                    )
+                    .annotate("@required is used here", required.name.location),
+                );
+            }
+            if let Some(location) = self.parent_inline_fragment_directive {
+                self.errors.push(
+                    Diagnostic::error(
+                        ValidationMessage::CatchWithinInlineDirective,
+                        catch.directive_location,
+                    )
+                    .annotate("The fragment is annotated as @inline here.", location),
+                );
+            }
+        }
+
+        self.assert_compatible_catch(path, MaybeCatchField { catch, field_name });
+        catch
+    }
+
+    fn assert_compatible_catch(&mut self, path: StringKey, current: MaybeCatchField) {
+        let previous = match self.path_catch_map.get(&path) {
+            Some(previous) => previous,
+            None => {
+                self.path_catch_map.insert(path, current);
+                return;
+            }
+        };
+        match (previous.catch, current.catch) {
+            (Some(previous_catch), Some(current_catch)) => {
+                if previous_catch.to != current_catch.to {
+                    self.errors.push(
+                        Diagnostic::error(
+                            ValidationMessage::CatchToMismatch {
+                                field_name: current.field_name.item,
+                            },
+                            previous_catch.directive_location,
+                        )
+                        .annotate(
+                            "should be the same as the `to` declared here",
+                            current_catch.directive_location,
+                        ),
+                    )
+                }
+            }
+            (Some(_), None) => self.errors.push(
+                Diagnostic::error(
+                    ValidationMessage::CatchFieldMismatch {
+                        field_name: current.field_name.item,
+                    },
+                    previous.field_name.location,
+                )
+                .annotate("but not caught here", current.field_name.location),
+            ),
+            (None, Some(_)) => self.errors.push(
+                Diagnostic::error(
+                    ValidationMessage::CatchFieldMismatch {
+                        field_name: current.field_name.item,
+                    },
+                    current.field_name.location,
+                )
+                .annotate("but not caught here", previous.field_name.location),
+            ),
+            (None, None) => {}
+        }
+    }
+
+    fn transform_field_directives(
+        &self,
+        directives: &[Directive],
+        catch: Option<CatchMetadata>,
+        path: StringKey,
+    ) -> Option<Vec<Directive>> {
+        if catch.is_none() && !self.within_catch {
+            return None;
+        }
+        let mut next_directives = Vec::with_capacity(directives.len() + 2);
+        next_directives.extend(directives.iter().cloned());
+        if let Some(catch) = catch {
+            next_directives.push(CatchMetadataDirective { to: catch.to, path }.into());
+        }
+        if self.within_catch {
+            next_directives.push(Directive {
+                name: WithLocation::generated(*WITHIN_CATCH_METADATA_KEY),
+                arguments: vec![],
+                data: None,
+            });
+        }
+        Some(next_directives)
+    }
+}
+
+impl<'s> Transformer for CatchDirective<'s> {
+    const NAME: &'static str = "CatchDirectiveTransform";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn transform_fragment(
+        &mut self,
+        fragment: &FragmentDefinition,
+    ) -> Transformed<FragmentDefinition> {
+        if !CatchDirectiveVisitor.find(fragment.selections.iter().collect()) {
+            return Transformed::Keep;
+        }
+        self.reset_state();
+        self.parent_inline_fragment_directive = fragment
+            .directives
+            .named(*INLINE_DIRECTIVE_NAME)
+            .map(|inline_directive| inline_directive.name.location);
+        self.default_transform_fragment(fragment)
+    }
+
+    fn transform_operation(
+        &mut self,
+        operation: &OperationDefinition,
+    ) -> Transformed<OperationDefinition> {
+        if !CatchDirectiveVisitor.find(operation.selections.iter().collect()) {
+            return Transformed::Keep;
+        }
+        self.reset_state();
+        self.default_transform_operation(operation)
+    }
+
+    fn transform_scalar_field(&mut self, field: &ScalarField) -> Transformed<Selection> {
+        let name = field.alias_or_name(&self.program.schema);
+        let path = self.current_path(name.lookup());
+        let catch = self.get_catch_metadata(
+            &field.directives,
+            path,
+            WithLocation::new(field.alias_or_name_location(), name),
+        );
+        match self.transform_field_directives(&field.directives, catch, path) {
+            None => Transformed::Keep,
+            Some(directives) => {
+                Transformed::Replace(Selection::ScalarField(Arc::new(ScalarField {
+                    directives,
+                    ..field.clone()
+                })))
+            }
+        }
+    }
+
+    fn transform_linked_field(&mut self, field: &LinkedField) -> Transformed<Selection> {
+        let name = field.alias_or_name(&self.program.schema);
+        let path = self.current_path(name.lookup());
+        let catch = self.get_catch_metadata(
+            &field.directives,
+            path,
+            WithLocation::new(field.alias_or_name_location(), name),
+        );
+        let next_directives = self.transform_field_directives(&field.directives, catch, path);
+
+        self.path.push(name.lookup());
+        let previous_within_catch = self.within_catch;
+        self.within_catch = previous_within_catch || catch.is_some();
+        let selections = self.transform_selections(&field.selections);
+        self.within_catch = previous_within_catch;
+        self.path.pop();
+
+        if selections.should_keep() && next_directives.is_none() {
+            Transformed::Keep
+        } else {
+            Transformed::Replace(Selection::LinkedField(Arc::new(LinkedField {
+                directives: next_directives.unwrap_or_else(|| field.directives.clone()),
+                selections: selections.replace_or_else(|| field.selections.clone()),
+                ..field.clone()
+            })))
+        }
+    }
+
+    fn transform_fragment_spread(&mut self, spread: &FragmentSpread) -> Transformed<Selection> {
+        let catch_directive = match spread.directives.named(*CATCH_DIRECTIVE_NAME) {
+            Some(directive) => directive,
+            None => return Transformed::Keep,
+        };
+        let alias = match FragmentAliasMetadata::find(&spread.directives) {
+            Some(metadata) => metadata.alias,
+            None => {
+                self.errors.push(Diagnostic::error(
+                    ValidationMessage::CatchOnFragmentSpreadWithoutAlias,
+                    catch_directive.name.location,
+                ));
+                return Transformed::Keep;
+            }
+        };
+        let path = self.current_path(alias.item.lookup());
+        let catch = self.get_catch_metadata(&spread.directives, path, alias);
+        let mut directives = spread.directives.clone();
+        if let Some(catch) = catch {
+            directives.push(CatchMetadataDirective { to: catch.to, path }.into());
+        }
+        Transformed::Replace(Selection::FragmentSpread(Arc::new(FragmentSpread {
+            directives,
+            ..spread.clone()
+        })))
+    }
+
+    fn transform_inline_fragment(&mut self, fragment: &InlineFragment) -> Transformed<Selection> {
+        let maybe_alias =
+            FragmentAliasMetadata::find(&fragment.directives).map(|metadata| metadata.alias.item);
+        if let Some(alias) = maybe_alias {
+            self.path.push(alias.lookup())
+        }
+        let next_fragment = self.default_transform_inline_fragment(fragment);
+        if maybe_alias.is_some() {
+            self.path.pop();
+        }
+        next_fragment
+    }
+}
+
+/// What a `@catch` field is read as when it, or a field within it, has a
+/// field error.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum CatchTo {
+    /// `{ok: true, value}` or `{ok: false, errors}`.
+    Result,
+    /// `null`, with the errors logged.
+    Null,
+}
+
+impl From<CatchTo> for StringKey {
+    fn from(to: CatchTo) -> Self {
+        match to {
+            CatchTo::Result => *RESULT_TO,
+            CatchTo::Null => *NULL_TO,
+        }
+    }
+}
+
+impl From<StringKey> for CatchTo {
+    fn from(to: StringKey) -> Self {
+        match to {
+            _ if to == *RESULT_TO => Self::Result,
+            _ if to == *NULL_TO => Self::Null,
+            // Values that don't conform to the GraphQL schema should have been filtered out in IR validation.
+            _ => unreachable!(),
+        }
+    }
+}
+
+struct CatchDirectiveVisitor;
+
+impl DirectiveFinder for CatchDirectiveVisitor {
+    fn visit_directive(&self, directive: &Directive) -> bool {
+        directive.name.item == *CATCH_DIRECTIVE_NAME
+    }
+
+    fn visit_fragment_spread(&mut self, fragment_spread: &FragmentSpread) -> bool {
+        self.visit_directives(&fragment_spread.directives)
+    }
+}
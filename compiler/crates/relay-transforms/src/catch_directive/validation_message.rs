@@ -0,0 +1,36 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use intern::string_key::StringKey;
+use thiserror::Error;
+
+#[derive(Error, Debug, serde::Serialize)]
+#[serde(tag = "type")]
+pub(super) enum ValidationMessage {
+    #[error("@catch is not supported within @inline fragments.")]
+    CatchWithinInlineDirective,
+
+    #[error(
+        "@catch on a fragment spread requires `@alias`. The result of reading the fragment is exposed as a field of the parent named by the alias."
+    )]
+    CatchOnFragmentSpreadWithoutAlias,
+
+    #[error(
+        "@catch and @required may not be used on the same field. A field that is caught is never null due to a field error."
+    )]
+    CatchWithRequired,
+
+    #[error(
+        "All references to a field must have matching @catch declarations. The `to` argument used for '{field_name}'"
+    )]
+    CatchToMismatch { field_name: StringKey },
+
+    #[error(
+        "All references to a field must have matching @catch declarations. The field '{field_name}' is caught here"
+    )]
+    CatchFieldMismatch { field_name: StringKey },
+}
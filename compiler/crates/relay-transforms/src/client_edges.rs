@@ -56,6 +56,7 @@ use crate::RequiredMetadataDirective;
 use crate::ValidationMessage;
 use crate::CHILDREN_CAN_BUBBLE_METADATA_KEY;
 use crate::REQUIRED_DIRECTIVE_NAME;
+use crate::WITHIN_CATCH_METADATA_KEY;
 
 lazy_static! {
     // This gets attached to the generated query
@@ -312,6 +313,7 @@ impl<'program, 'pc> ClientEdgesTransform<'program, 'pc> {
             *REQUIRED_DIRECTIVE_NAME,
             *CHILDREN_CAN_BUBBLE_METADATA_KEY,
             RequiredMetadataDirective::directive_name(),
+            *WITHIN_CATCH_METADATA_KEY,
         ];
 
         let other_directives = directives
@@ -0,0 +1,405 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::cmp::Reverse;
+use std::sync::Arc;
+
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::Location;
+use common::NamedItem;
+use common::WithLocation;
+use fnv::FnvHashMap;
+use fnv::FnvHashSet;
+use graphql_ir::associated_data_impl;
+use graphql_ir::Argument;
+use graphql_ir::ConstantValue;
+use graphql_ir::Directive;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::FragmentDefinitionName;
+use graphql_ir::FragmentSpread;
+use graphql_ir::InlineFragment;
+use graphql_ir::OperationDefinition;
+use graphql_ir::Program;
+use graphql_ir::Selection;
+use graphql_ir::Transformed;
+use graphql_ir::Transformer;
+use graphql_ir::Value;
+use graphql_syntax::OperationKind;
+use intern::string_key::StringKey;
+use relay_config::DeferStreamInterface;
+use schema::FieldID;
+use schema::SDLSchema;
+use schema::Schema;
+
+use super::transform_label;
+use super::ValidationMessage;
+use crate::get_applied_fragment_name;
+use crate::ModuleMetadata;
+
+/// Estimated costs of resolving fields, in milliseconds, keyed by the schema
+/// coordinate of the field (`Type.field`).
+#[derive(Clone, Debug, Default)]
+pub struct FieldCostHints {
+    costs: FnvHashMap<String, u64>,
+}
+
+impl FieldCostHints {
+    /// Parses a JSON object mapping schema coordinates to costs, e.g.
+    /// `{"User.friends": 120}`.
+    pub fn from_json(text: &str) -> serde_json::Result<Self> {
+        Ok(Self {
+            costs: serde_json::from_str(text)?,
+        })
+    }
+
+    fn field_cost(&self, schema: &SDLSchema, field_id: FieldID) -> u64 {
+        if self.costs.is_empty() {
+            return 0;
+        }
+        let field = schema.field(field_id);
+        match field.parent_type {
+            Some(parent_type) => {
+                let coordinate =
+                    format!("{}.{}", schema.get_type_name(parent_type), field.name.item);
+                self.costs.get(&coordinate).copied().unwrap_or(0)
+            }
+            None => 0,
+        }
+    }
+}
+
+/// A fragment spread deferred by `auto_defer`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AutoDeferredSpread {
+    pub fragment_name: FragmentDefinitionName,
+    pub label: StringKey,
+    pub cost_ms: u64,
+}
+
+/// Attached to queries with fragment spreads deferred by `auto_defer`, with
+/// the estimated cost of the initial payload once they are deferred.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AutoDeferMetadata {
+    pub cost_ms: u64,
+    pub deferred_spreads: Vec<AutoDeferredSpread>,
+}
+associated_data_impl!(AutoDeferMetadata);
+
+/// Defers fragment spreads of queries whose estimated cost exceeds
+/// `budget_ms`, most expensive first, until the initial payload fits the
+/// budget. The cost of a selection is the sum of the hinted costs of its
+/// fields, including the ones of spread fragments, excluding the ones
+/// already deferred.
+///
+/// Spreads are wrapped in an inline fragment with `@defer`, labeled like
+/// `transform_defer_stream` labels a spread deferred in the document, so
+/// this runs on the program of operations after it. Spreads of the same
+/// fragment spread more than once in a query are never deferred, as their
+/// labels would not be unique.
+pub fn auto_defer(
+    program: &Program,
+    defer_stream_interface: &DeferStreamInterface,
+    field_cost_hints: &FieldCostHints,
+    budget_ms: u64,
+) -> DiagnosticsResult<Program> {
+    if program
+        .schema
+        .get_directive(defer_stream_interface.defer_name)
+        .is_none()
+    {
+        return Err(vec![Diagnostic::error(
+            ValidationMessage::AutoDeferWithoutDeferDirective {
+                directive_name: defer_stream_interface.defer_name,
+            },
+            Location::generated(),
+        )]);
+    }
+    let mut transform = AutoDeferTransform {
+        defer_stream_interface,
+        costs: FragmentCosts {
+            program,
+            defer_stream_interface,
+            field_cost_hints,
+            fragment_costs: Default::default(),
+        },
+        budget_ms,
+        deferred_spreads: Default::default(),
+        spread_index: 0,
+    };
+    Ok(transform
+        .transform_program(program)
+        .replace_or_else(|| program.clone()))
+}
+
+struct AutoDeferTransform<'s> {
+    defer_stream_interface: &'s DeferStreamInterface,
+    costs: FragmentCosts<'s>,
+    budget_ms: u64,
+    /// The spreads of the current operation to defer, by their index in the
+    /// order `collect_candidates` visits them, with their label.
+    deferred_spreads: FnvHashMap<usize, StringKey>,
+    spread_index: usize,
+}
+
+struct Candidate {
+    index: usize,
+    fragment_name: FragmentDefinitionName,
+    label: StringKey,
+    cost_ms: u64,
+}
+
+impl AutoDeferTransform<'_> {
+    /// Collects the fragment spreads of the selections that may be deferred,
+    /// in the order that the transform visits them, and the labels of the
+    /// `@defer` and `@stream` directives already in them.
+    fn collect_candidates(
+        &mut self,
+        operation_name: StringKey,
+        selections: &[Selection],
+        candidates: &mut Vec<Candidate>,
+        labels: &mut FnvHashSet<StringKey>,
+    ) {
+        for selection in selections {
+            match selection {
+                Selection::FragmentSpread(spread) => {
+                    let label = transform_label(
+                        operation_name,
+                        self.defer_stream_interface.defer_name,
+                        get_applied_fragment_name(spread.fragment.item, &spread.arguments).0,
+                    );
+                    candidates.push(Candidate {
+                        index: candidates.len(),
+                        fragment_name: spread.fragment.item,
+                        label,
+                        cost_ms: self.costs.fragment_cost(spread.fragment.item),
+                    });
+                }
+                Selection::InlineFragment(inline_fragment) => {
+                    if self.is_deferred_or_module(&inline_fragment.directives) {
+                        labels.extend(self.label(&inline_fragment.directives));
+                    } else {
+                        self.collect_candidates(
+                            operation_name,
+                            &inline_fragment.selections,
+                            candidates,
+                            labels,
+                        );
+                    }
+                }
+                Selection::LinkedField(linked_field) => {
+                    labels.extend(self.label(&linked_field.directives));
+                    self.collect_candidates(
+                        operation_name,
+                        &linked_field.selections,
+                        candidates,
+                        labels,
+                    );
+                }
+                Selection::Condition(condition) => self.collect_candidates(
+                    operation_name,
+                    &condition.selections,
+                    candidates,
+                    labels,
+                ),
+                Selection::ScalarField(_) => {}
+            }
+        }
+    }
+
+    /// The selections of inline fragments with `@defer` are not part of the
+    /// initial payload, and the ones of `@module` are split operations.
+    fn is_deferred_or_module(&self, directives: &[Directive]) -> bool {
+        directives
+            .named(self.defer_stream_interface.defer_name)
+            .is_some()
+            || ModuleMetadata::find(directives).is_some()
+    }
+
+    fn label(&self, directives: &[Directive]) -> Option<StringKey> {
+        directives
+            .iter()
+            .filter(|directive| {
+                directive.name.item == self.defer_stream_interface.defer_name
+                    || directive.name.item == self.defer_stream_interface.stream_name
+            })
+            .find_map(|directive| {
+                directive
+                    .arguments
+                    .named(self.defer_stream_interface.label_arg)
+                    .and_then(|label| label.value.item.get_string_literal())
+            })
+    }
+
+    fn build_defer(&self, spread: &Arc<FragmentSpread>, label: StringKey) -> Selection {
+        let defer = Directive {
+            name: WithLocation::generated(self.defer_stream_interface.defer_name),
+            arguments: vec![Argument {
+                name: WithLocation::generated(self.defer_stream_interface.label_arg),
+                value: WithLocation::generated(Value::Constant(ConstantValue::String(label))),
+            }],
+            data: None,
+        };
+        Selection::InlineFragment(Arc::new(InlineFragment {
+            type_condition: None,
+            directives: vec![defer],
+            selections: vec![Selection::FragmentSpread(Arc::clone(spread))],
+            spread_location: Location::generated(),
+        }))
+    }
+}
+
+impl Transformer for AutoDeferTransform<'_> {
+    const NAME: &'static str = "AutoDeferTransform";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn transform_operation(
+        &mut self,
+        operation: &OperationDefinition,
+    ) -> Transformed<OperationDefinition> {
+        if operation.kind != OperationKind::Query {
+            return Transformed::Keep;
+        }
+        let mut cost_ms = self.costs.selections_cost(&operation.selections);
+        if cost_ms <= self.budget_ms {
+            return Transformed::Keep;
+        }
+
+        let mut candidates = Vec::new();
+        let mut labels = FnvHashSet::default();
+        self.collect_candidates(
+            operation.name.item.0,
+            &operation.selections,
+            &mut candidates,
+            &mut labels,
+        );
+        let mut label_counts: FnvHashMap<StringKey, usize> = FnvHashMap::default();
+        for candidate in &candidates {
+            *label_counts.entry(candidate.label).or_default() += 1;
+        }
+        candidates.retain(|candidate| {
+            candidate.cost_ms > 0
+                && label_counts[&candidate.label] == 1
+                && !labels.contains(&candidate.label)
+        });
+        candidates.sort_by_key(|candidate| (Reverse(candidate.cost_ms), candidate.index));
+
+        let mut deferred_spreads = Vec::new();
+        for candidate in candidates {
+            if cost_ms <= self.budget_ms {
+                break;
+            }
+            cost_ms = cost_ms.saturating_sub(candidate.cost_ms);
+            self.deferred_spreads
+                .insert(candidate.index, candidate.label);
+            deferred_spreads.push(AutoDeferredSpread {
+                fragment_name: candidate.fragment_name,
+                label: candidate.label,
+                cost_ms: candidate.cost_ms,
+            });
+        }
+        if deferred_spreads.is_empty() {
+            return Transformed::Keep;
+        }
+
+        self.spread_index = 0;
+        let transformed = self.default_transform_operation(operation);
+        self.deferred_spreads.clear();
+        let mut next_operation = match transformed {
+            Transformed::Delete => return Transformed::Delete,
+            Transformed::Keep => operation.clone(),
+            Transformed::Replace(replaced) => replaced,
+        };
+        next_operation.directives.push(
+            AutoDeferMetadata {
+                cost_ms,
+                deferred_spreads,
+            }
+            .into(),
+        );
+        Transformed::Replace(next_operation)
+    }
+
+    fn transform_fragment(&mut self, _: &FragmentDefinition) -> Transformed<FragmentDefinition> {
+        Transformed::Keep
+    }
+
+    fn transform_fragment_spread(&mut self, spread: &FragmentSpread) -> Transformed<Selection> {
+        let index = self.spread_index;
+        self.spread_index += 1;
+        match self.deferred_spreads.get(&index) {
+            Some(label) => {
+                Transformed::Replace(self.build_defer(&Arc::new(spread.clone()), *label))
+            }
+            None => Transformed::Keep,
+        }
+    }
+
+    fn transform_inline_fragment(&mut self, fragment: &InlineFragment) -> Transformed<Selection> {
+        if self.is_deferred_or_module(&fragment.directives) {
+            Transformed::Keep
+        } else {
+            self.default_transform_inline_fragment(fragment)
+        }
+    }
+}
+
+/// Computes the estimated cost of selections, memoizing the cost of
+/// fragments.
+struct FragmentCosts<'s> {
+    program: &'s Program,
+    defer_stream_interface: &'s DeferStreamInterface,
+    field_cost_hints: &'s FieldCostHints,
+    fragment_costs: FnvHashMap<FragmentDefinitionName, u64>,
+}
+
+impl FragmentCosts<'_> {
+    fn fragment_cost(&mut self, fragment_name: FragmentDefinitionName) -> u64 {
+        if let Some(cost) = self.fragment_costs.get(&fragment_name) {
+            return *cost;
+        }
+        let cost = match self.program.fragment(fragment_name) {
+            Some(fragment) => self.selections_cost(&fragment.selections),
+            None => 0,
+        };
+        self.fragment_costs.insert(fragment_name, cost);
+        cost
+    }
+
+    fn selections_cost(&mut self, selections: &[Selection]) -> u64 {
+        let program = self.program;
+        let schema = &program.schema;
+        let mut cost = 0;
+        for selection in selections {
+            cost += match selection {
+                Selection::ScalarField(field) => self
+                    .field_cost_hints
+                    .field_cost(schema, field.definition.item),
+                Selection::LinkedField(field) => {
+                    self.field_cost_hints
+                        .field_cost(schema, field.definition.item)
+                        + self.selections_cost(&field.selections)
+                }
+                Selection::InlineFragment(fragment) => {
+                    if fragment
+                        .directives
+                        .named(self.defer_stream_interface.defer_name)
+                        .is_some()
+                    {
+                        0
+                    } else {
+                        self.selections_cost(&fragment.selections)
+                    }
+                }
+                Selection::FragmentSpread(spread) => self.fragment_cost(spread.fragment.item),
+                Selection::Condition(condition) => self.selections_cost(&condition.selections),
+            };
+        }
+        cost
+    }
+}
@@ -5,11 +5,16 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+mod auto_defer;
 mod directives;
 
 use std::collections::HashMap;
 use std::sync::Arc;
 
+pub use auto_defer::auto_defer;
+pub use auto_defer::AutoDeferMetadata;
+pub use auto_defer::AutoDeferredSpread;
+pub use auto_defer::FieldCostHints;
 use common::ArgumentName;
 use common::Diagnostic;
 use common::DiagnosticsResult;
@@ -445,4 +450,8 @@ enum ValidationMessage {
         arg_name: ArgumentName,
         directive_name: DirectiveName,
     },
+    #[error(
+        "`autoDefer` is enabled, but the schema does not define the @{directive_name} directive."
+    )]
+    AutoDeferWithoutDeferDirective { directive_name: DirectiveName },
 }
@@ -0,0 +1,431 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+mod validation_message;
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common::ArgumentName;
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::DirectiveName;
+use common::Location;
+use common::NamedItem;
+use graphql_ir::associated_data_impl;
+use graphql_ir::Directive;
+use graphql_ir::Field;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::LinkedField;
+use graphql_ir::OperationDefinition;
+use graphql_ir::OperationDefinitionName;
+use graphql_ir::Program;
+use graphql_ir::ScalarField;
+use graphql_ir::Selection;
+use graphql_ir::Transformed;
+use graphql_ir::Transformer;
+use graphql_ir::VariableName;
+use graphql_syntax::OperationKind;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use lazy_static::lazy_static;
+use schema::Schema;
+use schema::Type;
+use schema::TypeReference;
+
+use self::validation_message::ValidationMessage;
+
+lazy_static! {
+    pub static ref EXPORT_AS_DIRECTIVE_NAME: DirectiveName = DirectiveName("exportAs".intern());
+    pub static ref DEPENDENT_OPERATION_DIRECTIVE_NAME: DirectiveName =
+        DirectiveName("dependentOperation".intern());
+    static ref VAR_ARGUMENT: ArgumentName = ArgumentName("var".intern());
+    static ref NAME_ARGUMENT: ArgumentName = ArgumentName("name".intern());
+}
+
+/// A query fetched with the response of the operation declaring it with
+/// `@dependentOperation`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DependentOperation {
+    pub operation_name: OperationDefinitionName,
+    /// Location of the name of the dependent operation, which determines the
+    /// path of its artifact.
+    pub location: Location,
+    /// The variables of the dependent operation set from `@exportAs` fields,
+    /// with the path of response keys to the field. Its other variables are
+    /// passed from the variables of the same name.
+    pub exported_variables: Vec<(VariableName, Vec<StringKey>)>,
+}
+
+/// Attached to operations declaring dependent operations.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DependentOperationsMetadata(pub Vec<DependentOperation>);
+associated_data_impl!(DependentOperationsMetadata);
+
+/// Validates the `@exportAs` fields and `@dependentOperation` directives of
+/// operations, removes them, and attaches a `DependentOperationsMetadata` to
+/// the operations declaring dependent operations.
+pub fn dependent_operations(program: &Program) -> DiagnosticsResult<Program> {
+    let mut transform = DependentOperations::new(program);
+
+    let next_program = transform
+        .transform_program(program)
+        .replace_or_else(|| program.clone());
+
+    if transform.errors.is_empty() {
+        Ok(next_program)
+    } else {
+        Err(transform.errors)
+    }
+}
+
+struct ExportedField {
+    variable_name: VariableName,
+    path: Vec<StringKey>,
+    type_: TypeReference<Type>,
+    location: Location,
+}
+
+struct DependentOperations<'s> {
+    program: &'s Program,
+    in_fragment: bool,
+    /// The path of response keys to the current selection, `None` within
+    /// plural fields.
+    current_path: Option<Vec<StringKey>>,
+    exported_fields: Vec<ExportedField>,
+    errors: Vec<Diagnostic>,
+}
+
+impl<'s> DependentOperations<'s> {
+    fn new(program: &'s Program) -> Self {
+        Self {
+            program,
+            in_fragment: false,
+            current_path: None,
+            exported_fields: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    fn build_dependent_operation(
+        &self,
+        operation: &OperationDefinition,
+        directive: &Directive,
+    ) -> DiagnosticsResult<DependentOperation> {
+        let schema = &self.program.schema;
+        let name = dependent_operation_name(directive);
+        let dependent_operation = self
+            .program
+            .operation(OperationDefinitionName(name))
+            .ok_or_else(|| {
+                vec![Diagnostic::error(
+                    ValidationMessage::UnknownDependentOperation {
+                        operation_name: name,
+                    },
+                    directive.name.location,
+                )]
+            })?;
+        let operation_name = dependent_operation.name.item;
+        if dependent_operation.kind != OperationKind::Query {
+            return Err(vec![
+                Diagnostic::error(
+                    ValidationMessage::DependentOperationNotAQuery { operation_name },
+                    directive.name.location,
+                )
+                .annotate("Dependent operation", dependent_operation.name.location),
+            ]);
+        }
+        if self.depends_on(operation_name, operation.name.item, &mut HashSet::new()) {
+            return Err(vec![Diagnostic::error(
+                ValidationMessage::DependentOperationCycle {
+                    operation_name: operation.name.item,
+                },
+                directive.name.location,
+            )]);
+        }
+
+        let mut errors = Vec::new();
+        let mut exported_variables = Vec::new();
+        for variable_definition in &dependent_operation.variable_definitions {
+            let variable_name = variable_definition.name.item;
+            let variable_type = &variable_definition.type_;
+            if let Some(exported_field) = self
+                .exported_fields
+                .iter()
+                .find(|exported_field| exported_field.variable_name == variable_name)
+            {
+                if schema.is_type_subtype_of(&exported_field.type_, variable_type) {
+                    exported_variables.push((variable_name, exported_field.path.clone()));
+                } else {
+                    errors.push(
+                        Diagnostic::error(
+                            ValidationMessage::ExportAsTypeMismatch {
+                                variable_name,
+                                field_type: schema.get_type_string(&exported_field.type_),
+                                variable_type: schema.get_type_string(variable_type),
+                                operation_name,
+                            },
+                            exported_field.location,
+                        )
+                        .annotate("Variable definition", variable_definition.name.location),
+                    );
+                }
+            } else if let Some(parent_variable_definition) =
+                operation.variable_definitions.named(variable_name)
+            {
+                if !schema.is_type_subtype_of(&parent_variable_definition.type_, variable_type) {
+                    errors.push(
+                        Diagnostic::error(
+                            ValidationMessage::DependentOperationVariableTypeMismatch {
+                                variable_name,
+                                parent_type: schema
+                                    .get_type_string(&parent_variable_definition.type_),
+                                variable_type: schema.get_type_string(variable_type),
+                                operation_name,
+                            },
+                            parent_variable_definition.name.location,
+                        )
+                        .annotate("Variable definition", variable_definition.name.location),
+                    );
+                }
+            } else if variable_type.is_non_null() && variable_definition.default_value.is_none() {
+                errors.push(
+                    Diagnostic::error(
+                        ValidationMessage::MissingDependentOperationVariable {
+                            variable_name,
+                            operation_name,
+                        },
+                        directive.name.location,
+                    )
+                    .annotate("Variable definition", variable_definition.name.location),
+                );
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(DependentOperation {
+                operation_name,
+                location: dependent_operation.name.location,
+                exported_variables,
+            })
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Whether `operation_name` declares `target` as a dependent operation,
+    /// directly or through its own dependent operations.
+    fn depends_on(
+        &self,
+        operation_name: OperationDefinitionName,
+        target: OperationDefinitionName,
+        visited: &mut HashSet<OperationDefinitionName>,
+    ) -> bool {
+        if operation_name == target {
+            return true;
+        }
+        if !visited.insert(operation_name) {
+            return false;
+        }
+        let operation = match self.program.operation(operation_name) {
+            Some(operation) => operation,
+            None => return false,
+        };
+        dependent_operation_directives(operation).any(|directive| {
+            self.depends_on(
+                OperationDefinitionName(dependent_operation_name(directive)),
+                target,
+                visited,
+            )
+        })
+    }
+}
+
+impl<'s> Transformer for DependentOperations<'s> {
+    const NAME: &'static str = "DependentOperationsTransform";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn transform_operation(
+        &mut self,
+        operation: &OperationDefinition,
+    ) -> Transformed<OperationDefinition> {
+        self.in_fragment = false;
+        self.current_path = Some(Vec::new());
+        self.exported_fields = Vec::new();
+        let transformed = self.default_transform_operation(operation);
+
+        let mut seen_variables = HashSet::new();
+        for exported_field in &self.exported_fields {
+            if !seen_variables.insert(exported_field.variable_name) {
+                self.errors.push(Diagnostic::error(
+                    ValidationMessage::DuplicateExportAs {
+                        variable_name: exported_field.variable_name,
+                    },
+                    exported_field.location,
+                ));
+            }
+        }
+
+        let mut dependent_operations = Vec::new();
+        for directive in dependent_operation_directives(operation) {
+            match self.build_dependent_operation(operation, directive) {
+                Ok(dependent_operation) => dependent_operations.push(dependent_operation),
+                Err(errors) => self.errors.extend(errors),
+            }
+        }
+        for exported_field in &self.exported_fields {
+            let is_used = dependent_operation_definitions(self.program, operation).any(
+                |dependent_operation| {
+                    dependent_operation
+                        .variable_definitions
+                        .named(exported_field.variable_name)
+                        .is_some()
+                },
+            );
+            if !is_used {
+                self.errors.push(Diagnostic::error(
+                    ValidationMessage::UnusedExportAs {
+                        variable_name: exported_field.variable_name,
+                        operation_name: operation.name.item,
+                    },
+                    exported_field.location,
+                ));
+            }
+        }
+        self.exported_fields.clear();
+
+        if operation
+            .directives
+            .named(*DEPENDENT_OPERATION_DIRECTIVE_NAME)
+            .is_none()
+        {
+            return transformed;
+        }
+        let mut transformed_operation = match transformed {
+            Transformed::Delete => return Transformed::Delete,
+            Transformed::Keep => operation.clone(),
+            Transformed::Replace(replaced) => replaced,
+        };
+        transformed_operation
+            .directives
+            .retain(|directive| directive.name.item != *DEPENDENT_OPERATION_DIRECTIVE_NAME);
+        if !dependent_operations.is_empty() {
+            transformed_operation
+                .directives
+                .push(DependentOperationsMetadata(dependent_operations).into());
+        }
+        Transformed::Replace(transformed_operation)
+    }
+
+    fn transform_fragment(
+        &mut self,
+        fragment: &FragmentDefinition,
+    ) -> Transformed<FragmentDefinition> {
+        self.in_fragment = true;
+        self.current_path = Some(Vec::new());
+        self.default_transform_fragment(fragment)
+    }
+
+    fn transform_scalar_field(&mut self, field: &ScalarField) -> Transformed<Selection> {
+        let directive = match field.directives.named(*EXPORT_AS_DIRECTIVE_NAME) {
+            Some(directive) => directive,
+            None => return Transformed::Keep,
+        };
+        let schema_field = self.program.schema.field(field.definition.item);
+        let location = directive.name.location;
+        match &self.current_path {
+            _ if self.in_fragment => self.errors.push(Diagnostic::error(
+                ValidationMessage::ExportAsInFragment,
+                location,
+            )),
+            Some(path) => {
+                let variable_name = directive
+                    .arguments
+                    .named(*VAR_ARGUMENT)
+                    .and_then(|argument| argument.value.item.get_string_literal())
+                    .unwrap();
+                let mut path = path.clone();
+                path.push(field.alias_or_name(&self.program.schema));
+                self.exported_fields.push(ExportedField {
+                    variable_name: VariableName(variable_name),
+                    path,
+                    type_: schema_field.type_.clone(),
+                    location,
+                });
+            }
+            None => self.errors.push(Diagnostic::error(
+                ValidationMessage::ExportAsInPluralField {
+                    field_name: schema_field.name.item,
+                },
+                location,
+            )),
+        }
+        Transformed::Replace(Selection::ScalarField(Arc::new(ScalarField {
+            directives: field
+                .directives
+                .iter()
+                .filter(|directive| directive.name.item != *EXPORT_AS_DIRECTIVE_NAME)
+                .cloned()
+                .collect(),
+            ..field.clone()
+        })))
+    }
+
+    fn transform_linked_field(&mut self, field: &LinkedField) -> Transformed<Selection> {
+        let schema_field = self.program.schema.field(field.definition.item);
+        if let Some(directive) = field.directives.named(*EXPORT_AS_DIRECTIVE_NAME) {
+            self.errors.push(Diagnostic::error(
+                ValidationMessage::ExportAsOnLinkedField {
+                    field_name: schema_field.name.item,
+                },
+                directive.name.location,
+            ));
+        }
+
+        let current_path_at_field = self.current_path.clone();
+        if schema_field.type_.is_list() {
+            self.current_path = None;
+        } else if let Some(path) = self.current_path.as_mut() {
+            path.push(field.alias_or_name(&self.program.schema));
+        }
+        let result = self.default_transform_linked_field(field);
+        self.current_path = current_path_at_field;
+        result
+    }
+}
+
+fn dependent_operation_directives(
+    operation: &OperationDefinition,
+) -> impl Iterator<Item = &Directive> {
+    operation
+        .directives
+        .iter()
+        .filter(|directive| directive.name.item == *DEPENDENT_OPERATION_DIRECTIVE_NAME)
+}
+
+/// The operations declared as dependent operations of `operation` with
+/// `@dependentOperation`, skipping unknown operations.
+pub(crate) fn dependent_operation_definitions<'a>(
+    program: &'a Program,
+    operation: &'a OperationDefinition,
+) -> impl Iterator<Item = &'a OperationDefinition> + 'a {
+    dependent_operation_directives(operation).filter_map(|directive| {
+        program
+            .operation(OperationDefinitionName(dependent_operation_name(directive)))
+            .map(|dependent_operation| dependent_operation.as_ref())
+    })
+}
+
+fn dependent_operation_name(directive: &Directive) -> StringKey {
+    // The `name` argument is required and `@static`.
+    directive
+        .arguments
+        .named(*NAME_ARGUMENT)
+        .and_then(|argument| argument.value.item.get_string_literal())
+        .unwrap()
+}
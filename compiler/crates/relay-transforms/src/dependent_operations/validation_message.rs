@@ -0,0 +1,84 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use graphql_ir::OperationDefinitionName;
+use graphql_ir::VariableName;
+use intern::string_key::StringKey;
+use thiserror::Error;
+
+#[derive(Error, Debug, serde::Serialize)]
+#[serde(tag = "type")]
+pub(super) enum ValidationMessage {
+    #[error("@exportAs can only be used on fields of operations, not fragments.")]
+    ExportAsInFragment,
+
+    #[error(
+        "@exportAs can only be used on scalar fields, but '{field_name}' is a linked field. Export a scalar field of '{field_name}' instead."
+    )]
+    ExportAsOnLinkedField { field_name: StringKey },
+
+    #[error(
+        "@exportAs cannot be used on field '{field_name}', which is within a plural field. An exported field must have a single value."
+    )]
+    ExportAsInPluralField { field_name: StringKey },
+
+    #[error("The variable '${variable_name}' is exported more than once.")]
+    DuplicateExportAs { variable_name: VariableName },
+
+    #[error(
+        "The variable '${variable_name}' is exported with @exportAs, but operation '{operation_name}' does not declare a dependent operation using it with @dependentOperation."
+    )]
+    UnusedExportAs {
+        variable_name: VariableName,
+        operation_name: OperationDefinitionName,
+    },
+
+    #[error("Unknown dependent operation '{operation_name}'.")]
+    UnknownDependentOperation { operation_name: StringKey },
+
+    #[error(
+        "Expected dependent operation '{operation_name}' to be a query, dependent mutations and subscriptions are not supported."
+    )]
+    DependentOperationNotAQuery {
+        operation_name: OperationDefinitionName,
+    },
+
+    #[error(
+        "Operation '{operation_name}' depends on itself through @dependentOperation, dependent operations cannot form a cycle."
+    )]
+    DependentOperationCycle {
+        operation_name: OperationDefinitionName,
+    },
+
+    #[error(
+        "Field of type '{field_type}' cannot be exported as variable '${variable_name}' of type '{variable_type}' of dependent operation '{operation_name}'."
+    )]
+    ExportAsTypeMismatch {
+        variable_name: VariableName,
+        field_type: String,
+        variable_type: String,
+        operation_name: OperationDefinitionName,
+    },
+
+    #[error(
+        "Variable '${variable_name}' of type '{parent_type}' cannot be passed as variable '${variable_name}' of type '{variable_type}' of dependent operation '{operation_name}'."
+    )]
+    DependentOperationVariableTypeMismatch {
+        variable_name: VariableName,
+        parent_type: String,
+        variable_type: String,
+        operation_name: OperationDefinitionName,
+    },
+
+    #[error(
+        "Dependent operation '{operation_name}' requires the variable '${variable_name}'. Export a field as '{variable_name}' with @exportAs, or define '${variable_name}' on this operation."
+    )]
+    MissingDependentOperationVariable {
+        variable_name: VariableName,
+        operation_name: OperationDefinitionName,
+    },
+}
@@ -82,6 +82,16 @@ pub enum ValidationMessage {
     )]
     RelayResolversDisabled,
 
+    #[error(
+        "The @batch Relay Resolver backing the field `{field_name}` has no root fragment. @batch resolvers are called with the keys of their root fragment."
+    )]
+    RelayResolverBatchWithoutRootFragment { field_name: StringKey },
+
+    #[error(
+        "The @batch Relay Resolver backing the field `{field_name}` returns an object. @batch resolvers may only return scalars."
+    )]
+    RelayResolverBatchWithCompositeType { field_name: StringKey },
+
     #[error(
         "The directive '{directive_name}' automatically adds '{actor_change_field}' to the selection of the field '{field_name}'. But the field '{actor_change_field}' does not exist on the type '{type_name}'. Please makes sure the GraphQL schema supports actor change on '{type_name}'."
     )]
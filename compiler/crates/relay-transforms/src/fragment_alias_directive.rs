@@ -35,6 +35,8 @@ use schema::Schema;
 use schema::Type;
 
 use crate::ValidationMessage;
+use crate::CATCH_DIRECTIVE_NAME;
+use crate::REQUIRED_DIRECTIVE_NAME;
 
 lazy_static! {
     pub static ref FRAGMENT_ALIAS_DIRECTIVE_NAME: DirectiveName = DirectiveName("alias".intern());
@@ -134,8 +136,13 @@ impl<'program> FragmentAliasTransform<'program> {
 
             // In the future we might want to relax this restriction, but for now this allows us
             // to avoid having to consider how @alias would interact
-            // with all other directives like @defer.
-            if directives.len() > 1 {
+            // with all other directives like @defer. @catch and @required are
+            // handled by their own transforms, which run after this one.
+            if directives.iter().any(|other| {
+                other.name.item != directive.name.item
+                    && other.name.item != *CATCH_DIRECTIVE_NAME
+                    && other.name.item != *REQUIRED_DIRECTIVE_NAME
+            }) {
                 self.errors.push(Diagnostic::error(
                     ValidationMessage::FragmentAliasIncompatibleDirective,
                     directive.name.location,
@@ -16,12 +16,14 @@ mod apply_custom_transforms;
 mod apply_fragment_arguments;
 mod apply_transforms;
 mod assignable_fragment_spread;
+mod catch_directive;
 mod client_edges;
 mod client_extensions;
 mod client_extensions_abstract_types;
 mod connections;
 mod declarative_connection;
 mod defer_stream;
+mod dependent_operations;
 mod directive_finder;
 mod errors;
 mod flatten;
@@ -42,6 +44,7 @@ mod match_;
 mod metadata_directive;
 mod murmurhash;
 mod no_inline;
+mod paginated_directive;
 mod preloadable_directive;
 mod provided_variable_fragment_transform;
 mod refetchable_fragment;
@@ -102,6 +105,11 @@ pub use assignable_fragment_spread::ASSIGNABLE_DIRECTIVE;
 pub use assignable_fragment_spread::ASSIGNABLE_DIRECTIVE_FOR_TYPEGEN;
 pub use assignable_fragment_spread::UPDATABLE_DIRECTIVE;
 pub use assignable_fragment_spread::UPDATABLE_DIRECTIVE_FOR_TYPEGEN;
+pub use catch_directive::catch_directive;
+pub use catch_directive::CatchMetadataDirective;
+pub use catch_directive::CatchTo;
+pub use catch_directive::CATCH_DIRECTIVE_NAME;
+pub use catch_directive::WITHIN_CATCH_METADATA_KEY;
 pub use client_edges::client_edges;
 pub use client_edges::remove_client_edge_selections;
 pub use client_edges::ClientEdgeGeneratedQueryMetadataDirective;
@@ -118,9 +126,18 @@ pub use connections::ConnectionConstants;
 pub use connections::ConnectionInterface;
 pub use connections::ConnectionMetadata;
 pub use declarative_connection::transform_declarative_connection;
+pub use defer_stream::auto_defer;
 pub use defer_stream::transform_defer_stream;
+pub use defer_stream::AutoDeferMetadata;
+pub use defer_stream::AutoDeferredSpread;
 pub use defer_stream::DeferDirective;
+pub use defer_stream::FieldCostHints;
 pub use defer_stream::StreamDirective;
+pub use dependent_operations::dependent_operations;
+pub use dependent_operations::DependentOperation;
+pub use dependent_operations::DependentOperationsMetadata;
+pub use dependent_operations::DEPENDENT_OPERATION_DIRECTIVE_NAME;
+pub use dependent_operations::EXPORT_AS_DIRECTIVE_NAME;
 pub use directive_finder::DirectiveFinder;
 pub use flatten::flatten;
 pub use fragment_alias_directive::fragment_alias_directive;
@@ -153,6 +170,11 @@ pub use match_::SplitOperationMetadata;
 pub use match_::DIRECTIVE_SPLIT_OPERATION;
 pub use match_::MATCH_CONSTANTS;
 pub use no_inline::NO_INLINE_DIRECTIVE_NAME;
+pub use paginated_directive::paginated_directive;
+pub use paginated_directive::PaginationKind;
+pub use paginated_directive::PaginationMetadata;
+pub use paginated_directive::PaginationMetadataDirective;
+pub use paginated_directive::PAGINATED_DIRECTIVE_NAME;
 pub use preloadable_directive::is_operation_preloadable;
 pub use preloadable_directive::should_generate_hack_preloader;
 pub use provided_variable_fragment_transform::provided_variable_fragment_transform;
@@ -190,6 +212,7 @@ pub use skip_unreachable_node::skip_unreachable_node_loose;
 pub use skip_unreachable_node::skip_unreachable_node_strict;
 pub use sort_selections::sort_selections;
 pub use test_operation_metadata::generate_test_operation_metadata;
+pub use test_operation_metadata::is_relay_test_operation;
 pub use transform_connections::transform_connections;
 pub use unwrap_custom_directive_selection::unwrap_custom_directive_selection;
 pub use util::extract_variable_name;
@@ -0,0 +1,424 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+mod validation_message;
+
+use std::sync::Arc;
+
+use common::ArgumentName;
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::DirectiveName;
+use common::NamedItem;
+use common::WithLocation;
+use graphql_ir::associated_data_impl;
+use graphql_ir::Argument;
+use graphql_ir::ConstantValue;
+use graphql_ir::Directive;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::LinkedField;
+use graphql_ir::OperationDefinition;
+use graphql_ir::Program;
+use graphql_ir::ScalarField;
+use graphql_ir::Selection;
+use graphql_ir::Transformed;
+use graphql_ir::Transformer;
+use graphql_ir::Value;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use lazy_static::lazy_static;
+use relay_config::PaginationInterface;
+use schema::Field;
+use schema::Schema;
+
+use self::validation_message::ValidationMessage;
+use crate::connections::extract_connection_directive;
+use crate::connections::ConnectionConstants;
+use crate::handle_fields::build_handle_field_directive;
+use crate::handle_fields::HandleFieldDirectiveValues;
+use crate::util::extract_variable_name;
+
+lazy_static! {
+    pub static ref PAGINATED_DIRECTIVE_NAME: DirectiveName = DirectiveName("paginated".intern());
+    static ref KIND_ARGUMENT: ArgumentName = ArgumentName("kind".intern());
+    static ref OFFSET_KIND: StringKey = "OFFSET".intern();
+    static ref PAGE_KIND: StringKey = "PAGE".intern();
+    /// The handle merging the items of the pages of `@paginated` fields.
+    static ref PAGINATED_HANDLER: StringKey = "paginated".intern();
+    static ref CURSOR_HANDLE_ARG: ArgumentName = ArgumentName("cursor".intern());
+    static ref COUNT_HANDLE_ARG: ArgumentName = ArgumentName("count".intern());
+    static ref ITEMS_HANDLE_ARG: ArgumentName = ArgumentName("items".intern());
+    static ref EMPTY_STRING: StringKey = "".intern();
+}
+
+/// How the items of a `@paginated` field are paged through.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum PaginationKind {
+    /// With `offset` and `limit` arguments.
+    Offset,
+    /// With `page` and `pageSize` arguments.
+    Page,
+}
+
+impl From<PaginationKind> for StringKey {
+    fn from(kind: PaginationKind) -> Self {
+        match kind {
+            PaginationKind::Offset => *OFFSET_KIND,
+            PaginationKind::Page => *PAGE_KIND,
+        }
+    }
+}
+
+impl From<StringKey> for PaginationKind {
+    fn from(kind: StringKey) -> Self {
+        match kind {
+            _ if kind == *OFFSET_KIND => Self::Offset,
+            _ if kind == *PAGE_KIND => Self::Page,
+            // Values that don't conform to the GraphQL schema should have been filtered out in IR validation.
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// The cursor (offset or page) and count (limit or page size) arguments of
+/// fields paginated with `kind`.
+pub(crate) fn pagination_arguments(
+    pagination_interface: &PaginationInterface,
+    kind: PaginationKind,
+) -> (ArgumentName, ArgumentName) {
+    match kind {
+        PaginationKind::Offset => (
+            pagination_interface.offset_arg,
+            pagination_interface.limit_arg,
+        ),
+        PaginationKind::Page => (
+            pagination_interface.page_arg,
+            pagination_interface.page_size_arg,
+        ),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PaginationMetadata {
+    pub path: Option<Vec<StringKey>>,
+    pub kind: PaginationKind,
+    /// The variable passed as the offset or page argument.
+    pub cursor: Option<StringKey>,
+    /// The variable passed as the limit or page size argument.
+    pub count: Option<StringKey>,
+    /// The list field containing the items of a page.
+    pub items: StringKey,
+}
+
+/// Attached to fragments with `@paginated` fields, the equivalent of
+/// `ConnectionMetadataDirective` for offset and page based pagination.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PaginationMetadataDirective(pub Vec<PaginationMetadata>);
+associated_data_impl!(PaginationMetadataDirective);
+
+/// Validates `@paginated` fields against the configured `PaginationInterface`,
+/// removes the directive, and attaches a `PaginationMetadataDirective` to the
+/// fragments containing them.
+pub fn paginated_directive(
+    program: &Program,
+    pagination_interface: &PaginationInterface,
+) -> DiagnosticsResult<Program> {
+    let mut transform = PaginatedDirective::new(program, pagination_interface);
+
+    let next_program = transform
+        .transform_program(program)
+        .replace_or_else(|| program.clone());
+
+    if transform.errors.is_empty() {
+        Ok(next_program)
+    } else {
+        Err(transform.errors)
+    }
+}
+
+struct PaginatedDirective<'s> {
+    program: &'s Program,
+    pagination_interface: &'s PaginationInterface,
+    connection_constants: ConnectionConstants,
+    current_path: Option<Vec<StringKey>>,
+    current_pagination_metadata: Vec<PaginationMetadata>,
+    errors: Vec<Diagnostic>,
+}
+
+impl<'s> PaginatedDirective<'s> {
+    fn new(program: &'s Program, pagination_interface: &'s PaginationInterface) -> Self {
+        Self {
+            program,
+            pagination_interface,
+            connection_constants: ConnectionConstants::default(),
+            current_path: None,
+            current_pagination_metadata: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Validates that the field is a valid paginated field:
+    /// - Is not also a connection.
+    /// - Is a non-plural object or interface with an `items` field returning
+    ///   a list of objects.
+    /// - Defines the cursor and count arguments of its kind, and is passed
+    ///   the count argument to prevent unconstrained data access.
+    /// - Has an `items` selection, otherwise there is nothing to paginate.
+    fn validate_paginated_field(
+        &self,
+        field: &LinkedField,
+        schema_field: &Field,
+        kind: PaginationKind,
+    ) -> DiagnosticsResult<()> {
+        let schema = &self.program.schema;
+        let field_name = schema_field.name.item;
+        let location = field.definition.location;
+
+        if extract_connection_directive(&field.directives, self.connection_constants).is_some() {
+            return Err(vec![Diagnostic::error(
+                ValidationMessage::PaginatedWithConnection { field_name },
+                location,
+            )]);
+        }
+
+        let field_type = schema_field.type_.nullable_type();
+        if field_type.is_list() || !field_type.inner().is_object_or_interface() {
+            return Err(vec![Diagnostic::error(
+                ValidationMessage::InvalidPaginatedFieldType {
+                    field_name,
+                    type_string: schema.get_type_string(field_type),
+                },
+                location,
+            )]);
+        }
+
+        let (cursor_arg, count_arg) = pagination_arguments(self.pagination_interface, kind);
+        if schema_field.arguments.named(cursor_arg).is_none()
+            || schema_field.arguments.named(count_arg).is_none()
+        {
+            return Err(vec![Diagnostic::error(
+                ValidationMessage::PaginatedFieldMissingArgumentDefinitions {
+                    field_name,
+                    cursor_arg,
+                    count_arg,
+                },
+                location,
+            )]);
+        }
+        if field.arguments.named(count_arg).is_none() {
+            return Err(vec![Diagnostic::error(
+                ValidationMessage::ExpectedPaginatedFieldToHaveCountArg {
+                    field_name,
+                    count_arg,
+                },
+                location,
+            )]);
+        }
+
+        let items = self.pagination_interface.items;
+        // The items are merged by the paginated handler, which only handles
+        // lists of records.
+        let has_items_list_field =
+            schema
+                .named_field(field_type.inner(), items)
+                .is_some_and(|items_field| {
+                    let items_type = &schema.field(items_field).type_;
+                    items_type.nullable_type().is_list() && items_type.inner().is_composite_type()
+                });
+        if !has_items_list_field {
+            return Err(vec![Diagnostic::error(
+                ValidationMessage::PaginatedFieldTypeWithoutItems {
+                    field_name,
+                    type_name: schema.get_type_name(field_type.inner()),
+                    items,
+                },
+                location,
+            )]);
+        }
+        let has_items_selection = field.selections.iter().any(|selection| match selection {
+            Selection::LinkedField(linked_field) => {
+                schema.field(linked_field.definition.item).name.item == items
+            }
+            Selection::ScalarField(scalar_field) => {
+                schema.field(scalar_field.definition.item).name.item == items
+            }
+            _ => false,
+        });
+        if !has_items_selection {
+            return Err(vec![Diagnostic::error(
+                ValidationMessage::ExpectedPaginatedFieldToHaveItemsSelection { field_name, items },
+                location,
+            )]);
+        }
+
+        Ok(())
+    }
+
+    /// Builds the handle directive of a valid paginated field, which merges
+    /// the items of the fetched pages into a single list in the store, the
+    /// same way connections are merged by the connection handler. Pages
+    /// fetched with different values of the other arguments are kept apart.
+    fn build_paginated_handle_directive(
+        &self,
+        field: &LinkedField,
+        kind: PaginationKind,
+    ) -> Directive {
+        let (cursor_arg, count_arg) = pagination_arguments(self.pagination_interface, kind);
+        let filters = field
+            .arguments
+            .iter()
+            .map(|argument| argument.name.item)
+            .filter(|name| *name != cursor_arg && *name != count_arg)
+            .map(|name| name.0)
+            .collect::<Vec<_>>();
+        let handle_arg = |name: ArgumentName, value: StringKey| Argument {
+            name: WithLocation::generated(name),
+            value: WithLocation::generated(Value::Constant(ConstantValue::String(value))),
+        };
+        build_handle_field_directive(HandleFieldDirectiveValues {
+            handle: *PAGINATED_HANDLER,
+            key: *EMPTY_STRING,
+            filters: if filters.is_empty() {
+                None
+            } else {
+                Some(filters)
+            },
+            dynamic_key: None,
+            handle_args: Some(vec![
+                handle_arg(*KIND_ARGUMENT, kind.into()),
+                handle_arg(*CURSOR_HANDLE_ARG, cursor_arg.0),
+                handle_arg(*COUNT_HANDLE_ARG, count_arg.0),
+                handle_arg(*ITEMS_HANDLE_ARG, self.pagination_interface.items),
+            ]),
+        })
+    }
+
+    fn transform_paginated_field(
+        &mut self,
+        field: &LinkedField,
+        paginated_directive: &Directive,
+    ) -> Transformed<Selection> {
+        let schema_field = self.program.schema.field(field.definition.item);
+        let kind = paginated_directive
+            .arguments
+            .named(*KIND_ARGUMENT)
+            .map_or(PaginationKind::Offset, |argument| {
+                PaginationKind::from(argument.value.item.expect_constant().unwrap_enum())
+            });
+
+        let mut directives: Vec<Directive> = field
+            .directives
+            .iter()
+            .filter(|directive| directive.name.item != *PAGINATED_DIRECTIVE_NAME)
+            .cloned()
+            .collect();
+        match self.validate_paginated_field(field, schema_field, kind) {
+            Ok(()) => {
+                let (cursor_arg, count_arg) = pagination_arguments(self.pagination_interface, kind);
+                self.current_pagination_metadata.push(PaginationMetadata {
+                    path: self.current_path.clone(),
+                    kind,
+                    cursor: extract_variable_name(field.arguments.named(cursor_arg)),
+                    count: extract_variable_name(field.arguments.named(count_arg)),
+                    items: self.pagination_interface.items,
+                });
+                directives.push(self.build_paginated_handle_directive(field, kind));
+            }
+            Err(errors) => self.errors.extend(errors),
+        }
+
+        let selections = self
+            .transform_selections(&field.selections)
+            .replace_or_else(|| field.selections.clone());
+        Transformed::Replace(Selection::LinkedField(Arc::new(LinkedField {
+            directives,
+            selections,
+            ..field.clone()
+        })))
+    }
+}
+
+impl<'s> Transformer for PaginatedDirective<'s> {
+    const NAME: &'static str = "PaginatedDirectiveTransform";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn transform_operation(
+        &mut self,
+        operation: &OperationDefinition,
+    ) -> Transformed<OperationDefinition> {
+        self.current_path = Some(Vec::new());
+        let transformed = self.default_transform_operation(operation);
+        // Pagination metadata is only used to refetch fragments.
+        self.current_pagination_metadata.clear();
+        transformed
+    }
+
+    fn transform_fragment(
+        &mut self,
+        fragment: &FragmentDefinition,
+    ) -> Transformed<FragmentDefinition> {
+        self.current_path = Some(Vec::new());
+        self.current_pagination_metadata = Vec::new();
+
+        let transformed = self.default_transform_fragment(fragment);
+        if self.current_pagination_metadata.is_empty() {
+            return transformed;
+        }
+
+        let mut transformed_fragment = match transformed {
+            Transformed::Delete => return Transformed::Delete,
+            Transformed::Keep => fragment.clone(),
+            Transformed::Replace(replaced) => replaced,
+        };
+        transformed_fragment.directives.push(
+            PaginationMetadataDirective(std::mem::take(&mut self.current_pagination_metadata))
+                .into(),
+        );
+        Transformed::Replace(transformed_fragment)
+    }
+
+    fn transform_scalar_field(&mut self, field: &ScalarField) -> Transformed<Selection> {
+        if field.directives.named(*PAGINATED_DIRECTIVE_NAME).is_some() {
+            let schema = &self.program.schema;
+            let schema_field = schema.field(field.definition.item);
+            self.errors.push(Diagnostic::error(
+                ValidationMessage::InvalidPaginatedFieldType {
+                    field_name: schema_field.name.item,
+                    type_string: schema.get_type_string(&schema_field.type_),
+                },
+                field.definition.location,
+            ));
+        }
+        Transformed::Keep
+    }
+
+    fn transform_linked_field(&mut self, field: &LinkedField) -> Transformed<Selection> {
+        let schema_field = self.program.schema.field(field.definition.item);
+        let current_path_at_field = self.current_path.clone();
+
+        // Keep track of the current path as long as we don't encounter plural field.
+        if schema_field.type_.is_list() {
+            self.current_path = None
+        } else if let Some(path) = self.current_path.as_mut() {
+            path.push(if let Some(alias) = field.alias {
+                alias.item
+            } else {
+                schema_field.name.item
+            })
+        }
+
+        let result =
+            if let Some(paginated_directive) = field.directives.named(*PAGINATED_DIRECTIVE_NAME) {
+                self.transform_paginated_field(field, paginated_directive)
+            } else {
+                self.default_transform_linked_field(field)
+            };
+        self.current_path = current_path_at_field;
+        result
+    }
+}
@@ -0,0 +1,61 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::ArgumentName;
+use intern::string_key::StringKey;
+use thiserror::Error;
+
+#[derive(Error, Debug, serde::Serialize)]
+#[serde(tag = "type")]
+pub(super) enum ValidationMessage {
+    #[error(
+        "@paginated may not be used together with @connection or @stream_connection on field '{field_name}'."
+    )]
+    PaginatedWithConnection { field_name: StringKey },
+
+    #[error(
+        "Expected @paginated field '{field_name}' to return a non-plural object or interface, got '{type_string}'."
+    )]
+    InvalidPaginatedFieldType {
+        field_name: StringKey,
+        type_string: String,
+    },
+
+    #[error(
+        "Expected @paginated field '{field_name}' to define the arguments '{cursor_arg}' and '{count_arg}'."
+    )]
+    PaginatedFieldMissingArgumentDefinitions {
+        field_name: StringKey,
+        cursor_arg: ArgumentName,
+        count_arg: ArgumentName,
+    },
+
+    #[error(
+        "Expected @paginated field '{field_name}' to be passed a '{count_arg}' argument, to prevent accidental, unconstrained data access."
+    )]
+    ExpectedPaginatedFieldToHaveCountArg {
+        field_name: StringKey,
+        count_arg: ArgumentName,
+    },
+
+    #[error(
+        "Expected the type '{type_name}' of @paginated field '{field_name}' to have a field '{items}' returning a list of objects."
+    )]
+    PaginatedFieldTypeWithoutItems {
+        field_name: StringKey,
+        type_name: StringKey,
+        items: StringKey,
+    },
+
+    #[error(
+        "Expected @paginated field '{field_name}' to have a selection of '{items}'. Select the '{items}' field directly on the field."
+    )]
+    ExpectedPaginatedFieldToHaveItemsSelection {
+        field_name: StringKey,
+        items: StringKey,
+    },
+}
@@ -49,6 +49,8 @@ pub use self::refetchable_directive::REFETCHABLE_NAME;
 use self::validation_message::ValidationMessage;
 use crate::connections::extract_connection_metadata_from_directive;
 use crate::connections::ConnectionConstants;
+use crate::paginated_directive::pagination_arguments;
+use crate::paginated_directive::PaginationMetadataDirective;
 use crate::relay_directive::PLURAL_ARG_NAME;
 use crate::relay_directive::RELAY_DIRECTIVE_NAME;
 use crate::root_variables::InferVariablesVisitor;
@@ -180,6 +182,7 @@ impl<'program, 'sc> RefetchableFragment<'program, 'sc> {
             )? {
                 if !self.for_typegen {
                     self.validate_connection_metadata(refetch_root.fragment.as_ref())?;
+                    self.validate_pagination_metadata(refetch_root.fragment.as_ref())?;
                 }
                 return Ok((refetchable_directive, refetch_root));
             }
@@ -321,6 +324,53 @@ impl<'program, 'sc> RefetchableFragment<'program, 'sc> {
         }
         Ok(())
     }
+
+    /// Validate that any @paginated usage is valid for refetching:
+    /// - Variables are used for the cursor and count arguments
+    /// - Exactly one paginated field, and no connection
+    /// - Has a stable path to the paginated field
+    ///
+    /// Pagination metadata is extracted in `paginated_directive`
+    fn validate_pagination_metadata(&self, fragment: &FragmentDefinition) -> DiagnosticsResult<()> {
+        if let Some(PaginationMetadataDirective(metadatas)) =
+            PaginationMetadataDirective::find(&fragment.directives)
+        {
+            if metadatas.len() > 1
+                || extract_connection_metadata_from_directive(&fragment.directives).is_some()
+            {
+                return Err(vec![Diagnostic::error(
+                    ValidationMessage::RefetchableWithMultiplePaginatedFields {
+                        fragment_name: fragment.name.item,
+                    },
+                    fragment.name.location,
+                )]);
+            } else if let [metadata] = metadatas.as_slice() {
+                if metadata.path.is_none() {
+                    return Err(vec![Diagnostic::error(
+                        ValidationMessage::RefetchableWithPaginatedFieldInPlural {
+                            fragment_name: fragment.name.item,
+                        },
+                        fragment.name.location,
+                    )]);
+                }
+                if metadata.cursor.is_none() || metadata.count.is_none() {
+                    let (cursor_arg, count_arg) = pagination_arguments(
+                        &self.schema_config.pagination_interface,
+                        metadata.kind,
+                    );
+                    return Err(vec![Diagnostic::error(
+                        ValidationMessage::RefetchableWithConstPaginationArguments {
+                            fragment_name: fragment.name.item,
+                            cursor_arg,
+                            count_arg,
+                        },
+                        fragment.name.location,
+                    )]);
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 type BuildRefetchOperationFn = fn(
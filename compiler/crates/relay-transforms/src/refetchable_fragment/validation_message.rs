@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use common::ArgumentName;
 use graphql_ir::FragmentDefinitionName;
 use graphql_ir::OperationDefinitionName;
 use graphql_ir::VariableName;
@@ -65,6 +66,29 @@ pub(super) enum ValidationMessage {
         arguments: &'static str,
     },
 
+    #[error(
+        "Invalid use of @refetchable with @paginated in fragment '{fragment_name}', at most one @connection or @paginated field can appear in a refetchable fragment."
+    )]
+    RefetchableWithMultiplePaginatedFields {
+        fragment_name: FragmentDefinitionName,
+    },
+
+    #[error(
+        "Invalid use of @refetchable with @paginated in fragment '{fragment_name}', refetchable paginated fields cannot appear inside plural fields."
+    )]
+    RefetchableWithPaginatedFieldInPlural {
+        fragment_name: FragmentDefinitionName,
+    },
+
+    #[error(
+        "Invalid use of @refetchable with @paginated in fragment '{fragment_name}', refetchable paginated fields must use variables for the {cursor_arg} and {count_arg} arguments."
+    )]
+    RefetchableWithConstPaginationArguments {
+        fragment_name: FragmentDefinitionName,
+        cursor_arg: ArgumentName,
+        count_arg: ArgumentName,
+    },
+
     #[error(
         r#"When provided, the `directives` argument to `@refetchable` needs to be a list of literal strings. Each string should be a server directive valid on queries. Example: `@refetchable(queryName: "ExampleQuery", directives: ["@owner(name: \"an owner\")"])"#
     )]
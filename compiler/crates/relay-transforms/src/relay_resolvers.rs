@@ -13,6 +13,7 @@ use common::DiagnosticsResult;
 use common::Location;
 use common::NamedItem;
 use common::WithLocation;
+use docblock_shared::BATCH_ARGUMENT_NAME;
 use docblock_shared::FRAGMENT_KEY_ARGUMENT_NAME;
 use docblock_shared::GENERATED_FRAGMENT_ARGUMENT_NAME;
 use docblock_shared::HAS_OUTPUT_TYPE_ARGUMENT_NAME;
@@ -58,6 +59,7 @@ use crate::RequiredMetadataDirective;
 use crate::CHILDREN_CAN_BUBBLE_METADATA_KEY;
 use crate::CLIENT_EDGE_WATERFALL_DIRECTIVE_NAME;
 use crate::REQUIRED_DIRECTIVE_NAME;
+use crate::WITHIN_CATCH_METADATA_KEY;
 
 /// Transform Relay Resolver fields. This is done in two passes.
 ///
@@ -121,6 +123,7 @@ struct RelayResolverFieldMetadata {
     fragment_data_injection_mode: Option<FragmentDataInjectionMode>,
     field_path: StringKey,
     live: bool,
+    batch: bool,
     output_type_info: ResolverOutputTypeInfo,
 }
 associated_data_impl!(RelayResolverFieldMetadata);
@@ -134,6 +137,8 @@ pub struct RelayResolverMetadata {
     pub field_path: StringKey,
     pub field_arguments: Vec<Argument>,
     pub live: bool,
+    /// The resolver is called once for all the records of a plural list.
+    pub batch: bool,
     pub output_type_info: ResolverOutputTypeInfo,
     /// A tuple with fragment name and field name we need read
     /// of that fragment to pass it to the resolver function.
@@ -239,6 +244,7 @@ impl<'program> RelayResolverSpreadTransform<'program> {
                 field_id: field.definition().item,
                 field_arguments,
                 live: field_metadata.live,
+                batch: field_metadata.batch,
                 output_type_info: field_metadata.output_type_info.clone(),
                 fragment_data_injection_mode: field_metadata
                     .fragment_data_injection_mode
@@ -400,6 +406,7 @@ impl<'program> RelayResolverFieldTransform<'program> {
                     import_path,
                     import_name,
                     live,
+                    batch,
                     has_output_type,
                     fragment_data_injection_mode,
                 }) => {
@@ -410,6 +417,7 @@ impl<'program> RelayResolverFieldTransform<'program> {
                                 && directive.name.item != *REQUIRED_DIRECTIVE_NAME
                                 && directive.name.item != *CHILDREN_CAN_BUBBLE_METADATA_KEY
                                 && directive.name.item != *CLIENT_EDGE_WATERFALL_DIRECTIVE_NAME
+                                && directive.name.item != *WITHIN_CATCH_METADATA_KEY
                         });
                     if let Some(directive) = non_required_directives.next() {
                         self.errors.push(Diagnostic::error(
@@ -490,6 +498,30 @@ impl<'program> RelayResolverFieldTransform<'program> {
                         ResolverOutputTypeInfo::Legacy
                     };
 
+                    if batch {
+                        if fragment_name.is_none() {
+                            self.errors.push(Diagnostic::error(
+                                ValidationMessage::RelayResolverBatchWithoutRootFragment {
+                                    field_name: schema_field.name.item,
+                                },
+                                schema_field.name.location,
+                            ));
+                            return None;
+                        }
+                        if matches!(
+                            output_type_info,
+                            ResolverOutputTypeInfo::Composite(_) | ResolverOutputTypeInfo::EdgeTo
+                        ) {
+                            self.errors.push(Diagnostic::error(
+                                ValidationMessage::RelayResolverBatchWithCompositeType {
+                                    field_name: schema_field.name.item,
+                                },
+                                schema_field.name.location,
+                            ));
+                            return None;
+                        }
+                    }
+
                     let resolver_field_metadata = RelayResolverFieldMetadata {
                         import_path,
                         import_name,
@@ -497,6 +529,7 @@ impl<'program> RelayResolverFieldTransform<'program> {
                         fragment_name,
                         field_path: self.path.join(".").intern(),
                         live,
+                        batch,
                         output_type_info,
                         fragment_data_injection_mode,
                     };
@@ -613,7 +646,8 @@ pub struct ResolverInfo {
     fragment_data_injection_mode: Option<FragmentDataInjectionMode>,
     pub import_path: StringKey,
     pub import_name: Option<StringKey>,
-    live: bool,
+    pub live: bool,
+    pub batch: bool,
     has_output_type: bool,
 }
 
@@ -637,6 +671,7 @@ pub fn get_resolver_info(
             let import_path =
                 get_argument_value(arguments, *IMPORT_PATH_ARGUMENT_NAME, error_location)?;
             let live = get_bool_argument_is_true(arguments, *LIVE_ARGUMENT_NAME);
+            let batch = get_bool_argument_is_true(arguments, *BATCH_ARGUMENT_NAME);
             let has_output_type =
                 get_bool_argument_is_true(arguments, *HAS_OUTPUT_TYPE_ARGUMENT_NAME);
             let import_name =
@@ -653,6 +688,7 @@ pub fn get_resolver_info(
                 import_path,
                 import_name,
                 live,
+                batch,
                 has_output_type,
                 fragment_data_injection_mode: inject_fragment_data.map(|field_name| {
                     let injected_field_id = schema
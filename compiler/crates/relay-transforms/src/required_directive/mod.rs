@@ -24,6 +24,7 @@ use graphql_ir::Directive;
 use graphql_ir::Field;
 use graphql_ir::FragmentDefinition;
 use graphql_ir::FragmentDefinitionNameMap;
+use graphql_ir::FragmentSpread;
 use graphql_ir::InlineFragment;
 use graphql_ir::LinkedField;
 use graphql_ir::OperationDefinition;
@@ -147,6 +148,15 @@ impl<'program> RequiredDirective<'program> {
         }
     }
 
+    fn assert_aliased_if_required(&mut self, directives: &[Directive]) {
+        if let Some(directive) = directives.named(*REQUIRED_DIRECTIVE_NAME) {
+            self.errors.push(Diagnostic::error(
+                ValidationMessage::RequiredOnFragmentWithoutAlias,
+                directive.name.location,
+            ))
+        }
+    }
+
     fn assert_compatible_nullability(&mut self, path: StringKey, current: MaybeRequiredField) {
         if let Some(previous) = self.path_required_map.get(&path) {
             if let Some(previous_metadata) = &previous.required {
@@ -452,27 +462,89 @@ impl<'s> Transformer for RequiredDirective<'s> {
         }
     }
 
+    fn transform_fragment_spread(&mut self, spread: &FragmentSpread) -> Transformed<Selection> {
+        let alias = match FragmentAliasMetadata::find(&spread.directives) {
+            Some(metadata) => metadata.alias.item,
+            None => {
+                self.assert_aliased_if_required(&spread.directives);
+                return Transformed::Keep;
+            }
+        };
+        self.path.push(alias.lookup());
+        let path_name = self.path.join(".").intern();
+        self.path.pop();
+
+        match self.get_required_metadata(spread, path_name) {
+            None => Transformed::Keep,
+            Some(required_metadata) => {
+                Transformed::Replace(Selection::FragmentSpread(Arc::new(FragmentSpread {
+                    directives: add_metadata_directive(
+                        &spread.directives,
+                        path_name,
+                        required_metadata.action,
+                    ),
+                    ..spread.clone()
+                })))
+            }
+        }
+    }
+
     fn transform_inline_fragment(&mut self, fragment: &InlineFragment) -> Transformed<Selection> {
         let previous = self.within_abstract_inline_fragment;
 
-        let maybe_alias =
-            FragmentAliasMetadata::find(&fragment.directives).map(|metadata| metadata.alias.item);
-
-        if let Some(alias) = maybe_alias {
-            self.path.push(alias.lookup())
-        } else if let Some(type_) = fragment.type_condition {
-            if type_.is_abstract_type() {
-                self.within_abstract_inline_fragment = true;
+        let alias = match FragmentAliasMetadata::find(&fragment.directives) {
+            Some(metadata) => metadata.alias.item,
+            None => {
+                self.assert_aliased_if_required(&fragment.directives);
+                if let Some(type_) = fragment.type_condition {
+                    if type_.is_abstract_type() {
+                        self.within_abstract_inline_fragment = true;
+                    }
+                }
+                let next_fragment = self.default_transform_inline_fragment(fragment);
+                self.within_abstract_inline_fragment = previous;
+                return next_fragment;
             }
-        }
+        };
 
-        let next_fragment = self.default_transform_inline_fragment(fragment);
+        self.path.push(alias.lookup());
+        let path_name = self.path.join(".").intern();
 
-        if maybe_alias.is_some() {
-            self.path.pop();
-        }
+        let next_fragment = match self.get_required_metadata(fragment, path_name) {
+            None => self.default_transform_inline_fragment(fragment),
+            Some(required_metadata) => {
+                // A @required aliased inline fragment is read as null if its
+                // type condition does not match or if a @required child
+                // bubbles up to it, so, like a linked field, it is the parent
+                // of its @required children.
+                let parent_node_required_children =
+                    mem::take(&mut self.current_node_required_children);
+
+                let selections = self.transform_selections(&fragment.selections);
+
+                self.assert_compatible_required_children(fragment, path_name);
+                self.assert_compatible_required_children_severity(required_metadata);
+
+                let required_children = mem::replace(
+                    &mut self.current_node_required_children,
+                    parent_node_required_children,
+                );
+                self.required_children_map
+                    .insert(path_name, required_children);
 
-        self.within_abstract_inline_fragment = previous;
+                Transformed::Replace(Selection::InlineFragment(Arc::new(InlineFragment {
+                    directives: add_metadata_directive(
+                        &fragment.directives,
+                        path_name,
+                        required_metadata.action,
+                    ),
+                    selections: selections.replace_or_else(|| fragment.selections.clone()),
+                    ..fragment.clone()
+                })))
+            }
+        };
+
+        self.path.pop();
         next_fragment
     }
 }
@@ -558,7 +630,10 @@ impl<'s> DirectiveFinder for RequiredDirectiveVisitor<'s> {
         directive.name.item == *REQUIRED_DIRECTIVE_NAME
     }
 
-    fn visit_fragment_spread(&mut self, fragment_spread: &graphql_ir::FragmentSpread) -> bool {
+    fn visit_fragment_spread(&mut self, fragment_spread: &FragmentSpread) -> bool {
+        if self.visit_directives(&fragment_spread.directives) {
+            return true;
+        }
         let fragment = self
             .program
             .fragment(fragment_spread.fragment.item)
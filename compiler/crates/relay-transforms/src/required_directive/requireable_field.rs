@@ -11,14 +11,19 @@ use common::NamedItem;
 use common::WithLocation;
 use graphql_ir::Directive;
 use graphql_ir::Field;
+use graphql_ir::FragmentSpread;
+use graphql_ir::InlineFragment;
 use graphql_ir::LinkedField;
 use graphql_ir::ScalarField;
+use intern::string_key::Intern;
 use intern::string_key::StringKey;
 use schema::SDLSchema;
+use schema::Schema;
 
 use super::validation_message::ValidationMessage;
 use super::ACTION_ARGUMENT;
 use super::REQUIRED_DIRECTIVE_NAME;
+use crate::FragmentAliasMetadata;
 use crate::RequiredAction;
 
 #[derive(Clone, Copy)]
@@ -63,6 +68,38 @@ impl RequireableField for LinkedField {
     }
 }
 
+// Fragment spreads and inline fragments can only be @required when aliased,
+// since the alias is the value which is null if the fragment can't be read.
+impl RequireableField for FragmentSpread {
+    fn directives(&self) -> &Vec<Directive> {
+        &self.directives
+    }
+    fn name_with_location(&self, _schema: &SDLSchema) -> WithLocation<StringKey> {
+        FragmentAliasMetadata::find(&self.directives).map_or_else(
+            || self.fragment.map(|name| name.0),
+            |metadata| metadata.alias,
+        )
+    }
+}
+
+impl RequireableField for InlineFragment {
+    fn directives(&self) -> &Vec<Directive> {
+        &self.directives
+    }
+    fn name_with_location(&self, schema: &SDLSchema) -> WithLocation<StringKey> {
+        FragmentAliasMetadata::find(&self.directives).map_or_else(
+            || {
+                WithLocation::new(
+                    self.spread_location,
+                    self.type_condition
+                        .map_or_else(|| "...".intern(), |type_| schema.get_type_name(type_)),
+                )
+            },
+            |metadata| metadata.alias,
+        )
+    }
+}
+
 fn get_action_argument(
     required_directive: &Directive,
 ) -> Result<WithLocation<RequiredAction>, Diagnostic> {
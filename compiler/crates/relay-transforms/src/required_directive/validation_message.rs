@@ -16,6 +16,11 @@ pub(super) enum ValidationMessage {
     )]
     RequiredWithinAbstractInlineFragment,
 
+    #[error(
+        "@required on a fragment spread or inline fragment requires `@alias`. The alias is the value which is null when the fragment's data cannot be read."
+    )]
+    RequiredOnFragmentWithoutAlias,
+
     #[error("@required is not supported within @inline fragments.")]
     RequiredWithinInlineDirective,
 
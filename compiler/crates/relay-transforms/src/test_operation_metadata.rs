@@ -35,6 +35,7 @@ use schema::Type;
 use crate::create_metadata_directive;
 use crate::ValidationMessage;
 use crate::DIRECTIVE_SPLIT_OPERATION;
+use crate::INTERNAL_METADATA_DIRECTIVE;
 
 lazy_static! {
     static ref TEST_OPERATION_DIRECTIVE: DirectiveName =
@@ -67,6 +68,17 @@ pub fn generate_test_operation_metadata(
     }
 }
 
+/// Whether the operation has the metadata of @relay_test_operation.
+pub fn is_relay_test_operation(operation: &OperationDefinition) -> bool {
+    operation.directives.iter().any(|directive| {
+        directive.name.item == *INTERNAL_METADATA_DIRECTIVE
+            && directive
+                .arguments
+                .named(*TEST_OPERATION_METADATA_KEY)
+                .is_some()
+    })
+}
+
 struct GenerateTestOperationMetadata<'a> {
     program: &'a Program,
     test_path_regex: &'a Option<Regex>,
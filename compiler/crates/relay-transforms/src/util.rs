@@ -23,18 +23,25 @@ use schema::SDLSchema;
 use schema::Schema;
 use schema::Type;
 
+use crate::catch_directive::CATCH_DIRECTIVE_NAME;
+use crate::catch_directive::WITHIN_CATCH_METADATA_KEY;
 use crate::client_extensions::CLIENT_EXTENSION_DIRECTIVE_NAME;
 use crate::connections::ConnectionMetadataDirective;
 use crate::handle_fields::HANDLE_FIELD_DIRECTIVE_NAME;
 use crate::inline_data_fragment::InlineDirectiveMetadata;
+use crate::paginated_directive::PAGINATED_DIRECTIVE_NAME;
 use crate::refetchable_fragment::RefetchableMetadata;
 use crate::relay_actor_change::RELAY_ACTOR_CHANGE_DIRECTIVE_FOR_CODEGEN;
 use crate::required_directive::CHILDREN_CAN_BUBBLE_METADATA_KEY;
 use crate::required_directive::REQUIRED_DIRECTIVE_NAME;
+use crate::AutoDeferMetadata;
+use crate::CatchMetadataDirective;
 use crate::ClientEdgeGeneratedQueryMetadataDirective;
 use crate::ClientEdgeMetadataDirective;
+use crate::DependentOperationsMetadata;
 use crate::FragmentAliasMetadata;
 use crate::ModuleMetadata;
+use crate::PaginationMetadataDirective;
 use crate::RefetchableDerivedFromMetadata;
 use crate::RelayResolverMetadata;
 use crate::RequiredMetadataDirective;
@@ -84,7 +91,7 @@ pub fn extract_variable_name(argument: Option<&Argument>) -> Option<StringKey> {
 }
 
 lazy_static! {
-    static ref CUSTOM_METADATA_DIRECTIVES: [DirectiveName; 19] = [
+    static ref CUSTOM_METADATA_DIRECTIVES: [DirectiveName; 26] = [
         *CLIENT_EXTENSION_DIRECTIVE_NAME,
         ConnectionMetadataDirective::directive_name(),
         *HANDLE_FIELD_DIRECTIVE_NAME,
@@ -104,8 +111,15 @@ lazy_static! {
         *RELAY_ACTOR_CHANGE_DIRECTIVE_FOR_CODEGEN,
         ProvidedVariableMetadata::directive_name(),
         FragmentAliasMetadata::directive_name(),
+        *CATCH_DIRECTIVE_NAME,
+        CatchMetadataDirective::directive_name(),
+        *WITHIN_CATCH_METADATA_KEY,
+        *PAGINATED_DIRECTIVE_NAME,
+        PaginationMetadataDirective::directive_name(),
+        DependentOperationsMetadata::directive_name(),
+        AutoDeferMetadata::directive_name(),
     ];
-    static ref DIRECTIVES_SKIPPED_IN_NODE_IDENTIFIER: [DirectiveName; 8] = [
+    static ref DIRECTIVES_SKIPPED_IN_NODE_IDENTIFIER: [DirectiveName; 10] = [
         *CLIENT_EXTENSION_DIRECTIVE_NAME,
         ConnectionMetadataDirective::directive_name(),
         *HANDLE_FIELD_DIRECTIVE_NAME,
@@ -114,6 +128,8 @@ lazy_static! {
         *INTERNAL_METADATA_DIRECTIVE,
         *ARGUMENT_DEFINITION,
         *REQUIRED_DIRECTIVE_NAME,
+        *CATCH_DIRECTIVE_NAME,
+        *PAGINATED_DIRECTIVE_NAME,
     ];
     static ref RELAY_CUSTOM_INLINE_FRAGMENT_DIRECTIVES: [DirectiveName; 8] = [
         *CLIENT_EXTENSION_DIRECTIVE_NAME,
@@ -7,30 +7,51 @@
 
 use std::sync::Arc;
 
+use common::ArgumentName;
 use common::Diagnostic;
 use common::DiagnosticTag;
 use common::DiagnosticsResult;
+use common::DirectiveName;
+use common::Location;
+use common::NamedItem;
 use common::WithLocation;
+use fnv::FnvHashSet;
 use graphql_ir::Argument;
 use graphql_ir::Directive;
 use graphql_ir::ExecutableDefinition;
+use graphql_ir::FragmentDefinition;
 use graphql_ir::LinkedField;
+use graphql_ir::OperationDefinition;
 use graphql_ir::Program;
 use graphql_ir::ScalarField;
 use graphql_ir::ValidationMessage;
 use graphql_ir::Validator;
 use graphql_ir::Value;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use intern::Lookup;
+use lazy_static::lazy_static;
+use regex::Regex;
+use relay_config::DeprecationConfig;
+use relay_config::DeprecationPolicy;
+use schema::DirectiveValue;
 use schema::FieldID;
 use schema::SDLSchema;
 use schema::Schema;
 
+lazy_static! {
+    static ref DEPRECATED: DirectiveName = DirectiveName("deprecated".intern());
+    static ref DATE: ArgumentName = ArgumentName("date".intern());
+    static ref DATE_REGEX: Regex = Regex::new(r"\b(\d{4}-\d{2}-\d{2})\b").unwrap();
+}
+
 pub fn deprecated_fields(
     schema: &Arc<SDLSchema>,
     program: &Program,
 ) -> DiagnosticsResult<Vec<Diagnostic>> {
     let mut validator = DeprecatedFields::new(schema);
     validator.validate_program(program)?;
-    Ok(validator.warnings)
+    Ok(validator.into_hints())
 }
 
 pub fn deprecated_fields_for_executable_definition(
@@ -43,47 +64,196 @@ pub fn deprecated_fields_for_executable_definition(
         ExecutableDefinition::Fragment(fragment) => validator.validate_fragment(fragment),
         ExecutableDefinition::Operation(operation) => validator.validate_operation(operation),
     }?;
-    Ok(validator.warnings)
+    Ok(validator.into_hints())
+}
+
+/// Returns all usages of deprecated fields, field arguments and directive
+/// arguments in the program.
+pub fn deprecated_usages(program: &Program) -> DiagnosticsResult<Vec<DeprecatedUsage>> {
+    let mut validator = DeprecatedFields::new(&program.schema);
+    validator.validate_program(program)?;
+    Ok(validator.usages)
+}
+
+/// Reports the usages of deprecated fields and arguments according to the
+/// policy of the project. Usages listed in the allowlist are not reported.
+/// `today` is the current date, formatted as `YYYY-MM-DD`.
+pub fn validate_deprecated_fields(
+    program: &Program,
+    deprecation_config: &DeprecationConfig,
+    allowlist: &DeprecatedUsageAllowlist,
+    today: &str,
+) -> DiagnosticsResult<()> {
+    let diagnostics: Vec<_> = deprecated_usages(program)?
+        .into_iter()
+        .filter(|usage| !allowlist.contains(usage))
+        .map(|usage| {
+            let is_error = match deprecation_config.policy {
+                DeprecationPolicy::Warn => false,
+                DeprecationPolicy::Error => true,
+                DeprecationPolicy::ErrorAfterDate => usage
+                    .removal_date
+                    .as_ref()
+                    .map_or(false, |removal_date| removal_date.as_str() <= today),
+            };
+            if is_error {
+                Diagnostic::error(usage.message, usage.location)
+            } else {
+                Diagnostic::warning(
+                    usage.message,
+                    usage.location,
+                    vec![DiagnosticTag::DEPRECATED],
+                )
+            }
+        })
+        .collect();
+    if diagnostics.is_empty() {
+        Ok(())
+    } else {
+        Err(diagnostics)
+    }
+}
+
+/// A usage of a deprecated field, field argument or directive argument.
+#[derive(Clone, Debug)]
+pub struct DeprecatedUsage {
+    /// The schema coordinate of the deprecated field or argument, e.g.
+    /// `User.name`, `User.friends(first:)` or `@defer(label:)`.
+    pub coordinate: String,
+    /// The operation or fragment containing the usage.
+    pub definition: StringKey,
+    pub reason: Option<StringKey>,
+    /// The date after which the deprecated field or argument may be removed,
+    /// formatted as `YYYY-MM-DD`.
+    pub removal_date: Option<String>,
+    pub location: Location,
+    message: ValidationMessage,
+}
+
+/// Usages of deprecated fields and arguments that are grandfathered in. See
+/// `DeprecationConfig::allowlist` for the format.
+#[derive(Default)]
+pub struct DeprecatedUsageAllowlist {
+    entries: FnvHashSet<(String, StringKey)>,
+}
+
+impl DeprecatedUsageAllowlist {
+    pub fn extend_from_str(&mut self, text: &str) {
+        self.entries.extend(text.lines().filter_map(|line| {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                return None;
+            }
+            let mut parts = line.split_whitespace();
+            match (parts.next(), parts.next()) {
+                (Some(coordinate), Some(definition)) => {
+                    Some((coordinate.to_string(), definition.intern()))
+                }
+                _ => None,
+            }
+        }));
+    }
+
+    fn contains(&self, usage: &DeprecatedUsage) -> bool {
+        self.entries
+            .contains(&(usage.coordinate.clone(), usage.definition))
+    }
+}
+
+/// The removal date of a deprecation: the `date` argument of `@deprecated`,
+/// or the first `YYYY-MM-DD` date in the reason.
+fn removal_date(directives: &[DirectiveValue], reason: Option<StringKey>) -> Option<String> {
+    directives
+        .named(*DEPRECATED)
+        .and_then(|directive| directive.arguments.named(*DATE))
+        .and_then(|date| date.get_string_literal())
+        .map(|date| date.to_string())
+        .or_else(|| {
+            reason.and_then(|reason| {
+                DATE_REGEX
+                    .captures(reason.lookup())
+                    .map(|captures| captures[1].to_string())
+            })
+        })
 }
 
 struct DeprecatedFields<'a> {
     schema: &'a Arc<SDLSchema>,
-    warnings: Vec<Diagnostic>,
+    usages: Vec<DeprecatedUsage>,
+    current_definition: Option<StringKey>,
 }
 
 impl<'a> DeprecatedFields<'a> {
     fn new(schema: &'a Arc<SDLSchema>) -> Self {
         Self {
             schema,
-            warnings: vec![],
+            usages: vec![],
+            current_definition: None,
         }
     }
 
+    fn into_hints(self) -> Vec<Diagnostic> {
+        self.usages
+            .into_iter()
+            .map(|usage| {
+                Diagnostic::hint(
+                    usage.message,
+                    usage.location,
+                    vec![DiagnosticTag::DEPRECATED],
+                )
+            })
+            .collect()
+    }
+
+    fn add_usage(
+        &mut self,
+        coordinate: String,
+        reason: Option<StringKey>,
+        directives: &[DirectiveValue],
+        message: ValidationMessage,
+        location: Location,
+    ) {
+        self.usages.push(DeprecatedUsage {
+            coordinate,
+            definition: self
+                .current_definition
+                .expect("Expected usages to be inside a definition"),
+            reason,
+            removal_date: removal_date(directives, reason),
+            location,
+            message,
+        });
+    }
+
     fn validate_field(&mut self, field_id: &WithLocation<FieldID>, arguments: &[Argument]) {
-        let schema = &self.schema;
+        let schema = self.schema;
         let field_definition = schema.field(field_id.item);
+        let parent_type = field_definition.parent_type.unwrap();
+        let parent_name = schema.get_type_name(parent_type);
         if let Some(deprecation) = field_definition.deprecated() {
-            let parent_type = field_definition.parent_type.unwrap();
-            let parent_name = schema.get_type_name(parent_type);
-
-            self.warnings.push(Diagnostic::hint(
+            self.add_usage(
+                format!("{}.{}", parent_name, field_definition.name.item),
+                deprecation.reason,
+                &field_definition.directives,
                 ValidationMessage::DeprecatedField {
                     field_name: field_definition.name.item,
                     parent_name,
                     deprecation_reason: deprecation.reason,
                 },
                 field_id.location,
-                vec![DiagnosticTag::DEPRECATED],
-            ));
+            );
         }
 
         for arg in arguments {
             if let Some(arg_definition) = field_definition.arguments.named(arg.name.item) {
                 if let Some(directive) = arg_definition.deprecated() {
-                    let parent_type = field_definition.parent_type.unwrap();
-                    let parent_name = schema.get_type_name(parent_type);
-
-                    self.warnings.push(Diagnostic::hint(
+                    self.add_usage(
+                        format!(
+                            "{}.{}({}:)",
+                            parent_name, field_definition.name.item, arg.name.item
+                        ),
+                        directive.reason,
+                        &arg_definition.directives,
                         ValidationMessage::DeprecatedFieldArgument {
                             argument_name: arg.name.item,
                             field_name: field_definition.name.item,
@@ -91,8 +261,7 @@ impl<'a> DeprecatedFields<'a> {
                             deprecation_reason: directive.reason,
                         },
                         arg.name.location,
-                        vec![DiagnosticTag::DEPRECATED],
-                    ));
+                    );
                 }
             }
         }
@@ -101,12 +270,22 @@ impl<'a> DeprecatedFields<'a> {
 
 // While the individual methods return a diagnostic, since using deprecated fields are not errors per-se, we reserve
 // returning an `Err` for cases where we are unable to correctly check.
-// Deprecation warnings are collected in `self.warnings`.
+// Deprecated usages are collected in `self.usages`.
 impl<'a> Validator for DeprecatedFields<'a> {
     const NAME: &'static str = "DeprecatedFields";
     const VALIDATE_ARGUMENTS: bool = false;
     const VALIDATE_DIRECTIVES: bool = true;
 
+    fn validate_operation(&mut self, operation: &OperationDefinition) -> DiagnosticsResult<()> {
+        self.current_definition = Some(operation.name.item.0);
+        self.default_validate_operation(operation)
+    }
+
+    fn validate_fragment(&mut self, fragment: &FragmentDefinition) -> DiagnosticsResult<()> {
+        self.current_definition = Some(fragment.name.item.0);
+        self.default_validate_fragment(fragment)
+    }
+
     fn validate_linked_field(&mut self, field: &LinkedField) -> DiagnosticsResult<()> {
         self.validate_field(&field.definition, &field.arguments);
         self.default_validate_linked_field(field)
@@ -131,15 +310,17 @@ impl<'a> Validator for DeprecatedFields<'a> {
             for arg in &directive.arguments {
                 if let Some(arg_definition) = directive_definition.arguments.named(arg.name.item) {
                     if let Some(deprecation) = arg_definition.deprecated() {
-                        self.warnings.push(Diagnostic::hint(
+                        self.add_usage(
+                            format!("@{}({}:)", directive.name.item, arg.name.item),
+                            deprecation.reason,
+                            &arg_definition.directives,
                             ValidationMessage::DeprecatedDirectiveArgument {
                                 argument_name: arg.name.item,
                                 directive_name: directive.name.item,
                                 deprecation_reason: deprecation.reason,
                             },
                             arg.name.location,
-                            vec![DiagnosticTag::DEPRECATED],
-                        ));
+                        );
                     }
                 }
             }
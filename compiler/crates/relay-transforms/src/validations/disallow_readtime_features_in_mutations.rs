@@ -11,9 +11,11 @@ use common::FeatureFlag;
 use common::NamedItem;
 use docblock_shared::RELAY_RESOLVER_DIRECTIVE_NAME;
 use graphql_ir::ConstantValue;
+use graphql_ir::Directive;
 use graphql_ir::Field;
 use graphql_ir::FragmentDefinition;
 use graphql_ir::FragmentSpread;
+use graphql_ir::InlineFragment;
 use graphql_ir::LinkedField;
 use graphql_ir::OperationDefinition;
 use graphql_ir::Program;
@@ -70,9 +72,9 @@ impl<'program> DisallowReadtimeFeaturesInMutations<'program> {
         }
     }
 
-    fn validate_field(&self, field: &impl Field) -> DiagnosticsResult<()> {
+    fn validate_required(&self, directives: &[Directive]) -> DiagnosticsResult<()> {
         if !self.allow_required_for_this_mutation {
-            if let Some(directive) = field.directives().named(*REQUIRED_DIRECTIVE_NAME) {
+            if let Some(directive) = directives.named(*REQUIRED_DIRECTIVE_NAME) {
                 let action = directive
                     .arguments
                     .named(*ACTION_ARGUMENT)
@@ -87,6 +89,11 @@ impl<'program> DisallowReadtimeFeaturesInMutations<'program> {
                 }
             }
         }
+        Ok(())
+    }
+
+    fn validate_field(&self, field: &impl Field) -> DiagnosticsResult<()> {
+        self.validate_required(field.directives())?;
         if !self.allow_resolvers_for_this_mutation
             && self
                 .program
@@ -137,10 +144,15 @@ impl Validator for DisallowReadtimeFeaturesInMutations<'_> {
         Ok(())
     }
 
-    fn validate_fragment_spread(&mut self, _spread: &FragmentSpread) -> DiagnosticsResult<()> {
+    fn validate_fragment_spread(&mut self, spread: &FragmentSpread) -> DiagnosticsResult<()> {
         // Values nested within fragment spreads are fine since they are not read as part of the
-        // mutation response.
-        Ok(())
+        // mutation response, but a @required on the (aliased) spread itself is.
+        self.validate_required(&spread.directives)
+    }
+
+    fn validate_inline_fragment(&mut self, fragment: &InlineFragment) -> DiagnosticsResult<()> {
+        self.validate_required(&fragment.directives)?;
+        self.default_validate_inline_fragment(fragment)
     }
 
     fn validate_scalar_field(&mut self, field: &ScalarField) -> DiagnosticsResult<()> {
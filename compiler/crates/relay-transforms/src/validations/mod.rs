@@ -28,6 +28,10 @@ mod validate_unused_variables;
 
 pub use deprecated_fields::deprecated_fields;
 pub use deprecated_fields::deprecated_fields_for_executable_definition;
+pub use deprecated_fields::deprecated_usages;
+pub use deprecated_fields::validate_deprecated_fields;
+pub use deprecated_fields::DeprecatedUsage;
+pub use deprecated_fields::DeprecatedUsageAllowlist;
 pub use disallow_circular_no_inline_fragments::disallow_circular_no_inline_fragments;
 pub use disallow_non_node_id_fields::disallow_non_node_id_fields;
 pub use disallow_readtime_features_in_mutations::disallow_readtime_features_in_mutations;
@@ -16,6 +16,7 @@ use graphql_ir::ValidationMessage;
 use graphql_ir::Validator;
 use intern::string_key::Intern;
 
+use crate::dependent_operations::dependent_operation_definitions;
 use crate::root_variables::InferVariablesVisitor;
 
 pub fn validate_unused_variables(program: &Program) -> DiagnosticsResult<()> {
@@ -23,6 +24,7 @@ pub fn validate_unused_variables(program: &Program) -> DiagnosticsResult<()> {
 }
 
 pub struct ValidateUnusedVariables<'program> {
+    program: &'program Program,
     visitor: InferVariablesVisitor<'program>,
     ignore_directive_name: DirectiveName,
 }
@@ -30,6 +32,7 @@ pub struct ValidateUnusedVariables<'program> {
 impl<'program> ValidateUnusedVariables<'program> {
     fn new(program: &'program Program) -> Self {
         Self {
+            program,
             visitor: InferVariablesVisitor::new(program),
             ignore_directive_name: DirectiveName(
                 "DEPRECATED__relay_ignore_unused_variables_error".intern(),
@@ -51,6 +54,17 @@ impl Validator for ValidateUnusedVariables<'_> {
             .variable_definitions
             .iter()
             .filter(|var| !variables.contains_key(&var.name.item))
+            // Variables passed to a dependent operation are used by it.
+            .filter(|var| {
+                !dependent_operation_definitions(self.program, operation).any(
+                    |dependent_operation| {
+                        dependent_operation
+                            .variable_definitions
+                            .named(var.name.item)
+                            .is_some()
+                    },
+                )
+            })
             .collect();
 
         let ignore_directive = operation.directives.named(self.ignore_directive_name);
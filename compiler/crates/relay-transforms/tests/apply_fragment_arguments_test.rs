@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<7a0be220ee0ccec98d9d78d2768944ba>>
+ * @generated SignedSource<<ce896b4d88f9e55dd1ba9dda253e23ca>>
  */
 
 mod apply_fragment_arguments;
@@ -33,6 +33,13 @@ async fn fragment_include_with_provided_argument() {
     test_fixture(transform_fixture, file!(), "fragment-include-with-provided-argument.graphql", "apply_fragment_arguments/fixtures/fragment-include-with-provided-argument.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn fragment_variable_definitions_syntax() {
+    let input = include_str!("apply_fragment_arguments/fixtures/fragment-variable-definitions-syntax.graphql");
+    let expected = include_str!("apply_fragment_arguments/fixtures/fragment-variable-definitions-syntax.expected");
+    test_fixture(transform_fixture, file!(), "fragment-variable-definitions-syntax.graphql", "apply_fragment_arguments/fixtures/fragment-variable-definitions-syntax.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn fragment_with_float_argument() {
     let input = include_str!("apply_fragment_arguments/fixtures/fragment-with-float-argument.graphql");
@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use fixture_tests::Fixture;
+use graphql_test_helpers::apply_transform_for_test;
+use relay_config::DeferStreamInterface;
+use relay_transforms::auto_defer;
+use relay_transforms::transform_defer_stream;
+use relay_transforms::FieldCostHints;
+
+const BUDGET_MS: u64 = 100;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let field_cost_hints = fixture
+        .content
+        .lines()
+        .find_map(|line| line.strip_prefix("# cost-hints: "))
+        .map(|hints| FieldCostHints::from_json(hints).unwrap())
+        .unwrap_or_default();
+    let defer_stream_interface = DeferStreamInterface::default();
+    apply_transform_for_test(fixture, |program| {
+        let program = transform_defer_stream(program, &defer_stream_interface)?;
+        auto_defer(
+            &program,
+            &defer_stream_interface,
+            &field_cost_hints,
+            BUDGET_MS,
+        )
+    })
+}
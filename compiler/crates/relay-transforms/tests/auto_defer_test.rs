@@ -0,0 +1,41 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<32d5c9e349f08592c5f4f0c0901ed80a>>
+ */
+
+mod auto_defer;
+
+use auto_defer::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn auto_defer_nested_fragments() {
+    let input = include_str!("auto_defer/fixtures/auto-defer-nested-fragments.graphql");
+    let expected = include_str!("auto_defer/fixtures/auto-defer-nested-fragments.expected");
+    test_fixture(transform_fixture, file!(), "auto-defer-nested-fragments.graphql", "auto_defer/fixtures/auto-defer-nested-fragments.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn auto_defer_over_budget() {
+    let input = include_str!("auto_defer/fixtures/auto-defer-over-budget.graphql");
+    let expected = include_str!("auto_defer/fixtures/auto-defer-over-budget.expected");
+    test_fixture(transform_fixture, file!(), "auto-defer-over-budget.graphql", "auto_defer/fixtures/auto-defer-over-budget.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn auto_defer_skipped_spreads() {
+    let input = include_str!("auto_defer/fixtures/auto-defer-skipped-spreads.graphql");
+    let expected = include_str!("auto_defer/fixtures/auto-defer-skipped-spreads.expected");
+    test_fixture(transform_fixture, file!(), "auto-defer-skipped-spreads.graphql", "auto_defer/fixtures/auto-defer-skipped-spreads.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn auto_defer_within_budget() {
+    let input = include_str!("auto_defer/fixtures/auto-defer-within-budget.graphql");
+    let expected = include_str!("auto_defer/fixtures/auto-defer-within-budget.expected");
+    test_fixture(transform_fixture, file!(), "auto-defer-within-budget.graphql", "auto_defer/fixtures/auto-defer-within-budget.expected", input, expected).await;
+}
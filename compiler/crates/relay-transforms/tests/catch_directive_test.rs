@@ -0,0 +1,69 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<c0bb7ff7bff1d864fea96812f2228811>>
+ */
+
+mod catch_directive;
+
+use catch_directive::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn aliased_fragment_spread() {
+    let input = include_str!("catch_directive/fixtures/aliased-fragment-spread.graphql");
+    let expected = include_str!("catch_directive/fixtures/aliased-fragment-spread.expected");
+    test_fixture(transform_fixture, file!(), "aliased-fragment-spread.graphql", "catch_directive/fixtures/aliased-fragment-spread.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn catch_with_required_invalid() {
+    let input = include_str!("catch_directive/fixtures/catch-with-required.invalid.graphql");
+    let expected = include_str!("catch_directive/fixtures/catch-with-required.invalid.expected");
+    test_fixture(transform_fixture, file!(), "catch-with-required.invalid.graphql", "catch_directive/fixtures/catch-with-required.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn duplicate_field_different_to_invalid() {
+    let input = include_str!("catch_directive/fixtures/duplicate-field-different-to.invalid.graphql");
+    let expected = include_str!("catch_directive/fixtures/duplicate-field-different-to.invalid.expected");
+    test_fixture(transform_fixture, file!(), "duplicate-field-different-to.invalid.graphql", "catch_directive/fixtures/duplicate-field-different-to.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn duplicate_field_missing_catch_invalid() {
+    let input = include_str!("catch_directive/fixtures/duplicate-field-missing-catch.invalid.graphql");
+    let expected = include_str!("catch_directive/fixtures/duplicate-field-missing-catch.invalid.expected");
+    test_fixture(transform_fixture, file!(), "duplicate-field-missing-catch.invalid.graphql", "catch_directive/fixtures/duplicate-field-missing-catch.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn fragment_spread_without_alias_invalid() {
+    let input = include_str!("catch_directive/fixtures/fragment-spread-without-alias.invalid.graphql");
+    let expected = include_str!("catch_directive/fixtures/fragment-spread-without-alias.invalid.expected");
+    test_fixture(transform_fixture, file!(), "fragment-spread-without-alias.invalid.graphql", "catch_directive/fixtures/fragment-spread-without-alias.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn inline_directive_invalid() {
+    let input = include_str!("catch_directive/fixtures/inline-directive.invalid.graphql");
+    let expected = include_str!("catch_directive/fixtures/inline-directive.invalid.expected");
+    test_fixture(transform_fixture, file!(), "inline-directive.invalid.graphql", "catch_directive/fixtures/inline-directive.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn linked_field() {
+    let input = include_str!("catch_directive/fixtures/linked-field.graphql");
+    let expected = include_str!("catch_directive/fixtures/linked-field.expected");
+    test_fixture(transform_fixture, file!(), "linked-field.graphql", "catch_directive/fixtures/linked-field.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn scalar_field() {
+    let input = include_str!("catch_directive/fixtures/scalar-field.graphql");
+    let expected = include_str!("catch_directive/fixtures/scalar-field.expected");
+    test_fixture(transform_fixture, file!(), "scalar-field.graphql", "catch_directive/fixtures/scalar-field.expected", input, expected).await;
+}
@@ -0,0 +1,55 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<cd670e3eab1bdd84e8977b5d9a80a2cd>>
+ */
+
+mod dependent_operations;
+
+use dependent_operations::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn dependent_operation() {
+    let input = include_str!("dependent_operations/fixtures/dependent-operation.graphql");
+    let expected = include_str!("dependent_operations/fixtures/dependent-operation.expected");
+    test_fixture(transform_fixture, file!(), "dependent-operation.graphql", "dependent_operations/fixtures/dependent-operation.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn dependent_operation_invalid_invalid() {
+    let input = include_str!("dependent_operations/fixtures/dependent-operation-invalid.invalid.graphql");
+    let expected = include_str!("dependent_operations/fixtures/dependent-operation-invalid.invalid.expected");
+    test_fixture(transform_fixture, file!(), "dependent-operation-invalid.invalid.graphql", "dependent_operations/fixtures/dependent-operation-invalid.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn dependent_operation_multiple() {
+    let input = include_str!("dependent_operations/fixtures/dependent-operation-multiple.graphql");
+    let expected = include_str!("dependent_operations/fixtures/dependent-operation-multiple.expected");
+    test_fixture(transform_fixture, file!(), "dependent-operation-multiple.graphql", "dependent_operations/fixtures/dependent-operation-multiple.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn dependent_operation_variables_invalid() {
+    let input = include_str!("dependent_operations/fixtures/dependent-operation-variables.invalid.graphql");
+    let expected = include_str!("dependent_operations/fixtures/dependent-operation-variables.invalid.expected");
+    test_fixture(transform_fixture, file!(), "dependent-operation-variables.invalid.graphql", "dependent_operations/fixtures/dependent-operation-variables.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn export_as_in_fragment_invalid() {
+    let input = include_str!("dependent_operations/fixtures/export-as-in-fragment.invalid.graphql");
+    let expected = include_str!("dependent_operations/fixtures/export-as-in-fragment.invalid.expected");
+    test_fixture(transform_fixture, file!(), "export-as-in-fragment.invalid.graphql", "dependent_operations/fixtures/export-as-in-fragment.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn export_as_invalid_invalid() {
+    let input = include_str!("dependent_operations/fixtures/export-as-invalid.invalid.graphql");
+    let expected = include_str!("dependent_operations/fixtures/export-as-invalid.invalid.expected");
+    test_fixture(transform_fixture, file!(), "export-as-invalid.invalid.graphql", "dependent_operations/fixtures/export-as-invalid.invalid.expected", input, expected).await;
+}
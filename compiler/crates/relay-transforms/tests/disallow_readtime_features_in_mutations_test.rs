@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<ce28c55dce46aeeb001d3f3402c4050e>>
+ * @generated SignedSource<<38ea241d99a1d1d7febc3fb655ff6a58>>
  */
 
 mod disallow_readtime_features_in_mutations;
@@ -26,6 +26,13 @@ async fn mutation_with_linked_resolver_invalid() {
     test_fixture(transform_fixture, file!(), "mutation_with_linked_resolver.invalid.graphql", "disallow_readtime_features_in_mutations/fixtures/mutation_with_linked_resolver.invalid.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn mutation_with_required_aliased_inline_fragment_invalid() {
+    let input = include_str!("disallow_readtime_features_in_mutations/fixtures/mutation_with_required_aliased_inline_fragment.invalid.graphql");
+    let expected = include_str!("disallow_readtime_features_in_mutations/fixtures/mutation_with_required_aliased_inline_fragment.invalid.expected");
+    test_fixture(transform_fixture, file!(), "mutation_with_required_aliased_inline_fragment.invalid.graphql", "disallow_readtime_features_in_mutations/fixtures/mutation_with_required_aliased_inline_fragment.invalid.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn mutation_with_required_field_in_inline_fragment_invalid() {
     let input = include_str!("disallow_readtime_features_in_mutations/fixtures/mutation_with_required_field_in_inline_fragment.invalid.graphql");
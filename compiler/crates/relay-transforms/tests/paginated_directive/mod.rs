@@ -0,0 +1,18 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use fixture_tests::Fixture;
+use graphql_test_helpers::apply_transform_for_test;
+use relay_config::PaginationInterface;
+use relay_transforms::paginated_directive;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let pagination_interface = PaginationInterface::default();
+    apply_transform_for_test(fixture, |program| {
+        paginated_directive(program, &pagination_interface)
+    })
+}
@@ -0,0 +1,62 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<ff65add1dafa6660b4eed2323a3c5373>>
+ */
+
+mod paginated_directive;
+
+use paginated_directive::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn paginated_in_plural_field() {
+    let input = include_str!("paginated_directive/fixtures/paginated-in-plural-field.graphql");
+    let expected = include_str!("paginated_directive/fixtures/paginated-in-plural-field.expected");
+    test_fixture(transform_fixture, file!(), "paginated-in-plural-field.graphql", "paginated_directive/fixtures/paginated-in-plural-field.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_invalid_arguments_invalid() {
+    let input = include_str!("paginated_directive/fixtures/paginated-invalid-arguments.invalid.graphql");
+    let expected = include_str!("paginated_directive/fixtures/paginated-invalid-arguments.invalid.expected");
+    test_fixture(transform_fixture, file!(), "paginated-invalid-arguments.invalid.graphql", "paginated_directive/fixtures/paginated-invalid-arguments.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_invalid_field_invalid() {
+    let input = include_str!("paginated_directive/fixtures/paginated-invalid-field.invalid.graphql");
+    let expected = include_str!("paginated_directive/fixtures/paginated-invalid-field.invalid.expected");
+    test_fixture(transform_fixture, file!(), "paginated-invalid-field.invalid.graphql", "paginated_directive/fixtures/paginated-invalid-field.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_offset() {
+    let input = include_str!("paginated_directive/fixtures/paginated-offset.graphql");
+    let expected = include_str!("paginated_directive/fixtures/paginated-offset.expected");
+    test_fixture(transform_fixture, file!(), "paginated-offset.graphql", "paginated_directive/fixtures/paginated-offset.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_page() {
+    let input = include_str!("paginated_directive/fixtures/paginated-page.graphql");
+    let expected = include_str!("paginated_directive/fixtures/paginated-page.expected");
+    test_fixture(transform_fixture, file!(), "paginated-page.graphql", "paginated_directive/fixtures/paginated-page.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_with_connection_invalid() {
+    let input = include_str!("paginated_directive/fixtures/paginated-with-connection.invalid.graphql");
+    let expected = include_str!("paginated_directive/fixtures/paginated-with-connection.invalid.expected");
+    test_fixture(transform_fixture, file!(), "paginated-with-connection.invalid.graphql", "paginated_directive/fixtures/paginated-with-connection.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_without_items_invalid() {
+    let input = include_str!("paginated_directive/fixtures/paginated-without-items.invalid.graphql");
+    let expected = include_str!("paginated_directive/fixtures/paginated-without-items.invalid.expected");
+    test_fixture(transform_fixture, file!(), "paginated-without-items.invalid.graphql", "paginated_directive/fixtures/paginated-without-items.invalid.expected", input, expected).await;
+}
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<5ed09766c9f906b6da4f1eb1e6082c33>>
+ * @generated SignedSource<<de6afa58d772f698fe0dbb3807823d0e>>
  */
 
 mod relay_resolvers;
@@ -75,6 +75,27 @@ async fn relay_resolver_backing_client_edge() {
     test_fixture(transform_fixture, file!(), "relay-resolver-backing-client-edge.graphql", "relay_resolvers/fixtures/relay-resolver-backing-client-edge.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn relay_resolver_batch() {
+    let input = include_str!("relay_resolvers/fixtures/relay-resolver-batch.graphql");
+    let expected = include_str!("relay_resolvers/fixtures/relay-resolver-batch.expected");
+    test_fixture(transform_fixture, file!(), "relay-resolver-batch.graphql", "relay_resolvers/fixtures/relay-resolver-batch.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn relay_resolver_batch_edge_to_invalid() {
+    let input = include_str!("relay_resolvers/fixtures/relay-resolver-batch-edge-to.invalid.graphql");
+    let expected = include_str!("relay_resolvers/fixtures/relay-resolver-batch-edge-to.invalid.expected");
+    test_fixture(transform_fixture, file!(), "relay-resolver-batch-edge-to.invalid.graphql", "relay_resolvers/fixtures/relay-resolver-batch-edge-to.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn relay_resolver_batch_without_fragment_invalid() {
+    let input = include_str!("relay_resolvers/fixtures/relay-resolver-batch-without-fragment.invalid.graphql");
+    let expected = include_str!("relay_resolvers/fixtures/relay-resolver-batch-without-fragment.invalid.expected");
+    test_fixture(transform_fixture, file!(), "relay-resolver-batch-without-fragment.invalid.graphql", "relay_resolvers/fixtures/relay-resolver-batch-without-fragment.invalid.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn relay_resolver_field_and_fragment_arguments() {
     let input = include_str!("relay_resolvers/fixtures/relay-resolver-field-and-fragment-arguments.graphql");
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<854adab7d4107de9ea7820c842e3868b>>
+ * @generated SignedSource<<3d88d40f84fd3e2fade6f48fb2c35a0f>>
  */
 
 mod required_directive;
@@ -19,6 +19,41 @@ async fn action_argument_omitted_invalid() {
     test_fixture(transform_fixture, file!(), "action-argument-omitted.invalid.graphql", "required_directive/fixtures/action-argument-omitted.invalid.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn aliased_fragment_spread() {
+    let input = include_str!("required_directive/fixtures/aliased-fragment-spread.graphql");
+    let expected = include_str!("required_directive/fixtures/aliased-fragment-spread.expected");
+    test_fixture(transform_fixture, file!(), "aliased-fragment-spread.graphql", "required_directive/fixtures/aliased-fragment-spread.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn aliased_inline_fragment() {
+    let input = include_str!("required_directive/fixtures/aliased-inline-fragment.graphql");
+    let expected = include_str!("required_directive/fixtures/aliased-inline-fragment.expected");
+    test_fixture(transform_fixture, file!(), "aliased-inline-fragment.graphql", "required_directive/fixtures/aliased-inline-fragment.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn aliased_inline_fragment_less_severe_child_invalid() {
+    let input = include_str!("required_directive/fixtures/aliased-inline-fragment-less-severe-child.invalid.graphql");
+    let expected = include_str!("required_directive/fixtures/aliased-inline-fragment-less-severe-child.invalid.expected");
+    test_fixture(transform_fixture, file!(), "aliased-inline-fragment-less-severe-child.invalid.graphql", "required_directive/fixtures/aliased-inline-fragment-less-severe-child.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn aliased_inline_fragment_within_inline_directive_invalid() {
+    let input = include_str!("required_directive/fixtures/aliased-inline-fragment-within-inline-directive.invalid.graphql");
+    let expected = include_str!("required_directive/fixtures/aliased-inline-fragment-within-inline-directive.invalid.expected");
+    test_fixture(transform_fixture, file!(), "aliased-inline-fragment-within-inline-directive.invalid.graphql", "required_directive/fixtures/aliased-inline-fragment-within-inline-directive.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn aliased_inline_fragment_within_required_field() {
+    let input = include_str!("required_directive/fixtures/aliased-inline-fragment-within-required-field.graphql");
+    let expected = include_str!("required_directive/fixtures/aliased-inline-fragment-within-required-field.expected");
+    test_fixture(transform_fixture, file!(), "aliased-inline-fragment-within-required-field.graphql", "required_directive/fixtures/aliased-inline-fragment-within-required-field.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn conflicting_required_status_across_aliased_inline_fragments() {
     let input = include_str!("required_directive/fixtures/conflicting-required-status-across-aliased-inline-fragments.graphql");
@@ -26,6 +61,13 @@ async fn conflicting_required_status_across_aliased_inline_fragments() {
     test_fixture(transform_fixture, file!(), "conflicting-required-status-across-aliased-inline-fragments.graphql", "required_directive/fixtures/conflicting-required-status-across-aliased-inline-fragments.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn duplicate_aliased_fragment_spread_invalid() {
+    let input = include_str!("required_directive/fixtures/duplicate-aliased-fragment-spread.invalid.graphql");
+    let expected = include_str!("required_directive/fixtures/duplicate-aliased-fragment-spread.invalid.expected");
+    test_fixture(transform_fixture, file!(), "duplicate-aliased-fragment-spread.invalid.graphql", "required_directive/fixtures/duplicate-aliased-fragment-spread.invalid.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn duplicate_field_different_actions_invalid() {
     let input = include_str!("required_directive/fixtures/duplicate-field-different-actions.invalid.graphql");
@@ -82,6 +124,13 @@ async fn duplicate_linked_field_nullable_parent_invalid() {
     test_fixture(transform_fixture, file!(), "duplicate-linked-field-nullable-parent.invalid.graphql", "required_directive/fixtures/duplicate-linked-field-nullable-parent.invalid.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn fragment_spread_without_alias_invalid() {
+    let input = include_str!("required_directive/fixtures/fragment-spread-without-alias.invalid.graphql");
+    let expected = include_str!("required_directive/fixtures/fragment-spread-without-alias.invalid.expected");
+    test_fixture(transform_fixture, file!(), "fragment-spread-without-alias.invalid.graphql", "required_directive/fixtures/fragment-spread-without-alias.invalid.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn fragments_are_isolated() {
     let input = include_str!("required_directive/fixtures/fragments-are-isolated.graphql");
@@ -138,6 +187,13 @@ async fn inline_fragment_on_union_invalid() {
     test_fixture(transform_fixture, file!(), "inline-fragment-on-union.invalid.graphql", "required_directive/fixtures/inline-fragment-on-union.invalid.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn inline_fragment_without_alias_invalid() {
+    let input = include_str!("required_directive/fixtures/inline-fragment-without-alias.invalid.graphql");
+    let expected = include_str!("required_directive/fixtures/inline-fragment-without-alias.invalid.expected");
+    test_fixture(transform_fixture, file!(), "inline-fragment-without-alias.invalid.graphql", "required_directive/fixtures/inline-fragment-without-alias.invalid.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn linked_field_log() {
     let input = include_str!("required_directive/fixtures/linked-field-log.graphql");
@@ -208,6 +264,13 @@ async fn required_with_different_actions_invalid() {
     test_fixture(transform_fixture, file!(), "required-with-different-actions.invalid.graphql", "required_directive/fixtures/required-with-different-actions.invalid.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn required_within_catch() {
+    let input = include_str!("required_directive/fixtures/required-within-catch.graphql");
+    let expected = include_str!("required_directive/fixtures/required-within-catch.expected");
+    test_fixture(transform_fixture, file!(), "required-within-catch.graphql", "required_directive/fixtures/required-within-catch.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn scalar_field_log() {
     let input = include_str!("required_directive/fixtures/scalar-field-log.graphql");
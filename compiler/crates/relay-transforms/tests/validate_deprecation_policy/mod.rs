@@ -0,0 +1,55 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::build;
+use graphql_ir::Program;
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use relay_config::DeprecationConfig;
+use relay_test_schema::get_test_schema_with_extensions;
+use relay_transforms::validate_deprecated_fields;
+use relay_transforms::DeprecatedUsageAllowlist;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split('%').collect();
+
+    if let [
+        base,
+        "config",
+        config,
+        "allowlist",
+        allowlist,
+        "extensions",
+        extensions,
+    ] = parts.as_slice()
+    {
+        let source_location = SourceLocationKey::standalone(fixture.file_name);
+        let ast = parse_executable(base, source_location).unwrap();
+        let schema = get_test_schema_with_extensions(extensions);
+        let ir = build(&schema, &ast.definitions).unwrap();
+        let program = Program::from_definitions(Arc::clone(&schema), ir);
+
+        let deprecation_config: DeprecationConfig = serde_json::from_str(config).unwrap();
+        let mut deprecated_usage_allowlist = DeprecatedUsageAllowlist::default();
+        deprecated_usage_allowlist.extend_from_str(allowlist);
+
+        validate_deprecated_fields(
+            &program,
+            &deprecation_config,
+            &deprecated_usage_allowlist,
+            "2024-06-01",
+        )
+        .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
+        Ok("OK".to_string())
+    } else {
+        panic!("Expected %config%, %allowlist% and %extensions% sections.")
+    }
+}
@@ -0,0 +1,41 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<3a1bdb729bc20c91fe50ecc06c282efe>>
+ */
+
+mod validate_deprecation_policy;
+
+use validate_deprecation_policy::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn policy_allowlisted() {
+    let input = include_str!("validate_deprecation_policy/fixtures/policy_allowlisted.graphql");
+    let expected = include_str!("validate_deprecation_policy/fixtures/policy_allowlisted.expected");
+    test_fixture(transform_fixture, file!(), "policy_allowlisted.graphql", "validate_deprecation_policy/fixtures/policy_allowlisted.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn policy_error_after_date() {
+    let input = include_str!("validate_deprecation_policy/fixtures/policy_error_after_date.graphql");
+    let expected = include_str!("validate_deprecation_policy/fixtures/policy_error_after_date.expected");
+    test_fixture(transform_fixture, file!(), "policy_error_after_date.graphql", "validate_deprecation_policy/fixtures/policy_error_after_date.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn policy_error_with_allowlist() {
+    let input = include_str!("validate_deprecation_policy/fixtures/policy_error_with_allowlist.graphql");
+    let expected = include_str!("validate_deprecation_policy/fixtures/policy_error_with_allowlist.expected");
+    test_fixture(transform_fixture, file!(), "policy_error_with_allowlist.graphql", "validate_deprecation_policy/fixtures/policy_error_with_allowlist.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn policy_warn() {
+    let input = include_str!("validate_deprecation_policy/fixtures/policy_warn.graphql");
+    let expected = include_str!("validate_deprecation_policy/fixtures/policy_warn.expected");
+    test_fixture(transform_fixture, file!(), "policy_warn.graphql", "validate_deprecation_policy/fixtures/policy_warn.expected", input, expected).await;
+}
@@ -0,0 +1,41 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<6ca89df4cd42da0a9db9bd18addcef56>>
+ */
+
+mod validate_unused_fragment_variables;
+
+use validate_unused_fragment_variables::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn fragment_variables_used() {
+    let input = include_str!("validate_unused_fragment_variables/fixtures/fragment-variables-used.graphql");
+    let expected = include_str!("validate_unused_fragment_variables/fixtures/fragment-variables-used.expected");
+    test_fixture(transform_fixture, file!(), "fragment-variables-used.graphql", "validate_unused_fragment_variables/fixtures/fragment-variables-used.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn unused_fragment_variables_error_suppressed() {
+    let input = include_str!("validate_unused_fragment_variables/fixtures/unused-fragment-variables-error-suppressed.graphql");
+    let expected = include_str!("validate_unused_fragment_variables/fixtures/unused-fragment-variables-error-suppressed.expected");
+    test_fixture(transform_fixture, file!(), "unused-fragment-variables-error-suppressed.graphql", "validate_unused_fragment_variables/fixtures/unused-fragment-variables-error-suppressed.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn unused_fragment_variables_invalid() {
+    let input = include_str!("validate_unused_fragment_variables/fixtures/unused-fragment-variables.invalid.graphql");
+    let expected = include_str!("validate_unused_fragment_variables/fixtures/unused-fragment-variables.invalid.expected");
+    test_fixture(transform_fixture, file!(), "unused-fragment-variables.invalid.graphql", "validate_unused_fragment_variables/fixtures/unused-fragment-variables.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn used_fragment_variable_error_suppressed_invalid() {
+    let input = include_str!("validate_unused_fragment_variables/fixtures/used-fragment-variable-error-suppressed.invalid.graphql");
+    let expected = include_str!("validate_unused_fragment_variables/fixtures/used-fragment-variable-error-suppressed.invalid.expected");
+    test_fixture(transform_fixture, file!(), "used-fragment-variable-error-suppressed.invalid.graphql", "validate_unused_fragment_variables/fixtures/used-fragment-variable-error-suppressed.invalid.expected", input, expected).await;
+}
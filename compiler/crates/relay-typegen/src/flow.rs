@@ -11,6 +11,8 @@ use std::fmt::Write;
 use intern::string_key::StringKey;
 use itertools::Itertools;
 
+use crate::writer::write_doc_comment;
+use crate::writer::DocCommentStyle;
 use crate::writer::FunctionTypeAssertion;
 use crate::writer::KeyValuePairProp;
 use crate::writer::Prop;
@@ -37,6 +39,7 @@ impl Writer for FlowPrinter {
         match ast {
             AST::Any => write!(&mut self.result, "any"),
             AST::Mixed => write!(&mut self.result, "mixed"),
+            AST::Never => write!(&mut self.result, "empty"),
             AST::String => write!(&mut self.result, "string"),
             AST::StringLiteral(literal) => self.write_string_literal(**literal),
             AST::OtherTypename => self.write_other_string(),
@@ -45,6 +48,7 @@ impl Writer for FlowPrinter {
             AST::Callable(return_type) => self.write_callable(&*return_type),
             AST::Identifier(identifier) => write!(&mut self.result, "{}", identifier),
             AST::RawType(raw) => write!(&mut self.result, "{}", raw),
+            AST::CustomScalar { type_, .. } => self.write(type_),
             AST::Union(members) => self.write_union(members),
             AST::ReadOnlyArray(of_type) => self.write_read_only_array(of_type),
             AST::Nullable(of_type) => self.write_nullable(of_type),
@@ -64,6 +68,10 @@ impl Writer for FlowPrinter {
             AST::ReturnTypeOfMethodCall(object, method_name) => {
                 self.write_return_type_of_method_call(object, *method_name)
             }
+            AST::ArrayElementType(array) => {
+                self.write(array)?;
+                write!(&mut self.result, "[number]")
+            }
             AST::ActorChangePoint(selections) => self.write_actor_change_point(selections),
             AST::AssertFunctionType(FunctionTypeAssertion {
                 function_name,
@@ -97,6 +105,14 @@ impl Writer for FlowPrinter {
         writeln!(&mut self.result, ";")
     }
 
+    fn write_export_opaque_type(&mut self, name: &str, underlying_type: &AST) -> FmtResult {
+        write!(&mut self.result, "export opaque type {}: ", name)?;
+        self.write(underlying_type)?;
+        write!(&mut self.result, " = ")?;
+        self.write(underlying_type)?;
+        writeln!(&mut self.result, ";")
+    }
+
     fn write_import_module_default(&mut self, name: &str, from: &str) -> FmtResult {
         writeln!(&mut self.result, "import {} from \"{}\";", name, from)
     }
@@ -249,6 +265,14 @@ impl FlowPrinter {
                     continue;
                 }
                 Prop::KeyValuePair(key_value_pair) => {
+                    if let Some(doc_comment) = &key_value_pair.doc_comment {
+                        write_doc_comment(
+                            &mut self.result,
+                            doc_comment,
+                            self.indentation,
+                            DocCommentStyle::Line,
+                        )?;
+                    }
                     if let AST::OtherTypename = key_value_pair.value {
                         writeln!(
                             &mut self.result,
@@ -450,6 +474,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
             ],))),
             r"{|
@@ -464,12 +489,14 @@ mod tests {
                     optional: true,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: "bar".intern(),
                     optional: false,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 }),
             ],))),
             r"{|
@@ -494,20 +521,24 @@ mod tests {
                             optional: true,
                             read_only: false,
                             value: AST::String,
+                            doc_comment: None,
                         }),
                         Prop::KeyValuePair(KeyValuePairProp {
                             key: "nested_foo2".intern(),
                             optional: false,
                             read_only: true,
                             value: AST::Number,
+                            doc_comment: None,
                         }),
                     ],)),
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: "bar".intern(),
                     optional: false,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 }),
             ],))),
             r"{|
@@ -538,6 +569,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
             ]))),
             r"{
@@ -554,12 +586,14 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: "bar".intern(),
                     optional: true,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 })
             ]))),
             r"{
@@ -580,6 +614,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::OtherTypename,
+                    doc_comment: None,
                 })
             ],))),
             r#"{|
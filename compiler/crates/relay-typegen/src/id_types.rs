@@ -0,0 +1,289 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Branded (TypeScript) and opaque (Flow) ID types, see
+//! `TypegenConfig::branded_id_types`.
+
+use std::path::PathBuf;
+
+use ::intern::string_key::Intern;
+use ::intern::string_key::StringKey;
+use ::intern::Lookup;
+use common::ArgumentName;
+use common::DirectiveName;
+use common::NamedItem;
+use fnv::FnvHashMap;
+use graphql_ir::Argument;
+use graphql_ir::LinkedField;
+use graphql_ir::OperationDefinition;
+use graphql_ir::ScalarField;
+use graphql_ir::Selection;
+use graphql_ir::Value;
+use graphql_ir::VariableName;
+use graphql_ir::Visitor;
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use schema::DirectiveValue;
+use schema::Field;
+use schema::Schema;
+use schema::Type;
+use schema::TypeReference;
+
+use crate::visit::transform_graphql_scalar_type;
+use crate::write::schema_types_module_import;
+use crate::write::CustomScalarsImports;
+use crate::writer::AST;
+use crate::TypegenContext;
+use crate::TYPE_ID;
+
+lazy_static! {
+    static ref ID_TYPE_DIRECTIVE_NAME: DirectiveName = DirectiveName("idType".intern());
+    static ref ID_TYPE_NAME_ARGUMENT: ArgumentName = ArgumentName("name".intern());
+}
+
+/// The branded ID types of the variables of an operation, by variable name.
+pub(crate) type VariableIdTypes = FnvHashMap<VariableName, AST>;
+
+/// The name of the ID type of objects of type `object_name`, e.g. `UserID`.
+fn id_type_name(object_name: StringKey) -> StringKey {
+    format!("{}ID", object_name).intern()
+}
+
+fn is_id_type(typegen_context: &'_ TypegenContext<'_>, type_: &TypeReference<Type>) -> bool {
+    match type_.inner() {
+        Type::Scalar(scalar_id) => typegen_context.schema.scalar(scalar_id).name.item == *TYPE_ID,
+        _ => false,
+    }
+}
+
+/// The object type named by `@idType(name: "...")`.
+fn id_type_directive(directives: &[DirectiveValue]) -> Option<StringKey> {
+    directives
+        .named(*ID_TYPE_DIRECTIVE_NAME)
+        .and_then(|directive| directive.arguments.named(*ID_TYPE_NAME_ARGUMENT))
+        .and_then(|argument| argument.value.get_string_literal())
+}
+
+/// Returns the ID type of `object_name` and adds its import, or `None` if
+/// branded ID types are not enabled for the artifact. They are not used in
+/// the shared schema types module and in runtime validators.
+fn import_id_type(
+    typegen_context: &'_ TypegenContext<'_>,
+    object_name: StringKey,
+    custom_scalars: &mut CustomScalarsImports,
+) -> Option<AST> {
+    if !typegen_context
+        .project_config
+        .typegen_config
+        .branded_id_types
+    {
+        return None;
+    }
+    let module = schema_types_module_import(typegen_context)?;
+    let name = id_type_name(object_name);
+    custom_scalars.insert((name, PathBuf::from(module.lookup())));
+    Some(AST::RawType(name))
+}
+
+/// The ID type of a selected field: the type named by its `@idType`
+/// directive, or for the `id` field of an object type, the object type.
+pub(crate) fn field_id_type(
+    typegen_context: &'_ TypegenContext<'_>,
+    field: &Field,
+    custom_scalars: &mut CustomScalarsImports,
+) -> Option<AST> {
+    if !is_id_type(typegen_context, &field.type_) {
+        return None;
+    }
+    let object_name = id_type_directive(&field.directives).or_else(|| match field.parent_type {
+        Some(parent_type @ Type::Object(_))
+            if field.name.item
+                == typegen_context
+                    .project_config
+                    .schema_config
+                    .node_interface_id_field =>
+        {
+            Some(typegen_context.schema.get_type_name(parent_type))
+        }
+        _ => None,
+    })?;
+    import_id_type(typegen_context, object_name, custom_scalars)
+}
+
+/// Infers the ID types of the variables of an operation from the arguments
+/// they are passed to. Arguments have the type named by their `@idType`
+/// directive. The `id` argument of a field returning an object type, e.g.
+/// `fetch__User(id:)`, has the ID type of that object type. For fields with
+/// an abstract type, e.g. `node(id:)`, the object type is inferred from the
+/// inline fragments selected on the field. Refetch queries of fragments on
+/// object types select such an inline fragment. Variables passed to
+/// arguments with different or without ID types are typed as `ID`.
+pub(crate) fn variable_id_types(
+    typegen_context: &'_ TypegenContext<'_>,
+    normalization_operation: &OperationDefinition,
+    custom_scalars: &mut CustomScalarsImports,
+) -> VariableIdTypes {
+    if !typegen_context
+        .project_config
+        .typegen_config
+        .branded_id_types
+    {
+        return Default::default();
+    }
+    let mut collector = VariableIdTypesCollector {
+        typegen_context,
+        usages: Default::default(),
+    };
+    collector.visit_operation(normalization_operation);
+    collector
+        .usages
+        .into_iter()
+        .filter_map(|(variable_name, object_names)| {
+            let object_name = object_names.into_iter().all_equal_value().ok()??;
+            Some((
+                variable_name,
+                import_id_type(typegen_context, object_name, custom_scalars)?,
+            ))
+        })
+        .collect()
+}
+
+struct VariableIdTypesCollector<'a, 'b> {
+    typegen_context: &'a TypegenContext<'b>,
+    usages: FnvHashMap<VariableName, Vec<Option<StringKey>>>,
+}
+
+impl VariableIdTypesCollector<'_, '_> {
+    fn add_arguments(&mut self, field: &Field, arguments: &[Argument], selections: &[Selection]) {
+        let schema = self.typegen_context.schema;
+        for argument in arguments {
+            let variable_name = match &argument.value.item {
+                Value::Variable(variable) => variable.name.item,
+                _ => continue,
+            };
+            let object_name = field
+                .arguments
+                .named(argument.name.item)
+                .filter(|definition| is_id_type(self.typegen_context, &definition.type_))
+                .and_then(|definition| {
+                    id_type_directive(&definition.directives).or_else(|| {
+                        if argument.name.item.0
+                            != self
+                                .typegen_context
+                                .project_config
+                                .schema_config
+                                .node_interface_id_field
+                        {
+                            return None;
+                        }
+                        match field.type_.inner() {
+                            type_ @ Type::Object(_) => Some(schema.get_type_name(type_)),
+                            _ => selections
+                                .iter()
+                                .filter_map(|selection| match selection {
+                                    Selection::InlineFragment(inline_fragment) => {
+                                        match inline_fragment.type_condition {
+                                            Some(type_ @ Type::Object(_)) => {
+                                                Some(schema.get_type_name(type_))
+                                            }
+                                            _ => None,
+                                        }
+                                    }
+                                    _ => None,
+                                })
+                                .unique()
+                                .exactly_one()
+                                .ok(),
+                        }
+                    })
+                });
+            self.usages
+                .entry(variable_name)
+                .or_default()
+                .push(object_name);
+        }
+    }
+}
+
+impl Visitor for VariableIdTypesCollector<'_, '_> {
+    const NAME: &'static str = "VariableIdTypesCollector";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_linked_field(&mut self, field: &LinkedField) {
+        let definition = self.typegen_context.schema.field(field.definition.item);
+        self.add_arguments(definition, &field.arguments, &field.selections);
+        self.default_visit_linked_field(field);
+    }
+
+    fn visit_scalar_field(&mut self, field: &ScalarField) {
+        let definition = self.typegen_context.schema.field(field.definition.item);
+        self.add_arguments(definition, &field.arguments, &[]);
+    }
+}
+
+/// The names of all ID types of the schema, with the type they are
+/// represented as at runtime: the object types with an `id` field, and the
+/// object types named by `@idType` directives.
+pub(crate) fn id_type_definitions(
+    typegen_context: &'_ TypegenContext<'_>,
+    custom_scalars: &mut CustomScalarsImports,
+) -> Vec<(StringKey, AST)> {
+    let schema = typegen_context.schema;
+    let id_scalar = match schema.get_type(TYPE_ID.0) {
+        Some(Type::Scalar(scalar_id)) => scalar_id,
+        _ => return vec![],
+    };
+    if !typegen_context
+        .project_config
+        .typegen_config
+        .branded_id_types
+    {
+        return vec![];
+    }
+    let node_interface_id_field = typegen_context
+        .project_config
+        .schema_config
+        .node_interface_id_field;
+
+    let mut object_names = vec![];
+    let object_fields = schema
+        .objects()
+        .map(|object| (object.name.item.0, &object.fields));
+    let interface_fields = schema
+        .interfaces()
+        .map(|interface| (interface.name.item.0, &interface.fields));
+    for (type_name, field_ids) in object_fields.chain(interface_fields) {
+        let is_object = matches!(schema.get_type(type_name), Some(Type::Object(_)));
+        for field_id in field_ids {
+            let field = schema.field(*field_id);
+            if is_id_type(typegen_context, &field.type_) {
+                if let Some(object_name) = id_type_directive(&field.directives) {
+                    object_names.push(object_name);
+                } else if is_object && field.name.item == node_interface_id_field {
+                    object_names.push(type_name);
+                }
+            }
+            object_names.extend(
+                field
+                    .arguments
+                    .iter()
+                    .filter(|argument| is_id_type(typegen_context, &argument.type_))
+                    .filter_map(|argument| id_type_directive(&argument.directives)),
+            );
+        }
+    }
+
+    let underlying_type = transform_graphql_scalar_type(typegen_context, id_scalar, custom_scalars);
+    object_names
+        .into_iter()
+        .map(id_type_name)
+        .unique()
+        .sorted_by_key(|name| name.lookup())
+        .map(|name| (name, underlying_type.clone()))
+        .collect()
+}
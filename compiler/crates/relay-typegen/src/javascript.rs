@@ -43,6 +43,10 @@ impl Writer for JavaScriptPrinter {
         Ok(())
     }
 
+    fn write_export_opaque_type(&mut self, _name: &str, _underlying_type: &AST) -> FmtResult {
+        Ok(())
+    }
+
     fn write_import_module_default(&mut self, _name: &str, _from: &str) -> FmtResult {
         Ok(())
     }
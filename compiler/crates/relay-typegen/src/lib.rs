@@ -10,7 +10,11 @@
 #![deny(clippy::all)]
 
 mod flow;
+mod id_types;
 mod javascript;
+mod mock_resolvers;
+mod rescript;
+mod runtime_validators;
 mod type_selection;
 mod typegen_state;
 mod typescript;
@@ -27,18 +31,24 @@ use common::WithLocation;
 use graphql_ir::FragmentDefinition;
 use graphql_ir::OperationDefinition;
 use lazy_static::lazy_static;
+pub use mock_resolvers::collect_mock_resolver_types;
+use mock_resolvers::write_mock_resolvers_module_section;
+pub use mock_resolvers::MockResolverTypes;
 use relay_config::ProjectConfig;
 pub use relay_config::TypegenConfig;
 pub use relay_config::TypegenLanguage;
 use relay_transforms::UPDATABLE_DIRECTIVE;
+use runtime_validators::RuntimeValidatorsPrinter;
 use schema::SDLSchema;
 pub use typegen_state::FragmentLocations;
 pub use write::has_raw_response_type_directive;
 use write::write_fragment_type_exports_section;
 use write::write_operation_type_exports_section;
+use write::write_schema_types_module_section;
 use write::write_split_operation_type_exports_section;
 use write::write_validator_function;
 use writer::new_writer_from_config;
+use writer::Writer;
 
 static REACT_RELAY_MULTI_ACTOR: &str = "react-relay/multi-actor";
 static RELAY_RUNTIME: &str = "relay-runtime";
@@ -50,6 +60,7 @@ static LIVE_RESOLVERS_LIVE_STATE: &str = "LiveState";
 lazy_static! {
     static ref KEY_CLIENTID: StringKey = "__id".intern();
     pub(crate) static ref KEY_DATA: StringKey = "$data".intern();
+    static ref KEY_DEPENDENT_DATA: StringKey = "dependentData".intern();
     static ref KEY_FRAGMENT_SPREADS: StringKey = "$fragmentSpreads".intern();
     static ref KEY_UPDATABLE_FRAGMENT_SPREADS: StringKey = "$updatableFragmentSpreads".intern();
     pub(crate) static ref KEY_FRAGMENT_TYPE: StringKey = "$fragmentType".intern();
@@ -65,6 +76,8 @@ lazy_static! {
     static ref RAW_RESPONSE_TYPE_DIRECTIVE_NAME: DirectiveName =
         DirectiveName("raw_response_type".intern());
     static ref RESPONSE: StringKey = "response".intern();
+    static ref SCHEMA_TYPES: StringKey = "SchemaTypes".intern();
+    static ref MOCK_RESOLVERS: StringKey = "MockResolvers".intern();
     static ref TYPE_BOOLEAN: ScalarName = ScalarName("Boolean".intern());
     static ref TYPE_FLOAT: ScalarName = ScalarName("Float".intern());
     static ref TYPE_ID: ScalarName = ScalarName("ID".intern());
@@ -157,6 +170,7 @@ fn generate_fragment_type_exports_section_impl(
         TypegenOptions {
             no_optional_fields_in_raw_response_type: false,
             is_extra_artifact_branch_module,
+            inline_schema_types: false,
         },
     );
     let mut writer = new_writer_from_config(&project_config.typegen_config);
@@ -183,13 +197,14 @@ pub fn generate_named_validator_export(
         TypegenOptions {
             no_optional_fields_in_raw_response_type: false,
             is_extra_artifact_branch_module: false,
+            inline_schema_types: false,
         },
     );
     let mut writer = new_writer_from_config(&project_config.typegen_config);
     write_validator_function(&typegen_context, fragment_definition, &mut writer).unwrap();
     let validator_function_body = writer.into_string();
 
-    if project_config.typegen_config.eager_es_modules {
+    if project_config.is_es_module() {
         format!("export {}", validator_function_body)
     } else {
         format!(
@@ -222,6 +237,7 @@ pub fn generate_operation_type_exports_section(
         TypegenOptions {
             no_optional_fields_in_raw_response_type: false,
             is_extra_artifact_branch_module: false,
+            inline_schema_types: false,
         },
     );
     let mut writer = new_writer_from_config(&project_config.typegen_config);
@@ -236,6 +252,126 @@ pub fn generate_operation_type_exports_section(
     writer.into_string()
 }
 
+/// Returns the runtime validators of the `$data`, `$variables` and
+/// `$rawResponse` types of the operation, if they are enabled in the config.
+pub fn generate_operation_validators_section(
+    typegen_operation: &OperationDefinition,
+    normalization_operation: &OperationDefinition,
+    schema: &SDLSchema,
+    project_config: &ProjectConfig,
+    fragment_locations: &FragmentLocations,
+    maybe_provided_variables: Option<String>,
+) -> Option<String> {
+    let runtime_validators = project_config.typegen_config.runtime_validators.as_ref()?;
+    let typegen_context = TypegenContext::new(
+        schema,
+        project_config,
+        false,
+        WithLocation::new(
+            typegen_operation.name.location,
+            typegen_operation.name.item.0,
+        ),
+        fragment_locations,
+        TypegenOptions {
+            no_optional_fields_in_raw_response_type: false,
+            is_extra_artifact_branch_module: false,
+            inline_schema_types: true,
+        },
+    );
+    let mut writer: Box<dyn Writer> = Box::new(RuntimeValidatorsPrinter::new(
+        project_config,
+        runtime_validators,
+    ));
+    write_operation_type_exports_section(
+        &typegen_context,
+        typegen_operation,
+        normalization_operation,
+        &mut writer,
+        maybe_provided_variables,
+    )
+    .unwrap();
+    Some(writer.into_string()).filter(|validators| !validators.is_empty())
+}
+
+/// Returns the runtime validator of the `$data` type of the fragment, if
+/// runtime validators are enabled in the config.
+pub fn generate_fragment_validators_section(
+    fragment_definition: &FragmentDefinition,
+    schema: &SDLSchema,
+    project_config: &ProjectConfig,
+    fragment_locations: &FragmentLocations,
+) -> Option<String> {
+    let runtime_validators = project_config.typegen_config.runtime_validators.as_ref()?;
+    let typegen_context = TypegenContext::new(
+        schema,
+        project_config,
+        false,
+        fragment_definition.name.map(|x| x.0),
+        fragment_locations,
+        TypegenOptions {
+            no_optional_fields_in_raw_response_type: false,
+            is_extra_artifact_branch_module: false,
+            inline_schema_types: true,
+        },
+    );
+    let mut writer: Box<dyn Writer> = Box::new(RuntimeValidatorsPrinter::new(
+        project_config,
+        runtime_validators,
+    ));
+    write_fragment_type_exports_section(&typegen_context, fragment_definition, &mut writer)
+        .unwrap();
+    Some(writer.into_string()).filter(|validators| !validators.is_empty())
+}
+
+/// Returns the enum and input object types of the schema, for the shared
+/// module that artifacts import them from.
+pub fn generate_schema_types_module_section(
+    schema: &SDLSchema,
+    project_config: &ProjectConfig,
+    fragment_locations: &FragmentLocations,
+) -> String {
+    let typegen_context = TypegenContext::new(
+        schema,
+        project_config,
+        false,
+        WithLocation::generated(*SCHEMA_TYPES),
+        fragment_locations,
+        TypegenOptions {
+            no_optional_fields_in_raw_response_type: false,
+            is_extra_artifact_branch_module: false,
+            inline_schema_types: true,
+        },
+    );
+    let mut writer = new_writer_from_config(&project_config.typegen_config);
+    write_schema_types_module_section(&typegen_context, &mut writer).unwrap();
+    writer.into_string()
+}
+
+/// Returns the `MockResolvers` type of the project, with the resolvers of
+/// `types`.
+pub fn generate_mock_resolvers_module_section(
+    schema: &SDLSchema,
+    project_config: &ProjectConfig,
+    fragment_locations: &FragmentLocations,
+    types: &MockResolverTypes,
+) -> String {
+    let typegen_context = TypegenContext::new(
+        schema,
+        project_config,
+        false,
+        WithLocation::generated(*MOCK_RESOLVERS),
+        fragment_locations,
+        TypegenOptions {
+            no_optional_fields_in_raw_response_type: false,
+            is_extra_artifact_branch_module: false,
+            inline_schema_types: true,
+        },
+    );
+    let mut writer = new_writer_from_config(&project_config.typegen_config);
+    write_mock_resolvers_module_section(&typegen_context, types, &mut writer).unwrap();
+    writer.into_string()
+}
+
 pub fn generate_split_operation_type_exports_section(
     typegen_operation: &OperationDefinition,
     normalization_operation: &OperationDefinition,
@@ -259,6 +395,7 @@ pub fn generate_split_operation_type_exports_section(
         TypegenOptions {
             no_optional_fields_in_raw_response_type,
             is_extra_artifact_branch_module: false,
+            inline_schema_types: false,
         },
     );
     let mut writer = new_writer_from_config(&project_config.typegen_config);
@@ -311,4 +448,7 @@ struct TypegenOptions {
     no_optional_fields_in_raw_response_type: bool,
     // Some extra artifacts require special type generation
     is_extra_artifact_branch_module: bool,
+    // Emit enum and input object types instead of importing them from their
+    // modules
+    inline_schema_types: bool,
 }
@@ -0,0 +1,375 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Types of the resolvers of `MockPayloadGenerator` in `relay-test-utils`,
+//! see `TypegenConfig::mock_resolvers`.
+
+use std::fmt::Result as FmtResult;
+
+use ::intern::string_key::Intern;
+use ::intern::string_key::StringKey;
+use ::intern::Lookup;
+use graphql_ir::ExecutableDefinition;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::InlineFragment;
+use graphql_ir::LinkedField;
+use graphql_ir::OperationDefinition;
+use graphql_ir::ScalarField;
+use graphql_ir::Visitor;
+use indexmap::IndexSet;
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+use schema::TypeReference;
+
+use crate::typegen_state::EncounteredEnums;
+use crate::visit::transform_graphql_scalar_type;
+use crate::write::write_custom_scalar_imports;
+use crate::write::write_enum_definitions;
+use crate::write::CustomScalarsImports;
+use crate::writer::ExactObject;
+use crate::writer::InexactObject;
+use crate::writer::KeyValuePairProp;
+use crate::writer::Prop;
+use crate::writer::SortedASTList;
+use crate::writer::StringLiteral;
+use crate::writer::Writer;
+use crate::writer::AST;
+use crate::TypegenContext;
+use crate::KEY_TYPENAME;
+
+lazy_static! {
+    static ref MOCK_RESOLVER_CONTEXT: StringKey = "MockResolverContext".intern();
+    static ref MOCK_RESOLVERS: StringKey = "MockResolvers".intern();
+}
+
+/// The object, interface, union and enum types of the schema that are
+/// reachable from the documents of a project, sorted by name.
+#[derive(Debug, Default)]
+pub struct MockResolverTypes(IndexSet<Type>);
+
+impl MockResolverTypes {
+    fn contains(&self, type_: Type) -> bool {
+        self.0.contains(&type_)
+    }
+}
+
+/// Collects the types of the selections, type conditions and root types of
+/// `definitions` for the `MockResolvers` type.
+pub fn collect_mock_resolver_types(
+    schema: &SDLSchema,
+    definitions: &[ExecutableDefinition],
+) -> MockResolverTypes {
+    let mut collector = MockResolverTypesCollector {
+        schema,
+        types: Default::default(),
+    };
+    for definition in definitions {
+        match definition {
+            ExecutableDefinition::Operation(operation) => collector.visit_operation(operation),
+            ExecutableDefinition::Fragment(fragment) => collector.visit_fragment(fragment),
+        }
+    }
+    MockResolverTypes(
+        collector
+            .types
+            .into_iter()
+            .sorted_by_key(|type_| schema.get_type_name(*type_))
+            .collect(),
+    )
+}
+
+struct MockResolverTypesCollector<'a> {
+    schema: &'a SDLSchema,
+    types: IndexSet<Type>,
+}
+
+impl MockResolverTypesCollector<'_> {
+    fn add_type(&mut self, type_: Type) {
+        if matches!(
+            type_,
+            Type::Object(_) | Type::Interface(_) | Type::Union(_) | Type::Enum(_)
+        ) {
+            self.types.insert(type_);
+        }
+    }
+}
+
+impl Visitor for MockResolverTypesCollector<'_> {
+    const NAME: &'static str = "MockResolverTypesCollector";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_operation(&mut self, operation: &OperationDefinition) {
+        self.add_type(operation.type_);
+        self.default_visit_operation(operation);
+    }
+
+    fn visit_fragment(&mut self, fragment: &FragmentDefinition) {
+        self.add_type(fragment.type_condition);
+        self.default_visit_fragment(fragment);
+    }
+
+    fn visit_inline_fragment(&mut self, fragment: &InlineFragment) {
+        if let Some(type_condition) = fragment.type_condition {
+            self.add_type(type_condition);
+        }
+        self.default_visit_inline_fragment(fragment);
+    }
+
+    fn visit_linked_field(&mut self, field: &LinkedField) {
+        self.add_type(self.schema.field(field.definition.item).type_.inner());
+        self.default_visit_linked_field(field);
+    }
+
+    fn visit_scalar_field(&mut self, field: &ScalarField) {
+        self.add_type(self.schema.field(field.definition.item).type_.inner());
+    }
+}
+
+/// The name of the type of the data returned by the resolver of an object,
+/// interface or union, e.g. `User$mockData`.
+fn mock_data_type_name(type_name: StringKey) -> StringKey {
+    format!("{}$mockData", type_name).intern()
+}
+
+/// Writes a `$mockData` type for each object, interface and union of
+/// `types`, and a `MockResolvers` type with an optional resolver for each
+/// type.
+pub(crate) fn write_mock_resolvers_module_section(
+    typegen_context: &'_ TypegenContext<'_>,
+    types: &MockResolverTypes,
+    writer: &mut Box<dyn Writer>,
+) -> FmtResult {
+    let schema = typegen_context.schema;
+    let mut encountered_enums = EncounteredEnums::default();
+    let mut custom_scalars = CustomScalarsImports::default();
+    let mut mock_data_types = Vec::new();
+    let mut resolvers = Vec::new();
+
+    for type_ in types.0.iter().copied() {
+        let type_name = schema.get_type_name(type_);
+        let return_type = match type_ {
+            Type::Enum(enum_id) => {
+                encountered_enums.0.insert(enum_id);
+                type_name
+            }
+            _ => {
+                let mock_data_type = mock_data_type(
+                    typegen_context,
+                    types,
+                    type_,
+                    &mut encountered_enums,
+                    &mut custom_scalars,
+                );
+                let mock_data_type_name = mock_data_type_name(type_name);
+                mock_data_types.push((mock_data_type_name, mock_data_type));
+                mock_data_type_name
+            }
+        };
+        resolvers.push(Prop::KeyValuePair(KeyValuePairProp {
+            key: type_name,
+            value: AST::RawType(
+                format!(
+                    "(context: {}, generateId: () => number) => {}",
+                    *MOCK_RESOLVER_CONTEXT, return_type
+                )
+                .intern(),
+            ),
+            read_only: true,
+            optional: true,
+            doc_comment: None,
+        }));
+    }
+
+    write_custom_scalar_imports(custom_scalars, writer)?;
+    write_enum_definitions(typegen_context, encountered_enums, writer)?;
+    writer.write_export_type(MOCK_RESOLVER_CONTEXT.lookup(), &mock_resolver_context())?;
+    for (name, mock_data_type) in mock_data_types {
+        writer.write_export_type(name.lookup(), &mock_data_type)?;
+    }
+    writer.write_export_type(
+        MOCK_RESOLVERS.lookup(),
+        &AST::ExactObject(ExactObject::new(resolvers)),
+    )
+}
+
+/// The first argument of mock resolvers.
+fn mock_resolver_context() -> AST {
+    let prop = |key: &str, value: AST| {
+        Prop::KeyValuePair(KeyValuePairProp {
+            key: key.intern(),
+            value: AST::Nullable(Box::new(value)),
+            read_only: true,
+            optional: false,
+            doc_comment: None,
+        })
+    };
+    AST::ExactObject(ExactObject::new(vec![
+        prop("alias", AST::String),
+        prop("args", AST::Mixed),
+        prop("name", AST::String),
+        prop("parentType", AST::String),
+        prop("path", AST::ReadOnlyArray(Box::new(AST::String))),
+    ]))
+}
+
+/// The data that the resolver of an object, interface or union can return:
+/// any of the fields of the type, with the data of the resolvers of their
+/// types for fields of other types that have resolvers.
+fn mock_data_type(
+    typegen_context: &'_ TypegenContext<'_>,
+    types: &MockResolverTypes,
+    type_: Type,
+    encountered_enums: &mut EncounteredEnums,
+    custom_scalars: &mut CustomScalarsImports,
+) -> AST {
+    let schema = typegen_context.schema;
+    let (typename, fields) = match type_ {
+        Type::Object(object_id) => {
+            let object = schema.object(object_id);
+            (
+                AST::StringLiteral(StringLiteral(object.name.item.0)),
+                &object.fields,
+            )
+        }
+        Type::Interface(interface_id) => (AST::String, &schema.interface(interface_id).fields),
+        Type::Union(union_id) => {
+            let members: Vec<AST> = schema
+                .union(union_id)
+                .members
+                .iter()
+                .filter(|member| types.contains(Type::Object(**member)))
+                .map(|member| {
+                    AST::Identifier(mock_data_type_name(schema.object(*member).name.item.0))
+                })
+                .collect();
+            return if members.is_empty() {
+                AST::ExactObject(ExactObject::new(vec![typename_prop(AST::String)]))
+            } else {
+                AST::Union(SortedASTList::new(members))
+            };
+        }
+        _ => panic!("Expected an object, interface or union type"),
+    };
+
+    let mut props = vec![typename_prop(typename)];
+    for field_id in fields {
+        let field = schema.field(*field_id);
+        if field.name.item.lookup().starts_with("__") {
+            continue;
+        }
+        let inner_type = field.type_.inner();
+        if inner_type.is_composite_type() && !types.contains(inner_type) {
+            continue;
+        }
+        props.push(Prop::KeyValuePair(KeyValuePairProp {
+            key: field.name.item,
+            value: transform_field_type(
+                typegen_context,
+                &field.type_,
+                encountered_enums,
+                custom_scalars,
+            ),
+            read_only: true,
+            optional: true,
+            doc_comment: None,
+        }));
+    }
+    AST::ExactObject(ExactObject::new(props))
+}
+
+fn typename_prop(value: AST) -> Prop {
+    Prop::KeyValuePair(KeyValuePairProp {
+        key: *KEY_TYPENAME,
+        value,
+        read_only: true,
+        optional: true,
+        doc_comment: None,
+    })
+}
+
+fn transform_field_type(
+    typegen_context: &'_ TypegenContext<'_>,
+    type_reference: &TypeReference<Type>,
+    encountered_enums: &mut EncounteredEnums,
+    custom_scalars: &mut CustomScalarsImports,
+) -> AST {
+    match type_reference {
+        TypeReference::NonNull(of_type) => transform_non_null_field_type(
+            typegen_context,
+            of_type,
+            encountered_enums,
+            custom_scalars,
+        ),
+        _ => AST::Nullable(Box::new(transform_non_null_field_type(
+            typegen_context,
+            type_reference,
+            encountered_enums,
+            custom_scalars,
+        ))),
+    }
+}
+
+fn transform_non_null_field_type(
+    typegen_context: &'_ TypegenContext<'_>,
+    type_reference: &TypeReference<Type>,
+    encountered_enums: &mut EncounteredEnums,
+    custom_scalars: &mut CustomScalarsImports,
+) -> AST {
+    match type_reference {
+        TypeReference::List(of_type) => AST::ReadOnlyArray(Box::new(transform_field_type(
+            typegen_context,
+            of_type,
+            encountered_enums,
+            custom_scalars,
+        ))),
+        TypeReference::Named(Type::Scalar(scalar_id)) => {
+            transform_graphql_scalar_type(typegen_context, *scalar_id, custom_scalars)
+        }
+        TypeReference::Named(Type::Enum(enum_id)) => {
+            encountered_enums.0.insert(*enum_id);
+            AST::Identifier(typegen_context.schema.enum_(*enum_id).name.item.0)
+        }
+        TypeReference::Named(type_) => AST::Identifier(mock_data_type_name(
+            typegen_context.schema.get_type_name(*type_),
+        )),
+        TypeReference::NonNull(_) => panic!("unexpected NonNull"),
+    }
+}
+
+/// Makes every field of the objects of `ast` optional, for the `$mockData`
+/// type of `@relay_test_operation` operations.
+pub(crate) fn deep_partial(ast: AST) -> AST {
+    match ast {
+        AST::ExactObject(object) => AST::ExactObject(ExactObject::new(
+            object.iter().cloned().map(partial_prop).collect(),
+        )),
+        AST::InexactObject(object) => AST::InexactObject(InexactObject::new(
+            object.iter().cloned().map(partial_prop).collect(),
+        )),
+        AST::ReadOnlyArray(of_type) => AST::ReadOnlyArray(Box::new(deep_partial(*of_type))),
+        AST::Nullable(of_type) => AST::Nullable(Box::new(deep_partial(*of_type))),
+        AST::Union(members) => AST::Union(SortedASTList::new(
+            members.iter().cloned().map(deep_partial).collect(),
+        )),
+        ast => ast,
+    }
+}
+
+fn partial_prop(prop: Prop) -> Prop {
+    match prop {
+        Prop::KeyValuePair(prop) => Prop::KeyValuePair(KeyValuePairProp {
+            value: deep_partial(prop.value),
+            optional: true,
+            ..prop
+        }),
+        prop => prop,
+    }
+}
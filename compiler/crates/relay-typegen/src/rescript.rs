@@ -0,0 +1,729 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::BTreeMap;
+use std::fmt::Result as FmtResult;
+use std::fmt::Write;
+
+use fnv::FnvHashMap;
+use fnv::FnvHashSet;
+use intern::string_key::StringKey;
+use intern::Lookup;
+use itertools::Itertools;
+
+use crate::writer::DocComment;
+use crate::writer::Prop;
+use crate::writer::Writer;
+use crate::writer::AST;
+use crate::FUTURE_ENUM_VALUE;
+use crate::KEY_DATA;
+use crate::KEY_DATA_ID;
+use crate::KEY_FRAGMENT_SPREADS;
+use crate::KEY_FRAGMENT_TYPE;
+
+const RESERVED_WORDS: &[&str] = &[
+    "and",
+    "as",
+    "assert",
+    "async",
+    "await",
+    "constraint",
+    "else",
+    "exception",
+    "external",
+    "false",
+    "for",
+    "if",
+    "in",
+    "include",
+    "lazy",
+    "let",
+    "module",
+    "mutable",
+    "of",
+    "open",
+    "private",
+    "rec",
+    "switch",
+    "true",
+    "try",
+    "type",
+    "when",
+    "while",
+    "with",
+];
+
+/// Prints ReScript types. ReScript records are nominal, so nested objects
+/// are hoisted into their own record types, named after their path, e.g.
+/// `userQuery_data_me` for the `me` field of `UserQuery$data`. All types are
+/// printed as a single group of recursive types, so that they can reference
+/// each other regardless of the order in which they are exported.
+#[derive(Default)]
+pub struct ReScriptPrinter {
+    result: String,
+    /// Type definitions in order, `None` for abstract types.
+    type_definitions: Vec<(String, Option<String>)>,
+    type_names: FnvHashSet<String>,
+    /// Functions converting abstract union types to polymorphic variants.
+    union_converters: Vec<String>,
+    /// The module of each custom scalar with a configured type, by scalar name.
+    custom_scalars: BTreeMap<String, String>,
+    /// Imported types, with the qualified name to print them as.
+    imported_types: FnvHashMap<String, String>,
+}
+
+impl Write for ReScriptPrinter {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        self.result.write_str(s)
+    }
+}
+
+impl Writer for ReScriptPrinter {
+    fn into_string(self: Box<Self>) -> String {
+        let mut output = String::new();
+        for (index, (name, definition)) in self.type_definitions.iter().enumerate() {
+            let keyword = if index == 0 { "type rec" } else { "and" };
+            match definition {
+                Some(definition) => writeln!(output, "{} {} = {}", keyword, name, definition),
+                None => writeln!(output, "{} {}", keyword, name),
+            }
+            .unwrap();
+        }
+        for converter in &self.union_converters {
+            writeln!(output).unwrap();
+            write!(output, "{}", converter).unwrap();
+        }
+        if !self.custom_scalars.is_empty() {
+            writeln!(output).unwrap();
+            writeln!(output, "module Converters = {{").unwrap();
+            for (scalar_name, module) in &self.custom_scalars {
+                writeln!(
+                    output,
+                    "  let parse{}: Js.Json.t => {}.t = {}.parse",
+                    scalar_name, module, module
+                )
+                .unwrap();
+                writeln!(
+                    output,
+                    "  let serialize{}: {}.t => Js.Json.t = {}.serialize",
+                    scalar_name, module, module
+                )
+                .unwrap();
+            }
+            writeln!(output, "}}").unwrap();
+        }
+        output.push_str(&self.result);
+        output
+    }
+
+    fn get_runtime_fragment_import(&self) -> &'static str {
+        "FragmentRefs"
+    }
+
+    fn write(&mut self, ast: &AST) -> FmtResult {
+        let type_ = self.print_type(ast, None);
+        self.result.write_str(&type_)
+    }
+
+    // Provided variables are only available at runtime.
+    fn write_type_assertion(&mut self, _name: &str, _ast: &AST) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_export_type(&mut self, name: &str, ast: &AST) -> FmtResult {
+        let name = type_name(name);
+        match ast {
+            // Objects are defined as the exported type itself.
+            AST::ExactObject(_) | AST::InexactObject(_) | AST::Union(_) => {
+                let type_ = self.print_type(ast, Some(&name));
+                if type_ != name {
+                    self.define_type(&name, Some(type_));
+                }
+            }
+            // Objects in e.g. the items of plural fragments are named
+            // `<name>_value`.
+            _ => {
+                let name = self.reserve_type_name(&name);
+                let index = self.type_definitions.len();
+                self.type_definitions.push((name.clone(), None));
+                let type_ = self.print_type(ast, Some(&format!("{}_value", name)));
+                self.type_definitions[index].1 = Some(type_);
+            }
+        }
+        Ok(())
+    }
+
+    fn write_export_opaque_type(&mut self, name: &str, underlying_type: &AST) -> FmtResult {
+        let type_ = self.print_type(underlying_type, None);
+        self.define_type(&type_name(name), Some(format!("private {}", type_)));
+        Ok(())
+    }
+
+    // Modules don't need to be imported in ReScript.
+    fn write_import_module_default(&mut self, _name: &str, _from: &str) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_import_module_named(
+        &mut self,
+        _name: &str,
+        _import_as: Option<&str>,
+        _from: &str,
+    ) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_import_type(&mut self, types: &[&str], from: &str) -> FmtResult {
+        // Runtime types are printed as ReScript types directly.
+        if from == "relay-runtime" || from.starts_with("react-relay") {
+            return Ok(());
+        }
+        let module = module_name(from);
+        for type_ in types {
+            let (name, import_as) = match type_.split_once(" as ") {
+                Some((name, import_as)) => (name, import_as),
+                None => (*type_, *type_),
+            };
+            self.imported_types.insert(
+                import_as.to_string(),
+                format!("{}.{}", module, type_name(name)),
+            );
+        }
+        Ok(())
+    }
+
+    fn write_import_fragment_type(&mut self, types: &[&str], from: &str) -> FmtResult {
+        self.write_import_type(types, from)
+    }
+
+    fn write_export_fragment_type(&mut self, name: &str) -> FmtResult {
+        let fragment_name = name.strip_suffix("$fragmentType").unwrap_or(name);
+        self.define_type(
+            &type_name(name),
+            Some(format!(
+                "RescriptRelay.fragmentRefs<[{}]>",
+                variant_tag(fragment_name)
+            )),
+        );
+        Ok(())
+    }
+
+    fn write_export_fragment_types(
+        &mut self,
+        _fragment_type_name_1: &str,
+        _fragment_type_name_2: &str,
+    ) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_any_type_definition(&mut self, name: &str) -> FmtResult {
+        self.define_type(&type_name(name), Some("Js.Json.t".to_string()));
+        Ok(())
+    }
+}
+
+impl ReScriptPrinter {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Prints `ast`. Objects are hoisted into record types named after
+    /// `path`, or printed as structural object types without a path.
+    fn print_type(&mut self, ast: &AST, path: Option<&str>) -> String {
+        match ast {
+            AST::Any | AST::Mixed | AST::Never => "Js.Json.t".to_string(),
+            AST::String | AST::OtherTypename => "string".to_string(),
+            AST::Number => "float".to_string(),
+            AST::Boolean => "bool".to_string(),
+            AST::StringLiteral(literal) => format!("[{}]", variant_tag(literal.lookup())),
+            AST::Identifier(name) | AST::RawType(name) => self.type_reference(*name),
+            AST::CustomScalar { name, type_ } => match &**type_ {
+                AST::RawType(module) => {
+                    self.custom_scalars
+                        .insert(name.to_string(), module.to_string());
+                    format!("{}.t", module)
+                }
+                type_ => self.print_type(type_, path),
+            },
+            AST::Union(members) => self.print_union(members, path),
+            AST::ReadOnlyArray(of_type) => format!("array<{}>", self.print_type(of_type, path)),
+            AST::Nullable(of_type) => {
+                format!("Js.Nullable.t<{}>", self.print_type(of_type, path))
+            }
+            AST::NonNullable(of_type) => self.print_type(of_type, path),
+            AST::ExactObject(object) => self.print_object(object, path),
+            AST::InexactObject(object) => self.print_object(object, path),
+            AST::Local3DPayload(_, selections) => self.print_type(selections, path),
+            AST::FragmentReference(fragments) => format!(
+                "RescriptRelay.fragmentRefs<[{}]>",
+                fragments
+                    .iter()
+                    .map(|fragment| variant_tag(fragment.lookup()))
+                    .join(" | ")
+            ),
+            AST::FragmentReferenceType(fragment) => format!(
+                "RescriptRelay.fragmentRefs<[{}]>",
+                variant_tag(fragment.lookup())
+            ),
+            AST::Callable(return_type) => format!("unit => {}", self.print_type(return_type, path)),
+            // Resolver function types are not generated for ReScript.
+            AST::AssertFunctionType(_) => String::new(),
+            AST::ReturnTypeOfFunctionWithName(_)
+            | AST::ReturnTypeOfMethodCall(_, _)
+            | AST::ArrayElementType(_)
+            | AST::ActorChangePoint(_)
+            | AST::GenericType { .. }
+            | AST::PropertyType { .. } => "Js.Json.t".to_string(),
+        }
+    }
+
+    fn type_reference(&self, name: StringKey) -> String {
+        if let Some(imported_type) = self.imported_types.get(name.lookup()) {
+            imported_type.clone()
+        } else if name == *KEY_DATA_ID {
+            "string".to_string()
+        } else {
+            type_name(name.lookup())
+        }
+    }
+
+    /// Enums are printed as polymorphic variants, which are represented as
+    /// strings at runtime. Unions of objects are printed as abstract types,
+    /// with a function converting them to a polymorphic variant of the
+    /// object types by their `__typename`.
+    fn print_union(&mut self, members: &[AST], path: Option<&str>) -> String {
+        if let [member] = members {
+            return self.print_type(member, path);
+        }
+        if members
+            .iter()
+            .all(|member| matches!(member, AST::StringLiteral(_)))
+        {
+            return format!(
+                "[{}]",
+                members
+                    .iter()
+                    .filter_map(|member| match member {
+                        AST::StringLiteral(literal) if **literal != *FUTURE_ENUM_VALUE => {
+                            Some(variant_tag(literal.lookup()))
+                        }
+                        _ => None,
+                    })
+                    .join(" | ")
+            );
+        }
+        let objects = members
+            .iter()
+            .filter_map(|member| match member {
+                AST::ExactObject(object) => Some(&***object),
+                AST::InexactObject(object) => Some(&***object),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        match path {
+            Some(path) if objects.len() == members.len() => self.define_union(path, &objects),
+            _ => "Js.Json.t".to_string(),
+        }
+    }
+
+    fn define_union(&mut self, path: &str, members: &[&[Prop]]) -> String {
+        // The `__typename` field, or an alias of it.
+        let discriminator = members[0].iter().find_map(|prop| match prop {
+            Prop::KeyValuePair(key_value_pair)
+                if members.iter().all(|props| {
+                    props
+                        .iter()
+                        .any(|prop| is_discriminator(prop, key_value_pair.key))
+                }) =>
+            {
+                Some(key_value_pair.key)
+            }
+            _ => None,
+        });
+        let discriminator = match discriminator {
+            Some(discriminator) => discriminator,
+            None => return "Js.Json.t".to_string(),
+        };
+
+        let name = self.reserve_type_name(path);
+        self.type_definitions.push((name.clone(), None));
+
+        let mut cases = vec![];
+        for props in members {
+            let typename = props.iter().find_map(|prop| match prop {
+                Prop::KeyValuePair(key_value_pair) if key_value_pair.key == discriminator => {
+                    match key_value_pair.value {
+                        AST::StringLiteral(literal) => Some(literal),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            });
+            // Selections on other types are converted to `UnselectedUnionMember`.
+            if let Some(typename) = typename {
+                let member_path = format!("{}_{}", name, type_name(typename.lookup()));
+                let member_type = self.print_object(props, Some(&member_path));
+                cases.push((typename, member_type));
+            }
+        }
+
+        let mut converter = String::new();
+        writeln!(
+            converter,
+            "let unwrap_{} = (value: {}): [{}] => {{",
+            name,
+            name,
+            cases
+                .iter()
+                .map(|(typename, member_type)| format!(
+                    "{}({})",
+                    variant_tag(typename.lookup()),
+                    member_type
+                ))
+                .chain(std::iter::once(
+                    "#UnselectedUnionMember(string)".to_string()
+                ))
+                .join(" | ")
+        )
+        .unwrap();
+        writeln!(
+            converter,
+            "  let typename: string = (Obj.magic(value): {{\"{}\": string}})[\"{}\"]",
+            discriminator, discriminator
+        )
+        .unwrap();
+        writeln!(converter, "  switch typename {{").unwrap();
+        for (typename, member_type) in &cases {
+            writeln!(
+                converter,
+                "  | \"{}\" => {}((Obj.magic(value): {}))",
+                typename.lookup(),
+                variant_tag(typename.lookup()),
+                member_type
+            )
+            .unwrap();
+        }
+        writeln!(converter, "  | _ => #UnselectedUnionMember(typename)").unwrap();
+        writeln!(converter, "  }}").unwrap();
+        writeln!(converter, "}}").unwrap();
+        self.union_converters.push(converter);
+        name
+    }
+
+    fn print_object(&mut self, props: &[Prop], path: Option<&str>) -> String {
+        match path {
+            Some(path) => self.define_record(path, props),
+            None => self.print_structural_object(props),
+        }
+    }
+
+    fn define_record(&mut self, path: &str, props: &[Prop]) -> String {
+        let name = self.reserve_type_name(path);
+        // Reserve the position of the record before its nested records.
+        let index = self.type_definitions.len();
+        self.type_definitions.push((name.clone(), None));
+
+        let mut fields = vec![];
+        for prop in props {
+            let (key, value, optional, doc_comment) = match prop {
+                Prop::KeyValuePair(key_value_pair) => (
+                    key_value_pair.key,
+                    &key_value_pair.value,
+                    key_value_pair.optional,
+                    key_value_pair.doc_comment.as_ref(),
+                ),
+                // Records can't have setters, only the getter type is used.
+                Prop::GetterSetterPair(getter_setter_pair) => (
+                    getter_setter_pair.key,
+                    &getter_setter_pair.getter_return_value,
+                    false,
+                    None,
+                ),
+                Prop::Spread(_) => continue,
+            };
+            // These keys are prefixed with a space, so that they can't
+            // conflict with selections.
+            let key =
+                if key == *KEY_FRAGMENT_SPREADS || key == *KEY_FRAGMENT_TYPE || key == *KEY_DATA {
+                    format!(" {}", key)
+                } else {
+                    key.to_string()
+                };
+            let field_name = field_name(&key);
+            let field_path = format!("{}_{}", name, field_name.trim_end_matches('_'));
+            let value = self.print_type(value, Some(&field_path));
+
+            let mut field = String::new();
+            if let Some(doc_comment) = doc_comment {
+                write_doc_comment(&mut field, doc_comment);
+            }
+            if field_name != key {
+                write!(field, "@as(\"{}\") ", key).unwrap();
+            }
+            write!(
+                field,
+                "{}{}: {}",
+                field_name,
+                if optional { "?" } else { "" },
+                value
+            )
+            .unwrap();
+            fields.push(field);
+        }
+
+        self.type_definitions[index].1 = Some(if fields.is_empty() {
+            "{.}".to_string()
+        } else {
+            format!(
+                "{{\n{}}}",
+                fields
+                    .iter()
+                    .map(|field| format!("  {},\n", field))
+                    .join("")
+            )
+        });
+        name
+    }
+
+    fn print_structural_object(&mut self, props: &[Prop]) -> String {
+        let fields = props
+            .iter()
+            .filter_map(|prop| match prop {
+                Prop::KeyValuePair(key_value_pair) => {
+                    Some((key_value_pair.key, &key_value_pair.value))
+                }
+                Prop::GetterSetterPair(getter_setter_pair) => Some((
+                    getter_setter_pair.key,
+                    &getter_setter_pair.getter_return_value,
+                )),
+                Prop::Spread(_) => None,
+            })
+            .collect::<Vec<_>>();
+        if fields.is_empty() {
+            return "{.}".to_string();
+        }
+        format!(
+            "{{{}}}",
+            fields
+                .into_iter()
+                .map(|(key, value)| format!("\"{}\": {}", key, self.print_type(value, None)))
+                .join(", ")
+        )
+    }
+
+    fn define_type(&mut self, name: &str, definition: Option<String>) {
+        let name = self.reserve_type_name(name);
+        self.type_definitions.push((name, definition));
+    }
+
+    /// Returns `name`, or `name` with a numeric suffix if another type is
+    /// already named `name`.
+    fn reserve_type_name(&mut self, name: &str) -> String {
+        let mut unique_name = name.to_string();
+        let mut suffix = 1;
+        while self.type_names.contains(&unique_name) {
+            unique_name = format!("{}_{}", name, suffix);
+            suffix += 1;
+        }
+        self.type_names.insert(unique_name.clone());
+        unique_name
+    }
+}
+
+fn write_doc_comment(output: &mut String, doc_comment: &DocComment) {
+    let lines = doc_comment.lines();
+    match lines.as_slice() {
+        [] => {}
+        [line] => write!(output, "/** {} */\n  ", line).unwrap(),
+        _ => {
+            writeln!(output, "/**").unwrap();
+            for line in lines {
+                if line.is_empty() {
+                    writeln!(output, "   *").unwrap();
+                } else {
+                    writeln!(output, "   * {}", line).unwrap();
+                }
+            }
+            write!(output, "   */\n  ").unwrap();
+        }
+    }
+}
+
+fn is_discriminator(prop: &Prop, key: StringKey) -> bool {
+    match prop {
+        Prop::KeyValuePair(key_value_pair) => {
+            key_value_pair.key == key
+                && matches!(
+                    key_value_pair.value,
+                    AST::StringLiteral(_) | AST::OtherTypename
+                )
+        }
+        _ => false,
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn lowercase_first(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn escape_reserved_word(name: String) -> String {
+    if RESERVED_WORDS.contains(&name.as_str()) {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+
+/// ReScript type names start with a lowercase letter and can't contain `$`,
+/// e.g. `UserQuery$data` is printed as `userQuery_data`.
+fn type_name(name: &str) -> String {
+    escape_reserved_word(lowercase_first(&name.replace('$', "_")))
+}
+
+/// The name of the record field for the property `key`. Fields that are not
+/// valid ReScript identifiers are renamed, and mapped to `key` with `@as`.
+fn field_name(key: &str) -> String {
+    let name: String = key
+        .trim_start_matches(|c: char| !is_identifier_char(c))
+        .chars()
+        .map(|c| if is_identifier_char(c) { c } else { '_' })
+        .collect();
+    escape_reserved_word(lowercase_first(&name))
+}
+
+/// The polymorphic variant tag for `name`, quoted if it's not an identifier.
+fn variant_tag(name: &str) -> String {
+    if name.starts_with(|c: char| c.is_ascii_alphabetic()) && name.chars().all(is_identifier_char) {
+        format!("#{}", name)
+    } else {
+        format!("#\"{}\"", name)
+    }
+}
+
+/// The ReScript module of the generated artifact or module at `path`, e.g.
+/// `UserAvatar_user_graphql` for `./UserAvatar_user.graphql`.
+fn module_name(path: &str) -> String {
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    let file_name = file_name.strip_suffix(".res").unwrap_or(file_name);
+    let mut chars = file_name.chars();
+    match chars.next() {
+        Some(first) => {
+            first.to_ascii_uppercase().to_string() + &chars.as_str().replace(['.', '-'], "_")
+        }
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use intern::string_key::Intern;
+
+    use super::*;
+    use crate::writer::ExactObject;
+    use crate::writer::KeyValuePairProp;
+    use crate::writer::SortedASTList;
+    use crate::writer::StringLiteral;
+
+    fn print_type(ast: &AST) -> String {
+        let mut printer = Box::new(ReScriptPrinter::new());
+        printer.write(ast).unwrap();
+        printer.into_string()
+    }
+
+    fn print_export_type(name: &str, ast: &AST) -> String {
+        let mut printer = Box::new(ReScriptPrinter::new());
+        printer.write_export_type(name, ast).unwrap();
+        printer.into_string()
+    }
+
+    #[test]
+    fn scalar_types() {
+        assert_eq!(print_type(&AST::Any), "Js.Json.t".to_string());
+        assert_eq!(print_type(&AST::String), "string".to_string());
+        assert_eq!(print_type(&AST::Number), "float".to_string());
+        assert_eq!(print_type(&AST::Boolean), "bool".to_string());
+    }
+
+    #[test]
+    fn nullable_array_type() {
+        assert_eq!(
+            print_type(&AST::Nullable(Box::new(AST::ReadOnlyArray(Box::new(
+                AST::String
+            ))))),
+            "Js.Nullable.t<array<string>>".to_string()
+        );
+    }
+
+    #[test]
+    fn enum_type() {
+        assert_eq!(
+            print_type(&AST::Union(SortedASTList::new(vec![
+                AST::StringLiteral(StringLiteral(*FUTURE_ENUM_VALUE)),
+                AST::StringLiteral(StringLiteral("RED".intern())),
+                AST::StringLiteral(StringLiteral("dark-blue".intern())),
+            ]))),
+            r#"[#RED | #"dark-blue"]"#.to_string()
+        );
+    }
+
+    #[test]
+    fn nested_record() {
+        assert_eq!(
+            print_export_type(
+                "Foo$data",
+                &AST::ExactObject(ExactObject::new(vec![
+                    Prop::KeyValuePair(KeyValuePairProp {
+                        key: "type".intern(),
+                        optional: true,
+                        read_only: true,
+                        value: AST::String,
+                        doc_comment: None,
+                    }),
+                    Prop::KeyValuePair(KeyValuePairProp {
+                        key: "Bar".intern(),
+                        optional: false,
+                        read_only: true,
+                        value: AST::Nullable(Box::new(AST::ExactObject(ExactObject::new(vec![
+                            Prop::KeyValuePair(KeyValuePairProp {
+                                key: "id".intern(),
+                                optional: false,
+                                read_only: true,
+                                value: AST::String,
+                                doc_comment: None,
+                            })
+                        ])))),
+                        doc_comment: None,
+                    }),
+                ]))
+            ),
+            r#"type rec foo_data = {
+  @as("Bar") bar: Js.Nullable.t<foo_data_bar>,
+  @as("type") type_?: string,
+}
+and foo_data_bar = {
+  id: string,
+}
+"#
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn module_names() {
+        assert_eq!(module_name("./Foo_user.graphql"), "Foo_user_graphql");
+        assert_eq!(module_name("../SchemaTypes"), "SchemaTypes");
+        assert_eq!(module_name("scalars/dateTime.res"), "DateTime");
+    }
+}
@@ -0,0 +1,526 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::BTreeSet;
+use std::fmt::Result as FmtResult;
+use std::fmt::Write;
+
+use fnv::FnvHashMap;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use intern::Lookup;
+use itertools::Itertools;
+use relay_config::ProjectConfig;
+use relay_config::RuntimeValidatorLibrary;
+use relay_config::RuntimeValidatorsConfig;
+use relay_config::TypegenLanguage;
+
+use crate::writer::Prop;
+use crate::writer::StringLiteral;
+use crate::writer::Writer;
+use crate::writer::AST;
+use crate::FUTURE_ENUM_VALUE;
+use crate::KEY_TYPENAME;
+
+static CUSTOM_SCALAR_PARSERS: &str = "customScalarParsers";
+static VALIDATORS_EXPORT_NAME: &str = "validators";
+
+/// Prints runtime validators instead of types. The type exports section is
+/// written as usual, and the exported types are collected: the `$data`,
+/// `$variables` and `$rawResponse` types are exported as validators, and the
+/// enums, input objects and `@module` types they reference are declared as
+/// local validators.
+pub struct RuntimeValidatorsPrinter {
+    library: RuntimeValidatorLibrary,
+    custom_scalar_parsers: Option<String>,
+    is_es_module: bool,
+    is_typescript: bool,
+    exported_types: Vec<(String, AST)>,
+    result: String,
+    indentation: usize,
+    referenced_locals: BTreeSet<StringKey>,
+    has_custom_scalar_parsers: bool,
+}
+
+impl Write for RuntimeValidatorsPrinter {
+    fn write_str(&mut self, _s: &str) -> FmtResult {
+        Ok(())
+    }
+}
+
+impl Writer for RuntimeValidatorsPrinter {
+    fn into_string(self: Box<Self>) -> String {
+        let mut printer = *self;
+        printer.print_validators().unwrap();
+        printer.result
+    }
+
+    fn get_runtime_fragment_import(&self) -> &'static str {
+        ""
+    }
+
+    fn write(&mut self, _ast: &AST) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_type_assertion(&mut self, _name: &str, _ast: &AST) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_export_type(&mut self, name: &str, ast: &AST) -> FmtResult {
+        self.exported_types.push((name.to_string(), ast.clone()));
+        Ok(())
+    }
+
+    fn write_export_opaque_type(&mut self, name: &str, underlying_type: &AST) -> FmtResult {
+        self.write_export_type(name, underlying_type)
+    }
+
+    fn write_import_module_default(&mut self, _name: &str, _from: &str) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_import_module_named(
+        &mut self,
+        _name: &str,
+        _import_as: Option<&str>,
+        _from: &str,
+    ) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_import_type(&mut self, _types: &[&str], _from: &str) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_import_fragment_type(&mut self, _types: &[&str], _from: &str) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_export_fragment_type(&mut self, _name: &str) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_export_fragment_types(
+        &mut self,
+        _fragment_type_name_1: &str,
+        _fragment_type_name_2: &str,
+    ) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_any_type_definition(&mut self, _name: &str) -> FmtResult {
+        Ok(())
+    }
+}
+
+impl RuntimeValidatorsPrinter {
+    pub fn new(project_config: &ProjectConfig, config: &RuntimeValidatorsConfig) -> Self {
+        let typegen_config = &project_config.typegen_config;
+        Self {
+            library: config.library,
+            custom_scalar_parsers: config.custom_scalar_parsers.clone(),
+            is_es_module: project_config.is_es_module()
+                || typegen_config.language == TypegenLanguage::TypeScript,
+            is_typescript: typegen_config.language == TypegenLanguage::TypeScript,
+            exported_types: Default::default(),
+            result: Default::default(),
+            indentation: 0,
+            referenced_locals: Default::default(),
+            has_custom_scalar_parsers: false,
+        }
+    }
+
+    fn print_validators(&mut self) -> FmtResult {
+        let exported_types = std::mem::take(&mut self.exported_types);
+        let mut validators = vec![];
+        let mut locals = FnvHashMap::default();
+        for (name, ast) in exported_types {
+            if let Some((_, suffix)) = name.rsplit_once('$') {
+                if matches!(suffix, "variables" | "data" | "rawResponse") {
+                    validators.push((suffix.to_string(), ast));
+                }
+            } else {
+                locals.insert(name.intern(), ast);
+            }
+        }
+        if validators.is_empty() {
+            return Ok(());
+        }
+
+        let validators_value = self.capture(|printer| {
+            writeln!(printer.result, "{{")?;
+            printer.indentation += 1;
+            for (key, ast) in &validators {
+                printer.write_indentation()?;
+                write!(printer.result, "{}: ", key)?;
+                printer.print(ast, &locals)?;
+                writeln!(printer.result, ",")?;
+            }
+            printer.indentation -= 1;
+            write!(printer.result, "}}")
+        })?;
+
+        // Locals can reference other locals, print them until no new ones
+        // are referenced.
+        let mut printed_locals = FnvHashMap::default();
+        loop {
+            let pending: Vec<_> = self
+                .referenced_locals
+                .iter()
+                .filter(|name| !printed_locals.contains_key(*name))
+                .copied()
+                .collect();
+            if pending.is_empty() {
+                break;
+            }
+            for name in pending {
+                let local = self.capture(|printer| printer.write_local(&locals[&name], &locals))?;
+                printed_locals.insert(name, local);
+            }
+        }
+
+        self.write_imports()?;
+        let declaration = if self.is_typescript { "const" } else { "var" };
+        for name in self
+            .referenced_locals
+            .iter()
+            .sorted_by_key(|name| name.lookup())
+        {
+            writeln!(
+                self.result,
+                "{} {} = {};",
+                declaration,
+                local_name(*name),
+                printed_locals[name]
+            )?;
+        }
+        if self.is_es_module {
+            writeln!(
+                self.result,
+                "export const {} = {};",
+                VALIDATORS_EXPORT_NAME, validators_value
+            )
+        } else {
+            writeln!(
+                self.result,
+                "module.exports.{} = {};",
+                VALIDATORS_EXPORT_NAME, validators_value
+            )
+        }
+    }
+
+    /// Returns what `f` prints, instead of appending it to the result.
+    fn capture(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> FmtResult,
+    ) -> Result<String, std::fmt::Error> {
+        let result = std::mem::take(&mut self.result);
+        f(self)?;
+        Ok(std::mem::replace(&mut self.result, result))
+    }
+
+    fn write_imports(&mut self) -> FmtResult {
+        match (self.library, self.is_es_module) {
+            (RuntimeValidatorLibrary::Zod, true) => {
+                writeln!(self.result, "import {{ z }} from \"zod\";")?
+            }
+            (RuntimeValidatorLibrary::Zod, false) => {
+                writeln!(self.result, "var z = require(\"zod\").z;")?
+            }
+            (RuntimeValidatorLibrary::Valibot, true) => {
+                writeln!(self.result, "import * as v from \"valibot\";")?
+            }
+            (RuntimeValidatorLibrary::Valibot, false) => {
+                writeln!(self.result, "var v = require(\"valibot\");")?
+            }
+        }
+        if self.has_custom_scalar_parsers {
+            let module = self.custom_scalar_parsers.as_ref().unwrap();
+            if self.is_es_module {
+                writeln!(
+                    self.result,
+                    "import * as {} from \"{}\";",
+                    CUSTOM_SCALAR_PARSERS, module
+                )?;
+            } else {
+                writeln!(
+                    self.result,
+                    "var {} = require(\"{}\");",
+                    CUSTOM_SCALAR_PARSERS, module
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn print(&mut self, ast: &AST, locals: &FnvHashMap<StringKey, AST>) -> FmtResult {
+        match ast {
+            AST::String => self.write_call("string", &[]),
+            AST::Number => self.write_call("number", &[]),
+            AST::Boolean => self.write_call("boolean", &[]),
+            AST::StringLiteral(literal) => {
+                self.write_call("literal", &[&format!("\"{}\"", **literal)])
+            }
+            AST::OtherTypename => self.write_call("string", &[]),
+            AST::Any | AST::RawType(_) => self.write_call("any", &[]),
+            AST::Nullable(of_type) => self.write_wrapped("nullish", of_type, locals),
+            AST::NonNullable(of_type) => self.print(of_type, locals),
+            AST::ReadOnlyArray(of_type) => {
+                write!(self.result, "{}.array(", self.namespace())?;
+                self.print(of_type, locals)?;
+                write!(self.result, ")")
+            }
+            AST::ExactObject(props) => self.write_object(props, &[], locals),
+            AST::InexactObject(props) => self.write_object(props, &[], locals),
+            AST::Union(members) => self.write_union(members, locals),
+            AST::Identifier(name) => {
+                if locals.contains_key(name) {
+                    self.referenced_locals.insert(*name);
+                    write!(self.result, "{}", local_name(*name))
+                } else {
+                    self.write_call("unknown", &[])
+                }
+            }
+            AST::CustomScalar { name, type_ } => {
+                if self.custom_scalar_parsers.is_some() {
+                    self.has_custom_scalar_parsers = true;
+                    write!(self.result, "{}.{}", CUSTOM_SCALAR_PARSERS, name)
+                } else {
+                    self.print(type_, locals)
+                }
+            }
+            AST::Local3DPayload(_, _)
+            | AST::Callable(_)
+            | AST::Mixed
+            | AST::Never
+            | AST::FragmentReference(_)
+            | AST::FragmentReferenceType(_)
+            | AST::ReturnTypeOfFunctionWithName(_)
+            | AST::ReturnTypeOfMethodCall(_, _)
+            | AST::ArrayElementType(_)
+            | AST::ActorChangePoint(_)
+            | AST::AssertFunctionType(_)
+            | AST::GenericType { .. }
+            | AST::PropertyType { .. } => self.write_call("unknown", &[]),
+        }
+    }
+
+    fn namespace(&self) -> &'static str {
+        match self.library {
+            RuntimeValidatorLibrary::Zod => "z",
+            RuntimeValidatorLibrary::Valibot => "v",
+        }
+    }
+
+    fn write_call(&mut self, function: &str, arguments: &[&str]) -> FmtResult {
+        write!(
+            self.result,
+            "{}.{}({})",
+            self.namespace(),
+            function,
+            arguments.join(", ")
+        )
+    }
+
+    /// Zod wraps schemas with methods, Valibot with functions:
+    /// `z.string().nullish()` and `v.nullish(v.string())`.
+    fn write_wrapped(
+        &mut self,
+        wrapper: &str,
+        of_type: &AST,
+        locals: &FnvHashMap<StringKey, AST>,
+    ) -> FmtResult {
+        match self.library {
+            RuntimeValidatorLibrary::Zod => {
+                self.print(of_type, locals)?;
+                write!(self.result, ".{}()", wrapper)
+            }
+            RuntimeValidatorLibrary::Valibot => {
+                write!(self.result, "v.{}(", wrapper)?;
+                self.print(of_type, locals)?;
+                write!(self.result, ")")
+            }
+        }
+    }
+
+    /// Input objects can be recursive, so they are wrapped in `lazy`.
+    fn write_local(&mut self, ast: &AST, locals: &FnvHashMap<StringKey, AST>) -> FmtResult {
+        if let AST::Union(_) = ast {
+            return self.print(ast, locals);
+        }
+        write!(self.result, "{}.lazy(() => ", self.namespace())?;
+        self.print(ast, locals)?;
+        write!(self.result, ")")
+    }
+
+    fn write_indentation(&mut self) -> FmtResult {
+        self.result.write_str(&"  ".repeat(self.indentation))
+    }
+
+    /// Writes an object validator. Type-only properties like
+    /// `$fragmentSpreads` are skipped, and unknown properties are allowed
+    /// since the response contains the fields of masked fragments.
+    /// `other_typenames` are the typenames that a `%other` typename can't be.
+    fn write_object(
+        &mut self,
+        props: &[Prop],
+        other_typenames: &[StringKey],
+        locals: &FnvHashMap<StringKey, AST>,
+    ) -> FmtResult {
+        let key_value_pairs: Vec<_> = props
+            .iter()
+            .filter_map(|prop| match prop {
+                Prop::KeyValuePair(key_value_pair)
+                    if !key_value_pair.key.lookup().starts_with('$') =>
+                {
+                    Some(key_value_pair)
+                }
+                _ => None,
+            })
+            .collect();
+        if key_value_pairs.is_empty() {
+            return write!(self.result, "{}.object({{}})", self.namespace());
+        }
+        writeln!(self.result, "{}.object({{", self.namespace())?;
+        self.indentation += 1;
+        for key_value_pair in key_value_pairs {
+            let key = key_value_pair.key;
+            self.write_indentation()?;
+            write!(self.result, "{}: ", key)?;
+            if let AST::OtherTypename = key_value_pair.value {
+                self.write_other_typename(other_typenames)?;
+            } else if key_value_pair.optional && !matches!(key_value_pair.value, AST::Nullable(_)) {
+                self.write_wrapped("optional", &key_value_pair.value, locals)?;
+            } else {
+                self.print(&key_value_pair.value, locals)?;
+            }
+            writeln!(self.result, ",")?;
+        }
+        self.indentation -= 1;
+        self.write_indentation()?;
+        write!(self.result, "}})")
+    }
+
+    /// `%other` stands for any typename that isn't selected in the other
+    /// members of the union.
+    fn write_other_typename(&mut self, other_typenames: &[StringKey]) -> FmtResult {
+        let typenames = other_typenames
+            .iter()
+            .map(|typename| format!("\"{}\"", typename))
+            .collect::<Vec<_>>()
+            .join(", ");
+        match self.library {
+            RuntimeValidatorLibrary::Zod => write!(
+                self.result,
+                "z.string().refine((typename) => ![{}].includes(typename))",
+                typenames
+            ),
+            RuntimeValidatorLibrary::Valibot => write!(
+                self.result,
+                "v.pipe(v.string(), v.notValues([{}]))",
+                typenames
+            ),
+        }
+    }
+
+    fn write_union(&mut self, members: &[AST], locals: &FnvHashMap<StringKey, AST>) -> FmtResult {
+        if let [member] = members {
+            return self.print(member, locals);
+        }
+
+        // Enums: a union of string literals, which accepts any string if it
+        // includes the future added value.
+        if members
+            .iter()
+            .all(|member| matches!(member, AST::StringLiteral(_)))
+        {
+            if members.contains(&AST::StringLiteral(StringLiteral(*FUTURE_ENUM_VALUE))) {
+                return self.write_call("string", &[]);
+            }
+            let values = members
+                .iter()
+                .map(|member| match member {
+                    AST::StringLiteral(literal) => format!("\"{}\"", **literal),
+                    _ => unreachable!(),
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            let function = match self.library {
+                RuntimeValidatorLibrary::Zod => "enum",
+                RuntimeValidatorLibrary::Valibot => "picklist",
+            };
+            return self.write_call(function, &[&format!("[{}]", values)]);
+        }
+
+        // Selections on abstract types: objects discriminated by `__typename`.
+        let typenames: Option<Vec<_>> = members.iter().map(typename).collect();
+        if let Some(typenames) = typenames {
+            let concrete_typenames: Vec<StringKey> =
+                typenames.iter().filter_map(|typename| *typename).collect();
+            let has_other_typename = concrete_typenames.len() < typenames.len();
+            if has_other_typename {
+                write!(self.result, "{}.union([", self.namespace())?;
+            } else {
+                match self.library {
+                    RuntimeValidatorLibrary::Zod => {
+                        write!(self.result, "z.discriminatedUnion(\"{}\", [", *KEY_TYPENAME)?
+                    }
+                    RuntimeValidatorLibrary::Valibot => {
+                        write!(self.result, "v.variant(\"{}\", [", *KEY_TYPENAME)?
+                    }
+                }
+            }
+            for (index, member) in members.iter().enumerate() {
+                if index > 0 {
+                    write!(self.result, ", ")?;
+                }
+                let props: &[Prop] = match member {
+                    AST::ExactObject(props) => props,
+                    AST::InexactObject(props) => props,
+                    _ => unreachable!(),
+                };
+                self.write_object(props, &concrete_typenames, locals)?;
+            }
+            return write!(self.result, "])");
+        }
+
+        write!(self.result, "{}.union([", self.namespace())?;
+        for (index, member) in members.iter().enumerate() {
+            if index > 0 {
+                write!(self.result, ", ")?;
+            }
+            self.print(member, locals)?;
+        }
+        write!(self.result, "])")
+    }
+}
+
+/// The `__typename` of a union member: `Some(Some(name))` for a concrete
+/// typename, `Some(None)` for `%other`, and `None` if the member is not an
+/// object with a typename.
+fn typename(member: &AST) -> Option<Option<StringKey>> {
+    let props: &[Prop] = match member {
+        AST::ExactObject(props) => props,
+        AST::InexactObject(props) => props,
+        _ => return None,
+    };
+    props.iter().find_map(|prop| match prop {
+        Prop::KeyValuePair(key_value_pair) if key_value_pair.key == *KEY_TYPENAME => {
+            match key_value_pair.value {
+                AST::StringLiteral(literal) => Some(Some(*literal)),
+                AST::OtherTypename => Some(None),
+                _ => None,
+            }
+        }
+        _ => None,
+    })
+}
+
+fn local_name(name: StringKey) -> String {
+    format!("{}Validator", name)
+}
@@ -14,6 +14,7 @@ use relay_transforms::TypeConditionInfo;
 use schema::Type;
 use schema::TypeReference;
 
+use crate::writer::DocComment;
 use crate::writer::AST;
 use crate::JS_FIELD_NAME;
 use crate::KEY_CLIENTID;
@@ -137,6 +138,9 @@ pub(crate) struct TypeSelectionLinkedField {
     pub(crate) node_selections: TypeSelectionMap,
     pub(crate) conditional: bool,
     pub(crate) concrete_type: Option<Type>,
+    pub(crate) doc_comment: Option<DocComment>,
+    /// Whether the field is read as a result, see `CatchTo::Result`.
+    pub(crate) catch_to_result: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -146,6 +150,7 @@ pub(crate) struct TypeSelectionScalarField {
     pub(crate) value: AST,
     pub(crate) conditional: bool,
     pub(crate) concrete_type: Option<Type>,
+    pub(crate) doc_comment: Option<DocComment>,
 }
 
 #[derive(Debug, Clone)]
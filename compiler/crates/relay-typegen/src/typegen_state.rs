@@ -21,7 +21,6 @@ use schema::EnumID;
 use schema::SDLSchema;
 use schema::Schema;
 
-use crate::writer::ExactObject;
 use crate::writer::Writer;
 use crate::writer::AST;
 use crate::KEY_DATA_ID;
@@ -65,14 +64,14 @@ impl RuntimeImports {
 /// An enum used to prevent redundantly processing input objects.
 pub(crate) enum GeneratedInputObject {
     Pending,
-    Resolved(ExactObject),
+    Resolved(AST),
 }
 
 impl GeneratedInputObject {
-    pub(crate) fn unwrap_resolved_type(self) -> ExactObject {
+    pub(crate) fn unwrap_resolved_type(self) -> AST {
         match self {
             GeneratedInputObject::Pending => panic!("Unexpected pending type"),
-            GeneratedInputObject::Resolved(exact_object) => exact_object,
+            GeneratedInputObject::Resolved(ast) => ast,
         }
     }
 }
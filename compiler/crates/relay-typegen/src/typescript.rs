@@ -13,6 +13,8 @@ use intern::string_key::StringKey;
 use itertools::Itertools;
 use relay_config::TypegenConfig;
 
+use crate::writer::write_doc_comment;
+use crate::writer::DocCommentStyle;
 use crate::writer::Prop;
 use crate::writer::SortedASTList;
 use crate::writer::SortedStringKeyList;
@@ -49,6 +51,7 @@ impl Writer for TypeScriptPrinter {
         match ast {
             AST::Any => write!(&mut self.result, "any"),
             AST::Mixed => write!(&mut self.result, "unknown"),
+            AST::Never => write!(&mut self.result, "never"),
             AST::String => write!(&mut self.result, "string"),
             AST::StringLiteral(literal) => self.write_string_literal(**literal),
             AST::OtherTypename => self.write_other_string(),
@@ -57,6 +60,7 @@ impl Writer for TypeScriptPrinter {
             AST::Callable(return_type) => self.write_callable(&*return_type),
             AST::Identifier(identifier) => write!(&mut self.result, "{}", identifier),
             AST::RawType(raw) => write!(&mut self.result, "{}", raw),
+            AST::CustomScalar { type_, .. } => self.write(type_),
             AST::Union(members) => self.write_union(members),
             AST::ReadOnlyArray(of_type) => self.write_read_only_array(of_type),
             AST::Nullable(of_type) => self.write_nullable(of_type),
@@ -77,6 +81,10 @@ impl Writer for TypeScriptPrinter {
             AST::ReturnTypeOfMethodCall(object, method_name) => {
                 self.write_return_type_of_method_call(object, *method_name)
             }
+            AST::ArrayElementType(array) => {
+                self.write(array)?;
+                write!(&mut self.result, "[number]")
+            }
             AST::AssertFunctionType(_) => {
                 // TODO: Implement type generation for typescript
                 Ok(())
@@ -104,6 +112,18 @@ impl Writer for TypeScriptPrinter {
         writeln!(&mut self.result, ";")
     }
 
+    // TypeScript has no opaque types, the type is branded with a property
+    // that doesn't exist at runtime instead.
+    fn write_export_opaque_type(&mut self, name: &str, underlying_type: &AST) -> FmtResult {
+        write!(&mut self.result, "export type {} = ", name)?;
+        self.write(underlying_type)?;
+        writeln!(
+            &mut self.result,
+            " & {{ readonly \" $brand\": \"{}\" }};",
+            name
+        )
+    }
+
     fn write_import_module_default(&mut self, name: &str, from: &str) -> FmtResult {
         let from_without_extension = from.strip_suffix(".ts").unwrap_or(from);
         writeln!(
@@ -257,6 +277,14 @@ impl TypeScriptPrinter {
                 Prop::Spread(_) => continue,
                 Prop::KeyValuePair(key_value_pair) => {
                     self.write_indentation()?;
+                    if let Some(doc_comment) = &key_value_pair.doc_comment {
+                        write_doc_comment(
+                            &mut self.result,
+                            doc_comment,
+                            self.indentation,
+                            DocCommentStyle::Block,
+                        )?;
+                    }
                     if let AST::OtherTypename = key_value_pair.value {
                         writeln!(
                             &mut self.result,
@@ -435,6 +463,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 })
             ]))),
             r"{
@@ -449,12 +478,14 @@ mod tests {
                     optional: true,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: "bar".intern(),
                     optional: false,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 }),
             ]))),
             r"{
@@ -479,20 +510,24 @@ mod tests {
                             optional: true,
                             read_only: false,
                             value: AST::String,
+                            doc_comment: None,
                         }),
                         Prop::KeyValuePair(KeyValuePairProp {
                             key: "nested_foo2".intern(),
                             optional: false,
                             read_only: true,
                             value: AST::Number,
+                            doc_comment: None,
                         }),
                     ])),
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: "bar".intern(),
                     optional: false,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 }),
             ]))),
             r"{
@@ -520,6 +555,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
             ]))),
             r"{
@@ -535,12 +571,14 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: "bar".intern(),
                     optional: true,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 })
             ]))),
             r"{
@@ -560,6 +598,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::OtherTypename,
+                    doc_comment: None,
                 }),
             ]))),
             r#"{
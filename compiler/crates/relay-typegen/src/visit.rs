@@ -36,6 +36,8 @@ use relay_config::TypegenLanguage;
 use relay_schema::CUSTOM_SCALAR_DIRECTIVE_NAME;
 use relay_schema::EXPORT_NAME_CUSTOM_SCALAR_ARGUMENT_NAME;
 use relay_schema::PATH_CUSTOM_SCALAR_ARGUMENT_NAME;
+use relay_transforms::CatchMetadataDirective;
+use relay_transforms::CatchTo;
 use relay_transforms::ClientEdgeMetadata;
 use relay_transforms::FragmentAliasMetadata;
 use relay_transforms::FragmentDataInjectionMode;
@@ -50,6 +52,7 @@ use relay_transforms::CHILDREN_CAN_BUBBLE_METADATA_KEY;
 use relay_transforms::CLIENT_EXTENSION_DIRECTIVE_NAME;
 use relay_transforms::RELAY_ACTOR_CHANGE_DIRECTIVE_FOR_CODEGEN;
 use relay_transforms::UPDATABLE_DIRECTIVE_FOR_TYPEGEN;
+use relay_transforms::WITHIN_CATCH_METADATA_KEY;
 use schema::EnumID;
 use schema::Field;
 use schema::SDLSchema;
@@ -58,6 +61,8 @@ use schema::Schema;
 use schema::Type;
 use schema::TypeReference;
 
+use crate::id_types::field_id_type;
+use crate::id_types::VariableIdTypes;
 use crate::type_selection::ModuleDirective;
 use crate::type_selection::RawResponseFragmentSpread;
 use crate::type_selection::ScalarFieldSpecialSchemaField;
@@ -81,6 +86,7 @@ use crate::typegen_state::InputObjectTypes;
 use crate::typegen_state::MatchFields;
 use crate::typegen_state::RuntimeImports;
 use crate::write::CustomScalarsImports;
+use crate::writer::DocComment;
 use crate::writer::ExactObject;
 use crate::writer::FunctionTypeAssertion;
 use crate::writer::GetterSetterPairProp;
@@ -285,12 +291,16 @@ fn visit_fragment_spread(
             // We will model the types as a linked filed containing just the fragment spread.
             TypeSelection::LinkedField(TypeSelectionLinkedField {
                 field_name_or_alias: fragment_alias_metadata.alias.item,
-                // If/when @required is supported here, we would apply that to this type reference.
                 // TODO: What about plural fragments, is that just handled by the parent?
-                node_type: TypeReference::Named(fragment_alias_metadata.selection_type),
+                node_type: apply_required_directive_nullability(
+                    &TypeReference::Named(fragment_alias_metadata.selection_type),
+                    &fragment_spread.directives,
+                ),
                 node_selections: selections_to_map(vec![spread_selection].into_iter(), true),
                 conditional: false,
                 concrete_type: None,
+                doc_comment: None,
+                catch_to_result: is_catch_to_result(&fragment_spread.directives),
             })
         } else {
             spread_selection
@@ -362,7 +372,7 @@ fn generate_resolver_type(
 
     let return_type = if matches!(
         typegen_context.project_config.typegen_config.language,
-        TypegenLanguage::TypeScript
+        TypegenLanguage::TypeScript | TypegenLanguage::ReScript
     ) {
         // TODO: Add proper support for Resolver type generation in typescript
         AST::Any
@@ -372,6 +382,8 @@ fn generate_resolver_type(
             outer: *LIVE_STATE_TYPE,
             inner: Box::new(ast),
         }
+    } else if resolver_metadata.batch {
+        AST::ReadOnlyArray(Box::new(ast))
     } else {
         ast
     };
@@ -405,16 +417,22 @@ fn get_resolver_arguments(
                         .0
                         .insert(EncounteredFragment::Data(fragment_name.item));
 
+                    let value = AST::PropertyType {
+                        type_: Box::new(AST::RawType(
+                            format!("{}$data", fragment_name.item).intern(),
+                        )),
+                        property_name: name,
+                    };
                     resolver_arguments.push(KeyValuePairProp {
                         key: name,
-                        value: AST::PropertyType {
-                            type_: Box::new(AST::RawType(
-                                format!("{}$data", fragment_name.item).intern(),
-                            )),
-                            property_name: name,
+                        value: if resolver_metadata.batch {
+                            AST::ReadOnlyArray(Box::new(value))
+                        } else {
+                            value
                         },
                         read_only: false,
                         optional: false,
+                        doc_comment: None,
                     });
                 }
             }
@@ -422,11 +440,18 @@ fn get_resolver_arguments(
             encountered_fragments
                 .0
                 .insert(EncounteredFragment::Key(fragment_name));
+            let root_key = AST::RawType(format!("{fragment_name}$key").intern());
+            let (key, value) = if resolver_metadata.batch {
+                ("rootKeys", AST::ReadOnlyArray(Box::new(root_key)))
+            } else {
+                ("rootKey", root_key)
+            };
             resolver_arguments.push(KeyValuePairProp {
-                key: "rootKey".intern(),
-                value: AST::RawType(format!("{fragment_name}$key").intern()),
+                key: key.intern(),
+                value,
                 read_only: false,
                 optional: false,
+                doc_comment: None,
             });
         }
     }
@@ -434,7 +459,7 @@ fn get_resolver_arguments(
     let mut args = vec![];
     for field_argument in schema_field.arguments.iter() {
         args.push(Prop::KeyValuePair(KeyValuePairProp {
-            key: field_argument.name.0,
+            key: field_argument.name.item.0,
             optional: false,
             read_only: false,
             value: transform_input_type(
@@ -444,6 +469,7 @@ fn get_resolver_arguments(
                 encountered_enums,
                 custom_scalars,
             ),
+            doc_comment: None,
         }));
     }
     if !args.is_empty() {
@@ -452,6 +478,7 @@ fn get_resolver_arguments(
             value: AST::ExactObject(ExactObject::new(args)),
             read_only: true,
             optional: false,
+            doc_comment: None,
         });
     }
     resolver_arguments
@@ -487,6 +514,10 @@ fn import_relay_resolver_function_type(
             .artifact_path_for_definition(typegen_context.definition_source_location),
         &PathBuf::from(resolver_metadata.import_path.lookup()),
     );
+    let import_path = typegen_context
+        .project_config
+        .js_source_module_import_specifier(import_path.lookup())
+        .intern();
 
     let imported_resolver = ImportedResolver {
         resolver_name,
@@ -565,6 +596,8 @@ fn relay_resolver_field_type(
         let inner_value = AST::ReturnTypeOfFunctionWithName(local_resolver_name);
         let inner_value = if live {
             AST::ReturnTypeOfMethodCall(Box::new(inner_value), intern!("read"))
+        } else if resolver_metadata.batch {
+            AST::ArrayElementType(Box::new(inner_value))
         } else {
             inner_value
         };
@@ -627,6 +660,10 @@ fn visit_relay_resolver(
         value: resolver_type,
         conditional: false,
         concrete_type: None,
+        doc_comment: field_doc_comment(
+            typegen_context,
+            resolver_metadata.field(typegen_context.schema),
+        ),
     }));
 }
 
@@ -717,6 +754,7 @@ fn visit_inline_fragment(
             value: AST::Nullable(Box::new(AST::String)),
             conditional: false,
             concrete_type: None,
+            doc_comment: None,
         }));
         type_selections.push(TypeSelection::ScalarField(TypeSelectionScalarField {
             field_name_or_alias: *MODULE_COMPONENT,
@@ -724,6 +762,7 @@ fn visit_inline_fragment(
             value: AST::Nullable(Box::new(AST::String)),
             conditional: false,
             concrete_type: None,
+            doc_comment: None,
         }));
         type_selections.push(TypeSelection::InlineFragment(TypeSelectionInlineFragment {
             fragment_name: name,
@@ -794,12 +833,17 @@ fn visit_inline_fragment(
                 // possibility that a `@required` has bubbled up to this
                 // field.
 
-                // Additionally, if/when @required is supported _on_ aliased
-                // fragments, we would apply that to this type reference.
-                node_type: TypeReference::Named(fragment_alias_metadata.selection_type),
+                // A `@required` on the aliased fragment itself makes it
+                // non-nullable.
+                node_type: apply_required_directive_nullability(
+                    &TypeReference::Named(fragment_alias_metadata.selection_type),
+                    &inline_fragment.directives,
+                ),
                 node_selections: selections_to_map(inline_selections.into_iter(), true),
                 conditional: false,
                 concrete_type: None,
+                doc_comment: None,
+                catch_to_result: false,
             })]
         } else {
             // If the inline fragment is on an abstract type, its selections must be
@@ -888,6 +932,7 @@ fn visit_actor_change(
         )))),
         conditional: false,
         concrete_type: None,
+        doc_comment: None,
     }));
 }
 
@@ -974,8 +1019,11 @@ fn gen_visit_linked_field(
     };
     let selections = visit_selections_fn(&linked_field.selections);
 
-    let node_type = apply_required_directive_nullability(
-        &field_type(field, typegen_context),
+    let node_type = apply_catch_directive_nullability(
+        &apply_required_directive_nullability(
+            &selection_field_type(field, &linked_field.directives, typegen_context),
+            &linked_field.directives,
+        ),
         &linked_field.directives,
     );
 
@@ -985,6 +1033,8 @@ fn gen_visit_linked_field(
         node_selections: selections_to_map(selections.into_iter(), true),
         conditional: false,
         concrete_type: None,
+        doc_comment: field_doc_comment(typegen_context, field),
+        catch_to_result: is_catch_to_result(&linked_field.directives),
     }));
 }
 
@@ -1003,8 +1053,11 @@ fn visit_scalar_field(
     } else {
         schema_name
     };
-    let field_type = apply_required_directive_nullability(
-        &field_type(field, typegen_context),
+    let field_type = apply_catch_directive_nullability(
+        &apply_required_directive_nullability(
+            &selection_field_type(field, &scalar_field.directives, typegen_context),
+            &scalar_field.directives,
+        ),
         &scalar_field.directives,
     );
     let special_field = ScalarFieldSpecialSchemaField::from_schema_name(
@@ -1033,18 +1086,26 @@ fn visit_scalar_field(
                 )),
                 conditional: false,
                 concrete_type: None,
+                doc_comment: None,
             }));
         }
     }
 
+    let id_type = field_id_type(typegen_context, field, custom_scalars);
     type_selections.push(TypeSelection::ScalarField(TypeSelectionScalarField {
         field_name_or_alias: key,
         special_field,
-        value: transform_type_reference_into_ast(&field_type, |type_| {
-            expect_scalar_type(typegen_context, encountered_enums, custom_scalars, type_)
-        }),
+        value: apply_catch_directive_result_type(
+            transform_type_reference_into_ast(&field_type, |type_| {
+                id_type.unwrap_or_else(|| {
+                    expect_scalar_type(typegen_context, encountered_enums, custom_scalars, type_)
+                })
+            }),
+            &scalar_field.directives,
+        ),
         conditional: false,
         concrete_type: None,
+        doc_comment: field_doc_comment(typegen_context, field),
     }));
 }
 
@@ -1255,6 +1316,7 @@ fn get_merged_object_with_optional_fields(
             optional: false,
             read_only: true,
             value: AST::FragmentReferenceType(fragment_type_name),
+            doc_comment: None,
         }));
     }
 
@@ -1310,6 +1372,7 @@ fn get_discriminated_union_ast(
                     read_only: true,
                     optional: false,
                     value: AST::OtherTypename,
+                    doc_comment: None,
                 })
             })
             .collect(),
@@ -1326,6 +1389,7 @@ fn get_discriminated_union_ast(
                         optional: false,
                         read_only: true,
                         value: AST::FragmentReferenceType(fragment_type_name),
+                        doc_comment: None,
                     }));
                 }
                 if mask_status == MaskStatus::Unmasked {
@@ -1562,6 +1626,7 @@ fn make_prop(
                             TypegenLanguage::TypeScript => {
                                 AST::RawType(intern!("null | undefined"))
                             }
+                            TypegenLanguage::ReScript => AST::RawType(intern!("unit")),
                         }
                     }
                 } else {
@@ -1582,6 +1647,7 @@ fn make_prop(
                                              value,
                                              read_only: true,
                                              optional: false,
+                                             doc_comment: None,
                                          });
                                          let assignable_fragment_spread_ref = Prop::KeyValuePair(KeyValuePairProp {
                                              key: *KEY_FRAGMENT_SPREADS,
@@ -1590,12 +1656,14 @@ fn make_prop(
                                              ),
                                              read_only: true,
                                              optional: false,
+                                             doc_comment: None,
                                          });
                                          let client_id_field = Prop::KeyValuePair(KeyValuePairProp {
                                              key: "__id".intern(),
                                              value: AST::String,
                                              read_only: true,
                                              optional: false,
+                                             doc_comment: None,
                                          });
 
                                          AST::InexactObject(InexactObject::new(vec![
@@ -1640,9 +1708,14 @@ fn make_prop(
 
                 Prop::KeyValuePair(KeyValuePairProp {
                     key,
-                    value,
+                    value: if linked_field.catch_to_result {
+                        catch_result_type(value)
+                    } else {
+                        value
+                    },
                     optional,
                     read_only: true,
+                    doc_comment: linked_field.doc_comment,
                 })
             }
         }
@@ -1656,6 +1729,7 @@ fn make_prop(
                         )),
                         optional,
                         read_only: true,
+                        doc_comment: None,
                     })
                 } else {
                     Prop::KeyValuePair(KeyValuePairProp {
@@ -1663,6 +1737,7 @@ fn make_prop(
                         value: scalar_field.value,
                         optional,
                         read_only: true,
+                        doc_comment: None,
                     })
                 }
             } else {
@@ -1674,6 +1749,7 @@ fn make_prop(
                     // all special fields are read only
                     read_only: !typegen_context.generating_updatable_types
                         || scalar_field.special_field.is_some(),
+                    doc_comment: scalar_field.doc_comment,
                 })
             }
         }
@@ -1731,6 +1807,7 @@ fn raw_response_make_prop(
                 }),
                 read_only: true,
                 optional,
+                doc_comment: linked_field.doc_comment,
             })
         }
         TypeSelection::ScalarField(scalar_field) => {
@@ -1743,6 +1820,7 @@ fn raw_response_make_prop(
                         )),
                         read_only: true,
                         optional,
+                        doc_comment: None,
                     })
                 } else {
                     Prop::KeyValuePair(KeyValuePairProp {
@@ -1750,6 +1828,7 @@ fn raw_response_make_prop(
                         value: scalar_field.value,
                         read_only: true,
                         optional,
+                        doc_comment: None,
                     })
                 }
             } else {
@@ -1758,6 +1837,7 @@ fn raw_response_make_prop(
                     value: scalar_field.value,
                     read_only: true,
                     optional,
+                    doc_comment: scalar_field.doc_comment,
                 })
             }
         }
@@ -1797,7 +1877,7 @@ fn transform_non_nullable_type_reference_into_ast(
     }
 }
 
-fn transform_graphql_scalar_type(
+pub(crate) fn transform_graphql_scalar_type(
     typegen_context: &'_ TypegenContext<'_>,
     scalar: ScalarID,
     custom_scalars: &mut CustomScalarsImports,
@@ -1833,12 +1913,15 @@ fn transform_graphql_scalar_type(
             ))
             .expect_string_literal();
         custom_scalars.insert((export_name, PathBuf::from(import_path.lookup())));
-        return AST::RawType(export_name);
+        return AST::CustomScalar {
+            name: scalar_name.item.0,
+            type_: Box::new(AST::RawType(export_name)),
+        };
     }
     // TODO: We could implement custom variables that are provided via the
     // config by inserting them into the schema with directives, thus avoiding
     // having two different ways to express typed custom scalars internally.
-    if let Some(custom_scalar) = typegen_context
+    let custom_scalar_type = if let Some(custom_scalar) = typegen_context
         .project_config
         .typegen_config
         .custom_scalar_types
@@ -1853,11 +1936,11 @@ fn transform_graphql_scalar_type(
             }
         }
     } else if scalar_name.item == *TYPE_ID || scalar_name.item == *TYPE_STRING {
-        AST::String
+        return AST::String;
     } else if scalar_name.item == *TYPE_FLOAT || scalar_name.item == *TYPE_INT {
-        AST::Number
+        return AST::Number;
     } else if scalar_name.item == *TYPE_BOOLEAN {
-        AST::Boolean
+        return AST::Boolean;
     } else {
         if typegen_context
             .project_config
@@ -1870,6 +1953,10 @@ fn transform_graphql_scalar_type(
             );
         }
         AST::Any
+    };
+    AST::CustomScalar {
+        name: scalar_name.item.0,
+        type_: Box::new(custom_scalar_type),
     }
 }
 
@@ -2022,35 +2109,43 @@ fn transform_non_nullable_input_type(
                     input_object_types
                         .insert(input_object.name.item, GeneratedInputObject::Pending);
 
-                    let props = ExactObject::new(
-                        input_object
-                            .fields
-                            .iter()
-                            .map(|field| {
-                                Prop::KeyValuePair(KeyValuePairProp {
-                                    key: field.name.0,
-                                    read_only: false,
-                                    optional: !field.type_.is_non_null()
-                                        || typegen_context
-                                            .project_config
-                                            .typegen_config
-                                            .optional_input_fields
-                                            .contains(&field.name.0)
-                                        || field.default_value.is_some(),
-                                    value: transform_input_type(
-                                        typegen_context,
-                                        &field.type_,
-                                        input_object_types,
-                                        encountered_enums,
-                                        custom_scalars,
-                                    ),
-                                })
+                    let props = input_object
+                        .fields
+                        .iter()
+                        .map(|field| {
+                            Prop::KeyValuePair(KeyValuePairProp {
+                                key: field.name.item.0,
+                                read_only: false,
+                                optional: !field.type_.is_non_null()
+                                    || typegen_context
+                                        .project_config
+                                        .typegen_config
+                                        .optional_input_fields
+                                        .contains(&field.name.item.0)
+                                    || field.default_value.is_some(),
+                                value: transform_input_type(
+                                    typegen_context,
+                                    &field.type_,
+                                    input_object_types,
+                                    encountered_enums,
+                                    custom_scalars,
+                                ),
+                                doc_comment: doc_comment(
+                                    typegen_context,
+                                    field.description,
+                                    field.deprecated().map(|deprecation| deprecation.reason),
+                                ),
                             })
-                            .collect(),
-                    );
+                        })
+                        .collect::<Vec<_>>();
+                    let type_ = if input_object.is_one_of() {
+                        one_of_input_object_type(props)
+                    } else {
+                        AST::ExactObject(ExactObject::new(props))
+                    };
                     input_object_types.insert(
                         input_object.name.item,
-                        GeneratedInputObject::Resolved(props),
+                        GeneratedInputObject::Resolved(type_),
                     );
                 }
                 AST::Identifier(input_object.name.item.0)
@@ -2063,6 +2158,42 @@ fn transform_non_nullable_input_type(
     }
 }
 
+/// A `@oneOf` input object is a union with one member per field: the field
+/// is required and non-null, and every other field must be absent.
+fn one_of_input_object_type(props: Vec<Prop>) -> AST {
+    AST::Union(SortedASTList::new(
+        (0..props.len())
+            .map(|index| {
+                AST::ExactObject(ExactObject::new(
+                    props
+                        .iter()
+                        .enumerate()
+                        .map(|(other_index, other)| match other {
+                            Prop::KeyValuePair(other) if index == other_index => {
+                                Prop::KeyValuePair(KeyValuePairProp {
+                                    optional: false,
+                                    value: match &other.value {
+                                        AST::Nullable(of_type) => (**of_type).clone(),
+                                        value => value.clone(),
+                                    },
+                                    ..other.clone()
+                                })
+                            }
+                            Prop::KeyValuePair(other) => Prop::KeyValuePair(KeyValuePairProp {
+                                optional: true,
+                                value: AST::Never,
+                                doc_comment: None,
+                                ..other.clone()
+                            }),
+                            other => other.clone(),
+                        })
+                        .collect(),
+                ))
+            })
+            .collect(),
+    ))
+}
+
 pub(crate) fn transform_input_type(
     typegen_context: &'_ TypegenContext<'_>,
     type_ref: &TypeReference<Type>,
@@ -2094,22 +2225,30 @@ pub(crate) fn get_input_variables_type<'a>(
     input_object_types: &'a mut InputObjectTypes,
     encountered_enums: &'a mut EncounteredEnums,
     custom_scalars: &'a mut CustomScalarsImports,
+    variable_id_types: &VariableIdTypes,
 ) -> ExactObject {
     ExactObject::new(
         node.variable_definitions
             .iter()
             .map(|var_def| {
-                Prop::KeyValuePair(KeyValuePairProp {
-                    key: var_def.name.item.0,
-                    read_only: false,
-                    optional: !var_def.type_.is_non_null(),
-                    value: transform_input_type(
+                let value = match variable_id_types.get(&var_def.name.item) {
+                    Some(id_type) => {
+                        transform_type_reference_into_ast(&var_def.type_, |_| id_type.clone())
+                    }
+                    None => transform_input_type(
                         typegen_context,
                         &var_def.type_,
                         input_object_types,
                         encountered_enums,
                         custom_scalars,
                     ),
+                };
+                Prop::KeyValuePair(KeyValuePairProp {
+                    key: var_def.name.item.0,
+                    read_only: false,
+                    optional: !var_def.type_.is_non_null(),
+                    value,
+                    doc_comment: None,
                 })
             })
             .collect(),
@@ -2261,6 +2400,7 @@ fn group_refs(props: impl Iterator<Item = TypeSelection>) -> impl Iterator<Item
                 special_field: None,
                 conditional: false,
                 concrete_type: None,
+                doc_comment: None,
             }));
         }
         if let Some(refs) = updatable_fragment_spreads.take() {
@@ -2271,6 +2411,7 @@ fn group_refs(props: impl Iterator<Item = TypeSelection>) -> impl Iterator<Item
                 special_field: None,
                 conditional: false,
                 concrete_type: None,
+                doc_comment: None,
             }));
         }
         None
@@ -2295,6 +2436,58 @@ fn apply_required_directive_nullability(
     }
 }
 
+/// A field caught with `@catch(to: NULL)` is null if it has a field error.
+fn apply_catch_directive_nullability(
+    field_type: &TypeReference<Type>,
+    directives: &[Directive],
+) -> TypeReference<Type> {
+    match CatchMetadataDirective::find(directives) {
+        Some(catch_metadata) if catch_metadata.to == CatchTo::Null => {
+            field_type.nullable_type().clone()
+        }
+        _ => field_type.clone(),
+    }
+}
+
+fn is_catch_to_result(directives: &[Directive]) -> bool {
+    matches!(
+        CatchMetadataDirective::find(directives),
+        Some(catch_metadata) if catch_metadata.to == CatchTo::Result
+    )
+}
+
+fn apply_catch_directive_result_type(value: AST, directives: &[Directive]) -> AST {
+    if is_catch_to_result(directives) {
+        catch_result_type(value)
+    } else {
+        value
+    }
+}
+
+/// The type of a field caught with `@catch(to: RESULT)`:
+/// `{ok: true, value: T} | {ok: false, errors: ReadonlyArray<unknown>}`.
+fn catch_result_type(value: AST) -> AST {
+    let prop = |key: &str, value: AST| {
+        Prop::KeyValuePair(KeyValuePairProp {
+            key: key.intern(),
+            value,
+            read_only: true,
+            optional: false,
+            doc_comment: None,
+        })
+    };
+    AST::Union(SortedASTList::new(vec![
+        AST::ExactObject(ExactObject::new(vec![
+            prop("ok", AST::RawType(intern!("true"))),
+            prop("value", value),
+        ])),
+        AST::ExactObject(ExactObject::new(vec![
+            prop("errors", AST::ReadOnlyArray(Box::new(AST::Mixed))),
+            prop("ok", AST::RawType(intern!("false"))),
+        ])),
+    ]))
+}
+
 fn get_type_condition_info(fragment_spread: &FragmentSpread) -> Option<TypeConditionInfo> {
     fragment_spread
          .directives
@@ -2315,6 +2508,7 @@ pub(crate) fn get_operation_type_export(
     variables_identifier_key: StringKey,
     response_identifier_key: StringKey,
     raw_response_prop: Option<KeyValuePairProp>,
+    dependent_data_prop: Option<KeyValuePairProp>,
 ) -> Result<ExactObject, std::fmt::Error> {
     let mut operation_types = vec![
         Prop::KeyValuePair(KeyValuePairProp {
@@ -2322,17 +2516,22 @@ pub(crate) fn get_operation_type_export(
             read_only: false,
             optional: false,
             value: AST::Identifier(variables_identifier_key),
+            doc_comment: None,
         }),
         Prop::KeyValuePair(KeyValuePairProp {
             key: *RESPONSE,
             read_only: false,
             optional: false,
             value: AST::Identifier(response_identifier_key),
+            doc_comment: None,
         }),
     ];
     if let Some(raw_response_prop) = raw_response_prop {
         operation_types.push(raw_response_prop.into());
     }
+    if let Some(dependent_data_prop) = dependent_data_prop {
+        operation_types.push(dependent_data_prop.into());
+    }
 
     Ok(ExactObject::new(operation_types))
 }
@@ -2356,6 +2555,7 @@ fn create_edge_to_return_type_ast(
         value: AST::RawType(*KEY_DATA_ID),
         read_only: true,
         optional: false,
+        doc_comment: None,
     })];
     if inner_type.is_abstract_type() && schema.is_extension_type(*inner_type) {
         // Note: there is currently no way to create a resolver that returns an abstract
@@ -2380,6 +2580,7 @@ fn create_edge_to_return_type_ast(
             )),
             read_only: true,
             optional: false,
+            doc_comment: None,
         }))
     }
 
@@ -2428,6 +2629,35 @@ fn return_ast_in_object_case(
     }
 }
 
+/// The JSDoc comment of a field with its description and deprecation reason,
+/// if doc comments are enabled.
+fn field_doc_comment(typegen_context: &'_ TypegenContext<'_>, field: &Field) -> Option<DocComment> {
+    doc_comment(
+        typegen_context,
+        field.description,
+        field.deprecated().map(|deprecation| deprecation.reason),
+    )
+}
+
+fn doc_comment(
+    typegen_context: &'_ TypegenContext<'_>,
+    description: Option<StringKey>,
+    deprecation: Option<Option<StringKey>>,
+) -> Option<DocComment> {
+    if !typegen_context
+        .project_config
+        .typegen_config
+        .emit_doc_comments
+        || (description.is_none() && deprecation.is_none())
+    {
+        return None;
+    }
+    Some(DocComment {
+        description,
+        deprecation,
+    })
+}
+
 /// Returns the type of the field, potentially wrapping the field or list items in a non-null type
 /// to reflect the semantic nullability of the field if that feature is enabled.
 fn field_type(field: &Field, typegen_options: &'_ TypegenContext<'_>) -> TypeReference<Type> {
@@ -2441,3 +2671,19 @@ fn field_type(field: &Field, typegen_options: &'_ TypegenContext<'_>) -> TypeRef
         field.type_.clone()
     }
 }
+
+/// Returns the type of a selected field. Like `field_type`, but the semantic nullability of the
+/// field is also used if the field errors of the field are caught by a `@catch`.
+fn selection_field_type(
+    field: &Field,
+    directives: &[Directive],
+    typegen_options: &'_ TypegenContext<'_>,
+) -> TypeReference<Type> {
+    if directives.named(*WITHIN_CATCH_METADATA_KEY).is_some()
+        || CatchMetadataDirective::find(directives).is_some()
+    {
+        field.semantic_type()
+    } else {
+        field_type(field, typegen_options)
+    }
+}
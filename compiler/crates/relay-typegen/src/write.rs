@@ -14,6 +14,7 @@ use ::intern::string_key::Intern;
 use ::intern::string_key::StringKey;
 use ::intern::Lookup;
 use common::InputObjectName;
+use common::Location;
 use common::NamedItem;
 use graphql_ir::FragmentDefinition;
 use graphql_ir::FragmentDefinitionName;
@@ -24,13 +25,21 @@ use indexmap::IndexMap;
 use itertools::Itertools;
 use relay_config::JsModuleFormat;
 use relay_config::TypegenLanguage;
+use relay_transforms::is_relay_test_operation;
+use relay_transforms::DependentOperation;
+use relay_transforms::DependentOperationsMetadata;
 use relay_transforms::RefetchableDerivedFromMetadata;
 use relay_transforms::RefetchableMetadata;
 use relay_transforms::RelayDirective;
 use relay_transforms::ASSIGNABLE_DIRECTIVE;
 use relay_transforms::CHILDREN_CAN_BUBBLE_METADATA_KEY;
 use schema::Schema;
+use schema::Type;
+use schema::TypeReference;
 
+use crate::id_types::id_type_definitions;
+use crate::id_types::variable_id_types;
+use crate::mock_resolvers::deep_partial;
 use crate::typegen_state::ActorChangeStatus;
 use crate::typegen_state::EncounteredEnums;
 use crate::typegen_state::EncounteredFragment;
@@ -63,6 +72,7 @@ use crate::ACTOR_CHANGE_POINT;
 use crate::FUTURE_ENUM_VALUE;
 use crate::KEY_CLIENTID;
 use crate::KEY_DATA;
+use crate::KEY_DEPENDENT_DATA;
 use crate::KEY_FRAGMENT_SPREADS;
 use crate::KEY_FRAGMENT_TYPE;
 use crate::KEY_RAW_RESPONSE;
@@ -119,34 +129,39 @@ pub(crate) fn write_operation_type_exports_section(
         &mut custom_scalars,
     );
 
-    let raw_response_type_and_match_fields =
-        if has_raw_response_type_directive(normalization_operation) {
-            let mut match_fields = Default::default();
-            let raw_response_selections = raw_response_visit_selections(
+    let has_raw_response_type = has_raw_response_type_directive(normalization_operation);
+    let has_mock_data_type = typegen_context
+        .project_config
+        .mock_resolvers_module_path()
+        .is_some()
+        && is_relay_test_operation(normalization_operation);
+    let raw_response_type_and_match_fields = if has_raw_response_type || has_mock_data_type {
+        let mut match_fields = Default::default();
+        let raw_response_selections = raw_response_visit_selections(
+            typegen_context,
+            &normalization_operation.selections,
+            &mut encountered_enums,
+            &mut match_fields,
+            &mut encountered_fragments,
+            &mut imported_raw_response_types,
+            &mut runtime_imports,
+            &mut custom_scalars,
+            None,
+        );
+        Some((
+            raw_response_selections_to_babel(
                 typegen_context,
-                &normalization_operation.selections,
+                raw_response_selections.into_iter(),
+                None,
                 &mut encountered_enums,
-                &mut match_fields,
-                &mut encountered_fragments,
-                &mut imported_raw_response_types,
                 &mut runtime_imports,
                 &mut custom_scalars,
-                None,
-            );
-            Some((
-                raw_response_selections_to_babel(
-                    typegen_context,
-                    raw_response_selections.into_iter(),
-                    None,
-                    &mut encountered_enums,
-                    &mut runtime_imports,
-                    &mut custom_scalars,
-                ),
-                match_fields,
-            ))
-        } else {
-            None
-        };
+            ),
+            match_fields,
+        ))
+    } else {
+        None
+    };
 
     let refetchable_fragment_name =
         RefetchableDerivedFromMetadata::find(&typegen_operation.directives);
@@ -166,6 +181,11 @@ pub(crate) fn write_operation_type_exports_section(
     write_fragment_imports(typegen_context, None, encountered_fragments, writer)?;
     write_relay_resolver_imports(imported_resolvers, writer)?;
     write_split_raw_response_type_imports(typegen_context, imported_raw_response_types, writer)?;
+    let dependent_operations = DependentOperationsMetadata::find(&typegen_operation.directives)
+        .map(|DependentOperationsMetadata(dependent_operations)| dependent_operations.as_slice());
+    if let Some(dependent_operations) = dependent_operations {
+        write_dependent_operation_imports(typegen_context, dependent_operations, writer)?;
+    }
 
     let mut input_object_types = IndexMap::default();
     let expected_provided_variables_type = generate_provided_variables_type(
@@ -176,12 +196,18 @@ pub(crate) fn write_operation_type_exports_section(
         &mut custom_scalars,
     );
 
+    let variable_id_types = variable_id_types(
+        typegen_context,
+        normalization_operation,
+        &mut custom_scalars,
+    );
     let input_variables_type = get_input_variables_type(
         typegen_context,
         typegen_operation,
         &mut input_object_types,
         &mut encountered_enums,
         &mut custom_scalars,
+        &variable_id_types,
     );
     let input_object_types = input_object_types
         .into_iter()
@@ -189,7 +215,7 @@ pub(crate) fn write_operation_type_exports_section(
 
     write_enum_definitions(typegen_context, encountered_enums, writer)?;
     write_custom_scalar_imports(custom_scalars, writer)?;
-    write_input_object_types(input_object_types, writer)?;
+    write_input_object_types(typegen_context, input_object_types, writer)?;
 
     let variables_identifier = format!("{}$variables", typegen_operation.name.item.0);
     let variables_identifier_key = variables_identifier.as_str().intern();
@@ -202,13 +228,34 @@ pub(crate) fn write_operation_type_exports_section(
 
     let raw_response_prop = write_raw_response_and_get_raw_response_prop(
         raw_response_type_and_match_fields,
+        has_raw_response_type,
+        has_mock_data_type,
         writer,
         typegen_operation,
     )?;
+    let dependent_data_prop = match dependent_operations {
+        Some(dependent_operations) => {
+            let dependent_data_identifier =
+                format!("{}$dependentData", typegen_operation.name.item.0);
+            writer.write_export_type(
+                &dependent_data_identifier,
+                &get_dependent_data_type(dependent_operations),
+            )?;
+            Some(KeyValuePairProp {
+                key: *KEY_DEPENDENT_DATA,
+                read_only: false,
+                optional: false,
+                value: AST::Identifier(dependent_data_identifier.intern()),
+                doc_comment: None,
+            })
+        }
+        None => None,
+    };
     let query_wrapper_type = get_operation_type_export(
         variables_identifier_key,
         response_identifier_key,
         raw_response_prop,
+        dependent_data_prop,
     )?;
     writer.write_export_type(
         typegen_operation.name.item.0.lookup(),
@@ -231,8 +278,12 @@ pub(crate) fn write_operation_type_exports_section(
     Ok(())
 }
 
+/// Writes the `$rawResponse` type and the `$mockData` type of
+/// `@relay_test_operation` operations, a deep partial of the raw response.
 fn write_raw_response_and_get_raw_response_prop(
     raw_response_type_and_match_fields: Option<(AST, MatchFields)>,
+    has_raw_response_type: bool,
+    has_mock_data_type: bool,
     writer: &mut Box<dyn Writer>,
     typegen_operation: &OperationDefinition,
 ) -> Result<Option<KeyValuePairProp>, std::fmt::Error> {
@@ -240,6 +291,15 @@ fn write_raw_response_and_get_raw_response_prop(
         for (key, ast) in match_fields.0 {
             writer.write_export_type(key.lookup(), &ast)?;
         }
+        if has_mock_data_type {
+            writer.write_export_type(
+                &format!("{}$mockData", typegen_operation.name.item.0),
+                &deep_partial(raw_response_type.clone()),
+            )?;
+        }
+        if !has_raw_response_type {
+            return Ok(None);
+        }
         let raw_response_identifier = format!("{}$rawResponse", typegen_operation.name.item.0);
         writer.write_export_type(&raw_response_identifier, &raw_response_type)?;
 
@@ -248,6 +308,7 @@ fn write_raw_response_and_get_raw_response_prop(
             read_only: false,
             optional: false,
             value: AST::Identifier(raw_response_identifier.intern()),
+            doc_comment: None,
         }))
     } else {
         Ok(None)
@@ -303,6 +364,51 @@ pub(crate) fn write_split_operation_type_exports_section(
     Ok(())
 }
 
+/// Writes the enum and input object types of the schema, which artifacts
+/// import when the shared schema types module is enabled.
+pub(crate) fn write_schema_types_module_section(
+    typegen_context: &'_ TypegenContext<'_>,
+    writer: &mut Box<dyn Writer>,
+) -> FmtResult {
+    let schema = typegen_context.schema;
+    let mut encountered_enums = EncounteredEnums::default();
+    let mut custom_scalars = CustomScalarsImports::default();
+    let mut input_object_types = IndexMap::default();
+
+    for input_object in schema.input_objects() {
+        let type_ = schema
+            .get_type(input_object.name.item.0)
+            .expect("Expected the input object to be defined in the schema");
+        transform_input_type(
+            typegen_context,
+            &TypeReference::NonNull(Box::new(TypeReference::Named(type_))),
+            &mut input_object_types,
+            &mut encountered_enums,
+            &mut custom_scalars,
+        );
+    }
+    for enum_ in schema.enums() {
+        if enum_.name.item.lookup().starts_with("__") {
+            continue;
+        }
+        if let Some(Type::Enum(enum_id)) = schema.get_type(enum_.name.item.0) {
+            encountered_enums.0.insert(enum_id);
+        }
+    }
+    let input_object_types = input_object_types
+        .into_iter()
+        .sorted_by_key(|(key, _)| key.lookup())
+        .map(|(key, val)| (key, val.unwrap_resolved_type()));
+    let id_types = id_type_definitions(typegen_context, &mut custom_scalars);
+
+    write_custom_scalar_imports(custom_scalars, writer)?;
+    write_enum_definitions(typegen_context, encountered_enums, writer)?;
+    for (name, underlying_type) in id_types {
+        writer.write_export_opaque_type(name.lookup(), &underlying_type)?;
+    }
+    write_input_object_types(typegen_context, input_object_types, writer)
+}
+
 pub(crate) fn write_fragment_type_exports_section(
     typegen_context: &'_ TypegenContext<'_>,
     fragment_definition: &FragmentDefinition,
@@ -359,6 +465,7 @@ pub(crate) fn write_fragment_type_exports_section(
         optional: true,
         read_only: true,
         value: AST::Identifier(data_type_name.as_str().intern()),
+        doc_comment: None,
     });
     let fragment_name = fragment_definition.name.item.0;
     let ref_type_fragment_spreads_property = Prop::KeyValuePair(KeyValuePairProp {
@@ -370,6 +477,7 @@ pub(crate) fn write_fragment_type_exports_section(
         optional: false,
         read_only: true,
         value: AST::FragmentReference(SortedStringKeyList::new(vec![fragment_name])),
+        doc_comment: None,
     });
     let is_plural_fragment = is_plural(fragment_definition);
     let mut ref_type = AST::InexactObject(InexactObject::new(vec![
@@ -410,7 +518,7 @@ pub(crate) fn write_fragment_type_exports_section(
         .into_iter()
         .map(|(key, val)| (key, val.unwrap_resolved_type()));
 
-    write_input_object_types(input_object_types, writer)?;
+    write_input_object_types(typegen_context, input_object_types, writer)?;
     write_fragment_imports(
         typegen_context,
         Some(fragment_definition.name.item),
@@ -431,11 +539,16 @@ pub(crate) fn write_fragment_type_exports_section(
     if let Some(refetchable_metadata) = refetchable_metadata {
         let variables_name = format!("{}$variables", refetchable_metadata.operation_name);
         match typegen_context.project_config.js_module_format {
-            JsModuleFormat::CommonJS => {
+            JsModuleFormat::CommonJS | JsModuleFormat::ESM => {
                 if typegen_context.has_unified_output {
                     writer.write_import_fragment_type(
                         &[&variables_name],
-                        &format!("./{}.graphql", refetchable_metadata.operation_name),
+                        &typegen_context
+                            .project_config
+                            .js_module_import_specifier(format!(
+                                "./{}.graphql",
+                                refetchable_metadata.operation_name
+                            )),
                     )?;
                 } else {
                     writer.write_any_type_definition(&variables_name)?;
@@ -495,11 +608,16 @@ fn write_fragment_imports(
         }
 
         match typegen_context.project_config.js_module_format {
-            JsModuleFormat::CommonJS => {
+            JsModuleFormat::CommonJS | JsModuleFormat::ESM => {
                 if typegen_context.has_unified_output {
                     writer.write_import_fragment_type(
                         &[&fragment_type_name],
-                        &format!("./{}.graphql", current_referenced_fragment),
+                        &typegen_context
+                            .project_config
+                            .js_module_import_specifier(format!(
+                                "./{}.graphql",
+                                current_referenced_fragment
+                            )),
                     )?;
                 } else {
                     let fragment_location = typegen_context
@@ -525,7 +643,12 @@ fn write_fragment_imports(
 
                     writer.write_import_fragment_type(
                         &[&fragment_type_name],
-                        &format!("./{}.graphql", fragment_import_path),
+                        &typegen_context
+                            .project_config
+                            .js_module_import_specifier(format!(
+                                "./{}.graphql",
+                                fragment_import_path
+                            )),
                     )?;
                 }
             }
@@ -587,60 +710,123 @@ fn write_split_raw_response_type_imports(
     for (imported_raw_response_type, imported_raw_response_document_location) in
         imported_raw_response_types.0
     {
-        match typegen_context.project_config.js_module_format {
-            JsModuleFormat::CommonJS => {
-                if typegen_context.has_unified_output {
-                    writer.write_import_fragment_type(
-                        &[imported_raw_response_type.lookup()],
-                        &format!("./{}.graphql", imported_raw_response_type),
-                    )?;
-                } else if let Some(imported_raw_response_document_location) =
-                    imported_raw_response_document_location
-                {
-                    let artifact_import_path =
-                        typegen_context.project_config.js_module_import_identifier(
-                            &typegen_context.project_config.artifact_path_for_definition(
-                                typegen_context.definition_source_location,
-                            ),
-                            &typegen_context.project_config.create_path_for_artifact(
-                                imported_raw_response_document_location.source_location(),
-                                imported_raw_response_type.to_string(),
-                            ),
-                        );
+        match artifact_import_specifier(
+            typegen_context,
+            imported_raw_response_type,
+            imported_raw_response_document_location,
+        ) {
+            Some(specifier) => writer
+                .write_import_fragment_type(&[imported_raw_response_type.lookup()], &specifier)?,
+            None => writer.write_any_type_definition(imported_raw_response_type.lookup())?,
+        }
+    }
 
-                    writer.write_import_fragment_type(
-                        &[imported_raw_response_type.lookup()],
-                        &format!("./{}.graphql", artifact_import_path),
-                    )?;
-                } else {
-                    writer.write_any_type_definition(imported_raw_response_type.lookup())?;
-                }
-            }
-            JsModuleFormat::Haste => {
-                writer.write_import_fragment_type(
-                    &[imported_raw_response_type.lookup()],
-                    &format!("{}.graphql", imported_raw_response_type),
-                )?;
+    Ok(())
+}
+
+/// The module specifier to import types from the artifact of the definition
+/// `name`, or `None` if its location is needed but unknown.
+fn artifact_import_specifier(
+    typegen_context: &'_ TypegenContext<'_>,
+    name: StringKey,
+    location: Option<Location>,
+) -> Option<String> {
+    match typegen_context.project_config.js_module_format {
+        JsModuleFormat::CommonJS | JsModuleFormat::ESM => {
+            if typegen_context.has_unified_output {
+                Some(
+                    typegen_context
+                        .project_config
+                        .js_module_import_specifier(format!("./{}.graphql", name)),
+                )
+            } else {
+                let location = location?;
+                let artifact_import_path =
+                    typegen_context.project_config.js_module_import_identifier(
+                        &typegen_context.project_config.artifact_path_for_definition(
+                            typegen_context.definition_source_location,
+                        ),
+                        &typegen_context
+                            .project_config
+                            .create_path_for_artifact(location.source_location(), name.to_string()),
+                    );
+                Some(
+                    typegen_context
+                        .project_config
+                        .js_module_import_specifier(format!("./{}.graphql", artifact_import_path)),
+                )
             }
         }
+        JsModuleFormat::Haste => Some(format!("{}.graphql", name)),
     }
+}
 
+/// Imports the `$data` types of the dependent operations of an operation.
+fn write_dependent_operation_imports(
+    typegen_context: &'_ TypegenContext<'_>,
+    dependent_operations: &[DependentOperation],
+    writer: &mut Box<dyn Writer>,
+) -> FmtResult {
+    for dependent_operation in dependent_operations {
+        let name = dependent_operation.operation_name.0;
+        let data_type = format!("{}$data", name);
+        match artifact_import_specifier(typegen_context, name, Some(dependent_operation.location)) {
+            Some(specifier) => writer.write_import_type(&[&data_type], &specifier)?,
+            None => writer.write_any_type_definition(&data_type)?,
+        }
+    }
     Ok(())
 }
 
-fn write_enum_definitions(
+/// The `$dependentData` type of an operation, with the nullable `$data` type
+/// of each dependent operation, `null` until its response is available.
+fn get_dependent_data_type(dependent_operations: &[DependentOperation]) -> AST {
+    AST::ExactObject(ExactObject::new(
+        dependent_operations
+            .iter()
+            .map(|dependent_operation| {
+                let name = dependent_operation.operation_name.0;
+                Prop::KeyValuePair(KeyValuePairProp {
+                    key: name,
+                    read_only: true,
+                    optional: false,
+                    value: AST::Nullable(Box::new(AST::Identifier(
+                        format!("{}$data", name).intern(),
+                    ))),
+                    doc_comment: None,
+                })
+            })
+            .collect(),
+    ))
+}
+
+pub(crate) fn write_enum_definitions(
     typegen_context: &'_ TypegenContext<'_>,
     encountered_enums: EncounteredEnums,
     writer: &mut Box<dyn Writer>,
 ) -> FmtResult {
     let enum_ids = encountered_enums.into_sorted_vec(typegen_context.schema);
+    let enum_module_suffix = typegen_context
+        .project_config
+        .typegen_config
+        .enum_module_suffix
+        .as_ref()
+        .filter(|_| !typegen_context.typegen_options.inline_schema_types);
+    if enum_module_suffix.is_none() {
+        if let Some(schema_types_module) = schema_types_module_import(typegen_context) {
+            let enum_names: Vec<_> = enum_ids
+                .iter()
+                .map(|enum_id| typegen_context.schema.enum_(*enum_id).name.item.lookup())
+                .collect();
+            if !enum_names.is_empty() {
+                writer.write_import_type(&enum_names, schema_types_module.lookup())?;
+            }
+            return Ok(());
+        }
+    }
     for enum_id in enum_ids {
         let enum_type = typegen_context.schema.enum_(enum_id);
-        if let Some(enum_module_suffix) = &typegen_context
-            .project_config
-            .typegen_config
-            .enum_module_suffix
-        {
+        if let Some(enum_module_suffix) = enum_module_suffix {
             writer.write_import_type(
                 &[enum_type.name.item.lookup()],
                 &format!("{}{}", enum_type.name.item, enum_module_suffix),
@@ -695,12 +881,14 @@ fn generate_provided_variables_type(
                 read_only: true,
                 optional: false,
                 value: provider_func,
+                doc_comment: None,
             });
             Some(Prop::KeyValuePair(KeyValuePairProp {
                 key: def.name.item.0,
                 read_only: true,
                 optional: false,
                 value: AST::ExactObject(ExactObject::new(vec![provider_module])),
+                doc_comment: None,
             }))
         })
         .collect_vec();
@@ -712,15 +900,56 @@ fn generate_provided_variables_type(
 }
 
 fn write_input_object_types(
-    input_object_types: impl Iterator<Item = (InputObjectName, ExactObject)>,
+    typegen_context: &'_ TypegenContext<'_>,
+    input_object_types: impl Iterator<Item = (InputObjectName, AST)>,
     writer: &mut Box<dyn Writer>,
 ) -> FmtResult {
+    if let Some(schema_types_module) = schema_types_module_import(typegen_context) {
+        let type_identifiers: Vec<_> = input_object_types
+            .map(|(type_identifier, _)| type_identifier.lookup())
+            .collect();
+        if !type_identifiers.is_empty() {
+            writer.write_import_type(&type_identifiers, schema_types_module.lookup())?;
+        }
+        return Ok(());
+    }
     for (type_identifier, input_object_type) in input_object_types {
-        writer.write_export_type(type_identifier.lookup(), &input_object_type.into())?;
+        writer.write_export_type(type_identifier.lookup(), &input_object_type)?;
     }
     Ok(())
 }
 
+/// The identifier to import the shared module with the enum and input object
+/// types of the schema from, if it is enabled.
+pub(crate) fn schema_types_module_import(
+    typegen_context: &'_ TypegenContext<'_>,
+) -> Option<StringKey> {
+    if typegen_context.typegen_options.inline_schema_types {
+        return None;
+    }
+    let project_config = typegen_context.project_config;
+    let schema_types_module_path = project_config.schema_types_module_path()?;
+    let import_path = project_config.js_module_import_identifier(
+        &project_config.artifact_path_for_definition(typegen_context.definition_source_location),
+        &schema_types_module_path,
+    );
+    match project_config.js_module_format {
+        JsModuleFormat::CommonJS | JsModuleFormat::ESM => {
+            let import_path = if import_path.lookup().starts_with('.') {
+                import_path.to_string()
+            } else {
+                format!("./{}", import_path)
+            };
+            Some(
+                project_config
+                    .js_module_import_specifier(import_path)
+                    .intern(),
+            )
+        }
+        JsModuleFormat::Haste => Some(import_path),
+    }
+}
+
 /// Write the assignable fragment validator function.
 ///
 /// Validators accept an item which *may* be valid for assignment and returns either
@@ -785,24 +1014,28 @@ fn write_abstract_validator_function(
         value: AST::String,
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
     let fragment_spread_prop = Prop::KeyValuePair(KeyValuePairProp {
         key: *KEY_FRAGMENT_SPREADS,
         value: AST::Identifier(format!("{}{}", fragment_name, *KEY_FRAGMENT_TYPE).intern()),
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
     let parameter_discriminator = Prop::KeyValuePair(KeyValuePairProp {
         key: abstract_fragment_spread_marker,
         value: AST::String,
         read_only: true,
         optional: true,
+        doc_comment: None,
     });
     let return_value_discriminator = Prop::KeyValuePair(KeyValuePairProp {
         key: abstract_fragment_spread_marker,
         value: AST::String,
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
 
     let parameter_type = AST::InexactObject(InexactObject::new(vec![
@@ -820,7 +1053,10 @@ fn write_abstract_validator_function(
     ]));
 
     let (open_comment, close_comment) = match language {
-        TypegenLanguage::Flow | TypegenLanguage::JavaScript => ("/*", "*/"),
+        // ReScript artifacts embed the validator as JavaScript.
+        TypegenLanguage::Flow | TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
+            ("/*", "*/")
+        }
         TypegenLanguage::TypeScript => ("", ""),
     };
 
@@ -841,7 +1077,7 @@ fn write_abstract_validator_function(
     )?;
 
     match language {
-        TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
+        TypegenLanguage::Flow | TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
             write!(writer, "(value{}: ", &open_comment)?;
             writer.write(&AST::Any)?;
             write!(writer, "{}) ", &close_comment)?;
@@ -884,24 +1120,28 @@ fn write_concrete_validator_function(
         value: AST::String,
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
     let fragment_spread_prop = Prop::KeyValuePair(KeyValuePairProp {
         key: *KEY_FRAGMENT_SPREADS,
         value: AST::Identifier(format!("{}{}", fragment_name, *KEY_FRAGMENT_TYPE).intern()),
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
     let parameter_discriminator = Prop::KeyValuePair(KeyValuePairProp {
         key: *KEY_TYPENAME,
         value: AST::String,
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
     let return_value_discriminator = Prop::KeyValuePair(KeyValuePairProp {
         key: *KEY_TYPENAME,
         value: AST::StringLiteral(StringLiteral(concrete_typename)),
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
 
     let parameter_type = AST::InexactObject(InexactObject::new(vec![
@@ -920,7 +1160,10 @@ fn write_concrete_validator_function(
 
     let typegen_language = typegen_context.project_config.typegen_config.language;
     let (open_comment, close_comment) = match typegen_language {
-        TypegenLanguage::Flow | TypegenLanguage::JavaScript => ("/*", "*/"),
+        // ReScript artifacts embed the validator as JavaScript.
+        TypegenLanguage::Flow | TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
+            ("/*", "*/")
+        }
         TypegenLanguage::TypeScript => ("", ""),
     };
 
@@ -941,7 +1184,7 @@ fn write_concrete_validator_function(
     )?;
 
     match typegen_language {
-        TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
+        TypegenLanguage::Flow | TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
             write!(writer, "(value{}: ", &open_comment)?;
             writer.write(&AST::Any)?;
             write!(writer, "{}) ", &close_comment)?;
@@ -979,7 +1222,7 @@ pub fn has_raw_response_type_directive(operation: &OperationDefinition) -> bool
         .is_some()
 }
 
-fn write_custom_scalar_imports(
+pub(crate) fn write_custom_scalar_imports(
     custom_scalars: CustomScalarsImports,
     writer: &mut Box<dyn Writer>,
 ) -> FmtResult {
@@ -17,6 +17,7 @@ use relay_config::TypegenLanguage;
 
 use crate::flow::FlowPrinter;
 use crate::javascript::JavaScriptPrinter;
+use crate::rescript::ReScriptPrinter;
 use crate::typescript::TypeScriptPrinter;
 use crate::FUTURE_ENUM_VALUE;
 use crate::KEY_FRAGMENT_SPREADS;
@@ -32,6 +33,12 @@ pub enum AST {
     Identifier(StringKey),
     /// Printed as is, should be valid Flow code.
     RawType(StringKey),
+    /// A custom GraphQL scalar, printed as `type_`. The scalar name is used
+    /// to look up its parser in runtime validators.
+    CustomScalar {
+        name: StringKey,
+        type_: Box<AST>,
+    },
     String,
     StringLiteral(StringLiteral),
     /// Prints as `"%other" with a comment explaining open enums.
@@ -44,10 +51,14 @@ pub enum AST {
     Callable(Box<AST>),
     Any,
     Mixed,
+    /// The empty type, printed as `empty` in Flow and `never` in TypeScript.
+    Never,
     FragmentReference(SortedStringKeyList),
     FragmentReferenceType(StringKey),
     ReturnTypeOfFunctionWithName(StringKey),
     ReturnTypeOfMethodCall(Box<AST>, StringKey),
+    /// The type of the elements of an array type, printed as `type_[number]`.
+    ArrayElementType(Box<AST>),
     ActorChangePoint(Box<AST>),
     AssertFunctionType(FunctionTypeAssertion),
     GenericType {
@@ -258,6 +269,91 @@ pub struct KeyValuePairProp {
     pub value: AST,
     pub read_only: bool,
     pub optional: bool,
+    pub doc_comment: Option<DocComment>,
+}
+
+/// The schema documentation of a field, printed as a comment before its
+/// property.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DocComment {
+    pub description: Option<StringKey>,
+    /// `Some` if the field is deprecated, with the deprecation reason if
+    /// there is one.
+    pub deprecation: Option<Option<StringKey>>,
+}
+
+impl DocComment {
+    /// The lines of the comment, without the comment delimiters.
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines: Vec<String> = self
+            .description
+            .iter()
+            .flat_map(|description| description.lookup().trim().lines())
+            .map(|line| line.trim_end().to_string())
+            .collect();
+        if let Some(reason) = self.deprecation {
+            lines.push(match reason {
+                Some(reason) => format!("@deprecated {}", reason.lookup().trim()),
+                None => "@deprecated".to_string(),
+            });
+        }
+        // `*/` would end the comment
+        lines
+            .into_iter()
+            .map(|line| line.replace("*/", "*\\/"))
+            .collect()
+    }
+}
+
+/// How a `DocComment` is delimited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocCommentStyle {
+    /// A JSDoc `/** ... */` block.
+    Block,
+    /// `//` line comments, for types printed within a `/*:: ... */` comment.
+    Line,
+}
+
+/// Writes `doc_comment` before a property indented by `indentation` levels,
+/// followed by the indentation of the property.
+pub fn write_doc_comment(
+    result: &mut String,
+    doc_comment: &DocComment,
+    indentation: usize,
+    style: DocCommentStyle,
+) -> FmtResult {
+    let indentation = "  ".repeat(indentation);
+    let lines = doc_comment.lines();
+    match (style, lines.as_slice()) {
+        (_, []) => return Ok(()),
+        (DocCommentStyle::Line, lines) => {
+            for (index, line) in lines.iter().enumerate() {
+                if index > 0 {
+                    result.write_str(&indentation)?;
+                }
+                if line.is_empty() {
+                    writeln!(result, "//")?;
+                } else {
+                    writeln!(result, "// {}", line)?;
+                }
+            }
+        }
+        (DocCommentStyle::Block, [line]) => writeln!(result, "/** {} */", line)?,
+        (DocCommentStyle::Block, lines) => {
+            writeln!(result, "/**")?;
+            for line in lines {
+                result.write_str(&indentation)?;
+                if line.is_empty() {
+                    writeln!(result, " *")?;
+                } else {
+                    writeln!(result, " * {}", line)?;
+                }
+            }
+            result.write_str(&indentation)?;
+            writeln!(result, " */")?;
+        }
+    }
+    result.write_str(&indentation)
 }
 
 impl Ord for Prop {
@@ -361,6 +457,10 @@ pub trait Writer: Write {
 
     fn write_export_type(&mut self, name: &str, ast: &AST) -> FmtResult;
 
+    /// Exports a type that is represented as `underlying_type`, but is not
+    /// interchangeable with it or with other such types.
+    fn write_export_opaque_type(&mut self, name: &str, underlying_type: &AST) -> FmtResult;
+
     fn write_import_module_default(&mut self, name: &str, from: &str) -> FmtResult;
 
     fn write_import_module_named(
@@ -436,5 +536,6 @@ pub(crate) fn new_writer_from_config(config: &TypegenConfig) -> Box<dyn Writer>
         TypegenLanguage::JavaScript => Box::new(JavaScriptPrinter::default()),
         TypegenLanguage::Flow => Box::new(FlowPrinter::new()),
         TypegenLanguage::TypeScript => Box::new(TypeScriptPrinter::new(config)),
+        TypegenLanguage::ReScript => Box::new(ReScriptPrinter::new()),
     }
 }
@@ -95,6 +95,9 @@ pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String>
             experimental_emit_semantic_nullability_types: fixture
                 .content
                 .contains("# relay:experimental_emit_semantic_nullability_types"),
+            emit_doc_comments: fixture
+                .content
+                .contains("# typegen_config.emit_doc_comments = true"),
             ..Default::default()
         },
         ..Default::default()
@@ -107,6 +110,7 @@ pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String>
         Arc::new(ConsoleLogger),
         None,
         None,
+        None,
     )
     .map_err(|diagnostics| diagnostics_to_sorted_string(source, &diagnostics))?;
 
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<0785113b958651724c9f7105d4f66b9a>>
+ * @generated SignedSource<<959ff9e25ad71b790cc2266d5a259ec4>>
  */
 
 mod generate_flow;
@@ -75,6 +75,20 @@ async fn aliased_inline_fragment_spread_without_type_condition_query_root() {
     test_fixture(transform_fixture, file!(), "aliased-inline-fragment-spread-without-type-condition-query-root.graphql", "generate_flow/fixtures/aliased-inline-fragment-spread-without-type-condition-query-root.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn catch() {
+    let input = include_str!("generate_flow/fixtures/catch.graphql");
+    let expected = include_str!("generate_flow/fixtures/catch.expected");
+    test_fixture(transform_fixture, file!(), "catch.graphql", "generate_flow/fixtures/catch.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn catch_semantic_non_null() {
+    let input = include_str!("generate_flow/fixtures/catch_semantic_non_null.graphql");
+    let expected = include_str!("generate_flow/fixtures/catch_semantic_non_null.expected");
+    test_fixture(transform_fixture, file!(), "catch_semantic_non_null.graphql", "generate_flow/fixtures/catch_semantic_non_null.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn conditional() {
     let input = include_str!("generate_flow/fixtures/conditional.graphql");
@@ -96,6 +110,20 @@ async fn default_input() {
     test_fixture(transform_fixture, file!(), "default-input.graphql", "generate_flow/fixtures/default-input.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn dependent_operation() {
+    let input = include_str!("generate_flow/fixtures/dependent-operation.graphql");
+    let expected = include_str!("generate_flow/fixtures/dependent-operation.expected");
+    test_fixture(transform_fixture, file!(), "dependent-operation.graphql", "generate_flow/fixtures/dependent-operation.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn doc_comments() {
+    let input = include_str!("generate_flow/fixtures/doc-comments.graphql");
+    let expected = include_str!("generate_flow/fixtures/doc-comments.expected");
+    test_fixture(transform_fixture, file!(), "doc-comments.graphql", "generate_flow/fixtures/doc-comments.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn fragment_spread() {
     let input = include_str!("generate_flow/fixtures/fragment-spread.graphql");
@@ -180,6 +208,13 @@ async fn no_inline_fragment() {
     test_fixture(transform_fixture, file!(), "no-inline-fragment.graphql", "generate_flow/fixtures/no-inline-fragment.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn one_of_input_object() {
+    let input = include_str!("generate_flow/fixtures/one-of-input-object.graphql");
+    let expected = include_str!("generate_flow/fixtures/one-of-input-object.expected");
+    test_fixture(transform_fixture, file!(), "one-of-input-object.graphql", "generate_flow/fixtures/one-of-input-object.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn plural_fragment() {
     let input = include_str!("generate_flow/fixtures/plural-fragment.graphql");
@@ -334,6 +369,20 @@ async fn relay_resolver() {
     test_fixture(transform_fixture, file!(), "relay-resolver.graphql", "generate_flow/fixtures/relay-resolver.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn relay_resolver_batch() {
+    let input = include_str!("generate_flow/fixtures/relay-resolver-batch.graphql");
+    let expected = include_str!("generate_flow/fixtures/relay-resolver-batch.expected");
+    test_fixture(transform_fixture, file!(), "relay-resolver-batch.graphql", "generate_flow/fixtures/relay-resolver-batch.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn relay_resolver_batch_inject_fragment_data() {
+    let input = include_str!("generate_flow/fixtures/relay-resolver-batch-inject-fragment-data.graphql");
+    let expected = include_str!("generate_flow/fixtures/relay-resolver-batch-inject-fragment-data.expected");
+    test_fixture(transform_fixture, file!(), "relay-resolver-batch-inject-fragment-data.graphql", "generate_flow/fixtures/relay-resolver-batch-inject-fragment-data.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn relay_resolver_client_edge() {
     let input = include_str!("generate_flow/fixtures/relay-resolver-client-edge.graphql");
@@ -621,6 +670,13 @@ async fn required_isolates_concrete_inline_fragments() {
     test_fixture(transform_fixture, file!(), "required-isolates-concrete-inline-fragments.graphql", "generate_flow/fixtures/required-isolates-concrete-inline-fragments.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn required_on_aliased_fragments() {
+    let input = include_str!("generate_flow/fixtures/required-on-aliased-fragments.graphql");
+    let expected = include_str!("generate_flow/fixtures/required-on-aliased-fragments.expected");
+    test_fixture(transform_fixture, file!(), "required-on-aliased-fragments.graphql", "generate_flow/fixtures/required-on-aliased-fragments.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn required_raw_response_type() {
     let input = include_str!("generate_flow/fixtures/required-raw-response-type.graphql");
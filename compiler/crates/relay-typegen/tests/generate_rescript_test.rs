@@ -0,0 +1,97 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<60b9f894ac9051ef29e87f46886e3dcd>>
+ */
+
+mod generate_rescript;
+
+use generate_rescript::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn branded_id_types() {
+    let input = include_str!("generate_rescript/fixtures/branded-id-types.graphql");
+    let expected = include_str!("generate_rescript/fixtures/branded-id-types.expected");
+    test_fixture(transform_fixture, file!(), "branded-id-types.graphql", "generate_rescript/fixtures/branded-id-types.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn custom_scalar_type_import() {
+    let input = include_str!("generate_rescript/fixtures/custom-scalar-type-import.graphql");
+    let expected = include_str!("generate_rescript/fixtures/custom-scalar-type-import.expected");
+    test_fixture(transform_fixture, file!(), "custom-scalar-type-import.graphql", "generate_rescript/fixtures/custom-scalar-type-import.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn doc_comments() {
+    let input = include_str!("generate_rescript/fixtures/doc-comments.graphql");
+    let expected = include_str!("generate_rescript/fixtures/doc-comments.expected");
+    test_fixture(transform_fixture, file!(), "doc-comments.graphql", "generate_rescript/fixtures/doc-comments.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn fragment_spread() {
+    let input = include_str!("generate_rescript/fixtures/fragment-spread.graphql");
+    let expected = include_str!("generate_rescript/fixtures/fragment-spread.expected");
+    test_fixture(transform_fixture, file!(), "fragment-spread.graphql", "generate_rescript/fixtures/fragment-spread.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn linked_field() {
+    let input = include_str!("generate_rescript/fixtures/linked-field.graphql");
+    let expected = include_str!("generate_rescript/fixtures/linked-field.expected");
+    test_fixture(transform_fixture, file!(), "linked-field.graphql", "generate_rescript/fixtures/linked-field.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn mutation_input_has_array() {
+    let input = include_str!("generate_rescript/fixtures/mutation-input-has-array.graphql");
+    let expected = include_str!("generate_rescript/fixtures/mutation-input-has-array.expected");
+    test_fixture(transform_fixture, file!(), "mutation-input-has-array.graphql", "generate_rescript/fixtures/mutation-input-has-array.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn mutation_with_enums_on_fragment() {
+    let input = include_str!("generate_rescript/fixtures/mutation-with-enums-on-fragment.graphql");
+    let expected = include_str!("generate_rescript/fixtures/mutation-with-enums-on-fragment.expected");
+    test_fixture(transform_fixture, file!(), "mutation-with-enums-on-fragment.graphql", "generate_rescript/fixtures/mutation-with-enums-on-fragment.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn plural_fragment() {
+    let input = include_str!("generate_rescript/fixtures/plural-fragment.graphql");
+    let expected = include_str!("generate_rescript/fixtures/plural-fragment.expected");
+    test_fixture(transform_fixture, file!(), "plural-fragment.graphql", "generate_rescript/fixtures/plural-fragment.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn refetchable_fragment() {
+    let input = include_str!("generate_rescript/fixtures/refetchable-fragment.graphql");
+    let expected = include_str!("generate_rescript/fixtures/refetchable-fragment.expected");
+    test_fixture(transform_fixture, file!(), "refetchable-fragment.graphql", "generate_rescript/fixtures/refetchable-fragment.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn required_raw_response_type() {
+    let input = include_str!("generate_rescript/fixtures/required-raw-response-type.graphql");
+    let expected = include_str!("generate_rescript/fixtures/required-raw-response-type.expected");
+    test_fixture(transform_fixture, file!(), "required-raw-response-type.graphql", "generate_rescript/fixtures/required-raw-response-type.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn schema_types_module() {
+    let input = include_str!("generate_rescript/fixtures/schema-types-module.graphql");
+    let expected = include_str!("generate_rescript/fixtures/schema-types-module.expected");
+    test_fixture(transform_fixture, file!(), "schema-types-module.graphql", "generate_rescript/fixtures/schema-types-module.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn typename_on_union() {
+    let input = include_str!("generate_rescript/fixtures/typename-on-union.graphql");
+    let expected = include_str!("generate_rescript/fixtures/typename-on-union.expected");
+    test_fixture(transform_fixture, file!(), "typename-on-union.graphql", "generate_rescript/fixtures/typename-on-union.expected", input, expected).await;
+}
@@ -0,0 +1,62 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<9ab81e1fbb77215bb8037e9e1cdb14e5>>
+ */
+
+mod generate_runtime_validators;
+
+use generate_runtime_validators::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn abstract_selections() {
+    let input = include_str!("generate_runtime_validators/fixtures/abstract-selections.graphql");
+    let expected = include_str!("generate_runtime_validators/fixtures/abstract-selections.expected");
+    test_fixture(transform_fixture, file!(), "abstract-selections.graphql", "generate_runtime_validators/fixtures/abstract-selections.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn custom_scalars() {
+    let input = include_str!("generate_runtime_validators/fixtures/custom-scalars.graphql");
+    let expected = include_str!("generate_runtime_validators/fixtures/custom-scalars.expected");
+    test_fixture(transform_fixture, file!(), "custom-scalars.graphql", "generate_runtime_validators/fixtures/custom-scalars.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn custom_scalars_without_parsers() {
+    let input = include_str!("generate_runtime_validators/fixtures/custom-scalars-without-parsers.graphql");
+    let expected = include_str!("generate_runtime_validators/fixtures/custom-scalars-without-parsers.expected");
+    test_fixture(transform_fixture, file!(), "custom-scalars-without-parsers.graphql", "generate_runtime_validators/fixtures/custom-scalars-without-parsers.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn flow_commonjs() {
+    let input = include_str!("generate_runtime_validators/fixtures/flow-commonjs.graphql");
+    let expected = include_str!("generate_runtime_validators/fixtures/flow-commonjs.expected");
+    test_fixture(transform_fixture, file!(), "flow-commonjs.graphql", "generate_runtime_validators/fixtures/flow-commonjs.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn operation() {
+    let input = include_str!("generate_runtime_validators/fixtures/operation.graphql");
+    let expected = include_str!("generate_runtime_validators/fixtures/operation.expected");
+    test_fixture(transform_fixture, file!(), "operation.graphql", "generate_runtime_validators/fixtures/operation.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn required() {
+    let input = include_str!("generate_runtime_validators/fixtures/required.graphql");
+    let expected = include_str!("generate_runtime_validators/fixtures/required.expected");
+    test_fixture(transform_fixture, file!(), "required.graphql", "generate_runtime_validators/fixtures/required.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn valibot() {
+    let input = include_str!("generate_runtime_validators/fixtures/valibot.graphql");
+    let expected = include_str!("generate_runtime_validators/fixtures/valibot.expected");
+    test_fixture(transform_fixture, file!(), "valibot.graphql", "generate_runtime_validators/fixtures/valibot.expected", input, expected).await;
+}
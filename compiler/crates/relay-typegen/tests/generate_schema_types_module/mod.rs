@@ -0,0 +1,62 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::ScalarName;
+use fixture_tests::Fixture;
+use fnv::FnvBuildHasher;
+use indexmap::IndexMap;
+use intern::string_key::Intern;
+use relay_config::CustomScalarType;
+use relay_config::CustomScalarTypeImport;
+use relay_config::ProjectConfig;
+use relay_config::SchemaTypesModuleConfig;
+use relay_typegen::FragmentLocations;
+use relay_typegen::TypegenConfig;
+use relay_typegen::TypegenLanguage;
+use schema::build_schema;
+
+type FnvIndexMap<K, V> = IndexMap<K, V, FnvBuildHasher>;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let schema = build_schema(fixture.content)
+        .unwrap_or_else(|e| panic!("Encountered error building schema: {:?}", e));
+    let mut custom_scalar_types = FnvIndexMap::default();
+    custom_scalar_types.insert(
+        ScalarName("JSON".intern()),
+        CustomScalarType::Path(CustomScalarTypeImport {
+            name: "JSON".intern(),
+            path: "TypeDefsFile".into(),
+        }),
+    );
+
+    let languages = [TypegenLanguage::Flow, TypegenLanguage::TypeScript];
+    let results = languages.iter().map(|language| {
+        let project_config = ProjectConfig {
+            typegen_config: TypegenConfig {
+                language: *language,
+                custom_scalar_types: custom_scalar_types.clone(),
+                no_future_proof_enums: fixture
+                    .content
+                    .contains("# typegen_config.no_future_proof_enums = true"),
+                schema_types_module: Some(SchemaTypesModuleConfig::default()),
+                branded_id_types: fixture
+                    .content
+                    .contains("# typegen_config.branded_id_types = true"),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        relay_typegen::generate_schema_types_module_section(
+            &schema,
+            &project_config,
+            &FragmentLocations::new(std::iter::empty()),
+        )
+    });
+    Ok(results
+        .collect::<Vec<_>>()
+        .join("-------------------------------------------------------------------------------\n"))
+}
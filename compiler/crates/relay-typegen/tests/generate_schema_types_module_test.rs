@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<731066c5fed96aae38e3fa4a76b8e92f>>
+ */
+
+mod generate_schema_types_module;
+
+use generate_schema_types_module::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn branded_id_types() {
+    let input = include_str!("generate_schema_types_module/fixtures/branded-id-types.graphql");
+    let expected = include_str!("generate_schema_types_module/fixtures/branded-id-types.expected");
+    test_fixture(transform_fixture, file!(), "branded-id-types.graphql", "generate_schema_types_module/fixtures/branded-id-types.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn enums_and_input_objects() {
+    let input = include_str!("generate_schema_types_module/fixtures/enums-and-input-objects.graphql");
+    let expected = include_str!("generate_schema_types_module/fixtures/enums-and-input-objects.expected");
+    test_fixture(transform_fixture, file!(), "enums-and-input-objects.graphql", "generate_schema_types_module/fixtures/enums-and-input-objects.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn no_future_proof_enums() {
+    let input = include_str!("generate_schema_types_module/fixtures/no-future-proof-enums.graphql");
+    let expected = include_str!("generate_schema_types_module/fixtures/no-future-proof-enums.expected");
+    test_fixture(transform_fixture, file!(), "no-future-proof-enums.graphql", "generate_schema_types_module/fixtures/no-future-proof-enums.expected", input, expected).await;
+}
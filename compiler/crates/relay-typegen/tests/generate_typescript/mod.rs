@@ -27,6 +27,7 @@ use relay_config::CustomScalarType;
 use relay_config::CustomScalarTypeImport;
 use relay_config::ProjectConfig;
 use relay_config::ProjectName;
+use relay_config::SchemaTypesModuleConfig;
 use relay_test_schema::get_test_schema;
 use relay_test_schema::get_test_schema_with_extensions;
 use relay_transforms::apply_transforms;
@@ -75,6 +76,16 @@ pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String>
             experimental_emit_semantic_nullability_types: fixture
                 .content
                 .contains("# relay:experimental_emit_semantic_nullability_types"),
+            emit_doc_comments: fixture
+                .content
+                .contains("# typegen_config.emit_doc_comments = true"),
+            schema_types_module: fixture
+                .content
+                .contains("# typegen_config.schema_types_module = true")
+                .then(SchemaTypesModuleConfig::default),
+            branded_id_types: fixture
+                .content
+                .contains("# typegen_config.branded_id_types = true"),
             ..Default::default()
         },
         feature_flags: Arc::new(FeatureFlags {
@@ -91,6 +102,7 @@ pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String>
         Arc::new(ConsoleLogger),
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<fd50a3d51a0a463eb51919b6c8aa53f8>>
+ * @generated SignedSource<<f581958304d1ef8ce568d013c7686efd>>
  */
 
 mod generate_typescript;
@@ -61,6 +61,27 @@ async fn aliased_inline_fragment_spread_without_type_condition_query_root() {
     test_fixture(transform_fixture, file!(), "aliased-inline-fragment-spread-without-type-condition-query-root.graphql", "generate_typescript/fixtures/aliased-inline-fragment-spread-without-type-condition-query-root.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn branded_id_types() {
+    let input = include_str!("generate_typescript/fixtures/branded-id-types.graphql");
+    let expected = include_str!("generate_typescript/fixtures/branded-id-types.expected");
+    test_fixture(transform_fixture, file!(), "branded-id-types.graphql", "generate_typescript/fixtures/branded-id-types.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn catch() {
+    let input = include_str!("generate_typescript/fixtures/catch.graphql");
+    let expected = include_str!("generate_typescript/fixtures/catch.expected");
+    test_fixture(transform_fixture, file!(), "catch.graphql", "generate_typescript/fixtures/catch.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn catch_semantic_non_null() {
+    let input = include_str!("generate_typescript/fixtures/catch_semantic_non_null.graphql");
+    let expected = include_str!("generate_typescript/fixtures/catch_semantic_non_null.expected");
+    test_fixture(transform_fixture, file!(), "catch_semantic_non_null.graphql", "generate_typescript/fixtures/catch_semantic_non_null.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn conditional() {
     let input = include_str!("generate_typescript/fixtures/conditional.graphql");
@@ -82,6 +103,20 @@ async fn default_input() {
     test_fixture(transform_fixture, file!(), "default-input.graphql", "generate_typescript/fixtures/default-input.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn dependent_operation() {
+    let input = include_str!("generate_typescript/fixtures/dependent-operation.graphql");
+    let expected = include_str!("generate_typescript/fixtures/dependent-operation.expected");
+    test_fixture(transform_fixture, file!(), "dependent-operation.graphql", "generate_typescript/fixtures/dependent-operation.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn doc_comments() {
+    let input = include_str!("generate_typescript/fixtures/doc-comments.graphql");
+    let expected = include_str!("generate_typescript/fixtures/doc-comments.expected");
+    test_fixture(transform_fixture, file!(), "doc-comments.graphql", "generate_typescript/fixtures/doc-comments.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn fragment_spread() {
     let input = include_str!("generate_typescript/fixtures/fragment-spread.graphql");
@@ -159,6 +194,13 @@ async fn mutation_with_response_on_inline_fragments() {
     test_fixture(transform_fixture, file!(), "mutation-with-response-on-inline-fragments.graphql", "generate_typescript/fixtures/mutation-with-response-on-inline-fragments.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn one_of_input_object() {
+    let input = include_str!("generate_typescript/fixtures/one-of-input-object.graphql");
+    let expected = include_str!("generate_typescript/fixtures/one-of-input-object.expected");
+    test_fixture(transform_fixture, file!(), "one-of-input-object.graphql", "generate_typescript/fixtures/one-of-input-object.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn plural_fragment() {
     let input = include_str!("generate_typescript/fixtures/plural-fragment.graphql");
@@ -257,6 +299,13 @@ async fn relay_client_id_field() {
     test_fixture(transform_fixture, file!(), "relay-client-id-field.graphql", "generate_typescript/fixtures/relay-client-id-field.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn relay_resolver_batch() {
+    let input = include_str!("generate_typescript/fixtures/relay-resolver-batch.graphql");
+    let expected = include_str!("generate_typescript/fixtures/relay-resolver-batch.expected");
+    test_fixture(transform_fixture, file!(), "relay-resolver-batch.graphql", "generate_typescript/fixtures/relay-resolver-batch.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn relay_resolver_with_output_type_client_interface() {
     let input = include_str!("generate_typescript/fixtures/relay-resolver-with-output-type-client-interface.graphql");
@@ -341,6 +390,13 @@ async fn required_isolates_concrete_inline_fragments() {
     test_fixture(transform_fixture, file!(), "required-isolates-concrete-inline-fragments.graphql", "generate_typescript/fixtures/required-isolates-concrete-inline-fragments.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn required_on_aliased_fragments() {
+    let input = include_str!("generate_typescript/fixtures/required-on-aliased-fragments.graphql");
+    let expected = include_str!("generate_typescript/fixtures/required-on-aliased-fragments.expected");
+    test_fixture(transform_fixture, file!(), "required-on-aliased-fragments.graphql", "generate_typescript/fixtures/required-on-aliased-fragments.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn required_raw_response_type() {
     let input = include_str!("generate_typescript/fixtures/required-raw-response-type.graphql");
@@ -397,6 +453,13 @@ async fn scalar_field() {
     test_fixture(transform_fixture, file!(), "scalar-field.graphql", "generate_typescript/fixtures/scalar-field.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn schema_types_module() {
+    let input = include_str!("generate_typescript/fixtures/schema-types-module.graphql");
+    let expected = include_str!("generate_typescript/fixtures/schema-types-module.expected");
+    test_fixture(transform_fixture, file!(), "schema-types-module.graphql", "generate_typescript/fixtures/schema-types-module.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn semantic_non_null_in_raw_response() {
     let input = include_str!("generate_typescript/fixtures/semantic_non_null_in_raw_response.graphql");
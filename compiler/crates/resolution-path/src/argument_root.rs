@@ -13,6 +13,9 @@ impl<'a> ArgumentParent<'a> {
             Self::Directive(directive_path) => ArgumentRoot::Directive(directive_path),
             Self::ScalarField(scalar_field_path) => ArgumentRoot::ScalarField(scalar_field_path),
             Self::LinkedField(linked_field_path) => ArgumentRoot::LinkedField(linked_field_path),
+            Self::FragmentSpread(fragment_spread_path) => {
+                ArgumentRoot::FragmentSpread(fragment_spread_path)
+            }
             Self::ConstantObject(ConstantObjectPath { inner: _, parent }) => parent
                 .parent
                 .find_enclosing_argument_path()
@@ -27,12 +30,14 @@ impl<'a> ArgumentParent<'a> {
 /// (i.e. the first parent that isn't another argument.)
 ///
 /// The ArgumentParentRoot enum represents the possible logical parents for these
-/// arguments. An argument can ultimately be passed to a field or a directive.
+/// arguments. An argument can ultimately be passed to a field, a directive or
+/// a fragment spread.
 #[derive(Debug)]
 pub enum ArgumentRoot<'a> {
     LinkedField(&'a LinkedFieldPath<'a>),
     ScalarField(&'a ScalarFieldPath<'a>),
     Directive(&'a DirectivePath<'a>),
+    FragmentSpread(&'a FragmentSpreadPath<'a>),
 }
 
 #[cfg(test)]
@@ -181,4 +186,50 @@ mod test {
             }
         });
     }
+
+    #[test]
+    fn argument_root_fragment_spread_constant_object_path() {
+        let source = r#"
+            query Foo {
+                ...Bar(foo: {bar: {baz: $qux}})
+            }
+        "#;
+        test_resolution(source, ": $qux", |resolved| {
+            if let ResolutionPath::Argument(ArgumentPath {
+                inner: _,
+                parent: parent @ ArgumentParent::ConstantObject(_),
+            }) = resolved
+            {
+                assert_matches!(parent.find_argument_root(), ArgumentRoot::FragmentSpread(_))
+            } else {
+                panic!(
+                    "Should resolve to Argument with ConstantObject parent, instead got {:?}",
+                    resolved
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn argument_root_fragment_spread_fragment_spread_path() {
+        let source = r#"
+            query Foo {
+                ...Bar(foo: {bar: {baz: $qux}})
+            }
+        "#;
+        test_resolution(source, ": {bar", |resolved| {
+            if let ResolutionPath::Argument(ArgumentPath {
+                inner: _,
+                parent: parent @ ArgumentParent::FragmentSpread(_),
+            }) = resolved
+            {
+                assert_matches!(parent.find_argument_root(), ArgumentRoot::FragmentSpread(_))
+            } else {
+                panic!(
+                    "Should resolve to Argument with FragmentSpread parent, instead got {:?}",
+                    resolved
+                );
+            }
+        });
+    }
 }
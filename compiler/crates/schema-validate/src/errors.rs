@@ -14,6 +14,8 @@ use schema::Type;
 use schema::TypeReference;
 use thiserror::Error;
 
+use crate::lint::NamingConvention;
+
 #[derive(Debug, Error)]
 pub enum SchemaValidationError {
     #[error("'{0}' root type must be provided.")]
@@ -88,4 +90,47 @@ pub enum SchemaValidationError {
 
     #[error("Cyclic reference found for interface inheritance: {0}.")]
     CyclicInterfaceInheritance(String),
+
+    #[error("OneOf input field '{0}.{1}' must be nullable.")]
+    NonNullOneOfInputField(StringKey, ArgumentName),
+
+    #[error("OneOf input field '{0}.{1}' cannot have a default value.")]
+    OneOfInputFieldWithDefaultValue(StringKey, ArgumentName),
+}
+
+#[derive(Debug, Error, serde::Serialize)]
+#[serde(tag = "type", content = "args")]
+pub enum SchemaLintError {
+    #[error("Type name '{0}' should be {1}.")]
+    InvalidTypeName(StringKey, NamingConvention),
+
+    #[error("Field name '{0}.{1}' should be {2}.")]
+    InvalidFieldName(StringKey, StringKey, NamingConvention),
+
+    #[error("Enum value '{0}.{1}' should be {2}.")]
+    InvalidEnumValueName(StringKey, StringKey, NamingConvention),
+
+    #[error("Type '{0}' should have a description.")]
+    MissingTypeDescription(StringKey),
+
+    #[error("The deprecation of '{0}' should have a reason.")]
+    MissingDeprecationReason(String),
+
+    #[error("Connection type '{0}' should have a field '{1}'.")]
+    ConnectionMissingField(StringKey, StringKey),
+
+    #[error("Field '{0}.{1}' should return a list of edges.")]
+    ConnectionEdgesNotList(StringKey, StringKey),
+
+    #[error("Edge type '{0}' should have a field '{1}'.")]
+    EdgeMissingField(StringKey, StringKey),
+
+    #[error("Page info type '{0}' should have a field '{1}'.")]
+    PageInfoMissingField(StringKey, StringKey),
+
+    #[error("Type '{0}' should have a field '{1}' of type 'ID!' to implement 'Node'.")]
+    InvalidNodeIdField(StringKey, StringKey),
+
+    #[error("Field '{0}.{1}' has type '{2}', list items should be non-null.")]
+    NullableListItems(StringKey, StringKey, String),
 }
@@ -6,6 +6,7 @@
  */
 
 mod errors;
+mod lint;
 
 use std::fmt::Write;
 use std::time::Instant;
@@ -20,6 +21,9 @@ use intern::string_key::Intern;
 use intern::string_key::StringKey;
 use intern::Lookup;
 use lazy_static::lazy_static;
+pub use lint::lint;
+pub use lint::NamingConvention;
+pub use lint::SchemaLintConfig;
 use log::info;
 use regex::Regex;
 use schema::EnumID;
@@ -125,17 +129,17 @@ impl<'schema> ValidationContext<'schema> {
             self.validate_name(directive.name.0, context);
             let mut arg_names = FnvHashSet::default();
             for argument in directive.arguments.iter() {
-                self.validate_name(argument.name.0, context);
+                self.validate_name(argument.name.item.0, context);
 
                 // Ensure unique arguments per directive.
-                if arg_names.contains(&argument.name) {
+                if arg_names.contains(&argument.name.item) {
                     self.report_error(
-                        SchemaValidationError::DuplicateArgument(argument.name, directive.name.0),
+                        SchemaValidationError::DuplicateArgument(argument.name.item, directive.name.0),
                         context,
                     );
                     continue;
                 }
-                arg_names.insert(argument.name);
+                arg_names.insert(argument.name.item);
             }
         }
     }
@@ -221,18 +225,18 @@ impl<'schema> ValidationContext<'schema> {
             let mut arg_names = FnvHashSet::default();
             for argument in field.arguments.iter() {
                 // Ensure they are named correctly.
-                self.validate_name(argument.name.0, context);
+                self.validate_name(argument.name.item.0, context);
 
                 // Ensure they are unique per field.
                 // Ensure unique arguments per directive.
-                if arg_names.contains(&argument.name) {
+                if arg_names.contains(&argument.name.item) {
                     self.report_error(
-                        SchemaValidationError::DuplicateArgument(argument.name, field.name.item),
+                        SchemaValidationError::DuplicateArgument(argument.name.item, field.name.item),
                         context,
                     );
                     continue;
                 }
-                arg_names.insert(argument.name);
+                arg_names.insert(argument.name.item);
 
                 // Ensure the type is an input type
                 if !is_input_type(&argument.type_) {
@@ -240,7 +244,7 @@ impl<'schema> ValidationContext<'schema> {
                         SchemaValidationError::InvalidArgumentType(
                             type_name,
                             field.name.item,
-                            argument.name,
+                            argument.name.item,
                             argument.type_.clone(),
                         ),
                         context,
@@ -301,20 +305,42 @@ impl<'schema> ValidationContext<'schema> {
         // Ensure the arguments are valid
         for field in input_object.fields.iter() {
             // Ensure they are named correctly.
-            self.validate_name(field.name.0, context);
+            self.validate_name(field.name.item.0, context);
 
             // Ensure the type is an input type
             if !is_input_type(&field.type_) {
                 self.report_error(
                     SchemaValidationError::InvalidArgumentType(
                         input_object.name.item.0,
-                        field.name.0,
-                        field.name,
+                        field.name.item.0,
+                        field.name.item,
                         field.type_.clone(),
                     ),
                     context,
                 );
             }
+
+            // Ensure `@oneOf` fields are nullable and have no default value.
+            if input_object.is_one_of() {
+                if field.type_.is_non_null() {
+                    self.report_error(
+                        SchemaValidationError::NonNullOneOfInputField(
+                            input_object.name.item.0,
+                            field.name.item,
+                        ),
+                        context,
+                    );
+                }
+                if field.default_value.is_some() {
+                    self.report_error(
+                        SchemaValidationError::OneOfInputFieldWithDefaultValue(
+                            input_object.name.item.0,
+                            field.name.item,
+                        ),
+                        context,
+                    );
+                }
+            }
         }
     }
 
@@ -387,7 +413,7 @@ impl<'schema> ValidationContext<'schema> {
                 let object_argument = object_field
                     .arguments
                     .iter()
-                    .find(|arg| arg.name == interface_argument.name);
+                    .find(|arg| arg.name.item == interface_argument.name.item);
 
                 // Assert interface field arg exists on object field.
                 if object_argument.is_none() {
@@ -395,7 +421,7 @@ impl<'schema> ValidationContext<'schema> {
                         SchemaValidationError::InterfaceFieldArgumentNotProvided(
                             interface.name.item,
                             field_name,
-                            interface_argument.name,
+                            interface_argument.name.item,
                             typename,
                         ),
                         context,
@@ -412,7 +438,7 @@ impl<'schema> ValidationContext<'schema> {
                         SchemaValidationError::NotEqualType(
                             interface.name.item,
                             field_name,
-                            interface_argument.name,
+                            interface_argument.name.item,
                             self.schema.get_type_name(interface_argument.type_.inner()),
                             typename,
                             self.schema.get_type_name(object_argument.type_.inner()),
@@ -425,14 +451,14 @@ impl<'schema> ValidationContext<'schema> {
 
             // Assert additional arguments must not be required.
             for object_argument in object_field.arguments.iter() {
-                if !interface_field.arguments.contains(object_argument.name.0)
+                if !interface_field.arguments.contains(object_argument.name.item.0)
                     && object_argument.type_.is_non_null()
                 {
                     self.report_error(
                         SchemaValidationError::MissingRequiredArgument(
                             typename,
                             field_name,
-                            object_argument.name,
+                            object_argument.name.item,
                             interface.name.item,
                         ),
                         context,
@@ -0,0 +1,433 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fmt;
+
+use common::Diagnostic;
+use common::Location;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use intern::Lookup;
+use lazy_static::lazy_static;
+use regex::Regex;
+use relay_config::ConnectionInterface;
+use schema::DirectiveValue;
+use schema::FieldID;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+use schema::TypeReference;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::errors::SchemaLintError;
+
+lazy_static! {
+    static ref PASCAL_CASE_REGEX: Regex = Regex::new(r"^[A-Z][a-zA-Z0-9]*$").unwrap();
+    static ref CAMEL_CASE_REGEX: Regex = Regex::new(r"^[a-z][a-zA-Z0-9]*$").unwrap();
+    static ref SNAKE_CASE_REGEX: Regex = Regex::new(r"^[a-z][a-z0-9_]*$").unwrap();
+    static ref SCREAMING_SNAKE_CASE_REGEX: Regex = Regex::new(r"^[A-Z][A-Z0-9_]*$").unwrap();
+    static ref DEPRECATED: StringKey = "deprecated".intern();
+    static ref REASON: StringKey = "reason".intern();
+    static ref NODE: StringKey = "Node".intern();
+    static ref BUILTIN_SCALARS: [StringKey; 5] = [
+        "ID".intern(),
+        "String".intern(),
+        "Int".intern(),
+        "Float".intern(),
+        "Boolean".intern(),
+    ];
+}
+
+/// Configuration of the style rules checked by `lint`. All rules are
+/// disabled by default.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct SchemaLintConfig {
+    /// Naming convention for the names of types.
+    #[serde(default)]
+    pub type_names: Option<NamingConvention>,
+
+    /// Naming convention for the names of fields and input object fields.
+    #[serde(default)]
+    pub field_names: Option<NamingConvention>,
+
+    /// Naming convention for enum values.
+    #[serde(default)]
+    pub enum_values: Option<NamingConvention>,
+
+    /// Require a description on all types, except for the builtin scalars.
+    #[serde(default)]
+    pub require_type_descriptions: bool,
+
+    /// Require a `reason` argument on all `@deprecated` directives.
+    #[serde(default)]
+    pub require_deprecation_reason: bool,
+
+    /// Check that types named `*Connection` have the shape described by the
+    /// connection interface.
+    #[serde(default)]
+    pub validate_connections: bool,
+
+    /// The connection interface used by `validateConnections`.
+    #[serde(default)]
+    pub connection_interface: ConnectionInterface,
+
+    /// Check that the `Node` interface, and the types implementing it, have a
+    /// `nodeInterfaceIdField` field of type `ID!`.
+    #[serde(default)]
+    pub validate_node_id_field: bool,
+
+    /// The name of the id field of the `Node` interface.
+    #[serde(default = "default_node_interface_id_field")]
+    pub node_interface_id_field: StringKey,
+
+    /// Disallow list types with nullable items, like `[User]`, for fields.
+    #[serde(default)]
+    pub disallow_nullable_list_items: bool,
+}
+
+impl Default for SchemaLintConfig {
+    fn default() -> Self {
+        Self {
+            type_names: None,
+            field_names: None,
+            enum_values: None,
+            require_type_descriptions: false,
+            require_deprecation_reason: false,
+            validate_connections: false,
+            connection_interface: Default::default(),
+            validate_node_id_field: false,
+            node_interface_id_field: default_node_interface_id_field(),
+            disallow_nullable_list_items: false,
+        }
+    }
+}
+
+fn default_node_interface_id_field() -> StringKey {
+    "id".intern()
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub enum NamingConvention {
+    #[serde(rename = "PascalCase")]
+    PascalCase,
+    #[serde(rename = "camelCase")]
+    CamelCase,
+    #[serde(rename = "snake_case")]
+    SnakeCase,
+    #[serde(rename = "SCREAMING_SNAKE_CASE")]
+    ScreamingSnakeCase,
+}
+
+impl NamingConvention {
+    fn matches(self, name: &str) -> bool {
+        match self {
+            NamingConvention::PascalCase => PASCAL_CASE_REGEX.is_match(name),
+            NamingConvention::CamelCase => CAMEL_CASE_REGEX.is_match(name),
+            NamingConvention::SnakeCase => SNAKE_CASE_REGEX.is_match(name),
+            NamingConvention::ScreamingSnakeCase => SCREAMING_SNAKE_CASE_REGEX.is_match(name),
+        }
+    }
+}
+
+impl fmt::Display for NamingConvention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NamingConvention::PascalCase => write!(f, "PascalCase"),
+            NamingConvention::CamelCase => write!(f, "camelCase"),
+            NamingConvention::SnakeCase => write!(f, "snake_case"),
+            NamingConvention::ScreamingSnakeCase => write!(f, "SCREAMING_SNAKE_CASE"),
+        }
+    }
+}
+
+/// Checks the style rules enabled in `config` for the server types of the
+/// schema. Client extensions and introspection types are not checked.
+pub fn lint(schema: &SDLSchema, config: &SchemaLintConfig) -> Vec<Diagnostic> {
+    let mut linter = SchemaLinter {
+        schema,
+        config,
+        diagnostics: Vec::new(),
+    };
+    linter.lint_types();
+    linter.diagnostics
+}
+
+struct SchemaLinter<'a> {
+    schema: &'a SDLSchema,
+    config: &'a SchemaLintConfig,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl SchemaLinter<'_> {
+    fn lint_types(&mut self) {
+        let mut types: Vec<_> = self.schema.get_type_map().collect();
+        types.sort_by_key(|(name, _)| name.lookup());
+        for (type_name, type_) in types {
+            let type_name = *type_name;
+            let type_ = *type_;
+            if type_name.lookup().starts_with("__") || self.schema.is_extension_type(type_) {
+                continue;
+            }
+            let location = self.type_location(type_);
+            self.lint_type_name(type_name, location);
+            self.lint_type_description(type_, type_name, location);
+            match type_ {
+                Type::Object(id) => {
+                    let object = self.schema.object(id);
+                    self.lint_fields(type_name, &object.fields);
+                    if object
+                        .interfaces
+                        .iter()
+                        .any(|interface| self.schema.interface(*interface).name.item.0 == *NODE)
+                    {
+                        self.lint_node_id_field(type_name, &object.fields, location);
+                    }
+                    if type_name.lookup().ends_with("Connection") {
+                        self.lint_connection(type_, type_name, location);
+                    }
+                }
+                Type::Interface(id) => {
+                    let interface = self.schema.interface(id);
+                    self.lint_fields(type_name, &interface.fields);
+                    if type_name == *NODE {
+                        self.lint_node_id_field(type_name, &interface.fields, location);
+                    }
+                }
+                Type::InputObject(id) => {
+                    for field in self.schema.input_object(id).fields.iter() {
+                        self.lint_field_name(type_name, field.name.item.0, field.name.location);
+                    }
+                }
+                Type::Enum(id) => {
+                    for value in &self.schema.enum_(id).values {
+                        if let Some(convention) = self.config.enum_values {
+                            if !convention.matches(value.value.lookup()) {
+                                self.report(
+                                    SchemaLintError::InvalidEnumValueName(
+                                        type_name,
+                                        value.value,
+                                        convention,
+                                    ),
+                                    location,
+                                );
+                            }
+                        }
+                        self.lint_deprecation_reason(
+                            &value.directives,
+                            format!("{}.{}", type_name, value.value),
+                            location,
+                        );
+                    }
+                }
+                Type::Scalar(_) | Type::Union(_) => {}
+            }
+        }
+    }
+
+    fn lint_type_name(&mut self, type_name: StringKey, location: Location) {
+        if let Some(convention) = self.config.type_names {
+            if !BUILTIN_SCALARS.contains(&type_name) && !convention.matches(type_name.lookup()) {
+                self.report(
+                    SchemaLintError::InvalidTypeName(type_name, convention),
+                    location,
+                );
+            }
+        }
+    }
+
+    fn lint_type_description(&mut self, type_: Type, type_name: StringKey, location: Location) {
+        if !self.config.require_type_descriptions || BUILTIN_SCALARS.contains(&type_name) {
+            return;
+        }
+        let description = match type_ {
+            Type::Enum(id) => self.schema.enum_(id).description,
+            Type::InputObject(id) => self.schema.input_object(id).description,
+            Type::Interface(id) => self.schema.interface(id).description,
+            Type::Object(id) => self.schema.object(id).description,
+            Type::Scalar(id) => self.schema.scalar(id).description,
+            Type::Union(id) => self.schema.union(id).description,
+        };
+        if description.map_or(true, |description| description.lookup().trim().is_empty()) {
+            self.report(SchemaLintError::MissingTypeDescription(type_name), location);
+        }
+    }
+
+    fn lint_fields(&mut self, type_name: StringKey, fields: &[FieldID]) {
+        for field_id in fields {
+            let field = self.schema.field(*field_id);
+            if field.is_extension {
+                continue;
+            }
+            let location = field.name.location;
+            self.lint_field_name(type_name, field.name.item, location);
+            self.lint_deprecation_reason(
+                &field.directives,
+                format!("{}.{}", type_name, field.name.item),
+                location,
+            );
+            if self.config.disallow_nullable_list_items && has_nullable_list_items(&field.type_) {
+                self.report(
+                    SchemaLintError::NullableListItems(
+                        type_name,
+                        field.name.item,
+                        self.schema.get_type_string(&field.type_),
+                    ),
+                    location,
+                );
+            }
+        }
+    }
+
+    fn lint_field_name(&mut self, type_name: StringKey, field_name: StringKey, location: Location) {
+        if let Some(convention) = self.config.field_names {
+            if !field_name.lookup().starts_with("__") && !convention.matches(field_name.lookup()) {
+                self.report(
+                    SchemaLintError::InvalidFieldName(type_name, field_name, convention),
+                    location,
+                );
+            }
+        }
+    }
+
+    fn lint_deprecation_reason(
+        &mut self,
+        directives: &[DirectiveValue],
+        coordinate: String,
+        location: Location,
+    ) {
+        if !self.config.require_deprecation_reason {
+            return;
+        }
+        if let Some(deprecated) = directives
+            .iter()
+            .find(|directive| directive.name.0 == *DEPRECATED)
+        {
+            let reason = deprecated
+                .arguments
+                .iter()
+                .find(|argument| argument.name.0 == *REASON)
+                .and_then(|argument| argument.get_string_literal());
+            if reason.map_or(true, |reason| reason.lookup().trim().is_empty()) {
+                self.report(
+                    SchemaLintError::MissingDeprecationReason(coordinate),
+                    location,
+                );
+            }
+        }
+    }
+
+    fn lint_node_id_field(&mut self, type_name: StringKey, fields: &[FieldID], location: Location) {
+        if !self.config.validate_node_id_field {
+            return;
+        }
+        let id_field_name = self.config.node_interface_id_field;
+        let id_field = fields
+            .iter()
+            .map(|field_id| self.schema.field(*field_id))
+            .find(|field| field.name.item == id_field_name);
+        let is_valid = id_field.is_some_and(|field| {
+            field.type_.is_non_null()
+                && !field.type_.nullable_type().is_list()
+                && self.schema.is_id(field.type_.inner())
+        });
+        if !is_valid {
+            self.report(
+                SchemaLintError::InvalidNodeIdField(type_name, id_field_name),
+                id_field.map_or(location, |field| field.name.location),
+            );
+        }
+    }
+
+    fn lint_connection(&mut self, connection_type: Type, type_name: StringKey, location: Location) {
+        if !self.config.validate_connections {
+            return;
+        }
+        let connection_interface = &self.config.connection_interface;
+
+        match self.field_type(connection_type, connection_interface.edges) {
+            None => self.report(
+                SchemaLintError::ConnectionMissingField(type_name, connection_interface.edges),
+                location,
+            ),
+            Some(edges_type) if !edges_type.nullable_type().is_list() => self.report(
+                SchemaLintError::ConnectionEdgesNotList(type_name, connection_interface.edges),
+                location,
+            ),
+            Some(edges_type) => {
+                let edge_type = edges_type.inner();
+                for field_name in [connection_interface.node, connection_interface.cursor] {
+                    if self.field_type(edge_type, field_name).is_none() {
+                        self.report(
+                            SchemaLintError::EdgeMissingField(
+                                self.schema.get_type_name(edge_type),
+                                field_name,
+                            ),
+                            self.type_location(edge_type),
+                        );
+                    }
+                }
+            }
+        }
+
+        match self.field_type(connection_type, connection_interface.page_info) {
+            None => self.report(
+                SchemaLintError::ConnectionMissingField(type_name, connection_interface.page_info),
+                location,
+            ),
+            Some(page_info_type) => {
+                let page_info_type = page_info_type.inner();
+                for field_name in [
+                    connection_interface.has_next_page,
+                    connection_interface.has_previous_page,
+                    connection_interface.start_cursor,
+                    connection_interface.end_cursor,
+                ] {
+                    if self.field_type(page_info_type, field_name).is_none() {
+                        self.report(
+                            SchemaLintError::PageInfoMissingField(
+                                self.schema.get_type_name(page_info_type),
+                                field_name,
+                            ),
+                            self.type_location(page_info_type),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn field_type(&self, parent_type: Type, field_name: StringKey) -> Option<TypeReference<Type>> {
+        self.schema
+            .named_field(parent_type, field_name)
+            .map(|field_id| self.schema.field(field_id).type_.clone())
+    }
+
+    fn type_location(&self, type_: Type) -> Location {
+        match type_ {
+            Type::Enum(id) => self.schema.enum_(id).name.location,
+            Type::InputObject(id) => self.schema.input_object(id).name.location,
+            Type::Interface(id) => self.schema.interface(id).name.location,
+            Type::Object(id) => self.schema.object(id).name.location,
+            Type::Scalar(id) => self.schema.scalar(id).name.location,
+            Type::Union(id) => self.schema.union(id).name.location,
+        }
+    }
+
+    fn report(&mut self, error: SchemaLintError, location: Location) {
+        self.diagnostics.push(Diagnostic::error(error, location));
+    }
+}
+
+fn has_nullable_list_items(type_: &TypeReference<Type>) -> bool {
+    match type_ {
+        TypeReference::Named(_) => false,
+        TypeReference::NonNull(of) => has_nullable_list_items(of),
+        TypeReference::List(of) => !of.is_non_null() || has_nullable_list_items(of),
+    }
+}
@@ -9,10 +9,13 @@ use std::fs;
 use std::path::Path;
 
 use clap::Parser;
-use common::DiagnosticsResult;
+use common::TextSource;
+use graphql_cli::DiagnosticPrinter;
 use schema::build_schema;
 use schema::SDLSchema;
+use schema_validate_lib::lint;
 use schema_validate_lib::validate;
+use schema_validate_lib::SchemaLintConfig;
 
 #[derive(Parser)]
 #[clap(name = "schema-validate", about = "Binary to Validate GraphQL Schema.")]
@@ -20,11 +23,17 @@ struct Opt {
     /// Path to Schema SDL. If schema is sharded, this is directory.
     #[clap(long)]
     schema_path: String,
+
+    /// Path to a JSON file configuring the lint rules to check in addition
+    /// to the spec validation.
+    #[clap(long)]
+    lint_config: Option<String>,
 }
 
 pub fn main() {
     let opt = Opt::parse();
-    match build_schema_from_file(&opt.schema_path) {
+    let data = read_schema(&opt.schema_path);
+    match build_schema(&data) {
         Ok(schema) => {
             let validation_context = validate(&schema);
             if !validation_context.errors.is_empty() {
@@ -34,6 +43,9 @@ pub fn main() {
                 );
                 std::process::exit(1);
             }
+            if let Some(lint_config) = &opt.lint_config {
+                lint_schema(&schema, &data, lint_config);
+            }
         }
         Err(error) => {
             eprintln!("Failed to parse schema:\n{:?}", error);
@@ -42,9 +54,29 @@ pub fn main() {
     }
 }
 
-fn build_schema_from_file(schema_file: &str) -> DiagnosticsResult<SDLSchema> {
+fn lint_schema(schema: &SDLSchema, data: &str, lint_config_path: &str) {
+    let config: SchemaLintConfig = serde_json::from_str(
+        &fs::read_to_string(lint_config_path).unwrap(),
+    )
+    .unwrap_or_else(|error| {
+        eprintln!("Failed to parse lint config:\n{}", error);
+        std::process::exit(1);
+    });
+    let diagnostics = lint(schema, &config);
+    if !diagnostics.is_empty() {
+        let printer =
+            DiagnosticPrinter::new(|_| Some(TextSource::from_whole_document(data.to_string())));
+        eprintln!(
+            "Schema failed linting with below errors:\n{}",
+            printer.diagnostics_to_string(&diagnostics)
+        );
+        std::process::exit(1);
+    }
+}
+
+fn read_schema(schema_file: &str) -> String {
     let path = Path::new(schema_file);
-    let data = if path.is_file() {
+    if path.is_file() {
         fs::read_to_string(path).unwrap()
     } else {
         let mut buffer = String::new();
@@ -52,6 +84,5 @@ fn build_schema_from_file(schema_file: &str) -> DiagnosticsResult<SDLSchema> {
             buffer.push_str(&fs::read_to_string(entry.unwrap().path()).unwrap());
         }
         buffer
-    };
-    build_schema(&data)
+    }
 }
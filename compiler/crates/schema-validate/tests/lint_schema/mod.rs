@@ -0,0 +1,28 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use fixture_tests::Fixture;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use schema::build_schema;
+use schema_validate_lib::lint;
+use schema_validate_lib::SchemaLintConfig;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split("%config%").collect();
+    let (sdl, config) = match parts.as_slice() {
+        [sdl, config] => (*sdl, *config),
+        _ => panic!("Expected exactly one %config% section in the fixture."),
+    };
+    let config: SchemaLintConfig = serde_json::from_str(config).map_err(|err| err.to_string())?;
+    let schema = build_schema(sdl).unwrap();
+    let diagnostics = lint(&schema, &config);
+    if diagnostics.is_empty() {
+        Ok("OK".to_string())
+    } else {
+        Err(diagnostics_to_sorted_string(sdl, &diagnostics))
+    }
+}
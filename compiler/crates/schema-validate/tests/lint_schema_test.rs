@@ -0,0 +1,62 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<1568b87ec6098a52d987a75e801bf593>>
+ */
+
+mod lint_schema;
+
+use lint_schema::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn connections_invalid() {
+    let input = include_str!("lint_schema/fixtures/connections.invalid.graphql");
+    let expected = include_str!("lint_schema/fixtures/connections.invalid.expected");
+    test_fixture(transform_fixture, file!(), "connections.invalid.graphql", "lint_schema/fixtures/connections.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn deprecation_reason_invalid() {
+    let input = include_str!("lint_schema/fixtures/deprecation_reason.invalid.graphql");
+    let expected = include_str!("lint_schema/fixtures/deprecation_reason.invalid.expected");
+    test_fixture(transform_fixture, file!(), "deprecation_reason.invalid.graphql", "lint_schema/fixtures/deprecation_reason.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn naming_conventions() {
+    let input = include_str!("lint_schema/fixtures/naming_conventions.graphql");
+    let expected = include_str!("lint_schema/fixtures/naming_conventions.expected");
+    test_fixture(transform_fixture, file!(), "naming_conventions.graphql", "lint_schema/fixtures/naming_conventions.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn naming_conventions_invalid() {
+    let input = include_str!("lint_schema/fixtures/naming_conventions.invalid.graphql");
+    let expected = include_str!("lint_schema/fixtures/naming_conventions.invalid.expected");
+    test_fixture(transform_fixture, file!(), "naming_conventions.invalid.graphql", "lint_schema/fixtures/naming_conventions.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn node_id_field_invalid() {
+    let input = include_str!("lint_schema/fixtures/node_id_field.invalid.graphql");
+    let expected = include_str!("lint_schema/fixtures/node_id_field.invalid.expected");
+    test_fixture(transform_fixture, file!(), "node_id_field.invalid.graphql", "lint_schema/fixtures/node_id_field.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn nullable_list_items_invalid() {
+    let input = include_str!("lint_schema/fixtures/nullable_list_items.invalid.graphql");
+    let expected = include_str!("lint_schema/fixtures/nullable_list_items.invalid.expected");
+    test_fixture(transform_fixture, file!(), "nullable_list_items.invalid.graphql", "lint_schema/fixtures/nullable_list_items.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn type_descriptions_invalid() {
+    let input = include_str!("lint_schema/fixtures/type_descriptions.invalid.graphql");
+    let expected = include_str!("lint_schema/fixtures/type_descriptions.invalid.expected");
+    test_fixture(transform_fixture, file!(), "type_descriptions.invalid.graphql", "lint_schema/fixtures/type_descriptions.invalid.expected", input, expected).await;
+}
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<5eb916d4ea81c5d7dd1c342770b1389e>>
+ * @generated SignedSource<<4c3b8cc9af8b84fa4c6ab5c1882f9bb8>>
  */
 
 mod validate_schema;
@@ -40,6 +40,13 @@ async fn validate_object() {
     test_fixture(transform_fixture, file!(), "validate_object.graphql", "validate_schema/fixtures/validate_object.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn validate_one_of_input() {
+    let input = include_str!("validate_schema/fixtures/validate_one_of_input.graphql");
+    let expected = include_str!("validate_schema/fixtures/validate_one_of_input.expected");
+    test_fixture(transform_fixture, file!(), "validate_one_of_input.graphql", "validate_schema/fixtures/validate_one_of_input.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn validate_root_types() {
     let input = include_str!("validate_schema/fixtures/validate_root_types.graphql");
@@ -35,6 +35,7 @@ use crate::Schema;
 lazy_static! {
     static ref DIRECTIVE_DEPRECATED: DirectiveName = DirectiveName("deprecated".intern());
     static ref ARGUMENT_REASON: ArgumentName = ArgumentName("reason".intern());
+    static ref DIRECTIVE_ONE_OF: DirectiveName = DirectiveName("oneOf".intern());
 }
 
 pub(crate) type TypeMap = HashMap<StringKey, Type>;
@@ -406,6 +407,14 @@ pub struct InputObject {
     pub hack_source: Option<StringKey>,
 }
 
+impl InputObject {
+    /// Whether this is a `@oneOf` input object, where exactly one field must
+    /// be provided and its value must be non-null.
+    pub fn is_one_of(&self) -> bool {
+        self.directives.named(*DIRECTIVE_ONE_OF).is_some()
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Enum {
     pub name: WithLocation<EnumName>,
@@ -485,7 +494,7 @@ impl Field {
 
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Argument {
-    pub name: ArgumentName,
+    pub name: WithLocation<ArgumentName>,
     pub type_: TypeReference<Type>,
     pub default_value: Option<ConstantValue>,
     pub description: Option<StringKey>,
@@ -508,7 +517,7 @@ impl Argument {
 impl Named for Argument {
     type Name = ArgumentName;
     fn name(&self) -> ArgumentName {
-        self.name
+        self.name.item
     }
 }
 
@@ -579,7 +588,7 @@ impl ArgumentDefinitions {
     }
 
     pub fn contains(&self, name: StringKey) -> bool {
-        self.0.iter().any(|x| x.name == ArgumentName(name))
+        self.0.iter().any(|x| x.name.item == ArgumentName(name))
     }
 
     pub fn iter(&self) -> Iter<'_, Argument> {
@@ -40,6 +40,15 @@ pub enum SchemaError {
     #[error("Duplicate definition for type '{0}'.")]
     DuplicateType(StringKey),
 
+    #[error("Duplicate definition for type '{0}' in '{1}', it is already defined in '{2}'.")]
+    DuplicateTypeInFiles(StringKey, &'static str, &'static str),
+
+    #[error("Type '{0}' is defined as a {1} in this subgraph, but as a {2} in another subgraph.")]
+    ConflictingSubgraphTypeKinds(StringKey, &'static str, &'static str),
+
+    #[error("Field '{0}.{1}' has type '{2}' in this subgraph, but type '{3}' in another subgraph.")]
+    ConflictingSubgraphFieldTypes(StringKey, StringKey, String, String),
+
     #[error("Invalid ID '{0}' provided for type '{1}'")]
     UnknownTypeID(usize, String),
 
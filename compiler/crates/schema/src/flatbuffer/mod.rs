@@ -350,7 +350,7 @@ impl<'fb> FlatBufferSchema<'fb> {
 
     fn parse_argument(&self, argument: schema_flatbuffer::Argument<'fb>) -> Option<Argument> {
         Some(Argument {
-            name: ArgumentName(argument.name().unwrap().intern()),
+            name: WithLocation::generated(ArgumentName(argument.name().unwrap().intern())),
             default_value: match argument.value() {
                 Some(value) => Some(self.parse_const_value(value)?),
                 _ => None,
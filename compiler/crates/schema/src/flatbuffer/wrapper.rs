@@ -166,7 +166,7 @@ impl SchemaWrapper {
             name: WithLocation::generated(result.is_fulfilled_field_name),
             is_extension: true,
             arguments: ArgumentDefinitions::new(vec![Argument {
-                name: ArgumentName("name".intern()),
+                name: WithLocation::generated(ArgumentName("name".intern())),
                 type_: TypeReference::NonNull(Box::new(TypeReference::Named(
                     result.get_type("String".intern()).unwrap(),
                 ))),
@@ -261,7 +261,7 @@ impl Schema for SchemaWrapper {
                     ("defer", Some(mut directive)) | ("stream", Some(mut directive)) => {
                         let mut next_args: Vec<_> = directive.arguments.iter().cloned().collect();
                         for arg in next_args.iter_mut() {
-                            if arg.name.0.lookup() == "label" {
+                            if arg.name.item.0.lookup() == "label" {
                                 if let TypeReference::NonNull(of) = &arg.type_ {
                                     arg.type_ = *of.clone()
                                 };
@@ -0,0 +1,776 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::Location;
+use common::SourceLocationKey;
+use graphql_syntax::*;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use intern::Lookup;
+
+use super::len_of_option_list;
+use crate::errors::SchemaError;
+
+const FEDERATION_BUILTINS: &str = include_str!("../federation_builtins.graphql");
+
+/// Types that subgraphs expose for the gateway, which are not part of the
+/// supergraph queried by clients.
+const SUBGRAPH_TYPES: [&str; 2] = ["_Entity", "_Service"];
+
+/// Query fields that subgraphs expose for the gateway.
+const SUBGRAPH_QUERY_FIELDS: [&str; 2] = ["_entities", "_service"];
+
+/// Composes the schemas of federation subgraphs into a single supergraph:
+/// - The first occurrence of a type (either `type` or `extend type`) becomes
+///   its definition. Later occurrences in other subgraphs become extensions
+///   located in their own subgraph file, with fields that are already defined
+///   removed.
+/// - `@external` fields are dropped if another subgraph resolves them.
+/// - Input objects only keep the fields accepted by every subgraph that
+///   defines them.
+/// - The federation directives and scalars (`@key`, `_Any`, ...) are builtins,
+///   and the `_entities`/`_service` gateway fields are removed.
+pub fn compose_subgraphs(
+    subgraph_documents: Vec<SchemaDocument>,
+) -> DiagnosticsResult<Vec<SchemaDocument>> {
+    let builtins = parse_schema_document(FEDERATION_BUILTINS, SourceLocationKey::generated())?;
+    let mut composer = Composer::new(&subgraph_documents);
+    composer.compose_document(builtins);
+    for document in subgraph_documents {
+        composer.compose_document(document);
+    }
+    composer.finish()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum TypeKind {
+    Object,
+    Interface,
+    Union,
+    InputObject,
+    Enum,
+    Scalar,
+}
+
+impl TypeKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            TypeKind::Object => "object type",
+            TypeKind::Interface => "interface",
+            TypeKind::Union => "union",
+            TypeKind::InputObject => "input object",
+            TypeKind::Enum => "enum",
+            TypeKind::Scalar => "scalar",
+        }
+    }
+}
+
+/// Position of a definition in `Composer::definitions`: the index of the
+/// document and the index of the definition in that document.
+type DefinitionIndex = (usize, usize);
+
+enum Occurrence {
+    /// First occurrence of the type, which becomes its definition.
+    Definition,
+    /// The type is already defined by the definition at the given index.
+    Extension(DefinitionIndex),
+}
+
+struct ComposedType {
+    kind: TypeKind,
+    location: Location,
+    definition_index: DefinitionIndex,
+    /// Fields (or enum values) of the type, from all subgraphs.
+    fields: HashMap<StringKey, ComposedField>,
+    /// Number of subgraphs that define the type.
+    subgraph_count: usize,
+}
+
+struct ComposedField {
+    location: Location,
+    type_: String,
+    /// Number of subgraphs that define the field.
+    subgraph_count: usize,
+}
+
+struct Composer {
+    query_type_name: StringKey,
+    /// Fields of object and interface types that are resolved by some
+    /// subgraph, i.e. that are defined without `@external`.
+    resolved_fields: HashSet<(StringKey, StringKey)>,
+    types: HashMap<StringKey, ComposedType>,
+    directives: HashSet<StringKey>,
+    operation_types: Vec<(OperationType, StringKey, Location)>,
+    /// The composed definitions, one document per subgraph.
+    definitions: Vec<SchemaDocument>,
+    /// The composed extensions, one document per subgraph. These are built
+    /// after all definitions, so the order of the subgraphs doesn't matter.
+    extensions: Vec<SchemaDocument>,
+    errors: Vec<Diagnostic>,
+}
+
+impl Composer {
+    fn new(subgraph_documents: &[SchemaDocument]) -> Self {
+        let mut query_type_name = None;
+        let mut resolved_fields = HashSet::new();
+        for definition in subgraph_documents
+            .iter()
+            .flat_map(|document| &document.definitions)
+        {
+            match definition {
+                TypeSystemDefinition::SchemaDefinition(SchemaDefinition {
+                    operation_types,
+                    ..
+                }) => {
+                    for operation_type in &operation_types.items {
+                        if operation_type.operation == OperationType::Query {
+                            query_type_name.get_or_insert(operation_type.type_.value);
+                        }
+                    }
+                }
+                TypeSystemDefinition::ObjectTypeDefinition(ObjectTypeDefinition {
+                    name,
+                    fields,
+                    ..
+                })
+                | TypeSystemDefinition::ObjectTypeExtension(ObjectTypeExtension {
+                    name,
+                    fields,
+                    ..
+                })
+                | TypeSystemDefinition::InterfaceTypeDefinition(InterfaceTypeDefinition {
+                    name,
+                    fields,
+                    ..
+                })
+                | TypeSystemDefinition::InterfaceTypeExtension(InterfaceTypeExtension {
+                    name,
+                    fields,
+                    ..
+                }) => {
+                    for field in fields.iter().flat_map(|fields| &fields.items) {
+                        if !is_external(&field.directives) {
+                            resolved_fields.insert((name.value, field.name.value));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Self {
+            query_type_name: query_type_name.unwrap_or_else(|| "Query".intern()),
+            resolved_fields,
+            types: Default::default(),
+            directives: Default::default(),
+            operation_types: Default::default(),
+            definitions: Default::default(),
+            extensions: Default::default(),
+            errors: Default::default(),
+        }
+    }
+
+    fn compose_document(&mut self, document: SchemaDocument) {
+        let source_location = document.location.source_location();
+        self.definitions.push(SchemaDocument {
+            location: document.location,
+            definitions: Vec::new(),
+        });
+        self.extensions.push(SchemaDocument {
+            location: document.location,
+            definitions: Vec::new(),
+        });
+        for definition in document.definitions {
+            self.compose_definition(definition, source_location);
+        }
+    }
+
+    fn compose_definition(
+        &mut self,
+        definition: TypeSystemDefinition,
+        source_location: SourceLocationKey,
+    ) {
+        match definition {
+            TypeSystemDefinition::SchemaDefinition(definition) => {
+                self.compose_schema_definition(definition, source_location)
+            }
+            TypeSystemDefinition::SchemaExtension(extension) => {
+                // Subgraphs use `extend schema @link(...)` to import the
+                // federation directives, which is only relevant to the gateway.
+                if extension.operation_types.is_some() {
+                    self.compose_schema_definition(extension.into(), source_location)
+                }
+            }
+            TypeSystemDefinition::DirectiveDefinition(definition) => {
+                if self.directives.insert(definition.name.value) {
+                    self.push_definition(TypeSystemDefinition::DirectiveDefinition(definition));
+                }
+            }
+            TypeSystemDefinition::ObjectTypeDefinition(definition) => {
+                self.compose_object(definition, source_location)
+            }
+            TypeSystemDefinition::ObjectTypeExtension(extension) => {
+                self.compose_object(extension.into(), source_location)
+            }
+            TypeSystemDefinition::InterfaceTypeDefinition(definition) => {
+                self.compose_interface(definition, source_location)
+            }
+            TypeSystemDefinition::InterfaceTypeExtension(extension) => {
+                self.compose_interface(extension.into(), source_location)
+            }
+            TypeSystemDefinition::UnionTypeDefinition(definition) => {
+                self.compose_union(definition, source_location)
+            }
+            TypeSystemDefinition::UnionTypeExtension(extension) => {
+                self.compose_union(extension.into(), source_location)
+            }
+            TypeSystemDefinition::InputObjectTypeDefinition(definition) => {
+                self.compose_input_object(definition, source_location)
+            }
+            TypeSystemDefinition::InputObjectTypeExtension(extension) => {
+                self.compose_input_object(extension.into(), source_location)
+            }
+            TypeSystemDefinition::EnumTypeDefinition(definition) => {
+                self.compose_enum(definition, source_location)
+            }
+            TypeSystemDefinition::EnumTypeExtension(extension) => {
+                self.compose_enum(extension.into(), source_location)
+            }
+            TypeSystemDefinition::ScalarTypeDefinition(definition) => {
+                self.compose_scalar(definition, source_location)
+            }
+            TypeSystemDefinition::ScalarTypeExtension(extension) => {
+                self.compose_scalar(extension.into(), source_location)
+            }
+        }
+    }
+
+    fn compose_schema_definition(
+        &mut self,
+        mut definition: SchemaDefinition,
+        source_location: SourceLocationKey,
+    ) {
+        let operation_types = std::mem::take(&mut definition.operation_types.items);
+        for operation_type in operation_types {
+            let location = Location::new(source_location, operation_type.type_.span);
+            let previous = self
+                .operation_types
+                .iter()
+                .find(|(operation, _, _)| *operation == operation_type.operation)
+                .copied();
+            match previous {
+                Some((operation, previous_type_name, previous_location)) => {
+                    if previous_type_name != operation_type.type_.value {
+                        self.errors.push(
+                            Diagnostic::error(
+                                SchemaError::DuplicateOperationDefinition(
+                                    operation,
+                                    operation_type.type_.value,
+                                    previous_type_name,
+                                ),
+                                location,
+                            )
+                            .annotate("previously defined here", previous_location),
+                        );
+                    }
+                }
+                None => {
+                    self.operation_types.push((
+                        operation_type.operation,
+                        operation_type.type_.value,
+                        location,
+                    ));
+                    definition.operation_types.items.push(operation_type);
+                }
+            }
+        }
+        if !definition.operation_types.items.is_empty() {
+            self.push_definition(TypeSystemDefinition::SchemaDefinition(definition));
+        }
+    }
+
+    fn compose_object(
+        &mut self,
+        mut definition: ObjectTypeDefinition,
+        source_location: SourceLocationKey,
+    ) {
+        let name = definition.name;
+        match self.register_type(&name, TypeKind::Object, source_location) {
+            Some(Occurrence::Definition) => {
+                self.compose_fields(name.value, &mut definition.fields, source_location);
+                self.push_definition(TypeSystemDefinition::ObjectTypeDefinition(definition));
+            }
+            Some(Occurrence::Extension(index)) => {
+                self.compose_fields(name.value, &mut definition.fields, source_location);
+                self.merge_interfaces(index, definition.interfaces);
+                if len_of_option_list(&definition.fields) > 0 || !definition.directives.is_empty() {
+                    self.push_extension(TypeSystemDefinition::ObjectTypeExtension(
+                        ObjectTypeExtension {
+                            name,
+                            interfaces: Vec::new(),
+                            directives: definition.directives,
+                            fields: definition.fields,
+                        },
+                    ));
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn compose_interface(
+        &mut self,
+        mut definition: InterfaceTypeDefinition,
+        source_location: SourceLocationKey,
+    ) {
+        let name = definition.name;
+        match self.register_type(&name, TypeKind::Interface, source_location) {
+            Some(Occurrence::Definition) => {
+                self.compose_fields(name.value, &mut definition.fields, source_location);
+                self.push_definition(TypeSystemDefinition::InterfaceTypeDefinition(definition));
+            }
+            Some(Occurrence::Extension(index)) => {
+                self.compose_fields(name.value, &mut definition.fields, source_location);
+                self.merge_interfaces(index, definition.interfaces);
+                if len_of_option_list(&definition.fields) > 0 || !definition.directives.is_empty() {
+                    self.push_extension(TypeSystemDefinition::InterfaceTypeExtension(
+                        InterfaceTypeExtension {
+                            name,
+                            interfaces: Vec::new(),
+                            directives: definition.directives,
+                            fields: definition.fields,
+                        },
+                    ));
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn compose_union(
+        &mut self,
+        definition: UnionTypeDefinition,
+        source_location: SourceLocationKey,
+    ) {
+        match self.register_type(&definition.name, TypeKind::Union, source_location) {
+            Some(Occurrence::Definition) => {
+                self.push_definition(TypeSystemDefinition::UnionTypeDefinition(definition));
+            }
+            Some(Occurrence::Extension(index)) => {
+                if let TypeSystemDefinition::UnionTypeDefinition(UnionTypeDefinition {
+                    members,
+                    ..
+                }) = self.definition_mut(index)
+                {
+                    for member in definition.members {
+                        if !members
+                            .iter()
+                            .any(|existing| existing.value == member.value)
+                        {
+                            members.push(member);
+                        }
+                    }
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn compose_input_object(
+        &mut self,
+        definition: InputObjectTypeDefinition,
+        source_location: SourceLocationKey,
+    ) {
+        let name = definition.name.value;
+        let occurrence =
+            self.register_type(&definition.name, TypeKind::InputObject, source_location);
+        if occurrence.is_none() {
+            return;
+        }
+        for field in definition.fields.iter().flat_map(|fields| &fields.items) {
+            self.compose_field(name, &field.name, field.type_.to_string(), source_location);
+        }
+        // Fields that are not accepted by all subgraphs are removed from the
+        // definition in `finish`.
+        if let Some(Occurrence::Definition) = occurrence {
+            self.push_definition(TypeSystemDefinition::InputObjectTypeDefinition(definition));
+        }
+    }
+
+    fn compose_enum(
+        &mut self,
+        mut definition: EnumTypeDefinition,
+        source_location: SourceLocationKey,
+    ) {
+        let name = definition.name;
+        let occurrence = self.register_type(&name, TypeKind::Enum, source_location);
+        if occurrence.is_none() {
+            return;
+        }
+        if let Some(values) = &mut definition.values {
+            let items = std::mem::take(&mut values.items);
+            values.items = items
+                .into_iter()
+                .filter(|value| {
+                    self.compose_field(name.value, &value.name, String::new(), source_location)
+                })
+                .collect();
+        }
+        match occurrence {
+            Some(Occurrence::Definition) => {
+                self.push_definition(TypeSystemDefinition::EnumTypeDefinition(definition));
+            }
+            _ => {
+                if len_of_option_list(&definition.values) > 0 {
+                    self.push_extension(TypeSystemDefinition::EnumTypeExtension(
+                        EnumTypeExtension {
+                            name,
+                            directives: definition.directives,
+                            values: definition.values,
+                        },
+                    ));
+                }
+            }
+        }
+    }
+
+    fn compose_scalar(
+        &mut self,
+        definition: ScalarTypeDefinition,
+        source_location: SourceLocationKey,
+    ) {
+        if let Some(Occurrence::Definition) =
+            self.register_type(&definition.name, TypeKind::Scalar, source_location)
+        {
+            self.push_definition(TypeSystemDefinition::ScalarTypeDefinition(definition));
+        }
+    }
+
+    /// Registers an occurrence of the type `name` in a subgraph. Returns
+    /// `None` if the definition should be dropped, either because it is only
+    /// used by the gateway, or because it conflicts with the definition in
+    /// another subgraph.
+    fn register_type(
+        &mut self,
+        name: &Identifier,
+        kind: TypeKind,
+        source_location: SourceLocationKey,
+    ) -> Option<Occurrence> {
+        if SUBGRAPH_TYPES.contains(&name.value.lookup()) {
+            return None;
+        }
+        let location = Location::new(source_location, name.span);
+        let definition_index = (
+            self.definitions.len() - 1,
+            self.definitions.last().unwrap().definitions.len(),
+        );
+        match self.types.entry(name.value) {
+            Entry::Vacant(entry) => {
+                entry.insert(ComposedType {
+                    kind,
+                    location,
+                    definition_index,
+                    fields: Default::default(),
+                    subgraph_count: 1,
+                });
+                Some(Occurrence::Definition)
+            }
+            Entry::Occupied(mut entry) => {
+                let composed_type = entry.get_mut();
+                if composed_type.kind != kind {
+                    self.errors.push(
+                        Diagnostic::error(
+                            SchemaError::ConflictingSubgraphTypeKinds(
+                                name.value,
+                                kind.as_str(),
+                                composed_type.kind.as_str(),
+                            ),
+                            location,
+                        )
+                        .annotate("previously defined here", composed_type.location),
+                    );
+                    return None;
+                }
+                composed_type.subgraph_count += 1;
+                Some(Occurrence::Extension(composed_type.definition_index))
+            }
+        }
+    }
+
+    /// Removes the fields that are already defined by another subgraph (or
+    /// only exist for the gateway) from `fields`.
+    fn compose_fields(
+        &mut self,
+        type_name: StringKey,
+        fields: &mut Option<List<FieldDefinition>>,
+        source_location: SourceLocationKey,
+    ) {
+        if let Some(fields) = fields {
+            let is_query_type = type_name == self.query_type_name;
+            let items = std::mem::take(&mut fields.items);
+            fields.items = items
+                .into_iter()
+                .filter(|field| {
+                    if is_query_type && SUBGRAPH_QUERY_FIELDS.contains(&field.name.value.lookup()) {
+                        return false;
+                    }
+                    if is_external(&field.directives)
+                        && self
+                            .resolved_fields
+                            .contains(&(type_name, field.name.value))
+                    {
+                        return false;
+                    }
+                    self.compose_field(
+                        type_name,
+                        &field.name,
+                        field.type_.to_string(),
+                        source_location,
+                    )
+                })
+                .collect();
+        }
+    }
+
+    /// Registers a field of the type `type_name`. Returns true if this is the
+    /// first definition of the field, and reports an error if the type of the
+    /// field differs from a previous definition.
+    fn compose_field(
+        &mut self,
+        type_name: StringKey,
+        name: &Identifier,
+        type_: String,
+        source_location: SourceLocationKey,
+    ) -> bool {
+        let location = Location::new(source_location, name.span);
+        let composed_type = self.types.get_mut(&type_name).unwrap();
+        match composed_type.fields.entry(name.value) {
+            Entry::Vacant(entry) => {
+                entry.insert(ComposedField {
+                    location,
+                    type_,
+                    subgraph_count: 1,
+                });
+                true
+            }
+            Entry::Occupied(mut entry) => {
+                let previous = entry.get_mut();
+                previous.subgraph_count += 1;
+                if previous.type_ != type_ {
+                    self.errors.push(
+                        Diagnostic::error(
+                            SchemaError::ConflictingSubgraphFieldTypes(
+                                type_name,
+                                name.value,
+                                type_,
+                                previous.type_.clone(),
+                            ),
+                            location,
+                        )
+                        .annotate("previously defined here", previous.location),
+                    );
+                }
+                false
+            }
+        }
+    }
+
+    fn merge_interfaces(&mut self, index: DefinitionIndex, interfaces: Vec<Identifier>) {
+        if let TypeSystemDefinition::ObjectTypeDefinition(ObjectTypeDefinition {
+            interfaces: existing_interfaces,
+            ..
+        })
+        | TypeSystemDefinition::InterfaceTypeDefinition(InterfaceTypeDefinition {
+            interfaces: existing_interfaces,
+            ..
+        }) = self.definition_mut(index)
+        {
+            for interface in interfaces {
+                if !existing_interfaces
+                    .iter()
+                    .any(|existing| existing.value == interface.value)
+                {
+                    existing_interfaces.push(interface);
+                }
+            }
+        }
+    }
+
+    fn definition_mut(
+        &mut self,
+        (document, definition): DefinitionIndex,
+    ) -> &mut TypeSystemDefinition {
+        &mut self.definitions[document].definitions[definition]
+    }
+
+    fn push_definition(&mut self, definition: TypeSystemDefinition) {
+        self.definitions
+            .last_mut()
+            .unwrap()
+            .definitions
+            .push(definition);
+    }
+
+    fn push_extension(&mut self, definition: TypeSystemDefinition) {
+        self.extensions
+            .last_mut()
+            .unwrap()
+            .definitions
+            .push(definition);
+    }
+
+    fn finish(mut self) -> DiagnosticsResult<Vec<SchemaDocument>> {
+        if !self.errors.is_empty() {
+            return Err(self.errors);
+        }
+        for composed_type in self.types.values() {
+            if composed_type.kind != TypeKind::InputObject || composed_type.subgraph_count == 1 {
+                continue;
+            }
+            let (document, definition) = composed_type.definition_index;
+            if let TypeSystemDefinition::InputObjectTypeDefinition(InputObjectTypeDefinition {
+                fields: Some(fields),
+                ..
+            }) = &mut self.definitions[document].definitions[definition]
+            {
+                fields.items.retain(|field| {
+                    composed_type
+                        .fields
+                        .get(&field.name.value)
+                        .is_some_and(|field| field.subgraph_count == composed_type.subgraph_count)
+                });
+            }
+        }
+        Ok(self
+            .definitions
+            .into_iter()
+            .chain(
+                self.extensions
+                    .into_iter()
+                    .filter(|document| !document.definitions.is_empty()),
+            )
+            .collect())
+    }
+}
+
+fn is_external(directives: &[ConstantDirective]) -> bool {
+    directives
+        .iter()
+        .any(|directive| directive.name.value.lookup() == "external")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    fn parse_subgraph(path: &'static str, sdl: &str) -> SchemaDocument {
+        parse_schema_document(sdl, SourceLocationKey::standalone(path)).unwrap()
+    }
+
+    fn print(documents: &[SchemaDocument]) -> Vec<String> {
+        documents
+            .iter()
+            .filter(|document| !document.location.source_location().is_generated())
+            .map(|document| {
+                format!(
+                    "# {}\n{}",
+                    Path::new(document.location.source_location().path()).display(),
+                    document
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_compose_subgraphs() {
+        let subgraphs = vec![
+            parse_subgraph(
+                "accounts.graphql",
+                r#"
+                extend schema @link(url: "https://specs.apollo.dev/federation/v2.0")
+                scalar _Any
+                union _Entity = User
+                type Query {
+                  me: User
+                  _entities(representations: [_Any!]!): [_Entity]!
+                }
+                type User @key(fields: "id") {
+                  id: ID!
+                  name: String
+                }
+                input UserFilter { name: String, minAge: Int }
+                enum Role { ADMIN }
+                "#,
+            ),
+            parse_subgraph(
+                "reviews.graphql",
+                r#"
+                extend type Query {
+                  _entities(representations: [_Any!]!): [_Entity]!
+                }
+                extend type User @key(fields: "id") {
+                  id: ID! @external
+                  reviews: [Review]
+                }
+                type Review { body: String, author: User }
+                input UserFilter { name: String }
+                enum Role { ADMIN, REVIEWER }
+                "#,
+            ),
+        ];
+        let composed = compose_subgraphs(subgraphs).unwrap();
+        let printed = print(&composed);
+        assert_eq!(printed.len(), 3);
+        // accounts.graphql definitions
+        assert!(printed[0].contains("me: User"));
+        assert!(!printed[0].contains("_entities"));
+        assert!(!printed[0].contains("_Entity"));
+        assert!(!printed[0].contains("scalar _Any"));
+        assert!(printed[0].contains("input UserFilter {\n  name: String\n}"));
+        // reviews.graphql definitions
+        assert!(printed[1].contains("type Review"));
+        assert!(!printed[1].contains("UserFilter"));
+        // reviews.graphql extensions
+        assert!(printed[2].contains("extend type User"));
+        assert!(printed[2].contains("reviews: [Review]"));
+        assert!(!printed[2].contains("@external"));
+        assert!(!printed[2].contains("extend type Query"));
+        assert!(printed[2].contains("extend enum Role"));
+        assert!(!printed[2].contains("ADMIN"));
+    }
+
+    #[test]
+    fn test_compose_subgraphs_conflicting_field_types() {
+        let subgraphs = vec![
+            parse_subgraph("a.graphql", "type User { id: ID! age: Int }"),
+            parse_subgraph("b.graphql", "type User { id: ID! age: String }"),
+        ];
+        let errors = compose_subgraphs(subgraphs).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].message().to_string(),
+            "Field 'User.age' has type 'String' in this subgraph, but type 'Int' in another subgraph."
+        );
+        assert_eq!(
+            errors[0].location().source_location(),
+            SourceLocationKey::standalone("b.graphql")
+        );
+        assert_eq!(
+            errors[0].related_information()[0]
+                .location
+                .source_location(),
+            SourceLocationKey::standalone("a.graphql")
+        );
+    }
+}
@@ -5,8 +5,11 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+mod federation;
+
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 
 use common::ArgumentName;
 use common::Diagnostic;
@@ -702,6 +705,8 @@ impl InMemorySchema {
             .flat_map(|document| &document.definitions)
             .collect();
 
+        validate_unique_server_type_names(schema_documents)?;
+
         // Step 1: build the type_map from type names to type keys
         let mut type_map =
             HashMap::with_capacity(schema_definitions.len() + client_definitions.len());
@@ -890,6 +895,31 @@ impl InMemorySchema {
         Ok(schema)
     }
 
+    /// Builds the supergraph schema of federation subgraphs: the subgraphs
+    /// in `subgraph_documents` are first composed into a single schema, see
+    /// `federation::compose_subgraphs`.
+    pub fn build_federated(
+        subgraph_documents: Vec<SchemaDocument>,
+        client_schema_documents: &[SchemaDocument],
+    ) -> DiagnosticsResult<Self> {
+        let subgraph_locations: HashSet<SourceLocationKey> = subgraph_documents
+            .iter()
+            .map(|document| document.location.source_location())
+            .filter(|source_location| !source_location.is_generated())
+            .collect();
+        let schema_documents = federation::compose_subgraphs(subgraph_documents)?;
+        let mut schema = Self::build(&schema_documents, client_schema_documents)?;
+
+        // Subgraphs add fields to types defined in other subgraphs with
+        // extensions, but these fields are still resolved by the server.
+        for field in &mut schema.fields {
+            if subgraph_locations.contains(&field.name.location.source_location()) {
+                field.is_extension = false;
+            }
+        }
+        Ok(schema)
+    }
+
     fn load_defaults(&mut self) {
         self.load_default_root_types();
         self.load_default_typename_field();
@@ -1000,7 +1030,7 @@ impl InMemorySchema {
             name: WithLocation::generated(self.is_fulfilled_field_name),
             is_extension: true,
             arguments: ArgumentDefinitions::new(vec![Argument {
-                name: ArgumentName("name".intern()),
+                name: WithLocation::generated(ArgumentName("name".intern())),
                 type_: TypeReference::NonNull(Box::new(TypeReference::Named(string_type))),
                 default_value: None,
                 description: None,
@@ -1198,7 +1228,7 @@ impl InMemorySchema {
                         )]);
                     }
                 }
-                let arguments = self.build_arguments(arguments)?;
+                let arguments = self.build_arguments(arguments, *location_key)?;
                 self.directives.insert(
                     DirectiveName(name.value),
                     Directive {
@@ -1217,6 +1247,7 @@ impl InMemorySchema {
                 interfaces,
                 fields,
                 directives,
+                description,
             }) => {
                 let parent_id = Type::Object(ObjectID(self.objects.len() as u32));
                 let fields = if is_extension {
@@ -1243,7 +1274,7 @@ impl InMemorySchema {
                     is_extension,
                     interfaces,
                     directives,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                     hack_source: None,
                 });
             }
@@ -1252,6 +1283,7 @@ impl InMemorySchema {
                 interfaces,
                 directives,
                 fields,
+                description,
             }) => {
                 let parent_id = Type::Interface(InterfaceID(self.interfaces.len() as u32));
                 let fields = if is_extension {
@@ -1280,7 +1312,7 @@ impl InMemorySchema {
                     fields,
                     directives,
                     interfaces,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                     hack_source: None,
                 });
             }
@@ -1288,6 +1320,7 @@ impl InMemorySchema {
                 name,
                 directives,
                 members,
+                description,
             }) => {
                 let members = members
                     .iter()
@@ -1302,7 +1335,7 @@ impl InMemorySchema {
                     is_extension,
                     members,
                     directives,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                     hack_source: None,
                 });
             }
@@ -1310,8 +1343,9 @@ impl InMemorySchema {
                 name,
                 fields,
                 directives,
+                description,
             }) => {
-                let fields = self.build_arguments(fields)?;
+                let fields = self.build_arguments(fields, *location_key)?;
                 let directives = self.build_directive_values(directives);
                 self.input_objects.push(InputObject {
                     name: WithLocation::new(
@@ -1321,7 +1355,7 @@ impl InMemorySchema {
 
                     fields,
                     directives,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                     hack_source: None,
                 });
             }
@@ -1329,6 +1363,7 @@ impl InMemorySchema {
                 name,
                 directives,
                 values,
+                description,
             }) => {
                 let directives = self.build_directive_values(directives);
                 let values = if let Some(values) = values {
@@ -1351,13 +1386,14 @@ impl InMemorySchema {
                     is_extension,
                     values,
                     directives,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                     hack_source: None,
                 });
             }
             TypeSystemDefinition::ScalarTypeDefinition(ScalarTypeDefinition {
                 name,
                 directives,
+                description,
             }) => {
                 let directives = self.build_directive_values(directives);
                 self.scalars.push(Scalar {
@@ -1367,7 +1403,7 @@ impl InMemorySchema {
                     ),
                     is_extension,
                     directives,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                     hack_source: None,
                 })
             }
@@ -1575,7 +1611,8 @@ impl InMemorySchema {
                 .items
                 .iter()
                 .map(|field_def| {
-                    let arguments = self.build_arguments(&field_def.arguments)?;
+                    let arguments =
+                        self.build_arguments(&field_def.arguments, field_location_key)?;
                     let type_ = self.build_type_reference(&field_def.type_, field_location_key)?;
                     let directives = self.build_directive_values(&field_def.directives);
                     let description = field_def.description.as_ref().map(|desc| desc.value);
@@ -1621,7 +1658,8 @@ impl InMemorySchema {
                             .annotate("previously defined here", prev_location),
                     ]);
                 }
-                let arguments = self.build_arguments(&field_def.arguments)?;
+                let arguments =
+                        self.build_arguments(&field_def.arguments, source_location_key)?;
                 let directives = self.build_directive_values(&field_def.directives);
                 let type_ = self.build_type_reference(&field_def.type_, source_location_key)?;
                 let description = field_def.description.as_ref().map(|desc| desc.value);
@@ -1649,6 +1687,7 @@ impl InMemorySchema {
     fn build_arguments(
         &mut self,
         arg_defs: &Option<List<InputValueDefinition>>,
+        arg_location_key: SourceLocationKey,
     ) -> DiagnosticsResult<ArgumentDefinitions> {
         if let Some(arg_defs) = arg_defs {
             let arg_defs: DiagnosticsResult<Vec<Argument>> = arg_defs
@@ -1656,7 +1695,10 @@ impl InMemorySchema {
                 .iter()
                 .map(|arg_def| {
                     Ok(Argument {
-                        name: ArgumentName(arg_def.name.value),
+                        name: WithLocation::new(
+                            Location::new(arg_location_key, arg_def.name.span),
+                            ArgumentName(arg_def.name.value),
+                        ),
                         type_: self.build_input_object_reference(&arg_def.type_)?,
                         default_value: arg_def.default_value.clone(),
                         description: None,
@@ -1776,6 +1818,65 @@ fn expect_object_type_name(type_map: &TypeMap, object_id: ObjectID) -> StringKey
         .0
 }
 
+/// Reports types that are defined in more than one server schema file.
+/// Duplicates within a single file, and definitions without a file (builtins
+/// and schemas built from a single in-memory SDL), keep the previous behavior
+/// where the last definition wins.
+fn validate_unique_server_type_names(schema_documents: &[SchemaDocument]) -> DiagnosticsResult<()> {
+    let mut type_locations: HashMap<StringKey, Location> = HashMap::new();
+    let mut errors = vec![];
+    for document in schema_documents {
+        let source_location = document.location.source_location();
+        for definition in &document.definitions {
+            let name = match definition {
+                TypeSystemDefinition::ObjectTypeDefinition(ObjectTypeDefinition {
+                    name, ..
+                })
+                | TypeSystemDefinition::InterfaceTypeDefinition(InterfaceTypeDefinition {
+                    name,
+                    ..
+                })
+                | TypeSystemDefinition::UnionTypeDefinition(UnionTypeDefinition { name, .. })
+                | TypeSystemDefinition::InputObjectTypeDefinition(InputObjectTypeDefinition {
+                    name,
+                    ..
+                })
+                | TypeSystemDefinition::EnumTypeDefinition(EnumTypeDefinition { name, .. })
+                | TypeSystemDefinition::ScalarTypeDefinition(ScalarTypeDefinition {
+                    name, ..
+                }) => name,
+                _ => continue,
+            };
+            let location = Location::new(source_location, name.span);
+            if let Some(prev_location) = type_locations.insert(name.value, location) {
+                let prev_source_location = prev_location.source_location();
+                if source_location.is_generated()
+                    || prev_source_location.is_generated()
+                    || prev_source_location == source_location
+                {
+                    continue;
+                }
+                errors.push(
+                    Diagnostic::error(
+                        SchemaError::DuplicateTypeInFiles(
+                            name.value,
+                            source_location.path(),
+                            prev_source_location.path(),
+                        ),
+                        location,
+                    )
+                    .annotate("previously defined here", prev_location),
+                );
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use common::Span;
@@ -1825,6 +1926,7 @@ mod tests {
                     interfaces: vec![identifier_from_value("ITunes".intern())],
                     directives: vec![],
                     fields: None,
+                    description: None,
                 },
                 SourceLocationKey::Generated,
             )
@@ -1837,4 +1939,42 @@ mod tests {
             "ITunes should have an implementing object"
         );
     }
+
+    #[test]
+    fn test_duplicate_type_across_files() {
+        let errors = crate::build_schema_with_extensions::<_, &str>(
+            &[
+                (
+                    "type Query { user: User }",
+                    SourceLocationKey::standalone("a.graphql"),
+                ),
+                (
+                    "type User { id: ID }",
+                    SourceLocationKey::standalone("b.graphql"),
+                ),
+                (
+                    "type User { name: String }",
+                    SourceLocationKey::standalone("c.graphql"),
+                ),
+            ],
+            &[],
+        )
+        .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].message().to_string(),
+            "Duplicate definition for type 'User' in 'c.graphql', it is already defined in 'b.graphql'."
+        );
+        assert_eq!(
+            errors[0].location().source_location(),
+            SourceLocationKey::standalone("c.graphql")
+        );
+        assert_eq!(
+            errors[0].related_information()[0]
+                .location
+                .source_location(),
+            SourceLocationKey::standalone("b.graphql")
+        );
+    }
 }
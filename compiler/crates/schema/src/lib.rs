@@ -19,6 +19,7 @@ mod graphql_schema;
 mod in_memory;
 mod schema;
 pub mod suggestion_list;
+mod type_prefix;
 
 use common::DiagnosticsResult;
 use common::SourceLocationKey;
@@ -51,9 +52,10 @@ use flatbuffer::FlatBufferSchema;
 pub use flatbuffer::SchemaWrapper;
 pub use graphql_schema::Schema;
 pub use graphql_syntax::DirectiveLocation;
-use graphql_syntax::SchemaDocument;
+pub use graphql_syntax::SchemaDocument;
 pub use graphql_syntax::TypeSystemDefinition;
 pub use in_memory::InMemorySchema;
+pub use type_prefix::prefix_type_names;
 
 pub use crate::schema::SDLSchema;
 
@@ -69,13 +71,23 @@ pub fn build_schema_with_extensions<T: AsRef<str>, U: AsRef<str>>(
     server_sdls: &[(T, SourceLocationKey)],
     extension_sdls: &[(U, SourceLocationKey)],
 ) -> DiagnosticsResult<SDLSchema> {
-    let mut server_documents = vec![builtins()?];
+    build_schema_from_documents_with_extensions(parse_server_sdls(server_sdls)?, extension_sdls)
+}
 
-    let server_schema_document = match server_sdls {
-        [(sdl, source_location)] => {
-            graphql_syntax::parse_schema_document(sdl.as_ref(), *source_location)?
-        }
-        _ => {
+/// Parses the server schema files. Each file is parsed on its own so that
+/// definitions keep the location of the file they come from.
+pub fn parse_server_sdls<T: AsRef<str>>(
+    server_sdls: &[(T, SourceLocationKey)],
+) -> DiagnosticsResult<Vec<SchemaDocument>> {
+    let documents = server_sdls
+        .iter()
+        .map(|(sdl, source_location)| {
+            graphql_syntax::parse_schema_document(sdl.as_ref(), *source_location)
+        })
+        .collect::<DiagnosticsResult<Vec<_>>>();
+    match documents {
+        Ok(documents) => Ok(documents),
+        Err(errors) if server_sdls.len() > 1 => {
             // When the schema is split across multiple files, the individual
             // files may not be syntactically complete, so we join them together
             // before parsing.
@@ -87,21 +99,44 @@ pub fn build_schema_with_extensions<T: AsRef<str>, U: AsRef<str>>(
                 combined_sdl.push_str(sdl.as_ref());
                 combined_sdl.push('\n');
             }
-            graphql_syntax::parse_schema_document(&combined_sdl, SourceLocationKey::Generated)?
+            graphql_syntax::parse_schema_document(&combined_sdl, SourceLocationKey::Generated)
+                .map(|document| vec![document])
+                .map_err(|_| errors)
         }
-    };
+        Err(errors) => Err(errors),
+    }
+}
 
-    server_documents.push(server_schema_document);
+pub fn build_schema_from_documents_with_extensions<U: AsRef<str>>(
+    server_schema_documents: Vec<SchemaDocument>,
+    extension_sdls: &[(U, SourceLocationKey)],
+) -> DiagnosticsResult<SDLSchema> {
+    let mut server_documents = vec![builtins()?];
+    server_documents.extend(server_schema_documents);
+    SDLSchema::build(&server_documents, &parse_extension_sdls(extension_sdls)?)
+}
 
-    let mut client_schema_documents = Vec::new();
-    for (extension_sdl, location_key) in extension_sdls {
-        client_schema_documents.push(graphql_syntax::parse_schema_document(
-            extension_sdl.as_ref(),
-            *location_key,
-        )?);
-    }
+/// Same as `build_schema_from_documents_with_extensions`, but the server
+/// schema documents are federation subgraphs that are composed into a single
+/// supergraph schema.
+pub fn build_federated_schema_from_documents_with_extensions<U: AsRef<str>>(
+    subgraph_documents: Vec<SchemaDocument>,
+    extension_sdls: &[(U, SourceLocationKey)],
+) -> DiagnosticsResult<SDLSchema> {
+    let mut server_documents = vec![builtins()?];
+    server_documents.extend(subgraph_documents);
+    SDLSchema::build_federated(server_documents, &parse_extension_sdls(extension_sdls)?)
+}
 
-    SDLSchema::build(&server_documents, &client_schema_documents)
+fn parse_extension_sdls<U: AsRef<str>>(
+    extension_sdls: &[(U, SourceLocationKey)],
+) -> DiagnosticsResult<Vec<SchemaDocument>> {
+    extension_sdls
+        .iter()
+        .map(|(extension_sdl, location_key)| {
+            graphql_syntax::parse_schema_document(extension_sdl.as_ref(), *location_key)
+        })
+        .collect()
 }
 
 pub fn build_schema_with_flat_buffer(bytes: Vec<u8>) -> SDLSchema {
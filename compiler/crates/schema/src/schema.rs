@@ -264,6 +264,16 @@ impl SDLSchema {
         )?))
     }
 
+    pub fn build_federated(
+        subgraph_documents: Vec<SchemaDocument>,
+        client_schema_documents: &[SchemaDocument],
+    ) -> DiagnosticsResult<Self> {
+        Ok(SDLSchema::InMemory(InMemorySchema::build_federated(
+            subgraph_documents,
+            client_schema_documents,
+        )?))
+    }
+
     pub fn build_flatbuffer(
         schema_documents: &[SchemaDocument],
         client_schema_documents: &[SchemaDocument],
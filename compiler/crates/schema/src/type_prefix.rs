@@ -0,0 +1,206 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::HashSet;
+
+use graphql_syntax::*;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+
+/// Prefixes the names of all types defined in `document` with `prefix`.
+/// References to those types within the document are renamed as well, while
+/// references to types defined elsewhere (builtins, other schema files) are
+/// left untouched.
+pub fn prefix_type_names(document: &mut SchemaDocument, prefix: &str) {
+    let defined_types: HashSet<StringKey> = document
+        .definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            TypeSystemDefinition::ObjectTypeDefinition(ObjectTypeDefinition { name, .. })
+            | TypeSystemDefinition::InterfaceTypeDefinition(InterfaceTypeDefinition {
+                name, ..
+            })
+            | TypeSystemDefinition::UnionTypeDefinition(UnionTypeDefinition { name, .. })
+            | TypeSystemDefinition::InputObjectTypeDefinition(InputObjectTypeDefinition {
+                name,
+                ..
+            })
+            | TypeSystemDefinition::EnumTypeDefinition(EnumTypeDefinition { name, .. })
+            | TypeSystemDefinition::ScalarTypeDefinition(ScalarTypeDefinition { name, .. }) => {
+                Some(name.value)
+            }
+            _ => None,
+        })
+        .collect();
+
+    let renamer = TypeRenamer {
+        prefix,
+        defined_types,
+    };
+    for definition in &mut document.definitions {
+        renamer.rename_definition(definition);
+    }
+}
+
+struct TypeRenamer<'a> {
+    prefix: &'a str,
+    defined_types: HashSet<StringKey>,
+}
+
+impl TypeRenamer<'_> {
+    fn rename_definition(&self, definition: &mut TypeSystemDefinition) {
+        match definition {
+            TypeSystemDefinition::SchemaDefinition(SchemaDefinition {
+                operation_types, ..
+            }) => {
+                for operation_type in &mut operation_types.items {
+                    self.rename_identifier(&mut operation_type.type_);
+                }
+            }
+            TypeSystemDefinition::SchemaExtension(SchemaExtension {
+                operation_types, ..
+            }) => {
+                if let Some(operation_types) = operation_types {
+                    for operation_type in &mut operation_types.items {
+                        self.rename_identifier(&mut operation_type.type_);
+                    }
+                }
+            }
+            TypeSystemDefinition::ObjectTypeDefinition(ObjectTypeDefinition {
+                name,
+                interfaces,
+                fields,
+                ..
+            })
+            | TypeSystemDefinition::ObjectTypeExtension(ObjectTypeExtension {
+                name,
+                interfaces,
+                fields,
+                ..
+            })
+            | TypeSystemDefinition::InterfaceTypeDefinition(InterfaceTypeDefinition {
+                name,
+                interfaces,
+                fields,
+                ..
+            })
+            | TypeSystemDefinition::InterfaceTypeExtension(InterfaceTypeExtension {
+                name,
+                interfaces,
+                fields,
+                ..
+            }) => {
+                self.rename_identifier(name);
+                for interface in interfaces {
+                    self.rename_identifier(interface);
+                }
+                if let Some(fields) = fields {
+                    for field in &mut fields.items {
+                        self.rename_type_annotation(&mut field.type_);
+                        self.rename_input_values(&mut field.arguments);
+                    }
+                }
+            }
+            TypeSystemDefinition::UnionTypeDefinition(UnionTypeDefinition {
+                name,
+                members,
+                ..
+            })
+            | TypeSystemDefinition::UnionTypeExtension(UnionTypeExtension {
+                name, members, ..
+            }) => {
+                self.rename_identifier(name);
+                for member in members {
+                    self.rename_identifier(member);
+                }
+            }
+            TypeSystemDefinition::InputObjectTypeDefinition(InputObjectTypeDefinition {
+                name,
+                fields,
+                ..
+            })
+            | TypeSystemDefinition::InputObjectTypeExtension(InputObjectTypeExtension {
+                name,
+                fields,
+                ..
+            }) => {
+                self.rename_identifier(name);
+                self.rename_input_values(fields);
+            }
+            TypeSystemDefinition::EnumTypeDefinition(EnumTypeDefinition { name, .. })
+            | TypeSystemDefinition::EnumTypeExtension(EnumTypeExtension { name, .. })
+            | TypeSystemDefinition::ScalarTypeDefinition(ScalarTypeDefinition { name, .. })
+            | TypeSystemDefinition::ScalarTypeExtension(ScalarTypeExtension { name, .. }) => {
+                self.rename_identifier(name);
+            }
+            TypeSystemDefinition::DirectiveDefinition(DirectiveDefinition {
+                arguments, ..
+            }) => {
+                self.rename_input_values(arguments);
+            }
+        }
+    }
+
+    fn rename_input_values(&self, input_values: &mut Option<List<InputValueDefinition>>) {
+        if let Some(input_values) = input_values {
+            for input_value in &mut input_values.items {
+                self.rename_type_annotation(&mut input_value.type_);
+            }
+        }
+    }
+
+    fn rename_type_annotation(&self, type_annotation: &mut TypeAnnotation) {
+        match type_annotation {
+            TypeAnnotation::Named(named) => self.rename_identifier(&mut named.name),
+            TypeAnnotation::List(list) => self.rename_type_annotation(&mut list.type_),
+            TypeAnnotation::NonNull(non_null) => self.rename_type_annotation(&mut non_null.type_),
+        }
+    }
+
+    fn rename_identifier(&self, identifier: &mut Identifier) {
+        if self.defined_types.contains(&identifier.value) {
+            identifier.value = format!("{}{}", self.prefix, identifier.value).intern();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::SourceLocationKey;
+
+    use super::*;
+
+    #[test]
+    fn test_prefix_type_names() {
+        let mut document = parse_schema_document(
+            r#"
+            type User implements Node {
+              id: ID!
+              posts(filter: PostFilter): [Post!]
+            }
+            type Post { author: User }
+            input PostFilter { after: Time }
+            extend type User { friends: [User] }
+            extend type Query { user: User }
+            "#,
+            SourceLocationKey::generated(),
+        )
+        .unwrap();
+        prefix_type_names(&mut document, "Blog");
+
+        let printed = document.to_string();
+        assert!(printed.contains("type BlogUser implements Node"));
+        assert!(printed.contains("posts(filter: BlogPostFilter): [BlogPost!]"));
+        assert!(printed.contains("type BlogPost"));
+        assert!(printed.contains("input BlogPostFilter"));
+        assert!(printed.contains("after: Time"));
+        assert!(printed.contains("extend type BlogUser"));
+        assert!(printed.contains("friends: [BlogUser]"));
+        assert!(printed.contains("extend type Query"));
+        assert!(printed.contains("user: BlogUser"));
+    }
+}
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<0cd59a6b663edc7d714c28b2f3a05b96>>
+ * @generated SignedSource<<c8135c2c803bf74daf7d4b01a8d4b85e>>
  */
 
 mod build_schema;
@@ -144,3 +144,10 @@ async fn kitchen_sink() {
     let expected = include_str!("build_schema/fixtures/kitchen-sink.expected");
     test_fixture(transform_fixture, file!(), "kitchen-sink.graphql", "build_schema/fixtures/kitchen-sink.expected", input, expected).await;
 }
+
+#[tokio::test]
+async fn one_of_input_object() {
+    let input = include_str!("build_schema/fixtures/one-of-input-object.graphql");
+    let expected = include_str!("build_schema/fixtures/one-of-input-object.expected");
+    test_fixture(transform_fixture, file!(), "one-of-input-object.graphql", "build_schema/fixtures/one-of-input-object.expected", input, expected).await;
+}
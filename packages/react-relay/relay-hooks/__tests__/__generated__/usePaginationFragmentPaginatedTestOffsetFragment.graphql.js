@@ -0,0 +1,140 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @oncall relay
+ *
+ * @generated SignedSource<<a0a7bbdf723534cccd87f1e3d9523ef0>>
+ * @flow
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+/*::
+import type { ReaderFragment, RefetchableFragment } from 'relay-runtime';
+import type { FragmentType } from "relay-runtime";
+declare export opaque type usePaginationFragmentPaginatedTestOffsetFragment$fragmentType: FragmentType;
+type usePaginationFragmentPaginatedTestOffsetPaginationQuery$variables = any;
+export type usePaginationFragmentPaginatedTestOffsetFragment$data = {|
+  +followers: ?{|
+    +items: ?$ReadOnlyArray<?{|
+      +id: string,
+      +name: ?string,
+    |}>,
+    +totalCount: ?number,
+  |},
+  +id: string,
+  +$fragmentType: usePaginationFragmentPaginatedTestOffsetFragment$fragmentType,
+|};
+export type usePaginationFragmentPaginatedTestOffsetFragment$key = {
+  +$data?: usePaginationFragmentPaginatedTestOffsetFragment$data,
+  +$fragmentSpreads: usePaginationFragmentPaginatedTestOffsetFragment$fragmentType,
+  ...
+};
+*/
+
+var node/*: ReaderFragment*/ = (function(){
+var v0 = {
+  "alias": null,
+  "args": null,
+  "kind": "ScalarField",
+  "name": "id",
+  "storageKey": null
+};
+return {
+  "argumentDefinitions": [
+    {
+      "defaultValue": 2,
+      "kind": "LocalArgument",
+      "name": "limit"
+    },
+    {
+      "defaultValue": 0,
+      "kind": "LocalArgument",
+      "name": "offset"
+    }
+  ],
+  "kind": "Fragment",
+  "metadata": {
+    "refetch": {
+      "connection": null,
+      "fragmentPathInResult": [
+        "node"
+      ],
+      "operation": require('./usePaginationFragmentPaginatedTestOffsetPaginationQuery.graphql'),
+      "pagination": {
+        "count": "limit",
+        "cursor": "offset",
+        "items": "items",
+        "kind": "OFFSET",
+        "path": [
+          "followers"
+        ]
+      },
+      "identifierInfo": {
+        "identifierField": "id",
+        "identifierQueryVariableName": "id"
+      }
+    }
+  },
+  "name": "usePaginationFragmentPaginatedTestOffsetFragment",
+  "selections": [
+    (v0/*: any*/),
+    {
+      "alias": "followers",
+      "args": null,
+      "concreteType": "UserPage",
+      "kind": "LinkedField",
+      "name": "__followers_paginated",
+      "plural": false,
+      "selections": [
+        {
+          "alias": null,
+          "args": null,
+          "kind": "ScalarField",
+          "name": "totalCount",
+          "storageKey": null
+        },
+        {
+          "alias": null,
+          "args": null,
+          "concreteType": "User",
+          "kind": "LinkedField",
+          "name": "items",
+          "plural": true,
+          "selections": [
+            (v0/*: any*/),
+            {
+              "alias": null,
+              "args": null,
+              "kind": "ScalarField",
+              "name": "name",
+              "storageKey": null
+            }
+          ],
+          "storageKey": null
+        }
+      ],
+      "storageKey": null
+    }
+  ],
+  "type": "User",
+  "abstractKey": null
+};
+})();
+
+if (__DEV__) {
+  (node/*: any*/).hash = "e3077fc3d2b7d97f0056c855e3873604";
+}
+
+module.exports = ((node/*: any*/)/*: RefetchableFragment<
+  usePaginationFragmentPaginatedTestOffsetFragment$fragmentType,
+  usePaginationFragmentPaginatedTestOffsetFragment$data,
+  usePaginationFragmentPaginatedTestOffsetPaginationQuery$variables,
+>*/);
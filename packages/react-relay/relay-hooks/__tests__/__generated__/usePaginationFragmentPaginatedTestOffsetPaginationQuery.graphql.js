@@ -0,0 +1,236 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @oncall relay
+ *
+ * @generated SignedSource<<2942d5941f0b8ba8a014bd4ff29934f0>>
+ * @flow
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+/*::
+import type { ConcreteRequest, Query } from 'relay-runtime';
+import type { FragmentType } from "relay-runtime";
+import type { usePaginationFragmentPaginatedTestOffsetFragment$fragmentType } from "./usePaginationFragmentPaginatedTestOffsetFragment.graphql";
+export type usePaginationFragmentPaginatedTestOffsetPaginationQuery$variables = {|
+  id: string,
+  limit?: ?number,
+  offset?: ?number,
+|};
+export type usePaginationFragmentPaginatedTestOffsetPaginationQuery$data = {|
+  +node: ?{|
+    +$fragmentSpreads: usePaginationFragmentPaginatedTestOffsetFragment$fragmentType,
+  |},
+|};
+export type usePaginationFragmentPaginatedTestOffsetPaginationQuery = {|
+  response: usePaginationFragmentPaginatedTestOffsetPaginationQuery$data,
+  variables: usePaginationFragmentPaginatedTestOffsetPaginationQuery$variables,
+|};
+*/
+
+var node/*: ConcreteRequest*/ = (function(){
+var v0 = {
+  "defaultValue": null,
+  "kind": "LocalArgument",
+  "name": "id"
+},
+v1 = {
+  "defaultValue": 2,
+  "kind": "LocalArgument",
+  "name": "limit"
+},
+v2 = {
+  "defaultValue": 0,
+  "kind": "LocalArgument",
+  "name": "offset"
+},
+v3 = [
+  {
+    "kind": "Variable",
+    "name": "id",
+    "variableName": "id"
+  }
+],
+v4 = [
+  {
+    "kind": "Variable",
+    "name": "limit",
+    "variableName": "limit"
+  },
+  {
+    "kind": "Variable",
+    "name": "offset",
+    "variableName": "offset"
+  }
+],
+v5 = {
+  "alias": null,
+  "args": null,
+  "kind": "ScalarField",
+  "name": "id",
+  "storageKey": null
+};
+return {
+  "fragment": {
+    "argumentDefinitions": [
+      (v0/*: any*/),
+      (v1/*: any*/),
+      (v2/*: any*/)
+    ],
+    "kind": "Fragment",
+    "metadata": null,
+    "name": "usePaginationFragmentPaginatedTestOffsetPaginationQuery",
+    "selections": [
+      {
+        "alias": null,
+        "args": (v3/*: any*/),
+        "concreteType": null,
+        "kind": "LinkedField",
+        "name": "node",
+        "plural": false,
+        "selections": [
+          {
+            "args": (v4/*: any*/),
+            "kind": "FragmentSpread",
+            "name": "usePaginationFragmentPaginatedTestOffsetFragment"
+          }
+        ],
+        "storageKey": null
+      }
+    ],
+    "type": "Query",
+    "abstractKey": null
+  },
+  "kind": "Request",
+  "operation": {
+    "argumentDefinitions": [
+      (v1/*: any*/),
+      (v2/*: any*/),
+      (v0/*: any*/)
+    ],
+    "kind": "Operation",
+    "name": "usePaginationFragmentPaginatedTestOffsetPaginationQuery",
+    "selections": [
+      {
+        "alias": null,
+        "args": (v3/*: any*/),
+        "concreteType": null,
+        "kind": "LinkedField",
+        "name": "node",
+        "plural": false,
+        "selections": [
+          {
+            "alias": null,
+            "args": null,
+            "kind": "ScalarField",
+            "name": "__typename",
+            "storageKey": null
+          },
+          (v5/*: any*/),
+          {
+            "kind": "InlineFragment",
+            "selections": [
+              {
+                "alias": null,
+                "args": (v4/*: any*/),
+                "concreteType": "UserPage",
+                "kind": "LinkedField",
+                "name": "followers",
+                "plural": false,
+                "selections": [
+                  {
+                    "alias": null,
+                    "args": null,
+                    "kind": "ScalarField",
+                    "name": "totalCount",
+                    "storageKey": null
+                  },
+                  {
+                    "alias": null,
+                    "args": null,
+                    "concreteType": "User",
+                    "kind": "LinkedField",
+                    "name": "items",
+                    "plural": true,
+                    "selections": [
+                      (v5/*: any*/),
+                      {
+                        "alias": null,
+                        "args": null,
+                        "kind": "ScalarField",
+                        "name": "name",
+                        "storageKey": null
+                      }
+                    ],
+                    "storageKey": null
+                  }
+                ],
+                "storageKey": null
+              },
+              {
+                "alias": null,
+                "args": (v4/*: any*/),
+                "filters": null,
+                "handle": "paginated",
+                "key": "",
+                "kind": "LinkedHandle",
+                "name": "followers",
+                "handleArgs": [
+                  {
+                    "kind": "Literal",
+                    "name": "count",
+                    "value": "limit"
+                  },
+                  {
+                    "kind": "Literal",
+                    "name": "cursor",
+                    "value": "offset"
+                  },
+                  {
+                    "kind": "Literal",
+                    "name": "items",
+                    "value": "items"
+                  },
+                  {
+                    "kind": "Literal",
+                    "name": "kind",
+                    "value": "OFFSET"
+                  }
+                ]
+              }
+            ],
+            "type": "User",
+            "abstractKey": null
+          }
+        ],
+        "storageKey": null
+      }
+    ]
+  },
+  "params": {
+    "cacheID": "e49847a89323993cccee026107fb17ee",
+    "id": null,
+    "metadata": {},
+    "name": "usePaginationFragmentPaginatedTestOffsetPaginationQuery",
+    "operationKind": "query",
+    "text": "query usePaginationFragmentPaginatedTestOffsetPaginationQuery(\n  $limit: Int = 2\n  $offset: Int = 0\n  $id: ID!\n) {\n  node(id: $id) {\n    __typename\n    ...usePaginationFragmentPaginatedTestOffsetFragment_21LIQA\n    id\n  }\n}\n\nfragment usePaginationFragmentPaginatedTestOffsetFragment_21LIQA on User {\n  id\n  followers(offset: $offset, limit: $limit) {\n    totalCount\n    items {\n      id\n      name\n    }\n  }\n}\n"
+  }
+};
+})();
+
+if (__DEV__) {
+  (node/*: any*/).hash = "e3077fc3d2b7d97f0056c855e3873604";
+}
+
+module.exports = ((node/*: any*/)/*: Query<
+  usePaginationFragmentPaginatedTestOffsetPaginationQuery$variables,
+  usePaginationFragmentPaginatedTestOffsetPaginationQuery$data,
+>*/);
@@ -0,0 +1,217 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @oncall relay
+ *
+ * @generated SignedSource<<6d57452da770c85848b7ec005b9e9c65>>
+ * @flow
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+/*::
+import type { ConcreteRequest, Query } from 'relay-runtime';
+import type { usePaginationFragmentPaginatedTestOffsetFragment$fragmentType } from "./usePaginationFragmentPaginatedTestOffsetFragment.graphql";
+export type usePaginationFragmentPaginatedTestOffsetQuery$variables = {|
+  id: string,
+|};
+export type usePaginationFragmentPaginatedTestOffsetQuery$data = {|
+  +node: ?{|
+    +$fragmentSpreads: usePaginationFragmentPaginatedTestOffsetFragment$fragmentType,
+  |},
+|};
+export type usePaginationFragmentPaginatedTestOffsetQuery = {|
+  response: usePaginationFragmentPaginatedTestOffsetQuery$data,
+  variables: usePaginationFragmentPaginatedTestOffsetQuery$variables,
+|};
+*/
+
+var node/*: ConcreteRequest*/ = (function(){
+var v0 = [
+  {
+    "defaultValue": null,
+    "kind": "LocalArgument",
+    "name": "id"
+  }
+],
+v1 = [
+  {
+    "kind": "Variable",
+    "name": "id",
+    "variableName": "id"
+  }
+],
+v2 = {
+  "alias": null,
+  "args": null,
+  "kind": "ScalarField",
+  "name": "id",
+  "storageKey": null
+},
+v3 = [
+  {
+    "kind": "Literal",
+    "name": "limit",
+    "value": 2
+  },
+  {
+    "kind": "Literal",
+    "name": "offset",
+    "value": 0
+  }
+];
+return {
+  "fragment": {
+    "argumentDefinitions": (v0/*: any*/),
+    "kind": "Fragment",
+    "metadata": null,
+    "name": "usePaginationFragmentPaginatedTestOffsetQuery",
+    "selections": [
+      {
+        "alias": null,
+        "args": (v1/*: any*/),
+        "concreteType": null,
+        "kind": "LinkedField",
+        "name": "node",
+        "plural": false,
+        "selections": [
+          {
+            "args": null,
+            "kind": "FragmentSpread",
+            "name": "usePaginationFragmentPaginatedTestOffsetFragment"
+          }
+        ],
+        "storageKey": null
+      }
+    ],
+    "type": "Query",
+    "abstractKey": null
+  },
+  "kind": "Request",
+  "operation": {
+    "argumentDefinitions": (v0/*: any*/),
+    "kind": "Operation",
+    "name": "usePaginationFragmentPaginatedTestOffsetQuery",
+    "selections": [
+      {
+        "alias": null,
+        "args": (v1/*: any*/),
+        "concreteType": null,
+        "kind": "LinkedField",
+        "name": "node",
+        "plural": false,
+        "selections": [
+          {
+            "alias": null,
+            "args": null,
+            "kind": "ScalarField",
+            "name": "__typename",
+            "storageKey": null
+          },
+          (v2/*: any*/),
+          {
+            "kind": "InlineFragment",
+            "selections": [
+              {
+                "alias": null,
+                "args": (v3/*: any*/),
+                "concreteType": "UserPage",
+                "kind": "LinkedField",
+                "name": "followers",
+                "plural": false,
+                "selections": [
+                  {
+                    "alias": null,
+                    "args": null,
+                    "kind": "ScalarField",
+                    "name": "totalCount",
+                    "storageKey": null
+                  },
+                  {
+                    "alias": null,
+                    "args": null,
+                    "concreteType": "User",
+                    "kind": "LinkedField",
+                    "name": "items",
+                    "plural": true,
+                    "selections": [
+                      (v2/*: any*/),
+                      {
+                        "alias": null,
+                        "args": null,
+                        "kind": "ScalarField",
+                        "name": "name",
+                        "storageKey": null
+                      }
+                    ],
+                    "storageKey": null
+                  }
+                ],
+                "storageKey": "followers(limit:2,offset:0)"
+              },
+              {
+                "alias": null,
+                "args": (v3/*: any*/),
+                "filters": null,
+                "handle": "paginated",
+                "key": "",
+                "kind": "LinkedHandle",
+                "name": "followers",
+                "handleArgs": [
+                  {
+                    "kind": "Literal",
+                    "name": "count",
+                    "value": "limit"
+                  },
+                  {
+                    "kind": "Literal",
+                    "name": "cursor",
+                    "value": "offset"
+                  },
+                  {
+                    "kind": "Literal",
+                    "name": "items",
+                    "value": "items"
+                  },
+                  {
+                    "kind": "Literal",
+                    "name": "kind",
+                    "value": "OFFSET"
+                  }
+                ]
+              }
+            ],
+            "type": "User",
+            "abstractKey": null
+          }
+        ],
+        "storageKey": null
+      }
+    ]
+  },
+  "params": {
+    "cacheID": "12724d661190976ae156047bc7a180b1",
+    "id": null,
+    "metadata": {},
+    "name": "usePaginationFragmentPaginatedTestOffsetQuery",
+    "operationKind": "query",
+    "text": "query usePaginationFragmentPaginatedTestOffsetQuery(\n  $id: ID!\n) {\n  node(id: $id) {\n    __typename\n    ...usePaginationFragmentPaginatedTestOffsetFragment\n    id\n  }\n}\n\nfragment usePaginationFragmentPaginatedTestOffsetFragment on User {\n  id\n  followers(offset: 0, limit: 2) {\n    totalCount\n    items {\n      id\n      name\n    }\n  }\n}\n"
+  }
+};
+})();
+
+if (__DEV__) {
+  (node/*: any*/).hash = "6a1d25a20bd6aba9d669b5fb0cb994ac";
+}
+
+module.exports = ((node/*: any*/)/*: Query<
+  usePaginationFragmentPaginatedTestOffsetQuery$variables,
+  usePaginationFragmentPaginatedTestOffsetQuery$data,
+>*/);
@@ -0,0 +1,140 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @oncall relay
+ *
+ * @generated SignedSource<<305f3b23655ebdc4a501019c84e0fa3f>>
+ * @flow
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+/*::
+import type { ReaderFragment, RefetchableFragment } from 'relay-runtime';
+import type { FragmentType } from "relay-runtime";
+declare export opaque type usePaginationFragmentPaginatedTestPageFragment$fragmentType: FragmentType;
+type usePaginationFragmentPaginatedTestPagePaginationQuery$variables = any;
+export type usePaginationFragmentPaginatedTestPageFragment$data = {|
+  +followersByPage: ?{|
+    +items: ?$ReadOnlyArray<?{|
+      +id: string,
+      +name: ?string,
+    |}>,
+    +totalCount: ?number,
+  |},
+  +id: string,
+  +$fragmentType: usePaginationFragmentPaginatedTestPageFragment$fragmentType,
+|};
+export type usePaginationFragmentPaginatedTestPageFragment$key = {
+  +$data?: usePaginationFragmentPaginatedTestPageFragment$data,
+  +$fragmentSpreads: usePaginationFragmentPaginatedTestPageFragment$fragmentType,
+  ...
+};
+*/
+
+var node/*: ReaderFragment*/ = (function(){
+var v0 = {
+  "alias": null,
+  "args": null,
+  "kind": "ScalarField",
+  "name": "id",
+  "storageKey": null
+};
+return {
+  "argumentDefinitions": [
+    {
+      "defaultValue": 1,
+      "kind": "LocalArgument",
+      "name": "page"
+    },
+    {
+      "defaultValue": 2,
+      "kind": "LocalArgument",
+      "name": "pageSize"
+    }
+  ],
+  "kind": "Fragment",
+  "metadata": {
+    "refetch": {
+      "connection": null,
+      "fragmentPathInResult": [
+        "node"
+      ],
+      "operation": require('./usePaginationFragmentPaginatedTestPagePaginationQuery.graphql'),
+      "pagination": {
+        "count": "pageSize",
+        "cursor": "page",
+        "items": "items",
+        "kind": "PAGE",
+        "path": [
+          "followersByPage"
+        ]
+      },
+      "identifierInfo": {
+        "identifierField": "id",
+        "identifierQueryVariableName": "id"
+      }
+    }
+  },
+  "name": "usePaginationFragmentPaginatedTestPageFragment",
+  "selections": [
+    (v0/*: any*/),
+    {
+      "alias": "followersByPage",
+      "args": null,
+      "concreteType": "UserPage",
+      "kind": "LinkedField",
+      "name": "__followersByPage_paginated",
+      "plural": false,
+      "selections": [
+        {
+          "alias": null,
+          "args": null,
+          "kind": "ScalarField",
+          "name": "totalCount",
+          "storageKey": null
+        },
+        {
+          "alias": null,
+          "args": null,
+          "concreteType": "User",
+          "kind": "LinkedField",
+          "name": "items",
+          "plural": true,
+          "selections": [
+            (v0/*: any*/),
+            {
+              "alias": null,
+              "args": null,
+              "kind": "ScalarField",
+              "name": "name",
+              "storageKey": null
+            }
+          ],
+          "storageKey": null
+        }
+      ],
+      "storageKey": null
+    }
+  ],
+  "type": "User",
+  "abstractKey": null
+};
+})();
+
+if (__DEV__) {
+  (node/*: any*/).hash = "2c7c7079208c85742c71e87c394034c1";
+}
+
+module.exports = ((node/*: any*/)/*: RefetchableFragment<
+  usePaginationFragmentPaginatedTestPageFragment$fragmentType,
+  usePaginationFragmentPaginatedTestPageFragment$data,
+  usePaginationFragmentPaginatedTestPagePaginationQuery$variables,
+>*/);
@@ -0,0 +1,236 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @oncall relay
+ *
+ * @generated SignedSource<<76d85b6f9923fae5db3eb19b1df7df40>>
+ * @flow
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+/*::
+import type { ConcreteRequest, Query } from 'relay-runtime';
+import type { FragmentType } from "relay-runtime";
+import type { usePaginationFragmentPaginatedTestPageFragment$fragmentType } from "./usePaginationFragmentPaginatedTestPageFragment.graphql";
+export type usePaginationFragmentPaginatedTestPagePaginationQuery$variables = {|
+  id: string,
+  page?: ?number,
+  pageSize?: ?number,
+|};
+export type usePaginationFragmentPaginatedTestPagePaginationQuery$data = {|
+  +node: ?{|
+    +$fragmentSpreads: usePaginationFragmentPaginatedTestPageFragment$fragmentType,
+  |},
+|};
+export type usePaginationFragmentPaginatedTestPagePaginationQuery = {|
+  response: usePaginationFragmentPaginatedTestPagePaginationQuery$data,
+  variables: usePaginationFragmentPaginatedTestPagePaginationQuery$variables,
+|};
+*/
+
+var node/*: ConcreteRequest*/ = (function(){
+var v0 = {
+  "defaultValue": null,
+  "kind": "LocalArgument",
+  "name": "id"
+},
+v1 = {
+  "defaultValue": 1,
+  "kind": "LocalArgument",
+  "name": "page"
+},
+v2 = {
+  "defaultValue": 2,
+  "kind": "LocalArgument",
+  "name": "pageSize"
+},
+v3 = [
+  {
+    "kind": "Variable",
+    "name": "id",
+    "variableName": "id"
+  }
+],
+v4 = [
+  {
+    "kind": "Variable",
+    "name": "page",
+    "variableName": "page"
+  },
+  {
+    "kind": "Variable",
+    "name": "pageSize",
+    "variableName": "pageSize"
+  }
+],
+v5 = {
+  "alias": null,
+  "args": null,
+  "kind": "ScalarField",
+  "name": "id",
+  "storageKey": null
+};
+return {
+  "fragment": {
+    "argumentDefinitions": [
+      (v0/*: any*/),
+      (v1/*: any*/),
+      (v2/*: any*/)
+    ],
+    "kind": "Fragment",
+    "metadata": null,
+    "name": "usePaginationFragmentPaginatedTestPagePaginationQuery",
+    "selections": [
+      {
+        "alias": null,
+        "args": (v3/*: any*/),
+        "concreteType": null,
+        "kind": "LinkedField",
+        "name": "node",
+        "plural": false,
+        "selections": [
+          {
+            "args": (v4/*: any*/),
+            "kind": "FragmentSpread",
+            "name": "usePaginationFragmentPaginatedTestPageFragment"
+          }
+        ],
+        "storageKey": null
+      }
+    ],
+    "type": "Query",
+    "abstractKey": null
+  },
+  "kind": "Request",
+  "operation": {
+    "argumentDefinitions": [
+      (v1/*: any*/),
+      (v2/*: any*/),
+      (v0/*: any*/)
+    ],
+    "kind": "Operation",
+    "name": "usePaginationFragmentPaginatedTestPagePaginationQuery",
+    "selections": [
+      {
+        "alias": null,
+        "args": (v3/*: any*/),
+        "concreteType": null,
+        "kind": "LinkedField",
+        "name": "node",
+        "plural": false,
+        "selections": [
+          {
+            "alias": null,
+            "args": null,
+            "kind": "ScalarField",
+            "name": "__typename",
+            "storageKey": null
+          },
+          (v5/*: any*/),
+          {
+            "kind": "InlineFragment",
+            "selections": [
+              {
+                "alias": null,
+                "args": (v4/*: any*/),
+                "concreteType": "UserPage",
+                "kind": "LinkedField",
+                "name": "followersByPage",
+                "plural": false,
+                "selections": [
+                  {
+                    "alias": null,
+                    "args": null,
+                    "kind": "ScalarField",
+                    "name": "totalCount",
+                    "storageKey": null
+                  },
+                  {
+                    "alias": null,
+                    "args": null,
+                    "concreteType": "User",
+                    "kind": "LinkedField",
+                    "name": "items",
+                    "plural": true,
+                    "selections": [
+                      (v5/*: any*/),
+                      {
+                        "alias": null,
+                        "args": null,
+                        "kind": "ScalarField",
+                        "name": "name",
+                        "storageKey": null
+                      }
+                    ],
+                    "storageKey": null
+                  }
+                ],
+                "storageKey": null
+              },
+              {
+                "alias": null,
+                "args": (v4/*: any*/),
+                "filters": null,
+                "handle": "paginated",
+                "key": "",
+                "kind": "LinkedHandle",
+                "name": "followersByPage",
+                "handleArgs": [
+                  {
+                    "kind": "Literal",
+                    "name": "count",
+                    "value": "pageSize"
+                  },
+                  {
+                    "kind": "Literal",
+                    "name": "cursor",
+                    "value": "page"
+                  },
+                  {
+                    "kind": "Literal",
+                    "name": "items",
+                    "value": "items"
+                  },
+                  {
+                    "kind": "Literal",
+                    "name": "kind",
+                    "value": "PAGE"
+                  }
+                ]
+              }
+            ],
+            "type": "User",
+            "abstractKey": null
+          }
+        ],
+        "storageKey": null
+      }
+    ]
+  },
+  "params": {
+    "cacheID": "fa5b567994b2e7fc66cf144439f28eea",
+    "id": null,
+    "metadata": {},
+    "name": "usePaginationFragmentPaginatedTestPagePaginationQuery",
+    "operationKind": "query",
+    "text": "query usePaginationFragmentPaginatedTestPagePaginationQuery(\n  $page: Int = 1\n  $pageSize: Int = 2\n  $id: ID!\n) {\n  node(id: $id) {\n    __typename\n    ...usePaginationFragmentPaginatedTestPageFragment_2IrURx\n    id\n  }\n}\n\nfragment usePaginationFragmentPaginatedTestPageFragment_2IrURx on User {\n  id\n  followersByPage(page: $page, pageSize: $pageSize) {\n    totalCount\n    items {\n      id\n      name\n    }\n  }\n}\n"
+  }
+};
+})();
+
+if (__DEV__) {
+  (node/*: any*/).hash = "2c7c7079208c85742c71e87c394034c1";
+}
+
+module.exports = ((node/*: any*/)/*: Query<
+  usePaginationFragmentPaginatedTestPagePaginationQuery$variables,
+  usePaginationFragmentPaginatedTestPagePaginationQuery$data,
+>*/);
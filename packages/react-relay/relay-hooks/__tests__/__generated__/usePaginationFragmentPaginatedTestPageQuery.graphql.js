@@ -0,0 +1,217 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @oncall relay
+ *
+ * @generated SignedSource<<9b43a11baa20fe3ae488e2f15a083eb5>>
+ * @flow
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+/*::
+import type { ConcreteRequest, Query } from 'relay-runtime';
+import type { usePaginationFragmentPaginatedTestPageFragment$fragmentType } from "./usePaginationFragmentPaginatedTestPageFragment.graphql";
+export type usePaginationFragmentPaginatedTestPageQuery$variables = {|
+  id: string,
+|};
+export type usePaginationFragmentPaginatedTestPageQuery$data = {|
+  +node: ?{|
+    +$fragmentSpreads: usePaginationFragmentPaginatedTestPageFragment$fragmentType,
+  |},
+|};
+export type usePaginationFragmentPaginatedTestPageQuery = {|
+  response: usePaginationFragmentPaginatedTestPageQuery$data,
+  variables: usePaginationFragmentPaginatedTestPageQuery$variables,
+|};
+*/
+
+var node/*: ConcreteRequest*/ = (function(){
+var v0 = [
+  {
+    "defaultValue": null,
+    "kind": "LocalArgument",
+    "name": "id"
+  }
+],
+v1 = [
+  {
+    "kind": "Variable",
+    "name": "id",
+    "variableName": "id"
+  }
+],
+v2 = {
+  "alias": null,
+  "args": null,
+  "kind": "ScalarField",
+  "name": "id",
+  "storageKey": null
+},
+v3 = [
+  {
+    "kind": "Literal",
+    "name": "page",
+    "value": 1
+  },
+  {
+    "kind": "Literal",
+    "name": "pageSize",
+    "value": 2
+  }
+];
+return {
+  "fragment": {
+    "argumentDefinitions": (v0/*: any*/),
+    "kind": "Fragment",
+    "metadata": null,
+    "name": "usePaginationFragmentPaginatedTestPageQuery",
+    "selections": [
+      {
+        "alias": null,
+        "args": (v1/*: any*/),
+        "concreteType": null,
+        "kind": "LinkedField",
+        "name": "node",
+        "plural": false,
+        "selections": [
+          {
+            "args": null,
+            "kind": "FragmentSpread",
+            "name": "usePaginationFragmentPaginatedTestPageFragment"
+          }
+        ],
+        "storageKey": null
+      }
+    ],
+    "type": "Query",
+    "abstractKey": null
+  },
+  "kind": "Request",
+  "operation": {
+    "argumentDefinitions": (v0/*: any*/),
+    "kind": "Operation",
+    "name": "usePaginationFragmentPaginatedTestPageQuery",
+    "selections": [
+      {
+        "alias": null,
+        "args": (v1/*: any*/),
+        "concreteType": null,
+        "kind": "LinkedField",
+        "name": "node",
+        "plural": false,
+        "selections": [
+          {
+            "alias": null,
+            "args": null,
+            "kind": "ScalarField",
+            "name": "__typename",
+            "storageKey": null
+          },
+          (v2/*: any*/),
+          {
+            "kind": "InlineFragment",
+            "selections": [
+              {
+                "alias": null,
+                "args": (v3/*: any*/),
+                "concreteType": "UserPage",
+                "kind": "LinkedField",
+                "name": "followersByPage",
+                "plural": false,
+                "selections": [
+                  {
+                    "alias": null,
+                    "args": null,
+                    "kind": "ScalarField",
+                    "name": "totalCount",
+                    "storageKey": null
+                  },
+                  {
+                    "alias": null,
+                    "args": null,
+                    "concreteType": "User",
+                    "kind": "LinkedField",
+                    "name": "items",
+                    "plural": true,
+                    "selections": [
+                      (v2/*: any*/),
+                      {
+                        "alias": null,
+                        "args": null,
+                        "kind": "ScalarField",
+                        "name": "name",
+                        "storageKey": null
+                      }
+                    ],
+                    "storageKey": null
+                  }
+                ],
+                "storageKey": "followersByPage(page:1,pageSize:2)"
+              },
+              {
+                "alias": null,
+                "args": (v3/*: any*/),
+                "filters": null,
+                "handle": "paginated",
+                "key": "",
+                "kind": "LinkedHandle",
+                "name": "followersByPage",
+                "handleArgs": [
+                  {
+                    "kind": "Literal",
+                    "name": "count",
+                    "value": "pageSize"
+                  },
+                  {
+                    "kind": "Literal",
+                    "name": "cursor",
+                    "value": "page"
+                  },
+                  {
+                    "kind": "Literal",
+                    "name": "items",
+                    "value": "items"
+                  },
+                  {
+                    "kind": "Literal",
+                    "name": "kind",
+                    "value": "PAGE"
+                  }
+                ]
+              }
+            ],
+            "type": "User",
+            "abstractKey": null
+          }
+        ],
+        "storageKey": null
+      }
+    ]
+  },
+  "params": {
+    "cacheID": "ddb4370af93ae133f983cc72262aee70",
+    "id": null,
+    "metadata": {},
+    "name": "usePaginationFragmentPaginatedTestPageQuery",
+    "operationKind": "query",
+    "text": "query usePaginationFragmentPaginatedTestPageQuery(\n  $id: ID!\n) {\n  node(id: $id) {\n    __typename\n    ...usePaginationFragmentPaginatedTestPageFragment\n    id\n  }\n}\n\nfragment usePaginationFragmentPaginatedTestPageFragment on User {\n  id\n  followersByPage(page: 1, pageSize: 2) {\n    totalCount\n    items {\n      id\n      name\n    }\n  }\n}\n"
+  }
+};
+})();
+
+if (__DEV__) {
+  (node/*: any*/).hash = "bfab6ceb16eb4cb45e5722bb25168854";
+}
+
+module.exports = ((node/*: any*/)/*: Query<
+  usePaginationFragmentPaginatedTestPageQuery$variables,
+  usePaginationFragmentPaginatedTestPageQuery$data,
+>*/);
@@ -0,0 +1,317 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @flow
+ * @format
+ * @oncall relay
+ */
+
+'use strict';
+
+import type {Sink} from '../../../relay-runtime/network/RelayObservable';
+
+const useBlockingPaginationFragment = require('../legacy/useBlockingPaginationFragment');
+const usePaginationFragment_LEGACY = require('../legacy/usePaginationFragment');
+const RelayEnvironmentProvider = require('../RelayEnvironmentProvider');
+const usePaginationFragment = require('../usePaginationFragment');
+const React = require('react');
+const TestRenderer = require('react-test-renderer');
+const {
+  Environment,
+  FRAGMENT_OWNER_KEY,
+  FRAGMENTS_KEY,
+  ID_KEY,
+  Network,
+  Observable,
+  RecordSource,
+  Store,
+  createOperationDescriptor,
+  graphql,
+} = require('relay-runtime');
+
+function createUser(id: string) {
+  return {__typename: 'User', id, name: `name:${id}`};
+}
+
+function readUser(id: string) {
+  return {id, name: `name:${id}`};
+}
+
+describe.each([
+  ['New', usePaginationFragment],
+  ['Legacy', usePaginationFragment_LEGACY],
+  ['Blocking', useBlockingPaginationFragment],
+])('%s pagination hook with @paginated', (_hookName, usePaginationHook) => {
+  let dataSource;
+  let environment;
+  let fetch;
+  let loadNext;
+  let renderSpy;
+
+  const OffsetQuery = graphql`
+    query usePaginationFragmentPaginatedTestOffsetQuery($id: ID!) {
+      node(id: $id) {
+        ...usePaginationFragmentPaginatedTestOffsetFragment
+      }
+    }
+  `;
+  const OffsetFragment = graphql`
+    fragment usePaginationFragmentPaginatedTestOffsetFragment on User
+    @refetchable(
+      queryName: "usePaginationFragmentPaginatedTestOffsetPaginationQuery"
+    )
+    @argumentDefinitions(
+      offset: {type: "Int", defaultValue: 0}
+      limit: {type: "Int", defaultValue: 2}
+    ) {
+      id
+      followers(offset: $offset, limit: $limit) @paginated(kind: OFFSET) {
+        totalCount
+        items {
+          id
+          name
+        }
+      }
+    }
+  `;
+  const PageQuery = graphql`
+    query usePaginationFragmentPaginatedTestPageQuery($id: ID!) {
+      node(id: $id) {
+        ...usePaginationFragmentPaginatedTestPageFragment
+      }
+    }
+  `;
+  const PageFragment = graphql`
+    fragment usePaginationFragmentPaginatedTestPageFragment on User
+    @refetchable(
+      queryName: "usePaginationFragmentPaginatedTestPagePaginationQuery"
+    )
+    @argumentDefinitions(
+      page: {type: "Int", defaultValue: 1}
+      pageSize: {type: "Int", defaultValue: 2}
+    ) {
+      id
+      followersByPage(page: $page, pageSize: $pageSize)
+        @paginated(kind: PAGE) {
+        totalCount
+        items {
+          id
+          name
+        }
+      }
+    }
+  `;
+
+  function Container(props: {fragment: $FlowFixMe, userRef: $FlowFixMe}) {
+    // $FlowFixMe[underconstrained-implicit-instantiation]
+    const {data, ...result} = usePaginationHook(
+      props.fragment,
+      props.userRef,
+    );
+    loadNext = result.loadNext;
+    renderSpy(data, result);
+    return null;
+  }
+
+  function render(query: $FlowFixMe, fragment: $FlowFixMe) {
+    const operation = createOperationDescriptor(query, {id: '1'});
+    const userRef = {
+      [ID_KEY]: '1',
+      [FRAGMENTS_KEY]: {[fragment.name]: {}},
+      [FRAGMENT_OWNER_KEY]: operation.request,
+    };
+    TestRenderer.act(() => {
+      TestRenderer.create(
+        <RelayEnvironmentProvider environment={environment}>
+          <React.Suspense fallback="Loading...">
+            <Container fragment={fragment} userRef={userRef} />
+          </React.Suspense>
+        </RelayEnvironmentProvider>,
+      );
+    });
+    return operation;
+  }
+
+  function expectLastRender(expected: {data: mixed, hasNext: boolean}) {
+    TestRenderer.act(() => jest.runAllImmediates());
+    const calls = renderSpy.mock.calls;
+    const [data, result] = calls[calls.length - 1];
+    expect(data).toEqual(expected.data);
+    expect(result.hasNext).toBe(expected.hasNext);
+    expect(result.hasPrevious).toBe(false);
+  }
+
+  function resolvePage(field: string, users: Array<string>) {
+    TestRenderer.act(() => {
+      dataSource.next({
+        data: {
+          node: {
+            __typename: 'User',
+            id: '1',
+            [field]: {totalCount: 5, items: users.map(createUser)},
+          },
+        },
+      });
+      dataSource.complete();
+    });
+  }
+
+  beforeEach(() => {
+    jest.spyOn(console, 'warn').mockImplementation(() => {});
+    renderSpy = jest.fn<_, mixed>();
+    fetch = jest.fn((_query, _variables, _cacheConfig) =>
+      Observable.create((sink: Sink<mixed>) => {
+        dataSource = sink;
+      }),
+    );
+    environment = new Environment({
+      network: Network.create(fetch),
+      store: new Store(new RecordSource()),
+    });
+  });
+
+  afterEach(() => {
+    jest.clearAllMocks();
+  });
+
+  it('appends the items of the following offsets', () => {
+    const operation = createOperationDescriptor(OffsetQuery, {id: '1'});
+    environment.commitPayload(operation, {
+      node: {
+        __typename: 'User',
+        id: '1',
+        followers: {totalCount: 5, items: [createUser('2'), createUser('3')]},
+      },
+    });
+    render(OffsetQuery, OffsetFragment);
+    expectLastRender({
+      data: {
+        id: '1',
+        followers: {totalCount: 5, items: [readUser('2'), readUser('3')]},
+      },
+      hasNext: true,
+    });
+
+    TestRenderer.act(() => {
+      loadNext(2);
+    });
+    expect(fetch).toBeCalledTimes(1);
+    expect(fetch.mock.calls[0][0].name).toBe(
+      'usePaginationFragmentPaginatedTestOffsetPaginationQuery',
+    );
+    expect(fetch.mock.calls[0][1]).toEqual({id: '1', offset: 2, limit: 2});
+    resolvePage('followers', ['4', '5']);
+    expectLastRender({
+      data: {
+        id: '1',
+        followers: {
+          totalCount: 5,
+          items: ['2', '3', '4', '5'].map(readUser),
+        },
+      },
+      hasNext: true,
+    });
+
+    TestRenderer.act(() => {
+      loadNext(2);
+    });
+    expect(fetch).toBeCalledTimes(2);
+    expect(fetch.mock.calls[1][1]).toEqual({id: '1', offset: 4, limit: 2});
+    // The last page has fewer items than requested
+    resolvePage('followers', ['6']);
+    expectLastRender({
+      data: {
+        id: '1',
+        followers: {
+          totalCount: 5,
+          items: ['2', '3', '4', '5', '6'].map(readUser),
+        },
+      },
+      hasNext: false,
+    });
+  });
+
+  it('appends the items of the following pages', () => {
+    const operation = createOperationDescriptor(PageQuery, {id: '1'});
+    environment.commitPayload(operation, {
+      node: {
+        __typename: 'User',
+        id: '1',
+        followersByPage: {
+          totalCount: 5,
+          items: [createUser('2'), createUser('3')],
+        },
+      },
+    });
+    render(PageQuery, PageFragment);
+    expectLastRender({
+      data: {
+        id: '1',
+        followersByPage: {
+          totalCount: 5,
+          items: [readUser('2'), readUser('3')],
+        },
+      },
+      hasNext: true,
+    });
+
+    TestRenderer.act(() => {
+      loadNext(2);
+    });
+    expect(fetch).toBeCalledTimes(1);
+    expect(fetch.mock.calls[0][0].name).toBe(
+      'usePaginationFragmentPaginatedTestPagePaginationQuery',
+    );
+    expect(fetch.mock.calls[0][1]).toEqual({id: '1', page: 2, pageSize: 2});
+    resolvePage('followersByPage', ['4', '5']);
+    expectLastRender({
+      data: {
+        id: '1',
+        followersByPage: {
+          totalCount: 5,
+          items: ['2', '3', '4', '5'].map(readUser),
+        },
+      },
+      hasNext: true,
+    });
+
+    TestRenderer.act(() => {
+      loadNext(2);
+    });
+    expect(fetch).toBeCalledTimes(2);
+    expect(fetch.mock.calls[1][1]).toEqual({id: '1', page: 3, pageSize: 2});
+    resolvePage('followersByPage', ['6']);
+    expectLastRender({
+      data: {
+        id: '1',
+        followersByPage: {
+          totalCount: 5,
+          items: ['2', '3', '4', '5', '6'].map(readUser),
+        },
+      },
+      hasNext: false,
+    });
+  });
+
+  it('does not have a next page when the first page is not full', () => {
+    const operation = createOperationDescriptor(OffsetQuery, {id: '1'});
+    environment.commitPayload(operation, {
+      node: {
+        __typename: 'User',
+        id: '1',
+        followers: {totalCount: 1, items: [createUser('2')]},
+      },
+    });
+    render(OffsetQuery, OffsetFragment);
+    expectLastRender({
+      data: {
+        id: '1',
+        followers: {totalCount: 1, items: [readUser('2')]},
+      },
+      hasNext: false,
+    });
+  });
+});
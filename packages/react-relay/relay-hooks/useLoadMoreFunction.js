@@ -18,6 +18,7 @@ import type {
   GraphQLResponse,
   Observer,
   ReaderFragment,
+  ReaderPaginatedMetadata,
   ReaderPaginationMetadata,
   Variables,
 } from 'relay-runtime';
@@ -81,10 +82,12 @@ function useLoadMoreFunction<TVariables: Variables>(
   const {isFetchingRef, startFetch, disposeFetch, completeFetch} =
     useFetchTrackingRef();
 
-  const {identifierInfo} = getRefetchMetadata(
+  const {identifierInfo, refetchMetadata} = getRefetchMetadata(
     fragmentNode,
     componentDisplayName,
   );
+  const paginatedMetadata =
+    refetchMetadata.connection == null ? refetchMetadata.pagination : null;
   const identifierValue =
     identifierInfo?.identifierField != null &&
     fragmentData != null &&
@@ -96,6 +99,9 @@ function useLoadMoreFunction<TVariables: Variables>(
   const [mirroredEnvironment, setMirroredEnvironment] = useState(environment);
   const [mirroredFragmentIdentifier, setMirroredFragmentIdentifier] =
     useState(fragmentIdentifier);
+  // The number of items a @paginated field is expected to have once the last
+  // page was fetched, fewer items means that there are no more pages.
+  const [expectedItemCount, setExpectedItemCount] = useState<?number>(null);
 
   const isParentQueryActive = useIsOperationNodeActive(
     fragmentNode,
@@ -110,14 +116,28 @@ function useLoadMoreFunction<TVariables: Variables>(
     onReset();
     setMirroredEnvironment(environment);
     setMirroredFragmentIdentifier(fragmentIdentifier);
+    setExpectedItemCount(null);
   }
 
-  const {cursor, hasMore} = getConnectionState(
-    direction,
-    fragmentNode,
-    fragmentData,
-    connectionPathInFragmentData,
-  );
+  const {cursor, hasMore, itemCount} =
+    paginatedMetadata != null
+      ? getPaginatedState(
+          direction,
+          fragmentNode,
+          fragmentRef,
+          fragmentData,
+          paginatedMetadata,
+          shouldReset ? null : expectedItemCount,
+        )
+      : {
+          ...getConnectionState(
+            direction,
+            fragmentNode,
+            fragmentData,
+            connectionPathInFragmentData,
+          ),
+          itemCount: null,
+        };
 
   // Dispose of pagination requests in flight when unmounting
   useEffect(() => {
@@ -236,6 +256,9 @@ function useLoadMoreFunction<TVariables: Variables>(
         },
         complete: () => {
           completeFetch();
+          if (itemCount != null) {
+            setExpectedItemCount(itemCount + count);
+          }
           observer.complete && observer.complete();
           onComplete && onComplete(null);
         },
@@ -255,6 +278,7 @@ function useLoadMoreFunction<TVariables: Variables>(
       identifierValue,
       direction,
       cursor,
+      itemCount,
       startFetch,
       disposeFetch,
       completeFetch,
@@ -347,4 +371,76 @@ function getConnectionState(
   return {cursor, hasMore};
 }
 
+function getPaginatedState(
+  direction: Direction,
+  fragmentNode: ReaderFragment,
+  fragmentRef: mixed,
+  fragmentData: mixed,
+  paginatedMetadata: ReaderPaginatedMetadata,
+  expectedItemCount: ?number,
+): {
+  cursor: ?number,
+  hasMore: boolean,
+  itemCount: ?number,
+} {
+  const page = getValueAtPath(fragmentData, paginatedMetadata.path);
+  // @paginated fields are only paginated forward
+  if (direction === 'backward' || page == null) {
+    return {cursor: null, hasMore: false, itemCount: null};
+  }
+
+  invariant(
+    typeof page === 'object',
+    'Relay: Expected @paginated field in fragment `%s` to have been `null`, ' +
+      'or a plain object with a %s property. Instead got `%s`.',
+    fragmentNode.name,
+    paginatedMetadata.items,
+    page,
+  );
+  const items = page[paginatedMetadata.items];
+  if (items == null) {
+    return {cursor: null, hasMore: false, itemCount: null};
+  }
+  invariant(
+    Array.isArray(items),
+    'Relay: Expected @paginated field in fragment `%s` to have a plural ' +
+      '`%s` field. Instead got `%s`.',
+    fragmentNode.name,
+    paginatedMetadata.items,
+    items,
+  );
+
+  // The items are merged from the offset or page the fragment was fetched
+  // with, the next page follows the last item.
+  const fragmentSelector = getSelector(fragmentNode, fragmentRef);
+  const fragmentVariables =
+    fragmentSelector != null && fragmentSelector.kind !== 'PluralReaderSelector'
+      ? fragmentSelector.variables
+      : {};
+  const firstCursor =
+    paginatedMetadata.cursor != null
+      ? fragmentVariables[paginatedMetadata.cursor]
+      : null;
+  const pageSize =
+    paginatedMetadata.count != null
+      ? fragmentVariables[paginatedMetadata.count]
+      : null;
+  const first = typeof firstCursor === 'number' ? firstCursor : 0;
+  let cursor;
+  if (paginatedMetadata.kind === 'PAGE') {
+    if (typeof pageSize !== 'number' || pageSize <= 0) {
+      return {cursor: null, hasMore: false, itemCount: null};
+    }
+    cursor = first + Math.ceil(items.length / pageSize);
+  } else {
+    cursor = first + items.length;
+  }
+
+  const expected =
+    expectedItemCount ?? (typeof pageSize === 'number' ? pageSize : null);
+  const hasMore =
+    items.length > 0 && expected != null && items.length >= expected;
+  return {cursor, hasMore, itemCount: items.length};
+}
+
 module.exports = useLoadMoreFunction;
@@ -11,6 +11,7 @@
 
 'use strict';
 
+const batchResolverDataInjector = require('./store/experimental-live-resolvers/batchResolverDataInjector');
 const resolverDataInjector = require('./store/experimental-live-resolvers/resolverDataInjector');
 const {
   weakObjectWrapper,
@@ -18,6 +19,7 @@ const {
 } = require('./store/experimental-live-resolvers/weakObjectWrapper');
 
 module.exports = {
+  batchResolverDataInjector,
   resolverDataInjector,
   weakObjectWrapper,
   weakObjectWrapperLive,
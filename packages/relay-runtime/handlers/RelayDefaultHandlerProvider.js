@@ -15,6 +15,7 @@ import type {Handler} from '../store/RelayStoreTypes';
 
 const ConnectionHandler = require('./connection/ConnectionHandler');
 const MutationHandlers = require('./connection/MutationHandlers');
+const PaginatedHandler = require('./paginated/PaginatedHandler');
 const invariant = require('invariant');
 
 export type HandlerProvider = (name: string) => ?Handler;
@@ -23,6 +24,8 @@ function RelayDefaultHandlerProvider(handle: string): Handler {
   switch (handle) {
     case 'connection':
       return ConnectionHandler;
+    case 'paginated':
+      return PaginatedHandler;
     case 'deleteRecord':
       return MutationHandlers.DeleteRecordHandler;
     case 'deleteEdge':
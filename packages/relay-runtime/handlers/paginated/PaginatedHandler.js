@@ -0,0 +1,114 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @flow
+ * @format
+ * @oncall relay
+ */
+
+'use strict';
+
+import type {
+  HandleFieldPayload,
+  RecordSourceProxy,
+} from '../../store/RelayStoreTypes';
+
+const {generateClientID} = require('../../store/ClientID');
+const invariant = require('invariant');
+const warning = require('warning');
+
+// The offset or page number the first page of the merged list was fetched with
+const FIRST_CURSOR = '__paginated_first_cursor';
+
+/**
+ * @public
+ *
+ * A default runtime handler for @paginated fields that merges the items of
+ * newly fetched pages into a single list, at the position given by the offset
+ * or page number they were fetched with. Fetching the first page again
+ * replaces the list.
+ *
+ * The handle arguments are generated by the compiler:
+ * - `kind`: `OFFSET` or `PAGE`.
+ * - `cursor`: the name of the offset or page number argument.
+ * - `count`: the name of the limit or page size argument.
+ * - `items`: the name of the list field containing the items of a page.
+ */
+function update(store: RecordSourceProxy, payload: HandleFieldPayload): void {
+  const record = store.get(payload.dataID);
+  if (!record) {
+    return;
+  }
+
+  const {kind, cursor: cursorArg, count: countArg, items} = payload.handleArgs;
+  invariant(
+    (kind === 'OFFSET' || kind === 'PAGE') &&
+      typeof cursorArg === 'string' &&
+      typeof countArg === 'string' &&
+      typeof items === 'string',
+    'PaginatedHandler: Expected the `kind`, `cursor`, `count` and `items` ' +
+      'handle arguments of field `%s` to be set by the compiler.',
+    payload.fieldKey,
+  );
+
+  const serverPage = record.getLinkedRecord(payload.fieldKey);
+  if (!serverPage) {
+    record.setValue(null, payload.handleKey);
+    return;
+  }
+
+  const clientPageID = generateClientID(record.getDataID(), payload.handleKey);
+  const clientPage =
+    record.getLinkedRecord(payload.handleKey) ??
+    store.get(clientPageID) ??
+    store.create(clientPageID, serverPage.getType());
+  const prevItems = clientPage.getLinkedRecords(items);
+  const firstCursor = clientPage.getValue(FIRST_CURSOR);
+  // Other fields of the page, e.g. a total count, are updated with every page.
+  clientPage.copyFieldsFrom(serverPage);
+  record.setLinkedRecord(clientPage, payload.handleKey);
+
+  const cursor = payload.args[cursorArg];
+  const count = payload.args[countArg];
+  const serverItems = serverPage.getLinkedRecords(items);
+  if (
+    prevItems == null ||
+    serverItems == null ||
+    typeof firstCursor !== 'number' ||
+    typeof cursor !== 'number' ||
+    cursor <= firstCursor
+  ) {
+    // The list was fetched from its first page: replace the items
+    clientPage.setValue(typeof cursor === 'number' ? cursor : 0, FIRST_CURSOR);
+    return;
+  }
+
+  let start = cursor - firstCursor;
+  if (kind === 'PAGE') {
+    start *= typeof count === 'number' ? count : 0;
+  }
+  if (start > prevItems.length) {
+    warning(
+      false,
+      'Relay: Unexpected %s argument `%s` of @paginated field `%s`, items ' +
+        'must be fetched from the end of the list (%s items).',
+      cursorArg,
+      cursor,
+      payload.fieldKey,
+      prevItems.length,
+    );
+    clientPage.setLinkedRecords(prevItems, items);
+    return;
+  }
+  clientPage.setLinkedRecords(
+    [...prevItems.slice(0, start), ...serverItems],
+    items,
+  );
+}
+
+module.exports = {
+  update,
+};
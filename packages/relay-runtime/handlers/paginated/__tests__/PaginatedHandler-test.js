@@ -0,0 +1,145 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @format
+ * @oncall relay
+ */
+
+'use strict';
+
+const RelayRecordSourceMutator = require('../../../mutations/RelayRecordSourceMutator');
+const RelayRecordSourceProxy = require('../../../mutations/RelayRecordSourceProxy');
+const defaultGetDataID = require('../../../store/defaultGetDataID');
+const RelayRecordSource = require('../../../store/RelayRecordSource');
+const RelayStoreUtils = require('../../../store/RelayStoreUtils');
+const getRelayHandleKey = require('../../../util/getRelayHandleKey');
+const PaginatedHandler = require('../PaginatedHandler');
+const warning = require('warning');
+
+const {ID_KEY, REF_KEY, REFS_KEY, TYPENAME_KEY, getStableStorageKey} =
+  RelayStoreUtils;
+
+jest.mock('warning');
+
+const OFFSET_HANDLE_ARGS = {
+  kind: 'OFFSET',
+  cursor: 'offset',
+  count: 'limit',
+  items: 'items',
+};
+const PAGE_HANDLE_ARGS = {
+  kind: 'PAGE',
+  cursor: 'page',
+  count: 'pageSize',
+  items: 'items',
+};
+
+describe('PaginatedHandler', () => {
+  const handleKey = getRelayHandleKey('paginated', '', 'followers');
+  let baseSource;
+  let proxy;
+  let sinkSource;
+
+  function addPage(args, items, handleArgs = OFFSET_HANDLE_ARGS) {
+    const fieldKey = getStableStorageKey('followers', args);
+    const pageID = `client:4:${fieldKey}`;
+    baseSource.set(pageID, {
+      [ID_KEY]: pageID,
+      [TYPENAME_KEY]: 'UserPage',
+      items: {[REFS_KEY]: items},
+      totalCount: 5,
+    });
+    items.forEach(id => {
+      baseSource.set(id, {[ID_KEY]: id, [TYPENAME_KEY]: 'User', id});
+    });
+    const user = baseSource.get('4');
+    baseSource.set('4', {...user, [fieldKey]: {[REF_KEY]: pageID}});
+    return {
+      args,
+      dataID: '4',
+      fieldKey,
+      handle: 'paginated',
+      handleKey,
+      handleArgs,
+    };
+  }
+
+  function getItems() {
+    const user = proxy.get('4');
+    const page = user?.getLinkedRecord(handleKey);
+    return page?.getLinkedRecords('items')?.map(item => item?.getDataID());
+  }
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+    baseSource = new RelayRecordSource({
+      '4': {[ID_KEY]: '4', [TYPENAME_KEY]: 'User', id: '4'},
+    });
+    sinkSource = new RelayRecordSource({});
+    const mutator = new RelayRecordSourceMutator(baseSource, sinkSource);
+    proxy = new RelayRecordSourceProxy(mutator, defaultGetDataID);
+  });
+
+  it('copies the first page', () => {
+    PaginatedHandler.update(proxy, addPage({offset: 0, limit: 2}, ['1', '2']));
+    expect(getItems()).toEqual(['1', '2']);
+    const page = proxy.get('4')?.getLinkedRecord(handleKey);
+    expect(page?.getValue('totalCount')).toBe(5);
+  });
+
+  it('appends the items of the following offsets', () => {
+    PaginatedHandler.update(proxy, addPage({offset: 0, limit: 2}, ['1', '2']));
+    PaginatedHandler.update(proxy, addPage({offset: 2, limit: 2}, ['3', '4']));
+    expect(getItems()).toEqual(['1', '2', '3', '4']);
+  });
+
+  it('replaces the items when the first page is fetched again', () => {
+    PaginatedHandler.update(proxy, addPage({offset: 0, limit: 2}, ['1', '2']));
+    PaginatedHandler.update(proxy, addPage({offset: 2, limit: 2}, ['3', '4']));
+    PaginatedHandler.update(proxy, addPage({offset: 0, limit: 2}, ['5', '1']));
+    expect(getItems()).toEqual(['5', '1']);
+  });
+
+  it('appends the items of the following pages', () => {
+    PaginatedHandler.update(
+      proxy,
+      addPage({page: 1, pageSize: 2}, ['1', '2'], PAGE_HANDLE_ARGS),
+    );
+    PaginatedHandler.update(
+      proxy,
+      addPage({page: 2, pageSize: 2}, ['3', '4'], PAGE_HANDLE_ARGS),
+    );
+    expect(getItems()).toEqual(['1', '2', '3', '4']);
+  });
+
+  it('keeps the items when a page is fetched past the end of the list', () => {
+    PaginatedHandler.update(proxy, addPage({offset: 0, limit: 2}, ['1', '2']));
+    PaginatedHandler.update(proxy, addPage({offset: 4, limit: 2}, ['5', '6']));
+    expect(getItems()).toEqual(['1', '2']);
+    expect(warning).toHaveBeenCalledWith(
+      false,
+      expect.stringContaining('must be fetched from the end of the list'),
+      'offset',
+      4,
+      getStableStorageKey('followers', {offset: 4, limit: 2}),
+      2,
+    );
+  });
+
+  it('sets the handle field to null when the page is null', () => {
+    const fieldKey = getStableStorageKey('followers', {offset: 0, limit: 2});
+    baseSource.set('4', {...baseSource.get('4'), [fieldKey]: null});
+    PaginatedHandler.update(proxy, {
+      args: {offset: 0, limit: 2},
+      dataID: '4',
+      fieldKey,
+      handle: 'paginated',
+      handleKey,
+      handleArgs: OFFSET_HANDLE_ARGS,
+    });
+    expect(proxy.get('4')?.getValue(handleKey)).toBe(null);
+  });
+});
@@ -14,6 +14,7 @@
 const ConnectionHandler = require('./handlers/connection/ConnectionHandler');
 const ConnectionInterface = require('./handlers/connection/ConnectionInterface');
 const MutationHandlers = require('./handlers/connection/MutationHandlers');
+const PaginatedHandler = require('./handlers/paginated/PaginatedHandler');
 const RelayDefaultHandlerProvider = require('./handlers/RelayDefaultHandlerProvider');
 const applyOptimisticMutation = require('./mutations/applyOptimisticMutation');
 const commitLocalUpdate = require('./mutations/commitLocalUpdate');
@@ -190,14 +191,18 @@ export type {
   NormalizationOperation,
 } from './util/NormalizationNode';
 export type {
+  CatchFieldTo,
+  PaginatedKind,
   ReaderArgument,
   ReaderArgumentDefinition,
+  ReaderCatchField,
   ReaderField,
   ReaderFragment,
   ReaderInlineDataFragment,
   ReaderInlineDataFragmentSpread,
   ReaderLinkedField,
   ReaderModuleImport,
+  ReaderPaginatedMetadata,
   ReaderPaginationMetadata,
   ReaderRefetchableFragment,
   ReaderRefetchMetadata,
@@ -319,6 +324,7 @@ module.exports = {
   DefaultHandlerProvider: RelayDefaultHandlerProvider,
   ConnectionHandler,
   MutationHandlers,
+  PaginatedHandler,
   VIEWER_ID: ViewerPattern.VIEWER_ID,
   VIEWER_TYPE: ViewerPattern.VIEWER_TYPE,
 
@@ -0,0 +1,114 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @oncall relay
+ *
+ * @generated SignedSource<<5daf2858089e38a122ec660f2862f5cd>>
+ * @flow
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+/*::
+import type { ConcreteRequest, Query } from 'relay-runtime';
+export type fetchQueryTest6Query$variables = {||};
+export type fetchQueryTest6Query$data = {|
+  +me: ?{|
+    +lastName: ?string,
+  |},
+|};
+export type fetchQueryTest6Query = {|
+  response: fetchQueryTest6Query$data,
+  variables: fetchQueryTest6Query$variables,
+|};
+*/
+
+var node/*: ConcreteRequest*/ = (function(){
+var v0 = {
+  "alias": null,
+  "args": null,
+  "kind": "ScalarField",
+  "name": "lastName",
+  "storageKey": null
+};
+return {
+  "fragment": {
+    "argumentDefinitions": [],
+    "kind": "Fragment",
+    "metadata": null,
+    "name": "fetchQueryTest6Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          {
+            "kind": "CatchField",
+            "field": (v0/*: any*/),
+            "to": "NULL",
+            "path": "me.lastName"
+          }
+        ],
+        "storageKey": null
+      }
+    ],
+    "type": "Query",
+    "abstractKey": null
+  },
+  "kind": "Request",
+  "operation": {
+    "argumentDefinitions": [],
+    "kind": "Operation",
+    "name": "fetchQueryTest6Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          (v0/*: any*/),
+          {
+            "alias": null,
+            "args": null,
+            "kind": "ScalarField",
+            "name": "id",
+            "storageKey": null
+          }
+        ],
+        "storageKey": null
+      }
+    ]
+  },
+  "params": {
+    "cacheID": "4a7c1d0635216a78627fdc0d77941540",
+    "id": null,
+    "metadata": {},
+    "name": "fetchQueryTest6Query",
+    "operationKind": "query",
+    "text": "query fetchQueryTest6Query {\n  me {\n    lastName\n    id\n  }\n}\n"
+  }
+};
+})();
+
+if (__DEV__) {
+  (node/*: any*/).hash = "0ff3f1a04bde12b5c0456a9dd12f055d";
+}
+
+module.exports = ((node/*: any*/)/*: Query<
+  fetchQueryTest6Query$variables,
+  fetchQueryTest6Query$data,
+>*/);
@@ -15,6 +15,7 @@ import type {RelayFieldLoggerEvent} from '../../store/RelayStoreTypes';
 import type {fetchQueryTest1Query$data} from './__generated__/fetchQueryTest1Query.graphql';
 import type {RequestParameters} from 'relay-runtime';
 
+const RelayFeatureFlags = require('../../util/RelayFeatureFlags');
 const fetchQuery = require('../fetchQuery');
 const {
   Environment,
@@ -343,6 +344,64 @@ describe('fetchQuery with missing @required value', () => {
   });
 });
 
+describe('fetchQuery with a field error caught by @catch(to: NULL)', () => {
+  const wasFieldErrorHandlingEnabled =
+    RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING;
+  const wasThrowByDefaultEnabled =
+    RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING_THROW_BY_DEFAULT;
+
+  beforeEach(() => {
+    RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING = true;
+    RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING_THROW_BY_DEFAULT = true;
+  });
+
+  afterEach(() => {
+    RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING =
+      wasFieldErrorHandlingEnabled;
+    RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING_THROW_BY_DEFAULT =
+      wasThrowByDefaultEnabled;
+  });
+
+  it('logs the error and provides null for the field', () => {
+    const relayFieldLogger = jest.fn<[RelayFieldLoggerEvent], void>();
+    const environment = createMockEnvironment({relayFieldLogger});
+    const query = graphql`
+      query fetchQueryTest6Query {
+        me {
+          lastName @catch(to: NULL)
+        }
+      }
+    `;
+
+    const observer = {
+      next: jest.fn<[$FlowFixMe], mixed>(),
+      error: jest.fn<[Error], mixed>(),
+    };
+    const subscription = fetchQuery(environment, query, {}).subscribe(observer);
+    const queryNode = getRequest(query);
+
+    environment.mock.nextValue(queryNode, {
+      data: {me: {id: 'ID-1', lastName: null}},
+      errors: [
+        {
+          message: 'There was an error!',
+          locations: [],
+          path: ['me', 'lastName'],
+        },
+      ],
+    });
+    subscription.unsubscribe();
+    expect(observer.error).not.toHaveBeenCalled();
+    expect(observer.next).toHaveBeenCalledWith({me: {lastName: null}});
+    expect(relayFieldLogger).toHaveBeenCalledWith({
+      kind: 'relay_field_payload.error',
+      owner: 'fetchQueryTest6Query',
+      fieldPath: 'me.lastName',
+      error: {message: 'There was an error!', path: ['me', 'lastName']},
+    });
+  });
+});
+
 test('client-only query with error', () => {
   const fetchFn = jest.fn((params: RequestParameters) => {
     if (params.id === null && params.text == null) {
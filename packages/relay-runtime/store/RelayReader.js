@@ -14,6 +14,8 @@
 import type {
   ReaderActorChange,
   ReaderAliasedFragmentSpread,
+  ReaderAliasedInlineFragmentSpread,
+  ReaderCatchField,
   ReaderClientEdgeToClientObject,
   ReaderClientEdgeToServerObject,
   ReaderFragment,
@@ -30,6 +32,7 @@ import type {
   ReaderSelection,
 } from '../util/ReaderNode';
 import type {DataID, Variables} from '../util/RelayRuntimeTypes';
+import type {TRelayFieldError} from './RelayErrorTrie';
 import type {
   ClientEdgeTraversalInfo,
   DataIDSet,
@@ -52,6 +55,7 @@ const {
   ACTOR_CHANGE,
   ALIASED_FRAGMENT_SPREAD,
   ALIASED_INLINE_FRAGMENT_SPREAD,
+  CATCH_FIELD,
   CLIENT_EDGE_TO_CLIENT_OBJECT,
   CLIENT_EDGE_TO_SERVER_OBJECT,
   CLIENT_EXTENSION,
@@ -118,6 +122,9 @@ class RelayReader {
   _isWithinUnmatchedTypeRefinement: boolean;
   _missingRequiredFields: ?MissingRequiredFields;
   _errorResponseFields: ?ErrorResponseFields;
+  // Whether a field within a @catch field is being read, in which case the
+  // field errors are collected even if field error handling is disabled.
+  _isWithinCatch: boolean;
   _owner: RequestDescriptor;
   _recordSource: RecordSource;
   _seenRecords: DataIDSet;
@@ -127,6 +134,17 @@ class RelayReader {
   _resolverCache: ResolverCache;
   _resolverErrors: RelayResolverErrors;
   _fragmentName: string;
+  // The IDs of the records of the plural linked fields being read, innermost
+  // last, with the storage keys of the singular linked fields read from them.
+  _pluralLinkedFields: Array<{
+    ids: $ReadOnlyArray<?DataID>,
+    path: Array<string>,
+  }>;
+  // The results of the @batch resolvers evaluated so far, by record.
+  _batchResolverResults: Map<
+    ReaderRelayResolver,
+    Map<DataID, EvaluationResult<mixed>>,
+  >;
 
   constructor(
     recordSource: RecordSource,
@@ -144,6 +162,7 @@ class RelayReader {
     this._isWithinUnmatchedTypeRefinement = false;
     this._missingRequiredFields = null;
     this._errorResponseFields = null;
+    this._isWithinCatch = false;
     this._owner = selector.owner;
     this._recordSource = recordSource;
     this._seenRecords = new Set();
@@ -153,6 +172,8 @@ class RelayReader {
     this._resolverErrors = [];
     this._fragmentName = selector.node.name;
     this._updatedDataIDs = new Set();
+    this._pluralLinkedFields = [];
+    this._batchResolverResults = new Map();
   }
 
   read(): Snapshot {
@@ -234,7 +255,10 @@ class RelayReader {
   }
 
   _maybeAddErrorResponseFields(record: Record, storageKey: string): void {
-    if (!RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING) {
+    if (
+      !RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING &&
+      !this._isWithinCatch
+    ) {
       return;
     }
     const errors = RelayModernRecord.getErrors(record, storageKey);
@@ -364,6 +388,9 @@ class RelayReader {
           }
           break;
         }
+        case CATCH_FIELD:
+          this._readCatchField(selection, record, data);
+          break;
         case SCALAR_FIELD:
           this._readScalar(selection, record, data);
           break;
@@ -413,15 +440,10 @@ class RelayReader {
           );
           break;
         case ALIASED_INLINE_FRAGMENT_SPREAD: {
-          let fieldValue = this._readInlineFragment(
-            selection.fragment,
+          data[selection.name] = this._readAliasedInlineFragment(
+            selection,
             record,
-            {},
           );
-          if (fieldValue === false) {
-            fieldValue = null;
-          }
-          data[selection.name] = fieldValue;
           break;
         }
         case MODULE_IMPORT:
@@ -525,6 +547,22 @@ class RelayReader {
           throw new Error('Relay Resolver fields are not yet supported.');
         }
         return this._readClientEdge(selection.field, record, data);
+      case ALIASED_FRAGMENT_SPREAD: {
+        const fieldValue = this._createAliasedFragmentSpread(
+          selection.field,
+          record,
+        );
+        data[selection.field.name] = fieldValue;
+        return fieldValue;
+      }
+      case ALIASED_INLINE_FRAGMENT_SPREAD: {
+        const fieldValue = this._readAliasedInlineFragment(
+          selection.field,
+          record,
+        );
+        data[selection.field.name] = fieldValue;
+        return fieldValue;
+      }
       default:
         (selection.field.kind: empty);
         invariant(
@@ -535,6 +573,112 @@ class RelayReader {
     }
   }
 
+  _readCatchField(
+    selection: ReaderCatchField,
+    record: Record,
+    data: SelectorData,
+  ): void {
+    const {field} = selection;
+    const applicationName =
+      field.kind === ALIASED_FRAGMENT_SPREAD ||
+      field.kind === ALIASED_INLINE_FRAGMENT_SPREAD
+        ? field.name
+        : field.alias ?? field.name;
+
+    // The field may be selected more than once, in which case the result of
+    // the previous selection is unwrapped so that the selections are merged.
+    const prevResult = data[applicationName];
+    if (
+      selection.to === 'RESULT' &&
+      prevResult != null &&
+      typeof prevResult === 'object'
+    ) {
+      data[applicationName] =
+        prevResult.ok === true ? prevResult.value : undefined;
+    }
+
+    // The field errors and the `@required(action: THROW)` fields within the
+    // caught field are read separately from those of the rest of the
+    // selector, so that they can be returned with the field instead.
+    const parentErrorResponseFields = this._errorResponseFields;
+    const parentMissingRequiredFields = this._missingRequiredFields;
+    const parentIsWithinCatch = this._isWithinCatch;
+    this._errorResponseFields = null;
+    this._missingRequiredFields = null;
+    this._isWithinCatch = true;
+
+    // The caught field is the boundary of the @required fields within it: it
+    // may be read as null, but that null never bubbles up to the selections
+    // of its parent, so whether they had all of their required data is
+    // ignored here.
+    this._traverseSelections([field], record, data);
+
+    const errorResponseFields = this._errorResponseFields;
+    const missingRequiredFields = this._missingRequiredFields;
+    this._errorResponseFields = parentErrorResponseFields;
+    this._missingRequiredFields = parentMissingRequiredFields;
+    this._isWithinCatch = parentIsWithinCatch;
+
+    if (selection.to === 'NULL') {
+      this._readCatchFieldToNull(
+        applicationName,
+        errorResponseFields,
+        missingRequiredFields,
+        data,
+      );
+      return;
+    }
+
+    const errors: Array<TRelayFieldError> = (errorResponseFields ?? []).map(
+      ({error}) => error,
+    );
+    if (missingRequiredFields?.action === 'THROW') {
+      const {path, owner} = missingRequiredFields.field;
+      errors.push({
+        message: `Relay: Missing @required value at path '${path}' in '${owner}'.`,
+      });
+    } else if (missingRequiredFields != null) {
+      this._addMissingRequiredFields(missingRequiredFields);
+    }
+
+    data[applicationName] =
+      errors.length > 0
+        ? {ok: false, errors}
+        : {ok: true, value: data[applicationName]};
+  }
+
+  // A `@catch(to: NULL)` field is null if it has errors, which are reported
+  // with the snapshot to be logged, but not thrown.
+  _readCatchFieldToNull(
+    applicationName: string,
+    errorResponseFields: ?ErrorResponseFields,
+    missingRequiredFields: ?MissingRequiredFields,
+    data: SelectorData,
+  ): void {
+    let hasErrors = false;
+    if (errorResponseFields != null && errorResponseFields.length > 0) {
+      hasErrors = true;
+      if (this._errorResponseFields == null) {
+        this._errorResponseFields = [];
+      }
+      for (const fieldError of errorResponseFields) {
+        this._errorResponseFields.push({...fieldError, handled: true});
+      }
+    }
+    if (missingRequiredFields?.action === 'THROW') {
+      hasErrors = true;
+      this._addMissingRequiredFields({
+        action: 'LOG',
+        fields: [missingRequiredFields.field],
+      });
+    } else if (missingRequiredFields != null) {
+      this._addMissingRequiredFields(missingRequiredFields);
+    }
+    if (hasErrors) {
+      data[applicationName] = null;
+    }
+  }
+
   _readResolverField(
     field: ReaderRelayResolver | ReaderRelayLiveResolver,
     record: Record,
@@ -600,6 +744,9 @@ class RelayReader {
     //   `getResolverValue`) and converted into an error object.
     const evaluate = (): EvaluationResult<mixed> => {
       if (fragment != null) {
+        if (field.kind === RELAY_RESOLVER && field.batch === true) {
+          return this._evaluateBatchResolver(field, fragment, parentRecordID);
+        }
         const key = {
           __id: parentRecordID,
           __fragmentOwner: this._owner,
@@ -655,6 +802,134 @@ class RelayReader {
     return result;
   }
 
+  // Evaluates a @batch resolver once for all the records read at the same path
+  // as `parentRecordID` under the innermost plural linked field, e.g. all the
+  // nodes of the edges of a connection, and keeps the results of the other
+  // records for when they are read.
+  _evaluateBatchResolver(
+    field: ReaderRelayResolver,
+    fragment: ReaderFragmentSpread,
+    parentRecordID: DataID,
+  ): EvaluationResult<mixed> {
+    let results = this._batchResolverResults.get(field);
+    if (results == null) {
+      results = new Map();
+      this._batchResolverResults.set(field, results);
+    }
+    const previousResult = results.get(parentRecordID);
+    if (previousResult != null) {
+      return previousResult;
+    }
+
+    const dataIDs: Set<DataID> = new Set();
+    const pluralLinkedField =
+      this._pluralLinkedFields[this._pluralLinkedFields.length - 1];
+    if (pluralLinkedField != null) {
+      for (const pluralLinkedID of pluralLinkedField.ids) {
+        const siblingID = this._getLinkedRecordIDAtPath(
+          pluralLinkedID,
+          pluralLinkedField.path,
+        );
+        if (siblingID != null && !results.has(siblingID)) {
+          dataIDs.add(siblingID);
+        }
+      }
+    }
+    if (!dataIDs.has(parentRecordID)) {
+      dataIDs.clear();
+      dataIDs.add(parentRecordID);
+    }
+
+    let batch = this._evaluateBatch(field, fragment, Array.from(dataIDs));
+    if (batch.isMissingData && dataIDs.size > 1) {
+      // The root fragment of one of the records is missing data: evaluate the
+      // resolver for this record alone, the others are evaluated when read.
+      batch = this._evaluateBatch(field, fragment, [parentRecordID]);
+    }
+    if (!batch.isMissingData) {
+      for (const [dataID, result] of batch.results) {
+        results.set(dataID, result);
+      }
+    }
+    const result = batch.results.get(parentRecordID);
+    invariant(
+      result != null,
+      'RelayReader(): Expected the @batch resolver `%s` to be evaluated for record `%s`.',
+      field.name,
+      parentRecordID,
+    );
+    return result;
+  }
+
+  _getLinkedRecordIDAtPath(
+    dataID: ?DataID,
+    path: $ReadOnlyArray<string>,
+  ): ?DataID {
+    let linkedID = dataID;
+    for (const storageKey of path) {
+      const record = linkedID != null ? this._recordSource.get(linkedID) : null;
+      if (record == null) {
+        return null;
+      }
+      linkedID = RelayModernRecord.getLinkedRecordID(record, storageKey);
+    }
+    return linkedID;
+  }
+
+  _evaluateBatch(
+    field: ReaderRelayResolver,
+    fragment: ReaderFragmentSpread,
+    dataIDs: $ReadOnlyArray<DataID>,
+  ): {
+    isMissingData: boolean,
+    results: Map<DataID, EvaluationResult<mixed>>,
+  } {
+    const snapshots: Map<DataID, Snapshot> = new Map();
+    const getDataForResolverFragment = (
+      singularReaderSelector: SingularReaderSelector,
+    ) => {
+      let snapshot = snapshots.get(singularReaderSelector.dataID);
+      if (snapshot == null) {
+        snapshot = read(
+          this._recordSource,
+          singularReaderSelector,
+          this._resolverCache,
+        );
+        snapshots.set(singularReaderSelector.dataID, snapshot);
+      }
+      return {
+        data: snapshot.data,
+        isMissingData: snapshot.isMissingData,
+      };
+    };
+
+    const fragmentArgs = fragment.args
+      ? getArgumentValues(fragment.args, this._variables)
+      : {};
+    const keys = dataIDs.map(dataID => ({
+      __id: dataID,
+      __fragmentOwner: this._owner,
+      __fragments: {[fragment.name]: fragmentArgs},
+    }));
+    const [resolverResults, resolverError] = withResolverContext(
+      {getDataForResolverFragment},
+      () => getBatchResolverValue(field, this._variables, keys),
+    );
+
+    const results: Map<DataID, EvaluationResult<mixed>> = new Map();
+    dataIDs.forEach((dataID, index) => {
+      results.set(dataID, {
+        resolverResult: resolverResults?.[index],
+        snapshot: snapshots.get(dataID),
+        error: resolverError,
+      });
+    });
+    return {
+      isMissingData: resolverResults === undefined && resolverError == null,
+      results,
+    };
+  }
+
   // Reading a resolver field can uncover missing data, errors, suspense,
   // additional seen records and updated dataIDs. All of these facts must be
   // represented in the snapshot we return for this fragment.
@@ -807,6 +1082,9 @@ class RelayReader {
           }
         }
         this._clientEdgeTraversalPath.push(traversalPathSegment);
+        // The records read from the client edge are not batched with the
+        // records of the enclosing plural linked field.
+        this._pluralLinkedFields.push({ids: [], path: []});
 
         const prevData = data[applicationName];
         invariant(
@@ -823,6 +1101,7 @@ class RelayReader {
           // $FlowFixMe[incompatible-variance]
           prevData,
         );
+        this._pluralLinkedFields.pop();
         this._clientEdgeTraversalPath.pop();
         data[applicationName] = edgeValue;
         return edgeValue;
@@ -875,8 +1154,12 @@ class RelayReader {
       RelayModernRecord.getDataID(record),
       prevData,
     );
+    const pluralLinkedField =
+      this._pluralLinkedFields[this._pluralLinkedFields.length - 1];
+    pluralLinkedField?.path.push(storageKey);
     // $FlowFixMe[incompatible-variance]
     const value = this._traverse(field, linkedID, prevData);
+    pluralLinkedField?.path.pop();
     data[applicationName] = value;
     return value;
   }
@@ -958,6 +1241,7 @@ class RelayReader {
       prevData,
     );
     const linkedArray = prevData || [];
+    this._pluralLinkedFields.push({ids: linkedIDs, path: []});
     linkedIDs.forEach((linkedID, nextIndex) => {
       if (linkedID == null) {
         if (linkedID === undefined) {
@@ -980,6 +1264,7 @@ class RelayReader {
       // $FlowFixMe[incompatible-variance]
       linkedArray[nextIndex] = this._traverse(field, linkedID, prevItem);
     });
+    this._pluralLinkedFields.pop();
     data[applicationName] = linkedArray;
     return linkedArray;
   }
@@ -1022,6 +1307,18 @@ class RelayReader {
     data[MODULE_COMPONENT_KEY] = component;
   }
 
+  _readAliasedInlineFragment(
+    aliasedInlineFragment: ReaderAliasedInlineFragmentSpread,
+    record: Record,
+  ): ?SelectorData {
+    const fieldValue = this._readInlineFragment(
+      aliasedInlineFragment.fragment,
+      record,
+      {},
+    );
+    return fieldValue === false ? null : fieldValue;
+  }
+
   _createAliasedFragmentSpread(
     namedFragmentSpread: ReaderAliasedFragmentSpread,
     record: Record,
@@ -1286,6 +1583,44 @@ function getResolverValue(
   return [resolverResult, resolverError];
 }
 
+// Like `getResolverValue`, but for @batch resolvers, which are called once
+// with the fragment keys of all the records they are read on and return the
+// value of the field for each of them.
+function getBatchResolverValue(
+  field: ReaderRelayResolver,
+  variables: Variables,
+  fragmentKeys: $ReadOnlyArray<mixed>,
+): [?$ReadOnlyArray<mixed>, ?Error] {
+  const resolverFunction =
+    typeof field.resolverModule === 'function'
+      ? field.resolverModule
+      : field.resolverModule.default;
+
+  let resolverResults = null;
+  let resolverError = null;
+  try {
+    const args = field.args
+      ? getArgumentValues(field.args, variables)
+      : undefined;
+    const results = resolverFunction.apply(null, [fragmentKeys, args]);
+    invariant(
+      Array.isArray(results) && results.length === fragmentKeys.length,
+      'RelayReader(): Expected the @batch resolver `%s` to return an array ' +
+        'with a value for each of the %s records it was called with.',
+      field.name,
+      fragmentKeys.length,
+    );
+    resolverResults = results;
+  } catch (e) {
+    if (e === RESOLVER_FRAGMENT_MISSING_DATA_SENTINEL) {
+      resolverResults = undefined;
+    } else {
+      resolverError = e;
+    }
+  }
+  return [resolverResults, resolverError];
+}
+
 type ValidClientEdgeResolverResponse =
   | {
       kind: 'PluralConcrete',
@@ -119,6 +119,9 @@ type FieldLocation = {
 type ErrorFieldLocation = {
   ...FieldLocation,
   error: TRelayFieldError,
+  // Set for the errors of fields within a `@catch(to: NULL)` field, which are
+  // only logged.
+  handled?: boolean,
 };
 
 export type MissingRequiredFields = $ReadOnly<
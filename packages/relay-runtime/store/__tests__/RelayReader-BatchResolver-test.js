@@ -0,0 +1,186 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @flow strict-local
+ * @format
+ * @oncall relay
+ */
+
+'use strict';
+
+const {
+  name_length_batched: UserNameLengthBatchResolver,
+} = require('./resolvers/UserNameLengthBatchResolver');
+const {RelayFeatureFlags} = require('relay-runtime');
+const {graphql} = require('relay-runtime/query/GraphQLTag');
+const {
+  createOperationDescriptor,
+} = require('relay-runtime/store/RelayModernOperationDescriptor');
+const {read} = require('relay-runtime/store/RelayReader');
+const RelayRecordSource = require('relay-runtime/store/RelayRecordSource');
+const {RecordResolverCache} = require('relay-runtime/store/ResolverCache');
+const {
+  disallowConsoleErrors,
+  disallowWarnings,
+} = require('relay-test-utils-internal');
+
+disallowWarnings();
+disallowConsoleErrors();
+
+beforeEach(() => {
+  RelayFeatureFlags.ENABLE_RELAY_RESOLVERS = true;
+  UserNameLengthBatchResolver._relayResolverTestCalls = [];
+});
+
+afterEach(() => {
+  RelayFeatureFlags.ENABLE_RELAY_RESOLVERS = false;
+});
+
+describe('@batch Relay Resolver', () => {
+  it('is called once for the records of a plural linked field', () => {
+    const source = RelayRecordSource.create({
+      'client:root': {
+        __id: 'client:root',
+        __typename: '__Root',
+        me: {__ref: '1'},
+      },
+      '1': {
+        __id: '1',
+        id: '1',
+        __typename: 'User',
+        neighbors: {__refs: ['2', '3']},
+      },
+      '2': {__id: '2', id: '2', __typename: 'User', name: 'Alice'},
+      '3': {__id: '3', id: '3', __typename: 'User', name: 'Bob'},
+    });
+    const resolverCache = new RecordResolverCache(() => source);
+
+    const FooQuery = graphql`
+      query RelayReaderBatchResolverTest1Query {
+        me {
+          neighbors {
+            name_length_batched
+          }
+        }
+      }
+    `;
+    const operation = createOperationDescriptor(FooQuery, {});
+    const {data} = read(source, operation.fragment, resolverCache);
+
+    expect(data).toEqual({
+      me: {
+        neighbors: [{name_length_batched: 5}, {name_length_batched: 3}],
+      },
+    });
+    expect(UserNameLengthBatchResolver._relayResolverTestCalls).toEqual([
+      ['Alice', 'Bob'],
+    ]);
+  });
+
+  it('is called once for the nodes of a connection', () => {
+    const source = RelayRecordSource.create({
+      'client:root': {
+        __id: 'client:root',
+        __typename: '__Root',
+        me: {__ref: '1'},
+      },
+      '1': {
+        __id: '1',
+        id: '1',
+        __typename: 'User',
+        'friends(first:3)': {__ref: 'client:1:friends(first:3)'},
+      },
+      'client:1:friends(first:3)': {
+        __id: 'client:1:friends(first:3)',
+        __typename: 'FriendsConnection',
+        edges: {
+          __refs: [
+            'client:1:friends(first:3):edges:0',
+            'client:1:friends(first:3):edges:1',
+            'client:1:friends(first:3):edges:2',
+          ],
+        },
+      },
+      'client:1:friends(first:3):edges:0': {
+        __id: 'client:1:friends(first:3):edges:0',
+        __typename: 'FriendsEdge',
+        node: {__ref: '2'},
+      },
+      'client:1:friends(first:3):edges:1': {
+        __id: 'client:1:friends(first:3):edges:1',
+        __typename: 'FriendsEdge',
+        node: {__ref: '3'},
+      },
+      'client:1:friends(first:3):edges:2': {
+        __id: 'client:1:friends(first:3):edges:2',
+        __typename: 'FriendsEdge',
+        node: null,
+      },
+      '2': {__id: '2', id: '2', __typename: 'User', name: 'Alice'},
+      '3': {__id: '3', id: '3', __typename: 'User', name: 'Bob'},
+    });
+    const resolverCache = new RecordResolverCache(() => source);
+
+    const FooQuery = graphql`
+      query RelayReaderBatchResolverTest2Query {
+        me {
+          friends(first: 3) {
+            edges {
+              node {
+                name_length_batched
+              }
+            }
+          }
+        }
+      }
+    `;
+    const operation = createOperationDescriptor(FooQuery, {});
+    const {data} = read(source, operation.fragment, resolverCache);
+
+    expect(data).toEqual({
+      me: {
+        friends: {
+          edges: [
+            {node: {name_length_batched: 5}},
+            {node: {name_length_batched: 3}},
+            {node: null},
+          ],
+        },
+      },
+    });
+    // The nodes are batched, not the edges they are read from
+    expect(UserNameLengthBatchResolver._relayResolverTestCalls).toEqual([
+      ['Alice', 'Bob'],
+    ]);
+  });
+
+  it('is called for a singular linked field outside of a plural one', () => {
+    const source = RelayRecordSource.create({
+      'client:root': {
+        __id: 'client:root',
+        __typename: '__Root',
+        me: {__ref: '1'},
+      },
+      '1': {__id: '1', id: '1', __typename: 'User', name: 'Alice'},
+    });
+    const resolverCache = new RecordResolverCache(() => source);
+
+    const FooQuery = graphql`
+      query RelayReaderBatchResolverTest3Query {
+        me {
+          name_length_batched
+        }
+      }
+    `;
+    const operation = createOperationDescriptor(FooQuery, {});
+    const {data} = read(source, operation.fragment, resolverCache);
+
+    expect(data).toEqual({me: {name_length_batched: 5}});
+    expect(UserNameLengthBatchResolver._relayResolverTestCalls).toEqual([
+      ['Alice'],
+    ]);
+  });
+});
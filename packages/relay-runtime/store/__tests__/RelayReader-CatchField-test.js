@@ -0,0 +1,276 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @format
+ * @oncall relay
+ */
+
+'use strict';
+
+const {graphql} = require('../../query/GraphQLTag');
+const RelayFeatureFlags = require('../../util/RelayFeatureFlags');
+const {
+  createOperationDescriptor,
+} = require('../RelayModernOperationDescriptor');
+const {read} = require('../RelayReader');
+const RelayRecordSource = require('../RelayRecordSource');
+
+describe('RelayReader @catch', () => {
+  const wasFieldErrorHandlingEnabled =
+    RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING;
+
+  beforeAll(() => {
+    RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING = true;
+  });
+
+  afterAll(() => {
+    RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING =
+      wasFieldErrorHandlingEnabled;
+  });
+
+  const source = RelayRecordSource.create({
+    'client:root': {
+      __id: 'client:root',
+      __typename: '__Root',
+      me: {__ref: '1'},
+    },
+    '1': {
+      __id: '1',
+      id: '1',
+      __typename: 'User',
+      lastName: null,
+      name: 'Alice',
+      profilePicture: {__ref: 'client:1:profilePicture'},
+      __errors: {
+        lastName: [
+          {
+            message: 'There was an error!',
+            path: ['me', 'lastName'],
+          },
+        ],
+      },
+    },
+    'client:1:profilePicture': {
+      __id: 'client:1:profilePicture',
+      __typename: 'Image',
+      uri: null,
+      width: 100,
+    },
+  });
+
+  describe('to: RESULT', () => {
+    it('returns the value of a field without errors', () => {
+      const FooQuery = graphql`
+        query RelayReaderCatchFieldTest1Query {
+          me {
+            name @catch
+          }
+        }
+      `;
+      const operation = createOperationDescriptor(FooQuery, {});
+      const {data, errorResponseFields} = read(source, operation.fragment);
+      expect(data).toEqual({me: {name: {ok: true, value: 'Alice'}}});
+      expect(errorResponseFields).toBe(null);
+    });
+
+    it('returns the errors of a field with errors', () => {
+      const FooQuery = graphql`
+        query RelayReaderCatchFieldTest2Query {
+          me {
+            lastName @catch
+          }
+        }
+      `;
+      const operation = createOperationDescriptor(FooQuery, {});
+      const {data, errorResponseFields} = read(source, operation.fragment);
+      expect(data).toEqual({
+        me: {
+          lastName: {
+            ok: false,
+            errors: [
+              {message: 'There was an error!', path: ['me', 'lastName']},
+            ],
+          },
+        },
+      });
+      // The caught errors are not reported with the snapshot.
+      expect(errorResponseFields).toBe(null);
+    });
+
+    it('returns the errors of a field when field error handling is disabled', () => {
+      RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING = false;
+      try {
+        const FooQuery = graphql`
+          query RelayReaderCatchFieldTest3Query {
+            me {
+              lastName @catch
+            }
+          }
+        `;
+        const operation = createOperationDescriptor(FooQuery, {});
+        const {data} = read(source, operation.fragment);
+        expect(data).toEqual({
+          me: {
+            lastName: {
+              ok: false,
+              errors: [
+                {message: 'There was an error!', path: ['me', 'lastName']},
+              ],
+            },
+          },
+        });
+      } finally {
+        RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING = true;
+      }
+    });
+
+    it('catches a missing @required(action: THROW) field within the field', () => {
+      const FooQuery = graphql`
+        query RelayReaderCatchFieldTest4Query {
+          me {
+            profilePicture @catch {
+              width
+              uri @required(action: THROW)
+            }
+          }
+        }
+      `;
+      const operation = createOperationDescriptor(FooQuery, {});
+      const {data, missingRequiredFields} = read(source, operation.fragment);
+      expect(data).toEqual({
+        me: {
+          profilePicture: {
+            ok: false,
+            errors: [
+              {
+                message:
+                  "Relay: Missing @required value at path 'me.profilePicture.uri' in 'RelayReaderCatchFieldTest4Query'.",
+              },
+            ],
+          },
+        },
+      });
+      expect(missingRequiredFields).toBe(null);
+    });
+
+    it('reports a missing @required(action: LOG) field within the field', () => {
+      const FooQuery = graphql`
+        query RelayReaderCatchFieldTest5Query {
+          me {
+            profilePicture @catch {
+              width
+              uri @required(action: LOG)
+            }
+          }
+        }
+      `;
+      const operation = createOperationDescriptor(FooQuery, {});
+      const {data, missingRequiredFields} = read(source, operation.fragment);
+      expect(data).toEqual({me: {profilePicture: {ok: true, value: null}}});
+      expect(missingRequiredFields).toEqual({
+        action: 'LOG',
+        fields: [
+          {
+            path: 'me.profilePicture.uri',
+            owner: 'RelayReaderCatchFieldTest5Query',
+          },
+        ],
+      });
+    });
+  });
+
+  describe('to: NULL', () => {
+    it('returns the value of a field without errors', () => {
+      const FooQuery = graphql`
+        query RelayReaderCatchFieldTest6Query {
+          me {
+            profilePicture @catch(to: NULL) {
+              width
+            }
+          }
+        }
+      `;
+      const operation = createOperationDescriptor(FooQuery, {});
+      const {data, errorResponseFields} = read(source, operation.fragment);
+      expect(data).toEqual({me: {profilePicture: {width: 100}}});
+      expect(errorResponseFields).toBe(null);
+    });
+
+    it('returns null for a field with errors and reports them as handled', () => {
+      const FooQuery = graphql`
+        query RelayReaderCatchFieldTest7Query {
+          me {
+            lastName @catch(to: NULL)
+          }
+        }
+      `;
+      const operation = createOperationDescriptor(FooQuery, {});
+      const {data, errorResponseFields} = read(source, operation.fragment);
+      expect(data).toEqual({me: {lastName: null}});
+      expect(errorResponseFields).toEqual([
+        {
+          owner: 'RelayReaderCatchFieldTest7Query',
+          path: 'me.lastName',
+          error: {
+            message: 'There was an error!',
+            path: ['me', 'lastName'],
+          },
+          handled: true,
+        },
+      ]);
+    });
+
+    it('logs a missing @required(action: THROW) field within the field', () => {
+      const FooQuery = graphql`
+        query RelayReaderCatchFieldTest8Query {
+          me {
+            profilePicture @catch(to: NULL) {
+              width
+              uri @required(action: THROW)
+            }
+          }
+        }
+      `;
+      const operation = createOperationDescriptor(FooQuery, {});
+      const {data, missingRequiredFields} = read(source, operation.fragment);
+      expect(data).toEqual({me: {profilePicture: null}});
+      expect(missingRequiredFields).toEqual({
+        action: 'LOG',
+        fields: [
+          {
+            path: 'me.profilePicture.uri',
+            owner: 'RelayReaderCatchFieldTest8Query',
+          },
+        ],
+      });
+    });
+
+    it('does not bubble a missing @required field within the field to its parent', () => {
+      const FooQuery = graphql`
+        query RelayReaderCatchFieldTest9Query {
+          me @required(action: LOG) {
+            name
+            profilePicture @catch(to: NULL) {
+              uri @required(action: LOG)
+            }
+          }
+        }
+      `;
+      const operation = createOperationDescriptor(FooQuery, {});
+      const {data, missingRequiredFields} = read(source, operation.fragment);
+      expect(data).toEqual({me: {name: 'Alice', profilePicture: null}});
+      expect(missingRequiredFields).toEqual({
+        action: 'LOG',
+        fields: [
+          {
+            path: 'me.profilePicture.uri',
+            owner: 'RelayReaderCatchFieldTest9Query',
+          },
+        ],
+      });
+    });
+  });
+});
@@ -0,0 +1,168 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @oncall relay
+ *
+ * @generated SignedSource<<937ee3a6c96bcb8ac7b829b221f54e7a>>
+ * @flow
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+/*::
+import type { ConcreteRequest, Query } from 'relay-runtime';
+import type { UserNameLengthBatchResolver$key } from "./../resolvers/__generated__/UserNameLengthBatchResolver.graphql";
+import {name_length_batched as userNameLengthBatchedResolverType} from "../resolvers/UserNameLengthBatchResolver.js";
+// Type assertion validating that `userNameLengthBatchedResolverType` resolver is correctly implemented.
+// A type error here indicates that the type signature of the resolver module is incorrect.
+(userNameLengthBatchedResolverType: (
+  rootKeys: $ReadOnlyArray<UserNameLengthBatchResolver$key>,
+) => $ReadOnlyArray<?mixed>);
+export type RelayReaderBatchResolverTest1Query$variables = {||};
+export type RelayReaderBatchResolverTest1Query$data = {|
+  +me: ?{|
+    +neighbors: ?$ReadOnlyArray<{|
+      +name_length_batched: ?ReturnType<typeof userNameLengthBatchedResolverType>[number],
+    |}>,
+  |},
+|};
+export type RelayReaderBatchResolverTest1Query = {|
+  response: RelayReaderBatchResolverTest1Query$data,
+  variables: RelayReaderBatchResolverTest1Query$variables,
+|};
+*/
+
+var node/*: ConcreteRequest*/ = (function(){
+var v0 = {
+  "alias": null,
+  "args": null,
+  "kind": "ScalarField",
+  "name": "id",
+  "storageKey": null
+};
+return {
+  "fragment": {
+    "argumentDefinitions": [],
+    "kind": "Fragment",
+    "metadata": null,
+    "name": "RelayReaderBatchResolverTest1Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          {
+            "alias": null,
+            "args": null,
+            "concreteType": "User",
+            "kind": "LinkedField",
+            "name": "neighbors",
+            "plural": true,
+            "selections": [
+              {
+                "alias": null,
+                "args": null,
+                "fragment": {
+                  "args": null,
+                  "kind": "FragmentSpread",
+                  "name": "UserNameLengthBatchResolver"
+                },
+                "kind": "RelayResolver",
+                "name": "name_length_batched",
+                "resolverModule": require('./../resolvers/UserNameLengthBatchResolver').name_length_batched,
+                "path": "me.neighbors.name_length_batched",
+                "batch": true
+              }
+            ],
+            "storageKey": null
+          }
+        ],
+        "storageKey": null
+      }
+    ],
+    "type": "Query",
+    "abstractKey": null
+  },
+  "kind": "Request",
+  "operation": {
+    "argumentDefinitions": [],
+    "kind": "Operation",
+    "name": "RelayReaderBatchResolverTest1Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          {
+            "alias": null,
+            "args": null,
+            "concreteType": "User",
+            "kind": "LinkedField",
+            "name": "neighbors",
+            "plural": true,
+            "selections": [
+              {
+                "name": "name_length_batched",
+                "args": null,
+                "fragment": {
+                  "kind": "InlineFragment",
+                  "selections": [
+                    {
+                      "alias": null,
+                      "args": null,
+                      "kind": "ScalarField",
+                      "name": "name",
+                      "storageKey": null
+                    }
+                  ],
+                  "type": "User",
+                  "abstractKey": null
+                },
+                "kind": "RelayResolver",
+                "storageKey": null,
+                "isOutputType": false
+              },
+              (v0/*: any*/)
+            ],
+            "storageKey": null
+          },
+          (v0/*: any*/)
+        ],
+        "storageKey": null
+      }
+    ]
+  },
+  "params": {
+    "cacheID": "e96f5add51187349e41bdd41f1061d71",
+    "id": null,
+    "metadata": {},
+    "name": "RelayReaderBatchResolverTest1Query",
+    "operationKind": "query",
+    "text": "query RelayReaderBatchResolverTest1Query {\n  me {\n    neighbors {\n      ...UserNameLengthBatchResolver\n      id\n    }\n    id\n  }\n}\n\nfragment UserNameLengthBatchResolver on User {\n  name\n}\n"
+  }
+};
+})();
+
+if (__DEV__) {
+  (node/*: any*/).hash = "f686938a89ba1f65ba4df33d6a6046ea";
+}
+
+module.exports = ((node/*: any*/)/*: Query<
+  RelayReaderBatchResolverTest1Query$variables,
+  RelayReaderBatchResolverTest1Query$data,
+>*/);
@@ -0,0 +1,223 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @oncall relay
+ *
+ * @generated SignedSource<<1a4601798ba482bd0202ffd637c3abcf>>
+ * @flow
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+/*::
+import type { ConcreteRequest, Query } from 'relay-runtime';
+import type { UserNameLengthBatchResolver$key } from "./../resolvers/__generated__/UserNameLengthBatchResolver.graphql";
+import {name_length_batched as userNameLengthBatchedResolverType} from "../resolvers/UserNameLengthBatchResolver.js";
+// Type assertion validating that `userNameLengthBatchedResolverType` resolver is correctly implemented.
+// A type error here indicates that the type signature of the resolver module is incorrect.
+(userNameLengthBatchedResolverType: (
+  rootKeys: $ReadOnlyArray<UserNameLengthBatchResolver$key>,
+) => $ReadOnlyArray<?mixed>);
+export type RelayReaderBatchResolverTest2Query$variables = {||};
+export type RelayReaderBatchResolverTest2Query$data = {|
+  +me: ?{|
+    +friends: ?{|
+      +edges: ?$ReadOnlyArray<?{|
+        +node: ?{|
+          +name_length_batched: ?ReturnType<typeof userNameLengthBatchedResolverType>[number],
+        |},
+      |}>,
+    |},
+  |},
+|};
+export type RelayReaderBatchResolverTest2Query = {|
+  response: RelayReaderBatchResolverTest2Query$data,
+  variables: RelayReaderBatchResolverTest2Query$variables,
+|};
+*/
+
+var node/*: ConcreteRequest*/ = (function(){
+var v0 = [
+  {
+    "kind": "Literal",
+    "name": "first",
+    "value": 3
+  }
+],
+v1 = {
+  "alias": null,
+  "args": null,
+  "kind": "ScalarField",
+  "name": "id",
+  "storageKey": null
+};
+return {
+  "fragment": {
+    "argumentDefinitions": [],
+    "kind": "Fragment",
+    "metadata": null,
+    "name": "RelayReaderBatchResolverTest2Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          {
+            "alias": null,
+            "args": (v0/*: any*/),
+            "concreteType": "FriendsConnection",
+            "kind": "LinkedField",
+            "name": "friends",
+            "plural": false,
+            "selections": [
+              {
+                "alias": null,
+                "args": null,
+                "concreteType": "FriendsEdge",
+                "kind": "LinkedField",
+                "name": "edges",
+                "plural": true,
+                "selections": [
+                  {
+                    "alias": null,
+                    "args": null,
+                    "concreteType": "User",
+                    "kind": "LinkedField",
+                    "name": "node",
+                    "plural": false,
+                    "selections": [
+                      {
+                        "alias": null,
+                        "args": null,
+                        "fragment": {
+                          "args": null,
+                          "kind": "FragmentSpread",
+                          "name": "UserNameLengthBatchResolver"
+                        },
+                        "kind": "RelayResolver",
+                        "name": "name_length_batched",
+                        "resolverModule": require('./../resolvers/UserNameLengthBatchResolver').name_length_batched,
+                        "path": "me.friends.edges.node.name_length_batched",
+                        "batch": true
+                      }
+                    ],
+                    "storageKey": null
+                  }
+                ],
+                "storageKey": null
+              }
+            ],
+            "storageKey": "friends(first:3)"
+          }
+        ],
+        "storageKey": null
+      }
+    ],
+    "type": "Query",
+    "abstractKey": null
+  },
+  "kind": "Request",
+  "operation": {
+    "argumentDefinitions": [],
+    "kind": "Operation",
+    "name": "RelayReaderBatchResolverTest2Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          {
+            "alias": null,
+            "args": (v0/*: any*/),
+            "concreteType": "FriendsConnection",
+            "kind": "LinkedField",
+            "name": "friends",
+            "plural": false,
+            "selections": [
+              {
+                "alias": null,
+                "args": null,
+                "concreteType": "FriendsEdge",
+                "kind": "LinkedField",
+                "name": "edges",
+                "plural": true,
+                "selections": [
+                  {
+                    "alias": null,
+                    "args": null,
+                    "concreteType": "User",
+                    "kind": "LinkedField",
+                    "name": "node",
+                    "plural": false,
+                    "selections": [
+                      {
+                        "name": "name_length_batched",
+                        "args": null,
+                        "fragment": {
+                          "kind": "InlineFragment",
+                          "selections": [
+                            {
+                              "alias": null,
+                              "args": null,
+                              "kind": "ScalarField",
+                              "name": "name",
+                              "storageKey": null
+                            }
+                          ],
+                          "type": "User",
+                          "abstractKey": null
+                        },
+                        "kind": "RelayResolver",
+                        "storageKey": null,
+                        "isOutputType": false
+                      },
+                      (v1/*: any*/)
+                    ],
+                    "storageKey": null
+                  }
+                ],
+                "storageKey": null
+              }
+            ],
+            "storageKey": "friends(first:3)"
+          },
+          (v1/*: any*/)
+        ],
+        "storageKey": null
+      }
+    ]
+  },
+  "params": {
+    "cacheID": "1be2c10969bad9eade5032009b1a631d",
+    "id": null,
+    "metadata": {},
+    "name": "RelayReaderBatchResolverTest2Query",
+    "operationKind": "query",
+    "text": "query RelayReaderBatchResolverTest2Query {\n  me {\n    friends(first: 3) {\n      edges {\n        node {\n          ...UserNameLengthBatchResolver\n          id\n        }\n      }\n    }\n    id\n  }\n}\n\nfragment UserNameLengthBatchResolver on User {\n  name\n}\n"
+  }
+};
+})();
+
+if (__DEV__) {
+  (node/*: any*/).hash = "9ca708c4a89e26b9055f8f9bb5c9f0fe";
+}
+
+module.exports = ((node/*: any*/)/*: Query<
+  RelayReaderBatchResolverTest2Query$variables,
+  RelayReaderBatchResolverTest2Query$data,
+>*/);
@@ -0,0 +1,140 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @oncall relay
+ *
+ * @generated SignedSource<<2e963537b2c83b13aa410abdee0cf27e>>
+ * @flow
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+/*::
+import type { ConcreteRequest, Query } from 'relay-runtime';
+import type { UserNameLengthBatchResolver$key } from "./../resolvers/__generated__/UserNameLengthBatchResolver.graphql";
+import {name_length_batched as userNameLengthBatchedResolverType} from "../resolvers/UserNameLengthBatchResolver.js";
+// Type assertion validating that `userNameLengthBatchedResolverType` resolver is correctly implemented.
+// A type error here indicates that the type signature of the resolver module is incorrect.
+(userNameLengthBatchedResolverType: (
+  rootKeys: $ReadOnlyArray<UserNameLengthBatchResolver$key>,
+) => $ReadOnlyArray<?mixed>);
+export type RelayReaderBatchResolverTest3Query$variables = {||};
+export type RelayReaderBatchResolverTest3Query$data = {|
+  +me: ?{|
+    +name_length_batched: ?ReturnType<typeof userNameLengthBatchedResolverType>[number],
+  |},
+|};
+export type RelayReaderBatchResolverTest3Query = {|
+  response: RelayReaderBatchResolverTest3Query$data,
+  variables: RelayReaderBatchResolverTest3Query$variables,
+|};
+*/
+
+var node/*: ConcreteRequest*/ = {
+  "fragment": {
+    "argumentDefinitions": [],
+    "kind": "Fragment",
+    "metadata": null,
+    "name": "RelayReaderBatchResolverTest3Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          {
+            "alias": null,
+            "args": null,
+            "fragment": {
+              "args": null,
+              "kind": "FragmentSpread",
+              "name": "UserNameLengthBatchResolver"
+            },
+            "kind": "RelayResolver",
+            "name": "name_length_batched",
+            "resolverModule": require('./../resolvers/UserNameLengthBatchResolver').name_length_batched,
+            "path": "me.name_length_batched",
+            "batch": true
+          }
+        ],
+        "storageKey": null
+      }
+    ],
+    "type": "Query",
+    "abstractKey": null
+  },
+  "kind": "Request",
+  "operation": {
+    "argumentDefinitions": [],
+    "kind": "Operation",
+    "name": "RelayReaderBatchResolverTest3Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          {
+            "name": "name_length_batched",
+            "args": null,
+            "fragment": {
+              "kind": "InlineFragment",
+              "selections": [
+                {
+                  "alias": null,
+                  "args": null,
+                  "kind": "ScalarField",
+                  "name": "name",
+                  "storageKey": null
+                }
+              ],
+              "type": "User",
+              "abstractKey": null
+            },
+            "kind": "RelayResolver",
+            "storageKey": null,
+            "isOutputType": false
+          },
+          {
+            "alias": null,
+            "args": null,
+            "kind": "ScalarField",
+            "name": "id",
+            "storageKey": null
+          }
+        ],
+        "storageKey": null
+      }
+    ]
+  },
+  "params": {
+    "cacheID": "56f217fb3afa4544a04a819c6e6b0ef8",
+    "id": null,
+    "metadata": {},
+    "name": "RelayReaderBatchResolverTest3Query",
+    "operationKind": "query",
+    "text": "query RelayReaderBatchResolverTest3Query {\n  me {\n    ...UserNameLengthBatchResolver\n    id\n  }\n}\n\nfragment UserNameLengthBatchResolver on User {\n  name\n}\n"
+  }
+};
+
+if (__DEV__) {
+  (node/*: any*/).hash = "29d793db6ea8432d94f28e1d5ccc8a74";
+}
+
+module.exports = ((node/*: any*/)/*: Query<
+  RelayReaderBatchResolverTest3Query$variables,
+  RelayReaderBatchResolverTest3Query$data,
+>*/);
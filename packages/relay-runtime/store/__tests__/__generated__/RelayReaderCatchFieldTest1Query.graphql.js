@@ -0,0 +1,120 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @oncall relay
+ *
+ * @generated SignedSource<<62985c9b9c0392695d740b44369fc68d>>
+ * @flow
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+/*::
+import type { ConcreteRequest, Query } from 'relay-runtime';
+export type RelayReaderCatchFieldTest1Query$variables = {||};
+export type RelayReaderCatchFieldTest1Query$data = {|
+  +me: ?{|
+    +name: {|
+      +errors: $ReadOnlyArray<mixed>,
+      +ok: false,
+    |} | {|
+      +ok: true,
+      +value: ?string,
+    |},
+  |},
+|};
+export type RelayReaderCatchFieldTest1Query = {|
+  response: RelayReaderCatchFieldTest1Query$data,
+  variables: RelayReaderCatchFieldTest1Query$variables,
+|};
+*/
+
+var node/*: ConcreteRequest*/ = (function(){
+var v0 = {
+  "alias": null,
+  "args": null,
+  "kind": "ScalarField",
+  "name": "name",
+  "storageKey": null
+};
+return {
+  "fragment": {
+    "argumentDefinitions": [],
+    "kind": "Fragment",
+    "metadata": null,
+    "name": "RelayReaderCatchFieldTest1Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          {
+            "kind": "CatchField",
+            "field": (v0/*: any*/),
+            "to": "RESULT",
+            "path": "me.name"
+          }
+        ],
+        "storageKey": null
+      }
+    ],
+    "type": "Query",
+    "abstractKey": null
+  },
+  "kind": "Request",
+  "operation": {
+    "argumentDefinitions": [],
+    "kind": "Operation",
+    "name": "RelayReaderCatchFieldTest1Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          (v0/*: any*/),
+          {
+            "alias": null,
+            "args": null,
+            "kind": "ScalarField",
+            "name": "id",
+            "storageKey": null
+          }
+        ],
+        "storageKey": null
+      }
+    ]
+  },
+  "params": {
+    "cacheID": "3850e896c1d7101de20cdd1a32f2df46",
+    "id": null,
+    "metadata": {},
+    "name": "RelayReaderCatchFieldTest1Query",
+    "operationKind": "query",
+    "text": "query RelayReaderCatchFieldTest1Query {\n  me {\n    name\n    id\n  }\n}\n"
+  }
+};
+})();
+
+if (__DEV__) {
+  (node/*: any*/).hash = "c68b59b66f1e69bd58a984c67728f7bc";
+}
+
+module.exports = ((node/*: any*/)/*: Query<
+  RelayReaderCatchFieldTest1Query$variables,
+  RelayReaderCatchFieldTest1Query$data,
+>*/);
@@ -0,0 +1,120 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @oncall relay
+ *
+ * @generated SignedSource<<8a96ad33ac742e71208f14de11a8da70>>
+ * @flow
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+/*::
+import type { ConcreteRequest, Query } from 'relay-runtime';
+export type RelayReaderCatchFieldTest2Query$variables = {||};
+export type RelayReaderCatchFieldTest2Query$data = {|
+  +me: ?{|
+    +lastName: {|
+      +errors: $ReadOnlyArray<mixed>,
+      +ok: false,
+    |} | {|
+      +ok: true,
+      +value: ?string,
+    |},
+  |},
+|};
+export type RelayReaderCatchFieldTest2Query = {|
+  response: RelayReaderCatchFieldTest2Query$data,
+  variables: RelayReaderCatchFieldTest2Query$variables,
+|};
+*/
+
+var node/*: ConcreteRequest*/ = (function(){
+var v0 = {
+  "alias": null,
+  "args": null,
+  "kind": "ScalarField",
+  "name": "lastName",
+  "storageKey": null
+};
+return {
+  "fragment": {
+    "argumentDefinitions": [],
+    "kind": "Fragment",
+    "metadata": null,
+    "name": "RelayReaderCatchFieldTest2Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          {
+            "kind": "CatchField",
+            "field": (v0/*: any*/),
+            "to": "RESULT",
+            "path": "me.lastName"
+          }
+        ],
+        "storageKey": null
+      }
+    ],
+    "type": "Query",
+    "abstractKey": null
+  },
+  "kind": "Request",
+  "operation": {
+    "argumentDefinitions": [],
+    "kind": "Operation",
+    "name": "RelayReaderCatchFieldTest2Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          (v0/*: any*/),
+          {
+            "alias": null,
+            "args": null,
+            "kind": "ScalarField",
+            "name": "id",
+            "storageKey": null
+          }
+        ],
+        "storageKey": null
+      }
+    ]
+  },
+  "params": {
+    "cacheID": "7e9513bbd59570266d31923f447b68d9",
+    "id": null,
+    "metadata": {},
+    "name": "RelayReaderCatchFieldTest2Query",
+    "operationKind": "query",
+    "text": "query RelayReaderCatchFieldTest2Query {\n  me {\n    lastName\n    id\n  }\n}\n"
+  }
+};
+})();
+
+if (__DEV__) {
+  (node/*: any*/).hash = "eade4a6055373c18cd9f4a587088e539";
+}
+
+module.exports = ((node/*: any*/)/*: Query<
+  RelayReaderCatchFieldTest2Query$variables,
+  RelayReaderCatchFieldTest2Query$data,
+>*/);
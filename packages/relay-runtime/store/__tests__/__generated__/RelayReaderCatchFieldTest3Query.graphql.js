@@ -0,0 +1,120 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @oncall relay
+ *
+ * @generated SignedSource<<a69dc97a6a1a3f064ebd7f855b2d730b>>
+ * @flow
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+/*::
+import type { ConcreteRequest, Query } from 'relay-runtime';
+export type RelayReaderCatchFieldTest3Query$variables = {||};
+export type RelayReaderCatchFieldTest3Query$data = {|
+  +me: ?{|
+    +lastName: {|
+      +errors: $ReadOnlyArray<mixed>,
+      +ok: false,
+    |} | {|
+      +ok: true,
+      +value: ?string,
+    |},
+  |},
+|};
+export type RelayReaderCatchFieldTest3Query = {|
+  response: RelayReaderCatchFieldTest3Query$data,
+  variables: RelayReaderCatchFieldTest3Query$variables,
+|};
+*/
+
+var node/*: ConcreteRequest*/ = (function(){
+var v0 = {
+  "alias": null,
+  "args": null,
+  "kind": "ScalarField",
+  "name": "lastName",
+  "storageKey": null
+};
+return {
+  "fragment": {
+    "argumentDefinitions": [],
+    "kind": "Fragment",
+    "metadata": null,
+    "name": "RelayReaderCatchFieldTest3Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          {
+            "kind": "CatchField",
+            "field": (v0/*: any*/),
+            "to": "RESULT",
+            "path": "me.lastName"
+          }
+        ],
+        "storageKey": null
+      }
+    ],
+    "type": "Query",
+    "abstractKey": null
+  },
+  "kind": "Request",
+  "operation": {
+    "argumentDefinitions": [],
+    "kind": "Operation",
+    "name": "RelayReaderCatchFieldTest3Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          (v0/*: any*/),
+          {
+            "alias": null,
+            "args": null,
+            "kind": "ScalarField",
+            "name": "id",
+            "storageKey": null
+          }
+        ],
+        "storageKey": null
+      }
+    ]
+  },
+  "params": {
+    "cacheID": "45c3577ad6a0b2986e8a052f08679d95",
+    "id": null,
+    "metadata": {},
+    "name": "RelayReaderCatchFieldTest3Query",
+    "operationKind": "query",
+    "text": "query RelayReaderCatchFieldTest3Query {\n  me {\n    lastName\n    id\n  }\n}\n"
+  }
+};
+})();
+
+if (__DEV__) {
+  (node/*: any*/).hash = "27f83a884993d184c16db61dd9547f61";
+}
+
+module.exports = ((node/*: any*/)/*: Query<
+  RelayReaderCatchFieldTest3Query$variables,
+  RelayReaderCatchFieldTest3Query$data,
+>*/);
@@ -0,0 +1,159 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @oncall relay
+ *
+ * @generated SignedSource<<f0c03585f0b693c0cadcdc65c48a363f>>
+ * @flow
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+/*::
+import type { ConcreteRequest, Query } from 'relay-runtime';
+export type RelayReaderCatchFieldTest4Query$variables = {||};
+export type RelayReaderCatchFieldTest4Query$data = {|
+  +me: ?{|
+    +profilePicture: {|
+      +errors: $ReadOnlyArray<mixed>,
+      +ok: false,
+    |} | {|
+      +ok: true,
+      +value: ?{|
+        +uri: string,
+        +width: ?number,
+      |},
+    |},
+  |},
+|};
+export type RelayReaderCatchFieldTest4Query = {|
+  response: RelayReaderCatchFieldTest4Query$data,
+  variables: RelayReaderCatchFieldTest4Query$variables,
+|};
+*/
+
+var node/*: ConcreteRequest*/ = (function(){
+var v0 = {
+  "alias": null,
+  "args": null,
+  "kind": "ScalarField",
+  "name": "width",
+  "storageKey": null
+},
+v1 = {
+  "alias": null,
+  "args": null,
+  "kind": "ScalarField",
+  "name": "uri",
+  "storageKey": null
+};
+return {
+  "fragment": {
+    "argumentDefinitions": [],
+    "kind": "Fragment",
+    "metadata": null,
+    "name": "RelayReaderCatchFieldTest4Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          {
+            "kind": "CatchField",
+            "field": {
+              "alias": null,
+              "args": null,
+              "concreteType": "Image",
+              "kind": "LinkedField",
+              "name": "profilePicture",
+              "plural": false,
+              "selections": [
+                (v0/*: any*/),
+                {
+                  "kind": "RequiredField",
+                  "field": (v1/*: any*/),
+                  "action": "THROW",
+                  "path": "me.profilePicture.uri"
+                }
+              ],
+              "storageKey": null
+            },
+            "to": "RESULT",
+            "path": "me.profilePicture"
+          }
+        ],
+        "storageKey": null
+      }
+    ],
+    "type": "Query",
+    "abstractKey": null
+  },
+  "kind": "Request",
+  "operation": {
+    "argumentDefinitions": [],
+    "kind": "Operation",
+    "name": "RelayReaderCatchFieldTest4Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          {
+            "alias": null,
+            "args": null,
+            "concreteType": "Image",
+            "kind": "LinkedField",
+            "name": "profilePicture",
+            "plural": false,
+            "selections": [
+              (v0/*: any*/),
+              (v1/*: any*/)
+            ],
+            "storageKey": null
+          },
+          {
+            "alias": null,
+            "args": null,
+            "kind": "ScalarField",
+            "name": "id",
+            "storageKey": null
+          }
+        ],
+        "storageKey": null
+      }
+    ]
+  },
+  "params": {
+    "cacheID": "afa217ae0d5a0efacc75d1ceb29bc42b",
+    "id": null,
+    "metadata": {},
+    "name": "RelayReaderCatchFieldTest4Query",
+    "operationKind": "query",
+    "text": "query RelayReaderCatchFieldTest4Query {\n  me {\n    profilePicture {\n      width\n      uri\n    }\n    id\n  }\n}\n"
+  }
+};
+})();
+
+if (__DEV__) {
+  (node/*: any*/).hash = "36ad19d85f3b9ab73bf2e6ad8d61aeaf";
+}
+
+module.exports = ((node/*: any*/)/*: Query<
+  RelayReaderCatchFieldTest4Query$variables,
+  RelayReaderCatchFieldTest4Query$data,
+>*/);
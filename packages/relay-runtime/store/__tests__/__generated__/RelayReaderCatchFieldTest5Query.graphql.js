@@ -0,0 +1,159 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @oncall relay
+ *
+ * @generated SignedSource<<4e35675de235a43d655b8842445128b7>>
+ * @flow
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+/*::
+import type { ConcreteRequest, Query } from 'relay-runtime';
+export type RelayReaderCatchFieldTest5Query$variables = {||};
+export type RelayReaderCatchFieldTest5Query$data = {|
+  +me: ?{|
+    +profilePicture: {|
+      +errors: $ReadOnlyArray<mixed>,
+      +ok: false,
+    |} | {|
+      +ok: true,
+      +value: ?{|
+        +uri: string,
+        +width: ?number,
+      |},
+    |},
+  |},
+|};
+export type RelayReaderCatchFieldTest5Query = {|
+  response: RelayReaderCatchFieldTest5Query$data,
+  variables: RelayReaderCatchFieldTest5Query$variables,
+|};
+*/
+
+var node/*: ConcreteRequest*/ = (function(){
+var v0 = {
+  "alias": null,
+  "args": null,
+  "kind": "ScalarField",
+  "name": "width",
+  "storageKey": null
+},
+v1 = {
+  "alias": null,
+  "args": null,
+  "kind": "ScalarField",
+  "name": "uri",
+  "storageKey": null
+};
+return {
+  "fragment": {
+    "argumentDefinitions": [],
+    "kind": "Fragment",
+    "metadata": null,
+    "name": "RelayReaderCatchFieldTest5Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          {
+            "kind": "CatchField",
+            "field": {
+              "alias": null,
+              "args": null,
+              "concreteType": "Image",
+              "kind": "LinkedField",
+              "name": "profilePicture",
+              "plural": false,
+              "selections": [
+                (v0/*: any*/),
+                {
+                  "kind": "RequiredField",
+                  "field": (v1/*: any*/),
+                  "action": "LOG",
+                  "path": "me.profilePicture.uri"
+                }
+              ],
+              "storageKey": null
+            },
+            "to": "RESULT",
+            "path": "me.profilePicture"
+          }
+        ],
+        "storageKey": null
+      }
+    ],
+    "type": "Query",
+    "abstractKey": null
+  },
+  "kind": "Request",
+  "operation": {
+    "argumentDefinitions": [],
+    "kind": "Operation",
+    "name": "RelayReaderCatchFieldTest5Query",
+    "selections": [
+      {
+        "alias": null,
+        "args": null,
+        "concreteType": "User",
+        "kind": "LinkedField",
+        "name": "me",
+        "plural": false,
+        "selections": [
+          {
+            "alias": null,
+            "args": null,
+            "concreteType": "Image",
+            "kind": "LinkedField",
+            "name": "profilePicture",
+            "plural": false,
+            "selections": [
+              (v0/*: any*/),
+              (v1/*: any*/)
+            ],
+            "storageKey": null
+          },
+          {
+            "alias": null,
+            "args": null,
+            "kind": "ScalarField",
+            "name": "id",
+            "storageKey": null
+          }
+        ],
+        "storageKey": null
+      }
+    ]
+  },
+  "params": {
+    "cacheID": "1c04c23e8c30ed442298bdb249f2e04b",
+    "id": null,
+    "metadata": {},
+    "name": "RelayReaderCatchFieldTest5Query",
+    "operationKind": "query",
+    "text": "query RelayReaderCatchFieldTest5Query {\n  me {\n    profilePicture {\n      width\n      uri\n    }\n    id\n  }\n}\n"
+  }
+};
+})();
+
+if (__DEV__) {
+  (node/*: any*/).hash = "efa04f69b197d639a70d45595e89072f";
+}
+
+module.exports = ((node/*: any*/)/*: Query<
+  RelayReaderCatchFieldTest5Query$variables,
+  RelayReaderCatchFieldTest5Query$data,
+>*/);
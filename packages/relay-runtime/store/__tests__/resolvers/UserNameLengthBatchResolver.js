@@ -0,0 +1,52 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @flow strict-local
+ * @format
+ * @oncall relay
+ */
+
+'use strict';
+
+import type {UserNameLengthBatchResolver$key} from './__generated__/UserNameLengthBatchResolver.graphql';
+
+const {graphql} = require('relay-runtime');
+const {readFragment} = require('relay-runtime/store/ResolverFragments');
+
+/**
+ * @RelayResolver
+ * @fieldName name_length_batched
+ * @rootFragment UserNameLengthBatchResolver
+ * @onType User
+ * @batch
+ *
+ * The length of the name of the user, resolved once for all the users of a
+ * list.
+ */
+function name_length_batched(
+  rootKeys: $ReadOnlyArray<UserNameLengthBatchResolver$key>,
+): $ReadOnlyArray<?number> {
+  const names = rootKeys.map(
+    rootKey =>
+      readFragment(
+        graphql`
+          fragment UserNameLengthBatchResolver on User {
+            name
+          }
+        `,
+        rootKey,
+      ).name,
+  );
+  name_length_batched._relayResolverTestCalls.push(names);
+  return names.map(name => name?.length);
+}
+name_length_batched._relayResolverTestCalls = ([]: Array<
+  $ReadOnlyArray<?string>,
+>);
+
+module.exports = {
+  name_length_batched,
+};
@@ -0,0 +1,59 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @oncall relay
+ *
+ * @generated SignedSource<<204afba9bdf6bf59fd14fcbbdd4e4395>>
+ * @flow
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+/*::
+import type { Fragment, ReaderFragment } from 'relay-runtime';
+import type { FragmentType } from "relay-runtime";
+declare export opaque type UserNameLengthBatchResolver$fragmentType: FragmentType;
+export type UserNameLengthBatchResolver$data = {|
+  +name: ?string,
+  +$fragmentType: UserNameLengthBatchResolver$fragmentType,
+|};
+export type UserNameLengthBatchResolver$key = {
+  +$data?: UserNameLengthBatchResolver$data,
+  +$fragmentSpreads: UserNameLengthBatchResolver$fragmentType,
+  ...
+};
+*/
+
+var node/*: ReaderFragment*/ = {
+  "argumentDefinitions": [],
+  "kind": "Fragment",
+  "metadata": null,
+  "name": "UserNameLengthBatchResolver",
+  "selections": [
+    {
+      "alias": null,
+      "args": null,
+      "kind": "ScalarField",
+      "name": "name",
+      "storageKey": null
+    }
+  ],
+  "type": "User",
+  "abstractKey": null
+};
+
+if (__DEV__) {
+  (node/*: any*/).hash = "dce153a1af74766eb5294f7b85d6e925";
+}
+
+module.exports = ((node/*: any*/)/*: Fragment<
+  UserNameLengthBatchResolver$fragmentType,
+  UserNameLengthBatchResolver$data,
+>*/);
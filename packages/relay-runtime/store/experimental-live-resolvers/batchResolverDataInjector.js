@@ -0,0 +1,50 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @flow strict-local
+ * @format
+ * @oncall relay
+ */
+
+'use strict';
+
+import type {Fragment} from '../../util/RelayRuntimeTypes';
+import type {FragmentType} from '../RelayStoreTypes';
+
+const resolverDataInjector = require('./resolverDataInjector');
+
+type BatchResolverFn = ($ReadOnlyArray<$FlowFixMe>, ?$FlowFixMe) => mixed;
+
+/**
+ * The counterpart of `resolverDataInjector` for @batch resolvers: returns a
+ * relay resolver which reads the data of the fragment for each of the
+ * fragment keys it is called with, and calls `resolverFn` once with all of it.
+ *
+ * The compiler generates calls to this function, ensuring the correct set of arguments.
+ */
+function batchResolverDataInjector<TFragmentType: FragmentType, TData: ?{...}>(
+  fragment: Fragment<TFragmentType, TData>,
+  // Resolvers have their own type assertions, we don't want to confuse users
+  // with a type error in their generated code at this point.
+  _resolverFn: $FlowFixMe,
+  fieldName?: string,
+  isRequiredField?: boolean,
+): (fragmentKeys: $ReadOnlyArray<TFragmentType>, args: mixed) => mixed {
+  const resolverFn: BatchResolverFn = _resolverFn;
+  const readData = resolverDataInjector(
+    fragment,
+    (data: mixed) => data,
+    fieldName,
+    isRequiredField,
+  );
+  return (fragmentKeys: $ReadOnlyArray<TFragmentType>, args: mixed): mixed =>
+    resolverFn(
+      fragmentKeys.map(fragmentKey => readData(fragmentKey, args)),
+      args,
+    );
+}
+
+module.exports = batchResolverDataInjector;
@@ -83,6 +83,7 @@ export type RefetchableIdentifierInfo = {
 
 export type ReaderRefetchMetadata = {
   +connection?: ?ReaderPaginationMetadata,
+  +pagination?: ?ReaderPaginatedMetadata,
   +operation: string | ConcreteRequest,
   +fragmentPathInResult: Array<string>,
   +identifierInfo?: ?RefetchableIdentifierInfo,
@@ -101,6 +102,17 @@ export type ReaderPaginationMetadata = {
   +path: $ReadOnlyArray<string>,
 };
 
+export type PaginatedKind = 'OFFSET' | 'PAGE';
+
+// Metadata of a @paginated field, paged through by offset or by page number
+export type ReaderPaginatedMetadata = {
+  +count: ?string,
+  +cursor: ?string,
+  +items: string,
+  +kind: PaginatedKind,
+  +path: $ReadOnlyArray<string>,
+};
+
 export type ReaderInlineDataFragment = {
   +kind: 'InlineDataFragment',
   +name: string,
@@ -234,11 +246,25 @@ export type ReaderRequiredField = {
   +field:
     | ReaderField
     | ReaderClientEdgeToClientObject
-    | ReaderClientEdgeToServerObject,
+    | ReaderClientEdgeToServerObject
+    | ReaderAliasedFragmentSpread
+    | ReaderAliasedInlineFragmentSpread,
   +action: RequiredFieldAction,
   +path: string,
 };
 
+export type CatchFieldTo = 'RESULT' | 'NULL';
+
+export type ReaderCatchField = {
+  +kind: 'CatchField',
+  +field:
+    | ReaderField
+    | ReaderAliasedFragmentSpread
+    | ReaderAliasedInlineFragmentSpread,
+  +to: CatchFieldTo,
+  +path: string,
+};
+
 export type ResolverFunction = (...args: Array<any>) => mixed; // flowlint-line unclear-type:off
 // With ES6 imports, a resolver function might be exported under the `default` key.
 export type ResolverModule = ResolverFunction | {default: ResolverFunction};
@@ -258,6 +284,7 @@ export type ReaderRelayResolver = {
   +path: string,
   +resolverModule: ResolverModule,
   +normalizationInfo?: ResolverNormalizationInfo,
+  +batch?: boolean,
 };
 
 export type ReaderRelayLiveResolver = {
@@ -308,6 +335,7 @@ export type ReaderSelection =
   | ReaderModuleImport
   | ReaderStream
   | ReaderRequiredField
+  | ReaderCatchField
   | ReaderRelayResolver;
 
 export type ReaderVariableArgument = {
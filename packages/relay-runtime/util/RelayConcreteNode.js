@@ -118,6 +118,7 @@ const RelayConcreteNode = {
   RELAY_RESOLVER: 'RelayResolver',
   RELAY_LIVE_RESOLVER: 'RelayLiveResolver',
   REQUIRED_FIELD: 'RequiredField',
+  CATCH_FIELD: 'CatchField',
   OBJECT_VALUE: 'ObjectValue',
   OPERATION: 'Operation',
   REQUEST: 'Request',
@@ -11,7 +11,11 @@
 
 'use strict';
 
-import type {ReaderFragment, ReaderPaginationMetadata} from './ReaderNode';
+import type {
+  ReaderFragment,
+  ReaderPaginatedMetadata,
+  ReaderPaginationMetadata,
+} from './ReaderNode';
 import type {ConcreteRequest} from './RelayConcreteNode';
 
 const getRefetchMetadata = require('./getRefetchMetadata');
@@ -25,10 +29,40 @@ function getPaginationMetadata(
   identifierField: ?string,
   paginationRequest: ConcreteRequest,
   paginationMetadata: ReaderPaginationMetadata,
+  paginatedMetadata: ?ReaderPaginatedMetadata,
   stream: boolean,
 } {
   const {refetchableRequest: paginationRequest, refetchMetadata} =
     getRefetchMetadata(fragmentNode, componentDisplayName);
+  const identifierInfo = refetchMetadata.identifierInfo;
+  invariant(
+    identifierInfo?.identifierField == null ||
+      typeof identifierInfo.identifierField === 'string',
+    'Relay: getRefetchMetadata(): Expected `identifierField` to be a string.',
+  );
+
+  const paginatedMetadata = refetchMetadata.pagination;
+  if (refetchMetadata.connection == null && paginatedMetadata != null) {
+    // A @paginated field is only paginated forward, from the offset or page
+    // following the items that were fetched.
+    const {count, cursor, path} = paginatedMetadata;
+    invariant(
+      count != null && cursor != null,
+      'Relay: getPaginationMetadata(): Expected the @paginated field in ' +
+        'fragment `%s` to be passed variables as its offset or page and ' +
+        'count arguments when using `%s`.',
+      fragmentNode.name,
+      componentDisplayName,
+    );
+    return {
+      connectionPathInFragmentData: path,
+      identifierField: identifierInfo?.identifierField,
+      paginationRequest,
+      paginationMetadata: {backward: null, forward: {count, cursor}, path},
+      paginatedMetadata,
+      stream: false,
+    };
+  }
 
   const paginationMetadata = refetchMetadata.connection;
   invariant(
@@ -50,17 +84,12 @@ function getPaginationMetadata(
     componentDisplayName,
     fragmentNode.name,
   );
-  const identifierInfo = refetchMetadata.identifierInfo;
-  invariant(
-    identifierInfo?.identifierField == null ||
-      typeof identifierInfo.identifierField === 'string',
-    'Relay: getRefetchMetadata(): Expected `identifierField` to be a string.',
-  );
   return {
     connectionPathInFragmentData,
     identifierField: identifierInfo?.identifierField,
     paginationRequest,
     paginationMetadata,
+    paginatedMetadata: null,
     stream: connectionMetadata.stream === true,
   };
 }
@@ -22,7 +22,7 @@ export type Direction = 'forward' | 'backward';
 function getPaginationVariables(
   direction: Direction,
   count: number,
-  cursor: ?string,
+  cursor: ?string | number,
   baseVariables: Variables,
   extraVariables: Variables,
   paginationMetadata: ReaderPaginationMetadata,
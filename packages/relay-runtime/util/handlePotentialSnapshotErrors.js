@@ -35,14 +35,15 @@ function handlePotentialSnapshotErrors(
       error: resolverError.error,
     });
   }
-  if (
-    RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING &&
-    errorResponseFields != null
-  ) {
-    if (errorResponseFields != null) {
-      for (const fieldError of errorResponseFields) {
-        const {path, owner, error} = fieldError;
-
+  if (errorResponseFields != null) {
+    // The errors of fields within a `@catch(to: NULL)` field are logged
+    // whether or not field error handling is enabled, and never thrown.
+    const unhandledErrorResponseFields = errorResponseFields.filter(
+      fieldError => fieldError.handled !== true,
+    );
+    for (const fieldError of errorResponseFields) {
+      const {path, owner, error, handled} = fieldError;
+      if (handled === true || RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING) {
         environment.relayFieldLogger({
           kind: 'relay_field_payload.error',
           owner: owner,
@@ -52,10 +53,14 @@ function handlePotentialSnapshotErrors(
       }
     }
 
-    if (RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING_THROW_BY_DEFAULT) {
+    if (
+      RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING &&
+      RelayFeatureFlags.ENABLE_FIELD_ERROR_HANDLING_THROW_BY_DEFAULT &&
+      unhandledErrorResponseFields.length > 0
+    ) {
       throw new RelayFieldError(
         `Relay: Unexpected response payload - this object includes an errors property in which you can access the underlying errors`,
-        errorResponseFields.map(({path, owner, error}) => error),
+        unhandledErrorResponseFields.map(({path, owner, error}) => error),
       );
     }
   }